@@ -0,0 +1,94 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Throughput of every algorithm across buffer sizes from 16 bytes to 1 GiB, on the selected tier.
+//!
+//! Only one tier is selected per process, so run this once per tier with `CRC_FAST_FORCE_TIER`
+//! (see `crc_fast::available_tiers()`). Each tier's results are grouped under its target name, so
+//! Criterion's baselines catch regressions in each tier's folding kernels separately:
+//!
+//! ```text
+//! CRC_FAST_FORCE_TIER=x86_64-sse-pclmulqdq cargo bench --bench throughput
+//! ```
+//!
+//! Use a Criterion filter, such as `-- "CRC-64/NVME"` or `-- "16 bytes"`, to limit the run.
+
+use crc_fast::test_vectors::CHECK_VALUES;
+use crc_fast::{checksum, get_calculator_target, CrcAlgorithm, Digest};
+use criterion::*;
+use rand::{rng, RngCore};
+use std::hint::black_box;
+use std::time::Duration;
+
+pub const SIZES: &[(&str, usize); 12] = &[
+    ("16 bytes", 16),
+    ("64 bytes", 64),
+    ("255 bytes", 255),
+    ("256 bytes", 256),
+    ("1 KiB", 1024),
+    ("4 KiB", 4 * 1024),
+    ("64 KiB", 64 * 1024),
+    ("1 MiB", 1024 * 1024),
+    ("16 MiB", 16 * 1024 * 1024),
+    ("128 MiB", 128 * 1024 * 1024),
+    ("512 MiB", 512 * 1024 * 1024),
+    ("1 GiB", 1024 * 1024 * 1024),
+];
+
+/// Sizes at or above this take long enough per iteration to need fewer, flat samples
+const LARGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// Copies random data to a 64-byte aligned buffer, so results don't vary with the allocator
+fn aligned_random_data(size: usize) -> (Vec<u8>, usize) {
+    let mut buf = vec![0u8; size + 64];
+    let start = buf.as_ptr().align_offset(64);
+    rng().fill_bytes(&mut buf[start..start + size]);
+
+    (buf, start)
+}
+
+fn bench_throughput(c: &mut Criterion) {
+    let target = get_calculator_target(CrcAlgorithm::Crc64Nvme);
+    let algorithms = CHECK_VALUES.map(|(algorithm, _)| algorithm);
+
+    // every size is a prefix of the largest
+    let (buf, start) = aligned_random_data(SIZES[SIZES.len() - 1].1);
+
+    for (size_name, size) in SIZES {
+        let data = &buf[start..start + size];
+
+        let mut group = c.benchmark_group(format!("{target}/{size_name}"));
+        group.throughput(Throughput::Bytes(*size as u64));
+
+        if *size >= LARGE_SIZE {
+            group.sample_size(10);
+            group.sampling_mode(SamplingMode::Flat);
+            group.measurement_time(Duration::from_secs(20));
+        } else {
+            group.sample_size(200);
+            group.measurement_time(Duration::from_secs(5));
+        }
+
+        for algorithm in algorithms {
+            group.bench_function(BenchmarkId::new(algorithm.to_string(), "checksum"), |b| {
+                b.iter(|| black_box(checksum(algorithm, black_box(data))))
+            });
+
+            // the streaming path, with updates which don't line up with the folding widths
+            group.bench_function(BenchmarkId::new(algorithm.to_string(), "digest"), |b| {
+                b.iter(|| {
+                    let mut digest = Digest::new(algorithm);
+                    data.chunks((size / 3).max(1))
+                        .for_each(|chunk| digest.update(black_box(chunk)));
+
+                    black_box(digest.finalize())
+                })
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_throughput);
+
+criterion_main!(benches);
@@ -0,0 +1,301 @@
+/* crc_fast library C++ API - Copyright 2025 Don MacAskill */
+/* RAII wrappers around libcrc_fast.h, which is auto-generated. Requires C++17. */
+
+#ifndef CRC_FAST_HPP
+#define CRC_FAST_HPP
+
+#include "libcrc_fast.h"
+
+#include <cstddef>
+#include <cstdint>
+#include <stdexcept>
+#include <string>
+#include <string_view>
+#include <utility>
+#include <vector>
+
+namespace crc_fast {
+
+using Algorithm = CrcFastAlgorithm;
+
+/**
+ * Thrown when a crc_fast function fails, with the CrcFastError it returned
+ */
+class Error : public std::runtime_error {
+ public:
+  explicit Error(CrcFastError code) : std::runtime_error(describe(code)), code_(code) {}
+
+  CrcFastError code() const noexcept { return code_; }
+
+  static const char *describe(CrcFastError code) noexcept {
+    switch (code) {
+      case Success: return "success";
+      case NullPointer: return "a required pointer argument was null";
+      case InvalidKeyCount: return "invalid key count in the custom parameters";
+      case UnsupportedWidth: return "unsupported CRC width";
+      case InvalidUtf8: return "a string argument wasn't valid UTF-8";
+      case IoError: return "the file couldn't be opened or read";
+      case UnknownAlgorithm: return "unknown algorithm name";
+      case Cancelled: return "the operation was cancelled";
+      case KeyMismatch: return "the custom parameters' keys don't match their polynomial";
+      case CheckMismatch: return "the custom parameters don't produce their check value";
+      case Panic: return "an unexpected internal error occurred";
+      case SelfTestFailed: return "the self-test produced an incorrect checksum on this CPU";
+      case UnknownTier: return "unknown performance tier name";
+      case UnsupportedTier: return "the performance tier isn't supported on this system";
+      case TierAlreadySelected: return "the performance tier was already selected";
+      case IncompatibleChecksums: return "the checksums being combined have different widths or parameters";
+      case InvalidCount: return "a count argument was negative";
+    }
+
+    return "unknown error";
+  }
+
+ private:
+  CrcFastError code_;
+};
+
+namespace detail {
+
+inline void check(CrcFastError result) {
+  if (result != Success) {
+    throw Error(result);
+  }
+}
+
+}  // namespace detail
+
+/**
+ * Custom CRC parameters, whose keys are released when they're destroyed
+ */
+class Params {
+ public:
+  Params(std::uint8_t width, std::uint64_t poly, std::uint64_t init, bool reflected, std::uint64_t xorout,
+         std::uint64_t check) {
+    detail::check(
+        crc_fast_get_custom_params_ex(nullptr, width, poly, init, reflected, xorout, check, &params_));
+  }
+
+  Params(const Params &) = delete;
+  Params &operator=(const Params &) = delete;
+
+  Params(Params &&other) noexcept : params_(other.params_) {
+    other.params_.keys = nullptr;
+    other.params_.key_count = 0;
+  }
+
+  Params &operator=(Params &&other) noexcept {
+    if (this != &other) {
+      crc_fast_free_params(&params_);
+      params_ = other.params_;
+      other.params_.keys = nullptr;
+      other.params_.key_count = 0;
+    }
+
+    return *this;
+  }
+
+  ~Params() { crc_fast_free_params(&params_); }
+
+  /**
+   * Throws if the parameters don't produce their own check value
+   */
+  void validate() const { detail::check(crc_fast_validate_params(&params_)); }
+
+  const CrcFastParams &get() const noexcept { return params_; }
+
+ private:
+  CrcFastParams params_{};
+};
+
+/**
+ * A Digest to compute CRC checksums over time, which is freed when it's destroyed
+ */
+class Digest {
+ public:
+  explicit Digest(Algorithm algorithm) { detail::check(crc_fast_digest_new_ex(algorithm, &handle_)); }
+
+  Digest(Algorithm algorithm, std::uint64_t init_state) {
+    detail::check(crc_fast_digest_new_with_init_state_ex(algorithm, init_state, &handle_));
+  }
+
+  explicit Digest(const Params &params) {
+    detail::check(crc_fast_digest_new_with_params_ex(params.get(), &handle_));
+  }
+
+  Digest(const Digest &other) { detail::check(crc_fast_digest_clone_ex(other.handle_, &handle_)); }
+
+  Digest &operator=(const Digest &other) {
+    if (this != &other) {
+      Digest copy(other);
+      std::swap(handle_, copy.handle_);
+    }
+
+    return *this;
+  }
+
+  Digest(Digest &&other) noexcept : handle_(std::exchange(other.handle_, nullptr)) {}
+
+  Digest &operator=(Digest &&other) noexcept {
+    std::swap(handle_, other.handle_);
+
+    return *this;
+  }
+
+  ~Digest() { crc_fast_digest_free(handle_); }
+
+  void update(const void *data, std::size_t len) {
+    detail::check(crc_fast_digest_update_ex(handle_, static_cast<const char *>(data), len));
+  }
+
+  void update(std::string_view data) { update(data.data(), data.size()); }
+
+  std::uint64_t finalize() const {
+    std::uint64_t checksum = 0;
+    detail::check(crc_fast_digest_finalize_ex(handle_, &checksum));
+
+    return checksum;
+  }
+
+  std::uint64_t finalize_reset() {
+    std::uint64_t checksum = 0;
+    detail::check(crc_fast_digest_finalize_reset_ex(handle_, &checksum));
+
+    return checksum;
+  }
+
+  void reset() { detail::check(crc_fast_digest_reset_ex(handle_)); }
+
+  /**
+   * Combines the checksum of other, which covers data following this Digest's data
+   */
+  void combine(const Digest &other) { detail::check(crc_fast_digest_combine_ex(handle_, other.handle_)); }
+
+  std::uint64_t amount() const {
+    std::uint64_t amount = 0;
+    detail::check(crc_fast_digest_get_amount_ex(handle_, &amount));
+
+    return amount;
+  }
+
+  std::uint64_t state() const {
+    std::uint64_t state = 0;
+    detail::check(crc_fast_digest_get_state_ex(handle_, &state));
+
+    return state;
+  }
+
+ private:
+  CrcFastDigestHandle *handle_ = nullptr;
+};
+
+inline std::uint64_t checksum(Algorithm algorithm, std::string_view data) {
+  std::uint64_t checksum = 0;
+  detail::check(crc_fast_checksum_ex(algorithm, data.data(), data.size(), &checksum));
+
+  return checksum;
+}
+
+inline std::uint64_t checksum(const Params &params, std::string_view data) {
+  std::uint64_t checksum = 0;
+  detail::check(crc_fast_checksum_with_params_ex(params.get(), data.data(), data.size(), &checksum));
+
+  return checksum;
+}
+
+inline std::uint64_t checksum_file(Algorithm algorithm, std::string_view path) {
+  std::uint64_t checksum = 0;
+  detail::check(crc_fast_checksum_file_ex(algorithm, reinterpret_cast<const std::uint8_t *>(path.data()),
+                                          path.size(), &checksum));
+
+  return checksum;
+}
+
+inline std::uint64_t checksum_file(const Params &params, std::string_view path) {
+  std::uint64_t checksum = 0;
+  detail::check(crc_fast_checksum_file_with_params_ex(
+      params.get(), reinterpret_cast<const std::uint8_t *>(path.data()), path.size(), &checksum));
+
+  return checksum;
+}
+
+inline std::uint64_t checksum_combine(Algorithm algorithm, std::uint64_t checksum1, std::uint64_t checksum2,
+                                      std::uint64_t checksum2_len) {
+  std::uint64_t checksum = 0;
+  detail::check(crc_fast_checksum_combine_ex(algorithm, checksum1, checksum2, checksum2_len, &checksum));
+
+  return checksum;
+}
+
+inline std::uint64_t checksum_combine(const Params &params, std::uint64_t checksum1, std::uint64_t checksum2,
+                                      std::uint64_t checksum2_len) {
+  std::uint64_t checksum = 0;
+  detail::check(
+      crc_fast_checksum_combine_with_params_ex(params.get(), checksum1, checksum2, checksum2_len, &checksum));
+
+  return checksum;
+}
+
+/**
+ * Looks up a predefined algorithm by name, such as "CRC-32/ISCSI"
+ */
+inline Algorithm algorithm_from_name(std::string_view name) {
+  const std::string terminated(name);
+
+  Algorithm algorithm{};
+  detail::check(crc_fast_algorithm_from_name(terminated.c_str(), &algorithm));
+
+  return algorithm;
+}
+
+/**
+ * Looks up a predefined algorithm by a common alias, such as "crc32c", "cksum", or "xz64", or by name
+ */
+inline Algorithm algorithm_from_alias(std::string_view alias) {
+  const std::string terminated(alias);
+
+  Algorithm algorithm{};
+  detail::check(crc_fast_algorithm_from_alias(terminated.c_str(), &algorithm));
+
+  return algorithm;
+}
+
+/**
+ * Whether value is the algorithm's checksum of empty input, which isn't always 0
+ */
+inline bool is_empty_crc(Algorithm algorithm, std::uint64_t value) { return crc_fast_is_empty_crc(algorithm, value); }
+
+inline std::string_view version() { return crc_fast_get_version(); }
+
+/**
+ * The target string of the active performance tier, such as "x86_64-avx512-vpclmulqdq"
+ */
+inline std::string_view tier() { return crc_fast_get_tier(); }
+
+/**
+ * The target strings of the performance tiers available on this system, from highest to lowest
+ */
+inline std::vector<std::string_view> tiers() {
+  std::vector<const char *> names(crc_fast_list_tiers(nullptr, 0));
+  names.resize(crc_fast_list_tiers(names.data(), names.size()));
+
+  return {names.begin(), names.end()};
+}
+
+/**
+ * Forces the performance tier by its target string, or "auto" for the fastest, before the first calculation
+ */
+inline void force_tier(std::string_view tier) {
+  const std::string terminated(tier);
+
+  detail::check(crc_fast_force_tier(terminated.c_str()));
+}
+
+/**
+ * Throws if the self-test produces an incorrect checksum on this CPU
+ */
+inline void self_test() { detail::check(crc_fast_self_test(nullptr)); }
+
+}  // namespace crc_fast
+
+#endif /* CRC_FAST_HPP */
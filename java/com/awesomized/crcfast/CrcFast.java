@@ -0,0 +1,114 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+package com.awesomized.crcfast;
+
+import java.nio.ByteBuffer;
+
+/**
+ * JVM bindings for the crc_fast library, which must be built with the {@code jni} feature and be
+ * on {@code java.library.path}.
+ */
+public final class CrcFast {
+    static {
+        System.loadLibrary("crc_fast");
+    }
+
+    /**
+     * The predefined CRC algorithms, whose ordinals must match the Rust library's.
+     */
+    public enum Algorithm {
+        CRC_32_AIXM("CRC-32/AIXM"),
+        CRC_32_AUTOSAR("CRC-32/AUTOSAR"),
+        CRC_32_BASE91_D("CRC-32/BASE91-D"),
+        CRC_32_BZIP2("CRC-32/BZIP2"),
+        CRC_32_CD_ROM_EDC("CRC-32/CD-ROM-EDC"),
+        CRC_32_CKSUM("CRC-32/CKSUM"),
+        CRC_32_ISCSI("CRC-32/ISCSI"),
+        CRC_32_ISO_HDLC("CRC-32/ISO-HDLC"),
+        CRC_32_JAMCRC("CRC-32/JAMCRC"),
+        CRC_32_MEF("CRC-32/MEF"),
+        CRC_32_MPEG_2("CRC-32/MPEG-2"),
+        CRC_32_XFER("CRC-32/XFER"),
+        CRC_64_ECMA_182("CRC-64/ECMA-182"),
+        CRC_64_GO_ISO("CRC-64/GO-ISO"),
+        CRC_64_MS("CRC-64/MS"),
+        CRC_64_NVME("CRC-64/NVME"),
+        CRC_64_REDIS("CRC-64/REDIS"),
+        CRC_64_WE("CRC-64/WE"),
+        CRC_64_XZ("CRC-64/XZ");
+
+        private final String name;
+
+        Algorithm(String name) {
+            this.name = name;
+        }
+
+        @Override
+        public String toString() {
+            return name;
+        }
+    }
+
+    private CrcFast() {
+    }
+
+    /**
+     * Calculates the CRC checksum of the data.
+     */
+    public static long checksum(Algorithm algorithm, byte[] data) {
+        return checksum(algorithm.ordinal(), data, 0, data.length);
+    }
+
+    /**
+     * Calculates the CRC checksum of a range of the data.
+     */
+    public static long checksum(Algorithm algorithm, byte[] data, int offset, int length) {
+        return checksum(algorithm.ordinal(), data, offset, length);
+    }
+
+    /**
+     * Calculates the CRC checksum of the remaining bytes of the buffer, without changing its
+     * position. Direct buffers are read in place, others are copied.
+     */
+    public static long checksum(Algorithm algorithm, ByteBuffer buffer) {
+        if (buffer.isDirect()) {
+            return checksumDirect(algorithm.ordinal(), buffer, buffer.position(), buffer.remaining());
+        }
+
+        if (buffer.hasArray()) {
+            return checksum(algorithm.ordinal(), buffer.array(), buffer.arrayOffset() + buffer.position(),
+                    buffer.remaining());
+        }
+
+        byte[] data = new byte[buffer.remaining()];
+        buffer.duplicate().get(data);
+
+        return checksum(algorithm, data);
+    }
+
+    /**
+     * Combines two CRC checksums, where checksum2 covers checksum2Length bytes following the data
+     * of checksum1.
+     */
+    public static long checksumCombine(Algorithm algorithm, long checksum1, long checksum2, long checksum2Length) {
+        return checksumCombine(algorithm.ordinal(), checksum1, checksum2, checksum2Length);
+    }
+
+    static native long checksum(int algorithm, byte[] data, int offset, int length);
+
+    static native long checksumDirect(int algorithm, ByteBuffer buffer, int offset, int length);
+
+    static native long checksumCombine(int algorithm, long checksum1, long checksum2, long checksum2Length);
+
+    static native long digestNew(int algorithm);
+
+    static native void digestUpdate(long handle, byte[] data, int offset, int length);
+
+    static native void digestUpdateDirect(long handle, ByteBuffer buffer, int offset, int length);
+
+    static native long digestFinalize(long handle);
+
+    static native void digestReset(long handle);
+
+    static native void digestFree(long handle);
+}
@@ -0,0 +1,59 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+package com.awesomized.crcfast;
+
+import java.nio.ByteBuffer;
+import java.util.zip.Checksum;
+
+/**
+ * A {@link Checksum} backed by a native Digest, which must be closed to free it.
+ *
+ * <p>Not thread-safe, like the JDK's own {@code CRC32} and {@code CRC32C}.
+ */
+public final class CrcFastChecksum implements Checksum, AutoCloseable {
+    private long handle;
+
+    public CrcFastChecksum(CrcFast.Algorithm algorithm) {
+        handle = CrcFast.digestNew(algorithm.ordinal());
+    }
+
+    @Override
+    public void update(int b) {
+        update(new byte[] {(byte) b}, 0, 1);
+    }
+
+    @Override
+    public void update(byte[] b, int off, int len) {
+        CrcFast.digestUpdate(handle, b, off, len);
+    }
+
+    /**
+     * Updates the checksum with the remaining bytes of the buffer, and advances its position to its
+     * limit. Direct buffers are read in place.
+     */
+    @Override
+    public void update(ByteBuffer buffer) {
+        if (buffer.isDirect()) {
+            CrcFast.digestUpdateDirect(handle, buffer, buffer.position(), buffer.remaining());
+            buffer.position(buffer.limit());
+        } else {
+            Checksum.super.update(buffer);
+        }
+    }
+
+    @Override
+    public long getValue() {
+        return CrcFast.digestFinalize(handle);
+    }
+
+    @Override
+    public void reset() {
+        CrcFast.digestReset(handle);
+    }
+
+    @Override
+    public void close() {
+        CrcFast.digestFree(handle);
+        handle = 0;
+    }
+}
@@ -1,5 +1,7 @@
 /* crc_fast library C/C++ API - Copyright 2025 Don MacAskill */
 /* This header is auto-generated. Do not edit directly. */
+/* Strings returned by this library are owned by it, remain valid for the life of the process, and must not be freed. */
+/* Checksums are uint64_t for every CRC width, with the bits above the width always zero. */
 
 #ifndef CRC_FAST_H
 #define CRC_FAST_H
@@ -9,6 +11,11 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+#if !defined(_WIN32)
+#include <sys/uio.h>
+typedef struct iovec iovec;
+#endif
+
 /**
  * The supported CRC algorithms
  */
@@ -36,6 +43,85 @@ typedef enum CrcFastAlgorithm {
   Crc64Xz,
 } CrcFastAlgorithm;
 
+/**
+ * The result of the `crc_fast_*_ex` functions
+ */
+typedef enum CrcFastError {
+  /**
+   * The operation succeeded, and any out-pointers were written
+   */
+  Success = 0,
+  /**
+   * A required pointer argument was null
+   */
+  NullPointer,
+  /**
+   * The custom parameters have a key count other than 23 or 25, or null keys
+   */
+  InvalidKeyCount,
+  /**
+   * The CRC width isn't 32 or 64
+   */
+  UnsupportedWidth,
+  /**
+   * A string argument wasn't valid UTF-8
+   */
+  InvalidUtf8,
+  /**
+   * The file couldn't be opened or read
+   */
+  IoError,
+  /**
+   * The algorithm name isn't one of the predefined algorithms
+   */
+  UnknownAlgorithm,
+  /**
+   * The operation was cancelled by the caller
+   */
+  Cancelled,
+  /**
+   * The custom parameters' keys weren't generated from their polynomial
+   */
+  KeyMismatch,
+  /**
+   * The custom parameters don't produce their own check value
+   */
+  CheckMismatch,
+  /**
+   * An unexpected internal error occurred
+   */
+  Panic,
+  /**
+   * The self-test produced an incorrect checksum on this CPU
+   */
+  SelfTestFailed,
+  /**
+   * The performance tier name isn't one of the tier target strings
+   */
+  UnknownTier,
+  /**
+   * The performance tier isn't supported on this system
+   */
+  UnsupportedTier,
+  /**
+   * The performance tier was already selected, by a previous calculation or call
+   */
+  TierAlreadySelected,
+  /**
+   * The checksums (or Digests) being combined don't have the same width (or parameters)
+   */
+  IncompatibleChecksums,
+  /**
+   * A count argument was negative
+   */
+  InvalidCount,
+} CrcFastError;
+
+/**
+ * The lock which serializes calls on a thread-safe Digest handle
+ */
+typedef struct CrcFastDigestLock CrcFastDigestLock;
+
 /**
  * Represents a CRC Digest, which is used to compute CRC checksums.
  *
@@ -46,10 +132,12 @@ typedef enum CrcFastAlgorithm {
 typedef struct CrcFastDigest CrcFastDigest;
 
 /**
- * A handle to the Digest object
+ * A handle to the Digest object, with a lock if it was created by one of the
+ * `crc_fast_digest_new_threadsafe*` functions
  */
 typedef struct CrcFastDigestHandle {
   struct CrcFastDigest *_0;
+  struct CrcFastDigestLock *_1;
 } CrcFastDigestHandle;
 
 /**
@@ -68,6 +156,62 @@ typedef struct CrcFastParams {
   const uint64_t *keys;
 } CrcFastParams;
 
+/**
+ * The CRC parameters which determine the folding keys, for pre-warming the key cache
+ */
+typedef struct CrcFastKeyCacheEntry {
+  uint8_t width;
+  uint64_t poly;
+  bool reflected;
+} CrcFastKeyCacheEntry;
+
+/**
+ * The first vector which failed a self-test
+ */
+typedef struct CrcFastSelfTestFailure {
+  enum CrcFastAlgorithm algorithm;
+  uintptr_t len;
+  uint64_t expected;
+  uint64_t actual;
+} CrcFastSelfTestFailure;
+
+/**
+ * Progress callback for file checksums, called after each chunk with the number of bytes
+ * processed so far, the total file size (or 0 if it's unknown), and the caller's user data
+ */
+typedef void (*CrcFastProgressCallback)(uint64_t bytes_processed,
+                                        uint64_t total_bytes,
+                                        void *user_data);
+
+/**
+ * Read callback for stream checksums, which reads up to `len` bytes into `buf` and returns the
+ * number of bytes read, 0 at the end of the stream, or a negative value on error
+ */
+typedef intptr_t (*CrcFastReadCallback)(uint8_t *buf, uintptr_t len, void *user_data);
+
+/**
+ * A checksum, or the error which prevented it (with a checksum of 0), returned by value from the
+ * `crc_fast_simple_*` functions
+ */
+typedef struct CrcFastResult {
+  uint64_t checksum;
+  enum CrcFastError error;
+} CrcFastResult;
+
+/**
+ * Custom CRC parameters as plain Rocksoft values, with no keys to manage and only blittable
+ * fields (`reflected` is 0 or 1), so they can be passed from C# P/Invoke without custom
+ * marshaling. The keys are generated (and cached) by the library.
+ */
+typedef struct CrcFastRocksoftParams {
+  uint8_t width;
+  uint8_t reflected;
+  uint64_t poly;
+  uint64_t init;
+  uint64_t xorout;
+  uint64_t check;
+} CrcFastRocksoftParams;
+
 #ifdef __cplusplus
 extern "C" {
 #endif // __cplusplus
@@ -88,6 +232,26 @@ struct CrcFastDigestHandle *crc_fast_digest_new_with_init_state(enum CrcFastAlgo
  */
 struct CrcFastDigestHandle *crc_fast_digest_new_with_params(struct CrcFastParams params);
 
+/**
+ * Creates a new Digest to compute CRC checksums using custom parameters and a custom initial
+ * state
+ */
+struct CrcFastDigestHandle *crc_fast_digest_new_with_params_and_state(struct CrcFastParams params,
+                                                                      uint64_t state);
+
+/**
+ * Creates a new Digest to compute CRC checksums using algorithm, whose handle can be used from
+ * multiple threads at once. Calls on it are serialized by an internal lock, except
+ * `crc_fast_digest_free()`, which must not race with any other call.
+ */
+struct CrcFastDigestHandle *crc_fast_digest_new_threadsafe(enum CrcFastAlgorithm algorithm);
+
+/**
+ * Creates a new Digest to compute CRC checksums using custom parameters, whose handle can be used
+ * from multiple threads at once, see `crc_fast_digest_new_threadsafe()`
+ */
+struct CrcFastDigestHandle *crc_fast_digest_new_threadsafe_with_params(struct CrcFastParams params);
+
 /**
  * Updates the Digest with data
  */
@@ -114,11 +278,19 @@ void crc_fast_digest_reset(struct CrcFastDigestHandle *handle);
 uint64_t crc_fast_digest_finalize_reset(struct CrcFastDigestHandle *handle);
 
 /**
- * Combine two Digest checksums
+ * Combine two Digest checksums, leaving the first unchanged if they use different parameters
+ * (`crc_fast_digest_combine_ex` reports that as an error)
  */
 void crc_fast_digest_combine(struct CrcFastDigestHandle *handle1,
                              struct CrcFastDigestHandle *handle2);
 
+/**
+ * Creates a new Digest with a copy of the state of an existing one, which can be finalized (or
+ * updated) independently, and is thread-safe if the existing one is. Returns null if the handle
+ * is null.
+ */
+struct CrcFastDigestHandle *crc_fast_digest_clone(struct CrcFastDigestHandle *handle);
+
 /**
  * Gets the amount of data processed by the Digest so far
  */
@@ -130,12 +302,14 @@ uint64_t crc_fast_digest_get_amount(struct CrcFastDigestHandle *handle);
 uint64_t crc_fast_digest_get_state(struct CrcFastDigestHandle *handle);
 
 /**
- * Helper method to calculate a CRC checksum directly for a string using algorithm
+ * Helper method to calculate a CRC checksum directly for a string using algorithm. `data` may
+ * only be null if `len` is 0, which is the checksum of empty input.
  */
 uint64_t crc_fast_checksum(enum CrcFastAlgorithm algorithm, const char *data, uintptr_t len);
 
 /**
- * Helper method to calculate a CRC checksum directly for data using custom parameters
+ * Helper method to calculate a CRC checksum directly for data using custom parameters. `data`
+ * may only be null if `len` is 0, which is the checksum of empty input.
  */
 uint64_t crc_fast_checksum_with_params(struct CrcFastParams params,
                                        const char *data,
@@ -156,7 +330,7 @@ uint64_t crc_fast_checksum_file_with_params(struct CrcFastParams params,
                                             uintptr_t path_len);
 
 /**
- * Combine two CRC checksums using algorithm
+ * Combine two CRC checksums using algorithm, returning 0 if either checksum is too wide for it
  */
 uint64_t crc_fast_checksum_combine(enum CrcFastAlgorithm algorithm,
                                    uint64_t checksum1,
@@ -164,15 +338,26 @@ uint64_t crc_fast_checksum_combine(enum CrcFastAlgorithm algorithm,
                                    uint64_t checksum2_len);
 
 /**
- * Combine two CRC checksums using custom parameters
+ * Combine two CRC checksums using custom parameters, returning 0 if either checksum is too wide
+ * for them
  */
 uint64_t crc_fast_checksum_combine_with_params(struct CrcFastParams params,
                                                uint64_t checksum1,
                                                uint64_t checksum2,
                                                uint64_t checksum2_len);
 
+/**
+ * Returns whether value is the algorithm's checksum of empty (zero-length) input, which is
+ * `init ^ xorout` and isn't always 0
+ */
+bool crc_fast_is_empty_crc(enum CrcFastAlgorithm algorithm, uint64_t value);
+
 /**
  * Returns the custom CRC parameters for a given set of Rocksoft CRC parameters
+ *
+ * The keys are shared by every CrcFastParams with the same parameters, and are kept until each of
+ * them has been released with `crc_fast_free_params()`. The name is unused, since CrcFastParams
+ * has no name field.
  */
 struct CrcFastParams crc_fast_get_custom_params(const char *name_ptr,
                                                 uint8_t width,
@@ -183,15 +368,546 @@ struct CrcFastParams crc_fast_get_custom_params(const char *name_ptr,
                                                 uint64_t check);
 
 /**
- * Gets the target build properties (CPU architecture and fine-tuning parameters) for this algorithm
+ * Releases the keys of custom CRC parameters returned by this library, freeing them once no other
+ * CrcFastParams with the same parameters uses them. The struct's keys are cleared, so it can't be
+ * used afterwards, but Digests created from it are unaffected.
+ */
+void crc_fast_free_params(struct CrcFastParams *params);
+
+/**
+ * Generates and caches the keys for `count` parameter sets at startup, so the first custom
+ * parameters created with each of them don't pay the key generation cost. Nothing is cached if
+ * any width is unsupported.
+ */
+enum CrcFastError crc_fast_prewarm_key_cache(const struct CrcFastKeyCacheEntry *entries,
+                                             uintptr_t count);
+
+/**
+ * Clears all cached keys, such as after a configuration reload which removed custom algorithms.
+ * CrcFastParams which were already created are unaffected.
+ */
+void crc_fast_clear_key_cache(void);
+
+/**
+ * Removes the cached keys for a single parameter set, returning whether they were cached
+ */
+bool crc_fast_invalidate_key_cache(uint8_t width, uint64_t poly, bool reflected);
+
+/**
+ * Runs the check string and longer vectors for every algorithm through the currently selected
+ * target, returning `SelfTestFailed` and writing the first failing vector to `out_failure` (if
+ * it's not null) if any checksum is incorrect
+ */
+enum CrcFastError crc_fast_self_test(struct CrcFastSelfTestFailure *out_failure);
+
+/**
+ * Gets the target build properties (CPU architecture and fine-tuning parameters) for this
+ * algorithm. The string is owned by the library and must not be freed.
  */
 const char *crc_fast_get_calculator_target(enum CrcFastAlgorithm algorithm);
 
+/**
+ * Gets the target string of the active performance tier (such as "x86_64-avx512-vpclmulqdq"),
+ * selecting it if no calculation has happened yet. The string is owned by the library and must not
+ * be freed.
+ */
+const char *crc_fast_get_tier(void);
+
+/**
+ * Writes the target strings of the performance tiers available on this system, from highest to
+ * lowest, to `out_tiers` (up to `capacity` of them), and returns how many are available, so a
+ * null `out_tiers` with a `capacity` of 0 gets the count. The strings are owned by the library
+ * and must not be freed.
+ */
+uintptr_t crc_fast_list_tiers(const char **out_tiers, uintptr_t capacity);
+
+/**
+ * Forces the performance tier used for all subsequent calculations by its target string (such as
+ * "x86_64-sse-pclmulqdq"), or selects the fastest one with "auto". Must be called before the
+ * first calculation.
+ */
+enum CrcFastError crc_fast_force_tier(const char *tier_ptr);
+
 /**
  * Gets the version of this library
  */
 const char *crc_fast_get_version(void);
 
+/**
+ * Gets the number of predefined algorithms, which can be enumerated with
+ * `crc_fast_algorithm_name()`
+ */
+uintptr_t crc_fast_algorithm_count(void);
+
+/**
+ * Gets the name of the predefined algorithm at index (such as "CRC-32/ISCSI"), or null if the
+ * index is out of range. The string is owned by the library and must not be freed.
+ */
+const char *crc_fast_algorithm_name(uintptr_t index);
+
+/**
+ * Looks up a predefined algorithm by name (such as "CRC-32/ISCSI"), writing it to
+ * `out_algorithm`
+ */
+enum CrcFastError crc_fast_algorithm_from_name(const char *name_ptr,
+                                               enum CrcFastAlgorithm *out_algorithm);
+
+/**
+ * Looks up a predefined algorithm by a common alias (such as "crc32c", "cksum", or "xz64") or by
+ * name (such as "CRC-32/ISCSI"), ignoring ASCII case, writing it to `out_algorithm`
+ */
+enum CrcFastError crc_fast_algorithm_from_alias(const char *alias_ptr,
+                                                enum CrcFastAlgorithm *out_algorithm);
+
+/**
+ * Creates a new Digest to compute CRC checksums using algorithm, writing it to `out_handle`
+ */
+enum CrcFastError crc_fast_digest_new_ex(enum CrcFastAlgorithm algorithm,
+                                         struct CrcFastDigestHandle **out_handle);
+
+/**
+ * Creates a new Digest with a custom initial state, writing it to `out_handle`
+ */
+enum CrcFastError crc_fast_digest_new_with_init_state_ex(enum CrcFastAlgorithm algorithm,
+                                                         uint64_t init_state,
+                                                         struct CrcFastDigestHandle **out_handle);
+
+/**
+ * Creates a new Digest to compute CRC checksums using custom parameters, writing it to
+ * `out_handle`
+ */
+enum CrcFastError crc_fast_digest_new_with_params_ex(struct CrcFastParams params,
+                                                     struct CrcFastDigestHandle **out_handle);
+
+/**
+ * Creates a new Digest to compute CRC checksums using custom parameters and a custom initial
+ * state, writing it to `out_handle`
+ */
+enum CrcFastError crc_fast_digest_new_with_params_and_state_ex(struct CrcFastParams params,
+                                                               uint64_t state,
+                                                               struct CrcFastDigestHandle **out_handle);
+
+/**
+ * Creates a new Digest to compute CRC checksums using algorithm, whose handle can be used from
+ * multiple threads at once, writing it to `out_handle`
+ */
+enum CrcFastError crc_fast_digest_new_threadsafe_ex(enum CrcFastAlgorithm algorithm,
+                                                    struct CrcFastDigestHandle **out_handle);
+
+/**
+ * Creates a new Digest to compute CRC checksums using custom parameters, whose handle can be used
+ * from multiple threads at once, writing it to `out_handle`
+ */
+enum CrcFastError crc_fast_digest_new_threadsafe_with_params_ex(struct CrcFastParams params,
+                                                                struct CrcFastDigestHandle **out_handle);
+
+/**
+ * Updates the Digest with data, which may only be null if `len` is 0
+ */
+enum CrcFastError crc_fast_digest_update_ex(struct CrcFastDigestHandle *handle,
+                                            const char *data,
+                                            uintptr_t len);
+
+/**
+ * Calculates the CRC checksum for data that's been written to the Digest, writing it to
+ * `out_checksum`
+ */
+enum CrcFastError crc_fast_digest_finalize_ex(struct CrcFastDigestHandle *handle,
+                                              uint64_t *out_checksum);
+
+/**
+ * Reset the Digest state
+ */
+enum CrcFastError crc_fast_digest_reset_ex(struct CrcFastDigestHandle *handle);
+
+/**
+ * Finalize and reset the Digest in one operation, writing the checksum to `out_checksum`
+ */
+enum CrcFastError crc_fast_digest_finalize_reset_ex(struct CrcFastDigestHandle *handle,
+                                                    uint64_t *out_checksum);
+
+/**
+ * Combine two Digest checksums
+ */
+enum CrcFastError crc_fast_digest_combine_ex(struct CrcFastDigestHandle *handle1,
+                                             struct CrcFastDigestHandle *handle2);
+
+/**
+ * Creates a new Digest with a copy of the state of an existing one, writing it to `out_handle`
+ */
+enum CrcFastError crc_fast_digest_clone_ex(struct CrcFastDigestHandle *handle,
+                                           struct CrcFastDigestHandle **out_handle);
+
+/**
+ * Gets the amount of data processed by the Digest so far, writing it to `out_amount`
+ */
+enum CrcFastError crc_fast_digest_get_amount_ex(struct CrcFastDigestHandle *handle,
+                                                uint64_t *out_amount);
+
+/**
+ * Gets the current state of the Digest, writing it to `out_state`
+ */
+enum CrcFastError crc_fast_digest_get_state_ex(struct CrcFastDigestHandle *handle,
+                                               uint64_t *out_state);
+
+/**
+ * Calculates a CRC checksum directly for data using algorithm, writing it to `out_checksum`
+ */
+enum CrcFastError crc_fast_checksum_ex(enum CrcFastAlgorithm algorithm,
+                                       const char *data,
+                                       uintptr_t len,
+                                       uint64_t *out_checksum);
+
+/**
+ * Calculates a CRC checksum directly for data using custom parameters, writing it to
+ * `out_checksum`
+ */
+enum CrcFastError crc_fast_checksum_with_params_ex(struct CrcFastParams params,
+                                                   const char *data,
+                                                   uintptr_t len,
+                                                   uint64_t *out_checksum);
+
+/**
+ * Calculates a CRC checksum directly for a file using algorithm, writing it to `out_checksum`
+ */
+enum CrcFastError crc_fast_checksum_file_ex(enum CrcFastAlgorithm algorithm,
+                                            const uint8_t *path_ptr,
+                                            uintptr_t path_len,
+                                            uint64_t *out_checksum);
+
+/**
+ * Calculates a CRC checksum directly for a file using custom parameters, writing it to
+ * `out_checksum`
+ */
+enum CrcFastError crc_fast_checksum_file_with_params_ex(struct CrcFastParams params,
+                                                        const uint8_t *path_ptr,
+                                                        uintptr_t path_len,
+                                                        uint64_t *out_checksum);
+
+/**
+ * Calculates a CRC checksum directly for a file using algorithm, writing it to `out_checksum`.
+ *
+ * A `chunk_size` of 0 uses the default (512KiB). The optional `progress` callback is called with
+ * `user_data` after each chunk, and the checksum stops with `CrcFastError::Cancelled` as soon as
+ * the optional `cancel` flag is set (which may be done from another thread).
+ */
+enum CrcFastError crc_fast_checksum_file_progress(enum CrcFastAlgorithm algorithm,
+                                                  const uint8_t *path_ptr,
+                                                  uintptr_t path_len,
+                                                  uintptr_t chunk_size,
+                                                  CrcFastProgressCallback progress,
+                                                  void *user_data,
+                                                  const bool *cancel,
+                                                  uint64_t *out_checksum);
+
+/**
+ * Calculates a CRC checksum directly for a file using custom parameters, writing it to
+ * `out_checksum`, with the same chunk size, progress, and cancellation handling as
+ * `crc_fast_checksum_file_progress()`
+ */
+enum CrcFastError crc_fast_checksum_file_with_params_progress(struct CrcFastParams params,
+                                                              const uint8_t *path_ptr,
+                                                              uintptr_t path_len,
+                                                              uintptr_t chunk_size,
+                                                              CrcFastProgressCallback progress,
+                                                              void *user_data,
+                                                              const bool *cancel,
+                                                              uint64_t *out_checksum);
+
+#if !defined(_WIN32)
+/**
+ * Calculates a CRC checksum directly for an open file descriptor using algorithm, from the start of
+ * the file regardless of its offset, which is left unchanged. The descriptor isn't closed.
+ *
+ * Returns 0 on error, see `crc_fast_checksum_fd_ex()` for error handling.
+ */
+uint64_t crc_fast_checksum_fd(enum CrcFastAlgorithm algorithm, int fd);
+#endif
+
+#if !defined(_WIN32)
+/**
+ * Calculates a CRC checksum directly for an open file descriptor using algorithm, writing it to
+ * `out_checksum`, with the same behavior as `crc_fast_checksum_fd()`
+ */
+enum CrcFastError crc_fast_checksum_fd_ex(enum CrcFastAlgorithm algorithm,
+                                          int fd,
+                                          uint64_t *out_checksum);
+#endif
+
+#if defined(_WIN32)
+/**
+ * Calculates a CRC checksum directly for an open file HANDLE using algorithm, from the start of
+ * the file. The file pointer is moved, and the HANDLE isn't closed.
+ *
+ * Returns 0 on error, see `crc_fast_checksum_handle_ex()` for error handling.
+ */
+uint64_t crc_fast_checksum_handle(enum CrcFastAlgorithm algorithm, void *handle);
+#endif
+
+#if defined(_WIN32)
+/**
+ * Calculates a CRC checksum directly for an open file HANDLE using algorithm, writing it to
+ * `out_checksum`, with the same behavior as `crc_fast_checksum_handle()`
+ */
+enum CrcFastError crc_fast_checksum_handle_ex(enum CrcFastAlgorithm algorithm,
+                                              void *handle,
+                                              uint64_t *out_checksum);
+#endif
+
+#if defined(_WIN32)
+/**
+ * Helper method to calculate a CRC checksum directly for a file with a UTF-16 path using
+ * algorithm, for Windows paths which can't be represented in UTF-8. The path length is in
+ * `wchar_t` units.
+ *
+ * Returns 0 on error, see `crc_fast_checksum_file_w_ex()` for error handling.
+ */
+uint64_t crc_fast_checksum_file_w(enum CrcFastAlgorithm algorithm,
+                                  const wchar_t *path_ptr,
+                                  uintptr_t path_len);
+#endif
+
+#if defined(_WIN32)
+/**
+ * Calculates a CRC checksum directly for a file with a UTF-16 path using algorithm, writing it to
+ * `out_checksum`
+ */
+enum CrcFastError crc_fast_checksum_file_w_ex(enum CrcFastAlgorithm algorithm,
+                                              const wchar_t *path_ptr,
+                                              uintptr_t path_len,
+                                              uint64_t *out_checksum);
+#endif
+
+#if defined(_WIN32)
+/**
+ * Helper method to calculate a CRC checksum directly for a file with a UTF-16 path using custom
+ * parameters. The path length is in `wchar_t` units.
+ *
+ * Returns 0 on error, see `crc_fast_checksum_file_with_params_w_ex()` for error handling.
+ */
+uint64_t crc_fast_checksum_file_with_params_w(struct CrcFastParams params,
+                                              const wchar_t *path_ptr,
+                                              uintptr_t path_len);
+#endif
+
+#if defined(_WIN32)
+/**
+ * Calculates a CRC checksum directly for a file with a UTF-16 path using custom parameters,
+ * writing it to `out_checksum`
+ */
+enum CrcFastError crc_fast_checksum_file_with_params_w_ex(struct CrcFastParams params,
+                                                          const wchar_t *path_ptr,
+                                                          uintptr_t path_len,
+                                                          uint64_t *out_checksum);
+#endif
+
+/**
+ * Calculates a CRC checksum for a stream using algorithm, repeatedly calling `read` with
+ * `user_data` to fill an internal buffer until it returns 0 at the end of the stream, so embedders
+ * can use their own I/O (sockets, archives, etc) without managing a Digest.
+ *
+ * Returns 0 on error, see `crc_fast_checksum_stream_ex()` for error handling.
+ */
+uint64_t crc_fast_checksum_stream(enum CrcFastAlgorithm algorithm,
+                                  CrcFastReadCallback read,
+                                  void *user_data);
+
+/**
+ * Calculates a CRC checksum for a stream using algorithm, writing it to `out_checksum`. Returns
+ * `CrcFastError::IoError` if `read` returns a negative value, or more bytes than requested.
+ */
+enum CrcFastError crc_fast_checksum_stream_ex(enum CrcFastAlgorithm algorithm,
+                                              CrcFastReadCallback read,
+                                              void *user_data,
+                                              uint64_t *out_checksum);
+
+/**
+ * Calculates a CRC checksum for a stream using custom parameters, with the same callback
+ * behavior as `crc_fast_checksum_stream()`
+ *
+ * Returns 0 on error, see `crc_fast_checksum_stream_with_params_ex()` for error handling.
+ */
+uint64_t crc_fast_checksum_stream_with_params(struct CrcFastParams params,
+                                              CrcFastReadCallback read,
+                                              void *user_data);
+
+/**
+ * Calculates a CRC checksum for a stream using custom parameters, writing it to `out_checksum`
+ */
+enum CrcFastError crc_fast_checksum_stream_with_params_ex(struct CrcFastParams params,
+                                                          CrcFastReadCallback read,
+                                                          void *user_data,
+                                                          uint64_t *out_checksum);
+
+#if !defined(_WIN32)
+/**
+ * Calculates a CRC checksum directly for a list of memory regions using algorithm, as if they
+ * were one contiguous buffer, so network stacks can hash scattered packet fragments in one call.
+ * `iov` may only be null if `iovcnt` is 0, and each region's base may only be null if its length
+ * is 0.
+ *
+ * Returns 0 on error, see `crc_fast_checksum_iovec_ex()` for error handling.
+ */
+uint64_t crc_fast_checksum_iovec(enum CrcFastAlgorithm algorithm, const iovec *iov, int iovcnt);
+#endif
+
+#if !defined(_WIN32)
+/**
+ * Calculates a CRC checksum directly for a list of memory regions using algorithm, writing it to
+ * `out_checksum`. Returns `CrcFastError::InvalidCount` if `iovcnt` is negative.
+ */
+enum CrcFastError crc_fast_checksum_iovec_ex(enum CrcFastAlgorithm algorithm,
+                                             const iovec *iov,
+                                             int iovcnt,
+                                             uint64_t *out_checksum);
+#endif
+
+#if !defined(_WIN32)
+/**
+ * Calculates a CRC checksum directly for a list of memory regions using custom parameters, with
+ * the same behavior as `crc_fast_checksum_iovec()`
+ *
+ * Returns 0 on error, see `crc_fast_checksum_iovec_with_params_ex()` for error handling.
+ */
+uint64_t crc_fast_checksum_iovec_with_params(struct CrcFastParams params,
+                                             const iovec *iov,
+                                             int iovcnt);
+#endif
+
+#if !defined(_WIN32)
+/**
+ * Calculates a CRC checksum directly for a list of memory regions using custom parameters,
+ * writing it to `out_checksum`
+ */
+enum CrcFastError crc_fast_checksum_iovec_with_params_ex(struct CrcFastParams params,
+                                                         const iovec *iov,
+                                                         int iovcnt,
+                                                         uint64_t *out_checksum);
+#endif
+
+/**
+ * Combine two CRC checksums using algorithm, writing the result to `out_checksum`
+ */
+enum CrcFastError crc_fast_checksum_combine_ex(enum CrcFastAlgorithm algorithm,
+                                               uint64_t checksum1,
+                                               uint64_t checksum2,
+                                               uint64_t checksum2_len,
+                                               uint64_t *out_checksum);
+
+/**
+ * Combine two CRC checksums using custom parameters, writing the result to `out_checksum`
+ */
+enum CrcFastError crc_fast_checksum_combine_with_params_ex(struct CrcFastParams params,
+                                                           uint64_t checksum1,
+                                                           uint64_t checksum2,
+                                                           uint64_t checksum2_len,
+                                                           uint64_t *out_checksum);
+
+/**
+ * Gets the custom CRC parameters for a given set of Rocksoft CRC parameters, writing them to
+ * `out_params`. A null `name_ptr` uses "custom" as the name.
+ */
+enum CrcFastError crc_fast_get_custom_params_ex(const char *name_ptr,
+                                                uint8_t width,
+                                                uint64_t poly,
+                                                uint64_t init,
+                                                bool reflected,
+                                                uint64_t xorout,
+                                                uint64_t check,
+                                                struct CrcFastParams *out_params);
+
+/**
+ * Validates custom parameters before use, checking the width, that the keys were generated from
+ * the polynomial, and that the checksum of "123456789" matches the check value, so hand-written
+ * parameter structs which would silently produce wrong results can be caught
+ */
+enum CrcFastError crc_fast_validate_params(const struct CrcFastParams *params);
+
+/**
+ * Gets the target build properties (CPU architecture and fine-tuning parameters) for this
+ * algorithm, writing the string to `out_target`
+ */
+enum CrcFastError crc_fast_get_calculator_target_ex(enum CrcFastAlgorithm algorithm,
+                                                    const char **out_target);
+
+/**
+ * Calculates a CRC checksum directly for data using algorithm, returning the checksum and error
+ * together by value.
+ *
+ * The `crc_fast_simple_*` functions are for callers which handle out-pointers and callbacks
+ * poorly, such as Go via cgo: they take only values and a data pointer, return everything by
+ * value, and keep no state between calls (errors aren't stored anywhere, thread-local or
+ * otherwise). `data` may only be null if `len` is 0.
+ */
+struct CrcFastResult crc_fast_simple_checksum(enum CrcFastAlgorithm algorithm,
+                                              const char *data,
+                                              uintptr_t len);
+
+/**
+ * Calculates a CRC checksum directly for data using custom parameters, returning the checksum
+ * and error together by value, see `crc_fast_simple_checksum()`
+ */
+struct CrcFastResult crc_fast_simple_checksum_with_params(struct CrcFastParams params,
+                                                          const char *data,
+                                                          uintptr_t len);
+
+/**
+ * Combines two CRC checksums using algorithm, returning the result and error together by value,
+ * see `crc_fast_simple_checksum()`
+ */
+struct CrcFastResult crc_fast_simple_checksum_combine(enum CrcFastAlgorithm algorithm,
+                                                      uint64_t checksum1,
+                                                      uint64_t checksum2,
+                                                      uint64_t checksum2_len);
+
+/**
+ * Calculates the CRC checksum for data that's been written to the Digest, returning the checksum
+ * and error together by value, see `crc_fast_simple_checksum()`
+ */
+struct CrcFastResult crc_fast_simple_digest_finalize(struct CrcFastDigestHandle *handle);
+
+/**
+ * Calculates a CRC checksum directly for `len` bytes at `bytes` using algorithm, returning the
+ * checksum and error together by value.
+ *
+ * The `*_bytes` functions take an untyped pointer, which Swift imports as `UnsafeRawPointer`, so
+ * the bytes of a `Data` or `[UInt8]` can be passed from `withUnsafeBytes` without rebinding them
+ * to `CChar`. `bytes` may only be null if `len` is 0.
+ */
+struct CrcFastResult crc_fast_checksum_bytes(enum CrcFastAlgorithm algorithm,
+                                             const void *bytes,
+                                             uintptr_t len);
+
+/**
+ * Updates the Digest with `len` bytes at `bytes`, see `crc_fast_checksum_bytes()`
+ */
+enum CrcFastError crc_fast_digest_update_bytes(struct CrcFastDigestHandle *handle,
+                                               const void *bytes,
+                                               uintptr_t len);
+
+/**
+ * Creates a new Digest to compute CRC checksums using algorithm, returning null if it couldn't be
+ * created.
+ *
+ * The `crc_fast_digest_create*` and `crc_fast_digest_destroy()` functions have explicit-lifetime
+ * semantics for wrappers such as .NET's `SafeHandle`: creation returns null rather than an
+ * invalid handle, and destruction is NULL-tolerant and idempotent. The handle is used with the
+ * other `crc_fast_digest_*` functions.
+ */
+struct CrcFastDigestHandle *crc_fast_digest_create(enum CrcFastAlgorithm algorithm);
+
+/**
+ * Creates a new Digest to compute CRC checksums using custom Rocksoft parameters, returning null
+ * if the parameters aren't supported, see `crc_fast_digest_create()`
+ */
+struct CrcFastDigestHandle *crc_fast_digest_create_with_params(struct CrcFastRocksoftParams params);
+
+/**
+ * Frees the Digest behind `*handle` and sets `*handle` to null, so destroying the same handle
+ * again (or a null one) does nothing, see `crc_fast_digest_create()`
+ */
+void crc_fast_digest_destroy(struct CrcFastDigestHandle **handle);
+
 #ifdef __cplusplus
 }  // extern "C"
 #endif  // __cplusplus
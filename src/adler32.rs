@@ -0,0 +1,392 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Adler-32, the (non-CRC) checksum zlib streams use, with the same `Digest`/`checksum` API shape
+//! as the CRCs.
+//!
+//! zlib streams carry an Adler-32 of the uncompressed data, while gzip and zip carry a CRC-32, so
+//! archival tools which already use this crate for one usually need the other.
+//!
+//! On `x86`/`x86_64` with AVX2, 32-byte blocks are summed with `vpsadbw` and `vpmaddubsw`,
+//! deferring the modulo for as long as the sums can't overflow. Otherwise, a scalar implementation
+//! with the same deferred modulo is used.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::adler32;
+//!
+//! assert_eq!(adler32::checksum(b"123456789"), 0x091e01de);
+//!
+//! let mut digest = adler32::Digest::new();
+//! digest.update(b"1234");
+//! digest.update(b"56789");
+//!
+//! assert_eq!(digest.finalize(), 0x091e01de);
+//! ```
+
+use digest::{DynDigest, InvalidBufferSize};
+#[cfg(feature = "std")]
+use std::io::Write;
+
+/// The largest prime smaller than 65536
+const MOD_ADLER: u32 = 65521;
+
+/// The most bytes which can be summed before `b` could overflow a `u32`, so the modulo can be
+/// deferred until then
+const NMAX: usize = 5552;
+
+/// The initial Adler-32 value, for empty data
+const INIT: u32 = 1;
+
+/// Computes the Adler-32 checksum for the given data.
+#[inline(always)]
+pub fn checksum(buf: &[u8]) -> u32 {
+    update(INIT, buf)
+}
+
+/// Combines two Adler-32 checksums, as if the data for `checksum2` (`checksum2_len` bytes long)
+/// was appended to the data for `checksum1`.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::adler32;
+///
+/// let checksum1 = adler32::checksum(b"1234");
+/// let checksum2 = adler32::checksum(b"56789");
+///
+/// assert_eq!(adler32::combine(checksum1, checksum2, 5), adler32::checksum(b"123456789"));
+/// ```
+pub fn combine(checksum1: u32, checksum2: u32, checksum2_len: u64) -> u32 {
+    let rem = (checksum2_len % MOD_ADLER as u64) as u32;
+
+    let a1 = checksum1 & 0xffff;
+    let b1 = checksum1 >> 16;
+    let a2 = checksum2 & 0xffff;
+    let b2 = checksum2 >> 16;
+
+    // the second checksum's `a` starts at 1 rather than `a1`, so its `b` is short `rem * (a1 - 1)`
+    let a = (a1 + a2 + MOD_ADLER - 1) % MOD_ADLER;
+    let b = ((rem * a1) % MOD_ADLER + b1 + b2 + MOD_ADLER - rem) % MOD_ADLER;
+
+    (b << 16) | a
+}
+
+/// Represents an in-progress Adler-32 computation
+#[derive(Copy, Clone, Debug)]
+pub struct Digest {
+    /// The current Adler-32 value, with `a` in the low half and `b` in the high half
+    state: u32,
+
+    /// The amount of data processed so far
+    amount: u64,
+}
+
+impl Digest {
+    /// Creates a new Adler-32 `Digest`.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::new_with_init_state(INIT)
+    }
+
+    /// Creates a new Adler-32 `Digest` resuming from a previous checksum, such as the one stored
+    /// for a prefix of the data.
+    #[inline(always)]
+    pub fn new_with_init_state(state: u32) -> Self {
+        Self { state, amount: 0 }
+    }
+
+    /// Updates the checksum with data.
+    #[inline(always)]
+    pub fn update(&mut self, data: &[u8]) {
+        self.state = update(self.state, data);
+        self.amount += data.len() as u64;
+    }
+
+    /// Finalizes the checksum, which leaves the `Digest` intact so it can be updated further.
+    #[inline(always)]
+    pub fn finalize(&self) -> u32 {
+        self.state
+    }
+
+    /// Finalizes the checksum and resets the `Digest`.
+    #[inline(always)]
+    pub fn finalize_reset(&mut self) -> u32 {
+        let checksum = self.finalize();
+        self.reset();
+
+        checksum
+    }
+
+    /// Resets the `Digest` to its initial state.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.state = INIT;
+        self.amount = 0;
+    }
+
+    /// Combines the checksum of another `Digest` into this one, as if its data was appended.
+    pub fn combine(&mut self, other: &Self) {
+        self.state = combine(self.state, other.state, other.amount);
+        self.amount += other.amount;
+    }
+
+    /// Gets the amount of data processed so far.
+    #[inline(always)]
+    pub fn get_amount(&self) -> u64 {
+        self.amount
+    }
+}
+
+impl Default for Digest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DynDigest for Digest {
+    #[inline(always)]
+    fn update(&mut self, data: &[u8]) {
+        self.update(data);
+    }
+
+    #[inline(always)]
+    fn finalize_into(self, buf: &mut [u8]) -> Result<(), InvalidBufferSize> {
+        if buf.len() != 4 {
+            return Err(InvalidBufferSize);
+        }
+
+        // zlib stores it big-endian
+        buf.copy_from_slice(&self.finalize().to_be_bytes());
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn finalize_into_reset(&mut self, out: &mut [u8]) -> Result<(), InvalidBufferSize> {
+        if out.len() != 4 {
+            return Err(InvalidBufferSize);
+        }
+
+        out.copy_from_slice(&self.finalize_reset().to_be_bytes());
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn reset(&mut self) {
+        self.reset();
+    }
+
+    #[inline(always)]
+    fn output_size(&self) -> usize {
+        4
+    }
+
+    fn box_clone(&self) -> Box<dyn DynDigest> {
+        Box::new(*self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Write for Digest {
+    #[inline(always)]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Updates an Adler-32 value with data, using the fastest implementation for the CPU
+#[inline]
+fn update(state: u32, data: &[u8]) -> u32 {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if has_avx2() {
+        return unsafe { avx2::update(state, data) };
+    }
+
+    update_scalar(state, data)
+}
+
+/// Returns whether the AVX2 implementation can be used
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+fn has_avx2() -> bool {
+    #[cfg(feature = "static-dispatch")]
+    {
+        cfg!(target_feature = "avx2")
+    }
+
+    #[cfg(not(feature = "static-dispatch"))]
+    {
+        is_x86_feature_detected!("avx2")
+    }
+}
+
+/// Updates an Adler-32 value with data, one byte at a time, taking the modulo every `NMAX` bytes
+fn update_scalar(state: u32, data: &[u8]) -> u32 {
+    let mut a = state & 0xffff;
+    let mut b = state >> 16;
+
+    for chunk in data.chunks(NMAX) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+
+        a %= MOD_ADLER;
+        b %= MOD_ADLER;
+    }
+
+    (b << 16) | a
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod avx2 {
+    use super::{update_scalar, MOD_ADLER, NMAX};
+
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    /// The bytes in each block
+    const BLOCK_SIZE: usize = 32;
+
+    /// The most bytes, in whole blocks, which can be summed before the modulo
+    const CHUNK_SIZE: usize = NMAX / BLOCK_SIZE * BLOCK_SIZE;
+
+    /// Updates an Adler-32 value with data, 32 bytes at a time.
+    ///
+    /// Within a block of bytes `x[0..32]`, `a` grows by `sum(x[i])` and `b` by
+    /// `32 * a + sum((32 - i) * x[i])`, so each block is one `vpsadbw` (for `a`) and one
+    /// `vpmaddubsw` + `vpmaddwd` (for the weighted part of `b`). The `32 * a` part is deferred by
+    /// summing `a` before each block, and multiplying once per chunk.
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn update(state: u32, data: &[u8]) -> u32 {
+        let mut a = state & 0xffff;
+        let mut b = state >> 16;
+
+        let weights = _mm256_setr_epi8(
+            32, 31, 30, 29, 28, 27, 26, 25, 24, 23, 22, 21, 20, 19, 18, 17, 16, 15, 14, 13, 12, 11,
+            10, 9, 8, 7, 6, 5, 4, 3, 2, 1,
+        );
+        let ones = _mm256_set1_epi16(1);
+        let zero = _mm256_setzero_si256();
+
+        let (blocks, remainder) = data.split_at(data.len() - data.len() % BLOCK_SIZE);
+
+        for chunk in blocks.chunks(CHUNK_SIZE) {
+            let mut sum_a = zero;
+            let mut sum_prior_a = zero;
+            let mut sum_b = zero;
+
+            for block in chunk.chunks_exact(BLOCK_SIZE) {
+                let bytes = _mm256_loadu_si256(block.as_ptr() as *const __m256i);
+
+                sum_prior_a = _mm256_add_epi32(sum_prior_a, sum_a);
+                sum_a = _mm256_add_epi32(sum_a, _mm256_sad_epu8(bytes, zero));
+                sum_b = _mm256_add_epi32(
+                    sum_b,
+                    _mm256_madd_epi16(_mm256_maddubs_epi16(bytes, weights), ones),
+                );
+            }
+
+            b += a * chunk.len() as u32 + horizontal_sum(_mm256_slli_epi32(sum_prior_a, 5));
+            b += horizontal_sum(sum_b);
+            a += horizontal_sum(sum_a);
+
+            a %= MOD_ADLER;
+            b %= MOD_ADLER;
+        }
+
+        update_scalar((b << 16) | a, remainder)
+    }
+
+    /// Sums the 32-bit lanes
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    unsafe fn horizontal_sum(sum: __m256i) -> u32 {
+        let sum = _mm_add_epi32(
+            _mm256_castsi256_si128(sum),
+            _mm256_extracti128_si256(sum, 1),
+        );
+        let sum = _mm_add_epi32(sum, _mm_shuffle_epi32(sum, 0b01_00_11_10));
+        let sum = _mm_add_epi32(sum, _mm_shuffle_epi32(sum, 0b10_11_00_01));
+
+        _mm_cvtsi128_si32(sum) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_check_values() {
+        assert_eq!(checksum(b""), 1);
+        assert_eq!(checksum(b"a"), 0x00620062);
+        assert_eq!(checksum(b"123456789"), 0x091e01de);
+        assert_eq!(checksum(b"Wikipedia"), 0x11e60398);
+
+        // enough to need several modulo reductions
+        assert_eq!(checksum(&[0xff; 100_000]), 0x149a302c);
+    }
+
+    #[test]
+    fn test_matches_scalar() {
+        let mut rng = rng();
+
+        for len in (0..1_024).chain([NMAX - 1, NMAX, NMAX + 1, 3 * NMAX + 7, 100_000]) {
+            let mut data = vec![0u8; len];
+            rng.fill(&mut data[..]);
+
+            // the largest bytes are the most likely to overflow
+            if len % 2 == 0 {
+                data.fill(0xff);
+            }
+
+            let state = (rng.random_range(0..MOD_ADLER) << 16) | rng.random_range(0..MOD_ADLER);
+
+            assert_eq!(update(state, &data), update_scalar(state, &data), "{len}");
+        }
+    }
+
+    #[test]
+    fn test_digest_and_combine() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i * 31 % 251) as u8).collect();
+        let expected = checksum(&data);
+
+        let mut digest = Digest::new();
+        for chunk in data.chunks(7_919) {
+            digest.update(chunk);
+        }
+        assert_eq!(digest.finalize(), expected);
+        assert_eq!(digest.get_amount(), data.len() as u64);
+
+        for split in [0, 1, 1_000, NMAX + 3, data.len()] {
+            let (left, right) = data.split_at(split);
+
+            let mut digest = Digest::new();
+            digest.update(left);
+
+            let mut other = Digest::new();
+            other.update(right);
+
+            digest.combine(&other);
+            assert_eq!(digest.finalize(), expected, "{split}");
+
+            let resumed = update(checksum(left), right);
+            assert_eq!(resumed, expected);
+        }
+
+        assert_eq!(digest.finalize_reset(), expected);
+        assert_eq!(digest.finalize(), checksum(b""));
+    }
+}
@@ -15,7 +15,7 @@
 
 #![cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
 
-use crate::consts::CRC_CHUNK_SIZE;
+use crate::consts::{CRC_CHUNK_SIZE, CRC_FOLDING_THRESHOLD};
 use crate::enums::{DataChunkProcessor, Reflector};
 use crate::structs::CrcState;
 use crate::traits::{ArchOps, EnhancedCrcWidth};
@@ -23,8 +23,11 @@ use crate::{crc32, crc64, CrcParams};
 
 /// Extract keys from CrcParams using safe accessor methods
 /// This ensures bounds checking and future compatibility
+///
+/// Keys 23 and 24 (512-byte folding distances) are 0 when the params only carry the legacy
+/// 23-key format, which signals the enhanced paths to stick to 256-byte folding distances.
 #[inline(always)]
-fn extract_keys_array(params: CrcParams) -> [u64; 23] {
+fn extract_keys_array(params: CrcParams) -> [u64; 25] {
     [
         params.get_key(0),
         params.get_key(1),
@@ -49,10 +52,15 @@ fn extract_keys_array(params: CrcParams) -> [u64; 23] {
         params.get_key(20),
         params.get_key(21),
         params.get_key(22),
+        params.get_key(23),
+        params.get_key(24),
     ]
 }
 
 /// Main entry point that works for both CRC-32 and CRC-64
+///
+/// # Safety
+/// May use native CPU features, so `ops` must be supported by the current CPU
 #[inline(always)]
 pub unsafe fn update<T: ArchOps, W: EnhancedCrcWidth>(
     state: W::Value,
@@ -83,7 +91,7 @@ where
     // Process data differently based on length
     // On ARM M4 Max, ARM c8g, x86 c7a, and x86 c7i, using 128 bytes is a measurably faster
     // threshold than 256 bytes...
-    if len < 128 {
+    if len < CRC_FOLDING_THRESHOLD {
         // Select processor based on input length
         let processor = DataChunkProcessor::for_length(len);
         return process_by_strategy::<T, W>(
@@ -118,7 +126,7 @@ unsafe fn process_by_strategy<T: ArchOps, W: EnhancedCrcWidth>(
     data: &[u8],
     state: &mut CrcState<T::Vector>,
     reflector: Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 25],
     ops: &T,
 ) -> W::Value
 where
@@ -153,7 +161,7 @@ unsafe fn process_large_aligned<T: ArchOps, W: EnhancedCrcWidth>(
     bytes: &[u8],
     state: &mut CrcState<T::Vector>,
     reflector: Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 25],
     ops: &T,
 ) -> W::Value
 where
@@ -207,7 +215,7 @@ unsafe fn process_simd_chunks<T: ArchOps, W: EnhancedCrcWidth>(
     first: &[T::Vector; 8],
     rest: &[[T::Vector; 8]],
     reflector: &Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 25],
     ops: &T,
 ) where
     T::Vector: Copy,
@@ -246,33 +254,171 @@ unsafe fn process_simd_chunks<T: ArchOps, W: EnhancedCrcWidth>(
         }
     }
 
-    // Fold the 8 xmm registers to 1 xmm register with different constants
+    // Fold the 8 xmm registers to 1 xmm register, and update the state
+    let final_value = fold_and_reduce_8::<T, W>(x, state.reflected, keys, ops);
+    *state = W::create_state(final_value, state.reflected, ops);
+}
+
+/// Fold 8 xmm registers to 1 xmm register with different constants, and perform the final
+/// reduction
+#[inline]
+#[cfg_attr(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature(enable = "ssse3,sse4.1,pclmulqdq")
+)]
+#[cfg_attr(target_arch = "aarch64", target_feature(enable = "aes"))]
+unsafe fn fold_and_reduce_8<T: ArchOps, W: EnhancedCrcWidth>(
+    x: [T::Vector; 8],
+    reflected: bool,
+    keys: [u64; 25],
+    ops: &T,
+) -> W::Value
+where
+    T::Vector: Copy,
+{
     let mut res = x[7];
 
     // Create fold coefficients for different distances
     let fold_coefficients = [
-        W::create_coefficient(keys[10], keys[9], state.reflected, ops), // 112 bytes
-        W::create_coefficient(keys[12], keys[11], state.reflected, ops), // 96 bytes
-        W::create_coefficient(keys[14], keys[13], state.reflected, ops), // 80 bytes
-        W::create_coefficient(keys[16], keys[15], state.reflected, ops), // 64 bytes
-        W::create_coefficient(keys[18], keys[17], state.reflected, ops), // 48 bytes
-        W::create_coefficient(keys[20], keys[19], state.reflected, ops), // 32 bytes
-        W::create_coefficient(keys[2], keys[1], state.reflected, ops),  // 16 bytes
+        W::create_coefficient(keys[10], keys[9], reflected, ops), // 112 bytes
+        W::create_coefficient(keys[12], keys[11], reflected, ops), // 96 bytes
+        W::create_coefficient(keys[14], keys[13], reflected, ops), // 80 bytes
+        W::create_coefficient(keys[16], keys[15], reflected, ops), // 64 bytes
+        W::create_coefficient(keys[18], keys[17], reflected, ops), // 48 bytes
+        W::create_coefficient(keys[20], keys[19], reflected, ops), // 32 bytes
+        W::create_coefficient(keys[2], keys[1], reflected, ops),  // 16 bytes
     ];
 
     for (i, &coeff) in fold_coefficients.iter().enumerate() {
         let mut temp_state = CrcState {
             value: x[i],
-            reflected: state.reflected,
+            reflected,
         };
         W::fold_16(&mut temp_state, coeff, res, ops);
 
         res = temp_state.value
     }
 
-    // Perform final reduction and update state
-    let final_value = W::perform_final_reduction(res, state.reflected, keys, ops);
-    *state = W::create_state(final_value, state.reflected, ops);
+    W::perform_final_reduction(res, reflected, keys, ops)
+}
+
+/// Interleaved entry point which calculates the CRCs of N independent buffers at once
+///
+/// The 128-byte folding loops of every buffer are interleaved, so the carryless multiplications
+/// of one buffer execute while those of the others are still in flight, hiding their latency.
+/// Only the leading blocks which every buffer has are interleaved, and the rest of each buffer is
+/// processed by `update()`.
+///
+/// # Safety
+/// May use native CPU features, so `ops` must be supported by the current CPU
+#[inline(always)]
+pub unsafe fn update_multi<T: ArchOps, W: EnhancedCrcWidth, const N: usize>(
+    mut states: [W::Value; N],
+    buffers: [&[u8]; N],
+    params: CrcParams,
+    ops: &T,
+) -> [W::Value; N]
+where
+    T::Vector: Copy,
+{
+    let blocks = buffers
+        .iter()
+        .map(|buffer| buffer.len() / INTERLEAVED_BLOCK_SIZE)
+        .min()
+        .unwrap_or(0);
+
+    // there's nothing to gain unless there are multiple buffers with multiple blocks each
+    let interleaved_len = if N > 1 && blocks > 1 {
+        states = process_interleaved_chunks::<T, W, N>(states, &buffers, blocks, params, ops);
+        blocks * INTERLEAVED_BLOCK_SIZE
+    } else {
+        0
+    };
+
+    for (state, buffer) in states.iter_mut().zip(buffers) {
+        *state = update::<T, W>(*state, &buffer[interleaved_len..], params, ops);
+    }
+
+    states
+}
+
+/// Size of the blocks folded per buffer in each round of `update_multi()`
+const INTERLEAVED_BLOCK_SIZE: usize = 8 * CRC_CHUNK_SIZE;
+
+/// Fold the leading 128-byte blocks of N buffers, interleaving the folds of each buffer
+#[inline]
+#[cfg_attr(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_feature(enable = "ssse3,sse4.1,pclmulqdq")
+)]
+#[cfg_attr(target_arch = "aarch64", target_feature(enable = "aes"))]
+unsafe fn process_interleaved_chunks<T: ArchOps, W: EnhancedCrcWidth, const N: usize>(
+    mut states: [W::Value; N],
+    buffers: &[&[u8]; N],
+    blocks: usize,
+    params: CrcParams,
+    ops: &T,
+) -> [W::Value; N]
+where
+    T::Vector: Copy,
+{
+    let reflector = if params.refin {
+        Reflector::NoReflector
+    } else {
+        let smask = ops.load_aligned(&W::load_constants(params.refin)[0] as *const [u64; 2]);
+        Reflector::ForwardReflector { smask }
+    };
+
+    let keys = extract_keys_array(params);
+
+    // Load the first 128 bytes of every buffer, and XOR in its initial CRC
+    let mut x = [[ops.create_vector_from_u64(0, false); 8]; N];
+    for (stream, buffer) in x.iter_mut().zip(buffers) {
+        for (i, xi) in stream.iter_mut().enumerate() {
+            *xi = reflect_bytes(
+                &reflector,
+                ops.load_bytes(buffer.as_ptr().add(i * CRC_CHUNK_SIZE)),
+                ops,
+            );
+        }
+    }
+
+    for (stream, state) in x.iter_mut().zip(states) {
+        stream[0] = ops.xor_vectors(stream[0], W::create_state(state, params.refin, ops).value);
+    }
+
+    // Load the coefficient pair for folding
+    let coeff = W::create_coefficient(keys[4], keys[3], params.refin, ops);
+
+    // Fold the remaining blocks, with the buffers innermost so the folds are independent
+    for block in 1..blocks {
+        let offset = block * INTERLEAVED_BLOCK_SIZE;
+
+        for i in 0..8 {
+            for (stream, buffer) in x.iter_mut().zip(buffers) {
+                let yi = reflect_bytes(
+                    &reflector,
+                    ops.load_bytes(buffer.as_ptr().add(offset + i * CRC_CHUNK_SIZE)),
+                    ops,
+                );
+
+                let mut temp_state = CrcState {
+                    value: stream[i],
+                    reflected: params.refin,
+                };
+
+                W::fold_16(&mut temp_state, coeff, yi, ops);
+
+                stream[i] = temp_state.value;
+            }
+        }
+    }
+
+    for (state, stream) in states.iter_mut().zip(x) {
+        *state = fold_and_reduce_8::<T, W>(stream, params.refin, keys, ops);
+    }
+
+    states
 }
 
 /// Process exactly 16 bytes
@@ -286,7 +432,7 @@ unsafe fn process_exactly_16<T: ArchOps, W: EnhancedCrcWidth>(
     data: &[u8],
     state: &mut CrcState<T::Vector>,
     reflector: &Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 25],
     ops: &T,
 ) -> W::Value
 where
@@ -386,7 +532,7 @@ unsafe fn process_17_to_31<T: ArchOps, W: EnhancedCrcWidth>(
     data: &[u8],
     state: &mut CrcState<T::Vector>,
     reflector: &Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 25],
     ops: &T,
 ) -> W::Value
 where
@@ -425,7 +571,7 @@ unsafe fn process_32_to_255<T: ArchOps, W: EnhancedCrcWidth>(
     data: &[u8],
     state: &mut CrcState<T::Vector>,
     reflector: &Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 25],
     ops: &T,
 ) -> W::Value
 where
@@ -487,7 +633,7 @@ unsafe fn get_last_two_xmms<T: ArchOps, W: EnhancedCrcWidth>(
     data: &[u8],
     remaining_len: usize,
     current_state: T::Vector,
-    keys: [u64; 23],
+    keys: [u64; 25],
     reflector: &Reflector<T::Vector>,
     reflected: bool,
     ops: &T,
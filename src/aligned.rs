@@ -0,0 +1,64 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Explicitly aligned path for page-aligned (e.g. memory-mapped) buffers.
+//!
+//! The SIMD kernels load whole 64-byte-aligned blocks, and otherwise calculate the misaligned bytes
+//! before the first block with the short-input strategies, and re-derive the alignment on every
+//! call. Here, the misaligned prologue (if any) is calculated once on its own, so the rest of the
+//! buffer always starts on a block boundary and goes straight to the aligned kernel.
+
+use crate::{CalculatorFn, CrcParams};
+
+/// The alignment, in bytes, which gets the best-case SIMD kernel, and which page-aligned buffers
+/// always have
+pub const CHECKSUM_ALIGNMENT: usize = 64;
+
+/// Updates the state using the calculator, calculating any bytes before the first aligned block
+/// separately
+#[inline]
+pub(crate) fn update(state: u64, data: &[u8], params: CrcParams, calculator: CalculatorFn) -> u64 {
+    let (prologue, aligned) = split_aligned(data);
+
+    let state = if prologue.is_empty() {
+        state
+    } else {
+        calculator(state, prologue, params)
+    };
+
+    calculator(state, aligned, params)
+}
+
+/// Splits the data into the misaligned bytes before the first aligned block, and the rest
+#[inline(always)]
+pub(crate) fn split_aligned(data: &[u8]) -> (&[u8], &[u8]) {
+    let offset = data.as_ptr().align_offset(CHECKSUM_ALIGNMENT);
+
+    data.split_at(offset.min(data.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_aligned() {
+        let data = vec![0u8; 4 * CHECKSUM_ALIGNMENT];
+        let base = data.as_ptr().align_offset(CHECKSUM_ALIGNMENT);
+
+        for offset in 0..CHECKSUM_ALIGNMENT {
+            let data = &data[base + offset..];
+            let (prologue, aligned) = split_aligned(data);
+
+            assert_eq!(
+                prologue.len(),
+                (CHECKSUM_ALIGNMENT - offset) % CHECKSUM_ALIGNMENT
+            );
+            assert_eq!(aligned.as_ptr() as usize % CHECKSUM_ALIGNMENT, 0);
+            assert_eq!(prologue.len() + aligned.len(), data.len());
+        }
+
+        // too short to reach an aligned block
+        let (prologue, aligned) = split_aligned(&data[base + 1..base + 8]);
+        assert_eq!((prologue.len(), aligned.len()), (7, 0));
+    }
+}
@@ -12,7 +12,7 @@ use std::arch::aarch64::*;
 
 /// Base AArch64 implementation with AES+NEON optimizations
 /// NEON is implicit with AES support on AArch64
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Default, Copy, Clone)]
 pub struct Aarch64AesOps;
 
 // Base implementation for AArch64 AES tier
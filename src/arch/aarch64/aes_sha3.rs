@@ -11,12 +11,12 @@ use std::arch::aarch64::*;
 
 /// AArch64 AES+SHA3 tier - delegates to AES tier and overrides XOR3 operations
 /// Provides EOR3 instruction for optimal XOR3 performance
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Default, Copy, Clone)]
 pub struct Aarch64AesSha3Ops(Aarch64AesOps);
 
 impl Aarch64AesSha3Ops {
     #[inline(always)]
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self(Aarch64AesOps)
     }
 }
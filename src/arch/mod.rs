@@ -4,7 +4,7 @@
 //!
 //! It dispatches to the appropriate architecture-specific implementation
 
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_arch = "aarch64", target_endian = "little"))]
 use std::arch::is_aarch64_feature_detected;
 
 use crate::CrcParams;
@@ -16,12 +16,28 @@ use crate::arch::aarch64::aes::Aarch64AesOps;
 use crate::arch::aarch64::aes_sha3::Aarch64AesSha3Ops;
 
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
-use crate::{
-    algorithm,
-    structs::{Width32, Width64},
-};
+use crate::{algorithm, traits::ArchOps};
+
+#[cfg(all(
+    not(feature = "crc64-only"),
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
+use crate::crc32::consts::CRC32_ISCSI;
+#[cfg(all(not(feature = "crc64-only"), target_arch = "aarch64"))]
+use crate::crc32::consts::CRC32_ISO_HDLC;
+#[cfg(all(
+    not(feature = "crc64-only"),
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
+use crate::structs::Width32;
+#[cfg(all(
+    not(feature = "crc32-only"),
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
+use crate::structs::Width64;
 
 pub mod aarch64;
+pub mod portable_simd;
 pub mod software;
 pub mod x86;
 pub mod x86_64;
@@ -33,23 +49,109 @@ pub mod x86_64;
 #[inline(always)]
 #[cfg(target_arch = "aarch64")]
 pub(crate) unsafe fn update(state: u64, bytes: &[u8], params: CrcParams) -> u64 {
-    use crate::feature_detection::{get_arch_ops, ArchOpsInstance};
+    update_with_arch_ops(
+        state,
+        bytes,
+        params,
+        crate::feature_detection::get_arch_ops(),
+    )
+}
+
+/// Dispatches to the specified architecture-specific implementation
+///
+/// # Safety
+/// May use native CPU features, so the instance must be supported by the current CPU
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+pub(crate) unsafe fn update_with_arch_ops(
+    state: u64,
+    bytes: &[u8],
+    params: CrcParams,
+    arch_ops: &crate::feature_detection::ArchOpsInstance,
+) -> u64 {
+    use crate::feature_detection::ArchOpsInstance;
 
-    match get_arch_ops() {
+    match arch_ops {
         ArchOpsInstance::Aarch64AesSha3(ops) => update_aarch64_aes_sha3(state, bytes, params, *ops),
         ArchOpsInstance::Aarch64Aes(ops) => update_aarch64_aes(state, bytes, params, *ops),
-        ArchOpsInstance::SoftwareFallback => {
-            if !is_aarch64_feature_detected!("aes") || !is_aarch64_feature_detected!("neon") {
-                #[cfg(any(not(target_feature = "aes"), not(target_feature = "neon")))]
-                {
-                    // Use software implementation when no SIMD support is available
-                    return crate::arch::software::update(state, bytes, params);
-                }
+        ArchOpsInstance::Aarch64Crc => aarch64_crc_update(state, bytes, params),
+        ArchOpsInstance::SoftwareFallback => aarch64_software_update(state, bytes, params),
+        #[cfg(feature = "portable-simd")]
+        ArchOpsInstance::PortableSimd(ops) => ops.update(state, bytes, params),
+        ArchOpsInstance::Custom(backend) => backend.update(state, bytes, params),
+    }
+}
+
+/// Uses the native CRC-32 instructions for CRC-32C and CRC-32 parameters, and the software
+/// fallback for everything else, for cores with the CRC extension but no PMULL
+///
+/// # Safety
+/// Uses the CRC extension's instructions, so it must be supported by the current CPU
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+unsafe fn aarch64_crc_update(state: u64, bytes: &[u8], params: CrcParams) -> u64 {
+    // the state is the raw CRC register, so any reflected CRC-32C or CRC-32 parameters can use it
+    #[cfg(not(feature = "crc64-only"))]
+    if params.width == 32 && params.refin {
+        if params.poly == CRC32_ISCSI.poly {
+            return crate::crc32::native::crc32_iscsi(state as u32, bytes) as u64;
+        }
+
+        if params.poly == CRC32_ISO_HDLC.poly {
+            return crate::crc32::native::crc32_iso_hdlc(state as u32, bytes) as u64;
+        }
+    }
+
+    aarch64_software_update(state, bytes, params)
+}
+
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+fn aarch64_software_update(state: u64, bytes: &[u8], params: CrcParams) -> u64 {
+    // big-endian always uses the software implementation, since the SIMD kernels assume
+    // little-endian loads
+    #[cfg(target_endian = "big")]
+    {
+        crate::arch::software::update(state, bytes, params)
+    }
+
+    #[cfg(target_endian = "little")]
+    {
+        if !is_aarch64_feature_detected!("aes") || !is_aarch64_feature_detected!("neon") {
+            #[cfg(any(not(target_feature = "aes"), not(target_feature = "neon")))]
+            {
+                // Use software implementation when no SIMD support is available
+                return crate::arch::software::update(state, bytes, params);
             }
+        }
 
-            // This should likely never happen, but just in case
-            panic!("aarch64 features missing (NEON and/or AES)");
+        // This should likely never happen, but just in case
+        panic!("aarch64 features missing (NEON and/or AES)");
+    }
+}
+
+/// Dispatches N independent updates, interleaved where possible, to the specified
+/// architecture-specific implementation
+///
+/// # Safety
+/// May use native CPU features, so the instance must be supported by the current CPU
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+pub(crate) unsafe fn update_multi_with_arch_ops<const N: usize>(
+    states: [u64; N],
+    buffers: [&[u8]; N],
+    params: CrcParams,
+    arch_ops: &crate::feature_detection::ArchOpsInstance,
+) -> [u64; N] {
+    use crate::feature_detection::ArchOpsInstance;
+
+    match arch_ops {
+        // the AES ops inline into the interleaved loop, and interleaving already provides the
+        // parallelism that EOR3 would
+        ArchOpsInstance::Aarch64AesSha3(_) | ArchOpsInstance::Aarch64Aes(_) => {
+            update_multi_simd(states, buffers, params, &Aarch64AesOps)
         }
+        _ => update_multi_sequential(states, buffers, params, arch_ops),
     }
 }
 
@@ -63,7 +165,9 @@ unsafe fn update_aarch64_aes(
     ops: Aarch64AesOps,
 ) -> u64 {
     match params.width {
+        #[cfg(not(feature = "crc32-only"))]
         64 => algorithm::update::<_, Width64>(state, bytes, params, &ops),
+        #[cfg(not(feature = "crc64-only"))]
         32 => algorithm::update::<_, Width32>(state as u32, bytes, params, &ops) as u64,
         _ => panic!("Unsupported CRC width: {}", params.width),
     }
@@ -79,87 +183,230 @@ unsafe fn update_aarch64_aes_sha3(
     ops: Aarch64AesSha3Ops,
 ) -> u64 {
     match params.width {
+        #[cfg(not(feature = "crc32-only"))]
         64 => algorithm::update::<_, Width64>(state, bytes, params, &ops),
+        #[cfg(not(feature = "crc64-only"))]
         32 => algorithm::update::<_, Width32>(state as u32, bytes, params, &ops) as u64,
         _ => panic!("Unsupported CRC width: {}", params.width),
     }
 }
 
-/// Main entry point for x86/x86_64 (Rust 1.89+ which supports AVX-512)
+/// Main entry point for x86/x86_64
 ///
 /// # Safety
 /// May use native CPU features
-#[rustversion::since(1.89)]
 #[inline(always)]
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub(crate) unsafe fn update(state: u64, bytes: &[u8], params: CrcParams) -> u64 {
-    use crate::feature_detection::{get_arch_ops, ArchOpsInstance};
+    update_with_arch_ops(
+        state,
+        bytes,
+        params,
+        crate::feature_detection::get_arch_ops(),
+    )
+}
+
+/// Dispatches to the specified architecture-specific implementation for x86/x86_64 (Rust 1.89+
+/// which supports AVX-512)
+///
+/// # Safety
+/// May use native CPU features, so the instance must be supported by the current CPU
+#[rustversion::since(1.89)]
+#[inline(always)]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub(crate) unsafe fn update_with_arch_ops(
+    state: u64,
+    bytes: &[u8],
+    params: CrcParams,
+    arch_ops: &crate::feature_detection::ArchOpsInstance,
+) -> u64 {
+    use crate::feature_detection::ArchOpsInstance;
 
-    match get_arch_ops() {
+    match arch_ops {
         #[cfg(target_arch = "x86_64")]
         ArchOpsInstance::X86_64Avx512Vpclmulqdq(ops) => match params.width {
+            #[cfg(not(feature = "crc32-only"))]
             64 => algorithm::update::<_, Width64>(state, bytes, params, ops),
+            #[cfg(not(feature = "crc64-only"))]
             32 => algorithm::update::<_, Width32>(state as u32, bytes, params, ops) as u64,
             _ => panic!("Unsupported CRC width: {}", params.width),
         },
         #[cfg(target_arch = "x86_64")]
         ArchOpsInstance::X86_64Avx512Pclmulqdq(ops) => match params.width {
+            #[cfg(not(feature = "crc32-only"))]
             64 => algorithm::update::<_, Width64>(state, bytes, params, ops),
+            #[cfg(not(feature = "crc64-only"))]
             32 => algorithm::update::<_, Width32>(state as u32, bytes, params, ops) as u64,
             _ => panic!("Unsupported CRC width: {}", params.width),
         },
         ArchOpsInstance::X86SsePclmulqdq(ops) => match params.width {
+            #[cfg(not(feature = "crc32-only"))]
             64 => algorithm::update::<_, Width64>(state, bytes, params, ops),
+            #[cfg(not(feature = "crc64-only"))]
             32 => algorithm::update::<_, Width32>(state as u32, bytes, params, ops) as u64,
             _ => panic!("Unsupported CRC width: {}", params.width),
         },
-        ArchOpsInstance::SoftwareFallback => {
-            #[cfg(target_arch = "x86")]
-            crate::arch::x86_software_update(state, bytes, params);
-
-            // This should never happen, but just in case
-            panic!("x86 features missing (SSE4.1 && PCLMULQDQ)");
-        }
+        #[cfg(target_arch = "x86_64")]
+        ArchOpsInstance::X86_64SseGfni(ops) => ops.update(state, bytes, params),
+        ArchOpsInstance::X86SseCrc32c => x86_sse_crc32c_update(state, bytes, params),
+        ArchOpsInstance::SoftwareFallback => x86_software_update(state, bytes, params),
+        #[cfg(feature = "portable-simd")]
+        ArchOpsInstance::PortableSimd(ops) => ops.update(state, bytes, params),
+        ArchOpsInstance::Custom(backend) => backend.update(state, bytes, params),
     }
 }
 
-/// Main entry point for x86/x86_64 (Rust < 1.89 with no AVX-512 support)
+/// Dispatches to the specified architecture-specific implementation for x86/x86_64 (Rust < 1.89
+/// with no AVX-512 support)
 ///
 /// # Safety
-/// May use native CPU features
+/// May use native CPU features, so the instance must be supported by the current CPU
 #[rustversion::before(1.89)]
 #[inline(always)]
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-pub(crate) unsafe fn update(state: u64, bytes: &[u8], params: CrcParams) -> u64 {
-    use crate::feature_detection::{get_arch_ops, ArchOpsInstance};
+pub(crate) unsafe fn update_with_arch_ops(
+    state: u64,
+    bytes: &[u8],
+    params: CrcParams,
+    arch_ops: &crate::feature_detection::ArchOpsInstance,
+) -> u64 {
+    use crate::feature_detection::ArchOpsInstance;
 
-    match get_arch_ops() {
+    match arch_ops {
         ArchOpsInstance::X86SsePclmulqdq(ops) => match params.width {
+            #[cfg(not(feature = "crc32-only"))]
             64 => algorithm::update::<_, Width64>(state, bytes, params, ops),
+            #[cfg(not(feature = "crc64-only"))]
             32 => algorithm::update::<_, Width32>(state as u32, bytes, params, ops) as u64,
             _ => panic!("Unsupported CRC width: {}", params.width),
         },
+        ArchOpsInstance::X86SseCrc32c => x86_sse_crc32c_update(state, bytes, params),
         ArchOpsInstance::SoftwareFallback => x86_software_update(state, bytes, params),
+        #[cfg(feature = "portable-simd")]
+        ArchOpsInstance::PortableSimd(ops) => ops.update(state, bytes, params),
+        ArchOpsInstance::Custom(backend) => backend.update(state, bytes, params),
     }
 }
 
+/// Dispatches N independent updates, interleaved where possible, to the specified
+/// architecture-specific implementation for x86/x86_64 (Rust 1.89+ which supports AVX-512)
+///
+/// # Safety
+/// May use native CPU features, so the instance must be supported by the current CPU
+#[rustversion::since(1.89)]
 #[inline(always)]
-#[allow(unused)]
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-fn x86_software_update(state: u64, bytes: &[u8], params: CrcParams) -> u64 {
-    if !is_x86_feature_detected!("sse4.1") || !is_x86_feature_detected!("pclmulqdq") {
-        #[cfg(all(
-            target_arch = "x86",
-            any(not(target_feature = "sse4.1"), not(target_feature = "pclmulqdq"))
-        ))]
-        {
-            // Use software implementation when no SIMD support is available
-            crate::arch::software::update(state, bytes, params);
+pub(crate) unsafe fn update_multi_with_arch_ops<const N: usize>(
+    states: [u64; N],
+    buffers: [&[u8]; N],
+    params: CrcParams,
+    arch_ops: &crate::feature_detection::ArchOpsInstance,
+) -> [u64; N] {
+    use crate::feature_detection::ArchOpsInstance;
+
+    match arch_ops {
+        // 512-bit folding already hides the latency, and is faster than interleaving 128-bit folds
+        #[cfg(target_arch = "x86_64")]
+        ArchOpsInstance::X86_64Avx512Vpclmulqdq(_) => {
+            update_multi_sequential(states, buffers, params, arch_ops)
         }
+        // the SSE ops inline into the interleaved loop, which is faster than the AVX-512 ones
+        #[cfg(target_arch = "x86_64")]
+        ArchOpsInstance::X86_64Avx512Pclmulqdq(_) => {
+            use crate::arch::x86::sse::X86SsePclmulqdqOps;
+            update_multi_simd(states, buffers, params, &X86SsePclmulqdqOps)
+        }
+        ArchOpsInstance::X86SsePclmulqdq(ops) => update_multi_simd(states, buffers, params, ops),
+        _ => update_multi_sequential(states, buffers, params, arch_ops),
+    }
+}
+
+/// Dispatches N independent updates, interleaved where possible, to the specified
+/// architecture-specific implementation for x86/x86_64 (Rust < 1.89 with no AVX-512 support)
+///
+/// # Safety
+/// May use native CPU features, so the instance must be supported by the current CPU
+#[rustversion::before(1.89)]
+#[inline(always)]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub(crate) unsafe fn update_multi_with_arch_ops<const N: usize>(
+    states: [u64; N],
+    buffers: [&[u8]; N],
+    params: CrcParams,
+    arch_ops: &crate::feature_detection::ArchOpsInstance,
+) -> [u64; N] {
+    use crate::feature_detection::ArchOpsInstance;
+
+    match arch_ops {
+        ArchOpsInstance::X86SsePclmulqdq(ops) => update_multi_simd(states, buffers, params, ops),
+        _ => update_multi_sequential(states, buffers, params, arch_ops),
+    }
+}
+
+/// Interleaves N independent updates using the generic SIMD algorithm
+///
+/// # Safety
+/// May use native CPU features, so the ops must be supported by the current CPU
+#[inline(always)]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+unsafe fn update_multi_simd<T: ArchOps, const N: usize>(
+    states: [u64; N],
+    buffers: [&[u8]; N],
+    params: CrcParams,
+    ops: &T,
+) -> [u64; N]
+where
+    T::Vector: Copy,
+{
+    match params.width {
+        #[cfg(not(feature = "crc32-only"))]
+        64 => algorithm::update_multi::<_, Width64, N>(states, buffers, params, ops),
+        #[cfg(not(feature = "crc64-only"))]
+        32 => algorithm::update_multi::<_, Width32, N>(
+            states.map(|state| state as u32),
+            buffers,
+            params,
+            ops,
+        )
+        .map(u64::from),
+        _ => panic!("Unsupported CRC width: {}", params.width),
     }
+}
 
-    // This should never happen, but just in case
-    panic!("x86 features missing (SSE4.1 && PCLMULQDQ)");
+/// Uses the native CRC-32C instruction for CRC-32C parameters, and the software fallback for
+/// everything else, for CPUs with SSE4.2 but no PCLMULQDQ
+///
+/// # Safety
+/// Uses the SSE4.2 CRC-32C instruction, so it must be supported by the current CPU
+#[inline(always)]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+unsafe fn x86_sse_crc32c_update(state: u64, bytes: &[u8], params: CrcParams) -> u64 {
+    // the state is the raw CRC register, so any reflected CRC-32C parameters can use it
+    #[cfg(not(feature = "crc64-only"))]
+    if params.width == 32 && params.refin && params.poly == CRC32_ISCSI.poly {
+        return crate::crc32::native::crc32_iscsi(state as u32, bytes) as u64;
+    }
+
+    x86_software_update(state, bytes, params)
+}
+
+#[inline(always)]
+#[allow(unused)]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn x86_software_update(state: u64, bytes: &[u8], params: CrcParams) -> u64 {
+    // Use software implementation when no SIMD support is available (or it's been disabled)
+    #[cfg(any(
+        not(target_feature = "sse4.1"),
+        not(target_feature = "pclmulqdq"),
+        test
+    ))]
+    return crate::arch::software::update(state, bytes, params);
+
+    // This should never happen, since the SIMD tiers are always available, but just in case
+    #[allow(unreachable_code)]
+    {
+        panic!("x86 features missing (SSE4.1 && PCLMULQDQ)")
+    }
 }
 
 #[inline]
@@ -169,13 +416,79 @@ fn x86_software_update(state: u64, bytes: &[u8], params: CrcParams) -> u64 {
     not(target_arch = "aarch64")
 ))]
 pub(crate) unsafe fn update(state: u64, bytes: &[u8], params: CrcParams) -> u64 {
-    crate::arch::software::update(state, bytes, params)
+    update_with_arch_ops(
+        state,
+        bytes,
+        params,
+        crate::feature_detection::get_arch_ops(),
+    )
+}
+
+#[inline]
+#[cfg(all(
+    not(target_arch = "x86"),
+    not(target_arch = "x86_64"),
+    not(target_arch = "aarch64")
+))]
+pub(crate) unsafe fn update_with_arch_ops(
+    state: u64,
+    bytes: &[u8],
+    params: CrcParams,
+    arch_ops: &crate::feature_detection::ArchOpsInstance,
+) -> u64 {
+    use crate::feature_detection::ArchOpsInstance;
+
+    match arch_ops {
+        #[cfg(feature = "portable-simd")]
+        ArchOpsInstance::PortableSimd(ops) => ops.update(state, bytes, params),
+        ArchOpsInstance::Custom(backend) => backend.update(state, bytes, params),
+        _ => crate::arch::software::update(state, bytes, params),
+    }
+}
+
+/// Dispatches N independent updates to the specified implementation for other architectures
+///
+/// # Safety
+/// May use native CPU features
+#[inline]
+#[cfg(all(
+    not(target_arch = "x86"),
+    not(target_arch = "x86_64"),
+    not(target_arch = "aarch64")
+))]
+pub(crate) unsafe fn update_multi_with_arch_ops<const N: usize>(
+    states: [u64; N],
+    buffers: [&[u8]; N],
+    params: CrcParams,
+    arch_ops: &crate::feature_detection::ArchOpsInstance,
+) -> [u64; N] {
+    update_multi_sequential(states, buffers, params, arch_ops)
+}
+
+/// Updates N independent states one at a time, for implementations which can't be interleaved
+///
+/// # Safety
+/// May use native CPU features, so the instance must be supported by the current CPU
+#[inline(always)]
+unsafe fn update_multi_sequential<const N: usize>(
+    mut states: [u64; N],
+    buffers: [&[u8]; N],
+    params: CrcParams,
+    arch_ops: &crate::feature_detection::ArchOpsInstance,
+) -> [u64; N] {
+    for (state, buffer) in states.iter_mut().zip(buffers) {
+        *state = update_with_arch_ops(*state, buffer, params, arch_ops);
+    }
+
+    states
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(not(feature = "crc64-only"))]
     use crate::crc32::consts::CRC32_BZIP2;
+    #[cfg(not(feature = "crc32-only"))]
     use crate::crc64::consts::CRC64_NVME;
     use crate::test::consts::{TEST_256_BYTES_STRING, TEST_ALL_CONFIGS, TEST_CHECK_STRING};
     use crate::test::create_aligned_data;
@@ -208,7 +521,7 @@ mod tests {
             let actual = unsafe {
                 update(
                     config.get_init(),
-                    &*create_aligned_data(TEST_256_BYTES_STRING),
+                    &create_aligned_data(TEST_256_BYTES_STRING),
                     *config.get_params(),
                 ) ^ config.get_xorout()
             };
@@ -232,7 +545,7 @@ mod tests {
             let actual = unsafe {
                 update(
                     config.get_init(),
-                    &*create_aligned_data(test_string),
+                    &create_aligned_data(test_string),
                     *config.get_params(),
                 ) ^ config.get_xorout()
             };
@@ -256,7 +569,7 @@ mod tests {
             let actual = unsafe {
                 update(
                     config.get_init(),
-                    &*create_aligned_data(test_string),
+                    &create_aligned_data(test_string),
                     *config.get_params(),
                 ) ^ config.get_xorout()
             };
@@ -275,36 +588,9 @@ mod tests {
     // CRC-64/NVME is a special flower in that Rust's crc library doesn't support it yet, so we have
     // tested values to check against.
     #[test]
+    #[cfg(not(feature = "crc32-only"))]
     fn test_crc64_nvme_standard_vectors() {
-        static CASES: &[(&[u8], u64)] = &[
-            // from our own internal tests, since the Check value in the NVM Express® NVM Command
-            // Set Specification (Revision 1.0d, December 2023) is incorrect
-            // (Section 5.2.1.3.4, Figure 120, page 83).
-            (b"123456789", 0xae8b14860a799888),
-
-            // from the NVM Express® NVM Command Set Specification (Revision 1.0d, December 2023),
-            // Section 5.2.1.3.5, Figure 122, page 84.
-            // https://nvmexpress.org/wp-content/uploads/NVM-Express-NVM-Command-Set-Specification-1.0d-2023.12.28-Ratified.pdf
-            // and the Linux kernel
-            // https://github.com/torvalds/linux/blob/f3813f4b287e480b1fcd62ca798d8556644b8278/crypto/testmgr.h#L3685-L3695
-            (&[0; 4096], 0x6482d367eb22b64e),
-            (&[255; 4096], 0xc0ddba7302eca3ac),
-
-            // custom values
-            (TEST_256_BYTES_STRING, 0xabdb9e6c30937916),
-            (b"", 0),
-            (b"@", 0x2808afa9582aa47),
-            (b"1\x97", 0xb4af0ae0feb08e0f),
-            (b"M\"\xdf", 0x85d7cd041a2a8a5d),
-            (b"l\xcd\x13\xd7", 0x1860820ea79b0fa3),
-            (&[0; 32], 0xcf3473434d4ecf3b),
-            (&[255; 32], 0xa0a06974c34d63c4),
-            (b"\x00\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0A\x0B\x0C\x0D\x0E\x0F\x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1A\x1B\x1C\x1D\x1E\x1F", 0xb9d9d4a8492cbd7f),
-            (&[0; 1024], 0x691bb2b09be5498a),
-            (b"hello, world!", 0xf8046e40c403f1d0),
-        ];
-
-        for (input, expected) in CASES {
+        for (input, expected) in crate::test_vectors::CRC64_NVME_VECTORS {
             unsafe {
                 let actual = update(CRC64_NVME.init, input, CRC64_NVME) ^ CRC64_NVME.xorout;
 
@@ -323,15 +609,9 @@ mod tests {
     ///
     /// https://www.php.net/manual/en/function.hash-file.php#104836
     #[test]
+    #[cfg(not(feature = "crc64-only"))]
     fn test_crc32_php_standard_vectors() {
-        static CASES: &[(&[u8], u64)] = &[
-            (b"123456789", 0x181989fc),
-            (&[0; 4096], 0xe3380088),
-            (&[255; 4096], 0x8f2ae650),
-            (b"hello, world!", 0x5eacce7),
-        ];
-
-        for (input, expected) in CASES {
+        for (input, expected) in crate::test_vectors::PHP_CRC32_VECTORS {
             let bzip2_crc = unsafe {
                 (update(CRC32_BZIP2.init, input, CRC32_BZIP2) ^ CRC32_BZIP2.xorout) as u32
             };
@@ -340,7 +620,7 @@ mod tests {
             let actual = bzip2_crc.swap_bytes();
 
             assert_eq!(
-                actual, *expected as u32,
+                actual, *expected,
                 "Mismatch CRC, expected {:#x}, got {:#x}, input: {:?}",
                 expected, actual, input
             );
@@ -369,6 +649,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_512_byte_folding_lengths() {
+        for config in TEST_ALL_CONFIGS {
+            // Test each length from 1024 to 2560, which should fold at 512-byte distances (where
+            // supported) and include handling the 256-byte and 128-byte remainders
+            for len in 1024..=2560 {
+                test_length(len, config);
+            }
+        }
+    }
+
     #[test]
     fn test_large_lengths() {
         // Test each CRC-64 variant
@@ -380,6 +671,34 @@ mod tests {
         }
     }
 
+    /// Distinct bytes at every offset within each 64-bit word and 128-bit lane, so a load in the
+    /// wrong byte order (such as on big-endian targets) can't produce a matching checksum
+    #[test]
+    fn test_byte_order() {
+        let data: Vec<u8> = (0..512u32).map(|i| (i * 167 + 13) as u8).collect();
+
+        for config in TEST_ALL_CONFIGS {
+            for offset in 0..8 {
+                for len in 0..=(data.len() - offset) {
+                    let bytes = &data[offset..offset + len];
+
+                    let actual = unsafe {
+                        update(config.get_init(), bytes, *config.get_params()) ^ config.get_xorout()
+                    };
+
+                    assert_eq!(
+                        actual,
+                        config.checksum_with_reference(bytes),
+                        "Failed for {} with offset {} and length {}",
+                        config.get_name(),
+                        offset,
+                        len
+                    );
+                }
+            }
+        }
+    }
+
     fn test_length(length: usize, config: &AnyCrcTestConfig) {
         let mut data = vec![0u8; length];
         rng().fill(&mut data[..]);
@@ -401,4 +720,37 @@ mod tests {
             expected
         );
     }
+
+    #[test]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+    fn test_update_multi_simd() {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        let ops = crate::arch::x86::sse::X86SsePclmulqdqOps;
+        #[cfg(target_arch = "aarch64")]
+        let ops = Aarch64AesOps;
+
+        if crate::feature_detection::get_arch_ops().get_tier()
+            == crate::feature_detection::PerformanceTier::SoftwareTable
+        {
+            return;
+        }
+
+        let mut data = vec![0u8; 4096];
+        rng().fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            // the leading 2 blocks of each are interleaved, then each continues with update()
+            let buffers = [&data[..2048], &data[5..1029], &data[7..300], &data[..256]];
+            let actual = unsafe { update_multi_simd([params.init; 4], buffers, params, &ops) };
+
+            assert_eq!(
+                actual.map(|state| state ^ params.xorout),
+                buffers.map(|buffer| config.checksum_with_reference(buffer)),
+                "Failed for {}",
+                config.get_name()
+            );
+        }
+    }
 }
@@ -0,0 +1,203 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides a portable SIMD implementation using `std::simd` (nightly only), for
+//! architectures without a hand-written intrinsics path.
+//!
+//! `std::simd` has no carryless multiplication, so rather than folding, the input is split into 8
+//! interleaved streams (the Nth 8-byte word of every 64-byte block belongs to stream N), one per
+//! lane, and every stream is updated at once using slice-by-8 table gathers. The streams are
+//! merged serially at the end.
+
+#![cfg(feature = "portable-simd")]
+
+use crate::cache::CrcParamsCacheKey;
+use crate::generate::reflected_word_bit_images;
+use crate::CrcParams;
+use std::simd::num::SimdUint;
+use std::simd::{u64x8, usizex8};
+use std::sync::Arc;
+
+/// Portable SIMD tier, which doesn't implement the ArchOps folding operations since `std::simd`
+/// can't do carryless multiplication
+#[derive(Debug, Default, Copy, Clone)]
+pub struct PortableSimdOps;
+
+impl PortableSimdOps {
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Updates a raw CRC state with `data`
+    #[inline]
+    pub fn update(&self, state: u64, data: &[u8], params: CrcParams) -> u64 {
+        let width = params.width as u32;
+        if width != 32 && width != 64 {
+            panic!("Unsupported CRC width: {}", params.width);
+        }
+
+        let tables = get_or_generate_tables(params);
+
+        // the tables are always for reflected registers, so the input bytes are reflected for
+        // non-reflected parameters
+        if params.refin {
+            update_reflected(state, data, &tables, true)
+        } else {
+            let state = state.reverse_bits() >> (64 - width);
+
+            update_reflected(state, data, &tables, false).reverse_bits() >> (64 - width)
+        }
+    }
+}
+
+/// Slice-by-8 tables for a polynomial, in reflected form, indexed by input byte * 256 + value
+struct PortableSimdTables {
+    /// One word of a stream, followed by the 56 bytes of the other streams
+    block: Box<[u64; 2048]>,
+    /// A single word
+    word: Box<[u64; 2048]>,
+}
+
+/// Gets the cached tables for the parameters, or generates and caches them if not present, in
+/// the key cache so they're bounded and cleared along with the keys
+fn get_or_generate_tables(params: CrcParams) -> Arc<PortableSimdTables> {
+    crate::cache::get_or_generate_tables(
+        CrcParamsCacheKey::new(params.width, params.poly, params.refin),
+        || PortableSimdTables {
+            block: images_to_table(&reflected_word_bit_images(
+                params.width,
+                params.poly,
+                56 * 8,
+            )),
+            word: images_to_table(&reflected_word_bit_images(params.width, params.poly, 0)),
+        },
+    )
+}
+
+/// Expands the images of each input bit into a table for each input byte
+fn images_to_table(images: &[u64; 64]) -> Box<[u64; 2048]> {
+    let mut table = Box::new([0u64; 2048]);
+
+    for (index, entry) in table.iter_mut().enumerate() {
+        let (byte, value) = (index / 256, index % 256);
+
+        *entry = (0..8)
+            .filter(|bit| value & (1 << bit) != 0)
+            .fold(0, |entry, bit| entry ^ images[byte * 8 + bit]);
+    }
+
+    table
+}
+
+/// Updates a reflected CRC register, reflecting each byte of `data` first if `reflected` is false
+fn update_reflected(
+    mut state: u64,
+    data: &[u8],
+    tables: &PortableSimdTables,
+    reflected: bool,
+) -> u64 {
+    let mut rest = data;
+
+    // the streams are only worth merging if there's at least one block before the last
+    if data.len() >= 128 {
+        let (bulk, tail) = data.split_at((data.len() / 64 - 1) * 64);
+
+        // only the first stream continues the state
+        let mut streams = u64x8::from_array([state, 0, 0, 0, 0, 0, 0, 0]);
+
+        let offsets = usizex8::from_array([0, 256, 512, 768, 1024, 1280, 1536, 1792]);
+        let byte_mask = u64x8::splat(0xff);
+
+        for block in bulk.chunks_exact(64) {
+            let words = u64x8::from_array(core::array::from_fn(|stream| {
+                read_word(&block[stream * 8..stream * 8 + 8], reflected)
+            }));
+            let words = words ^ streams;
+
+            streams = u64x8::splat(0);
+            for byte in 0..8 {
+                let values = ((words >> u64x8::splat(byte as u64 * 8)) & byte_mask).cast();
+                let indexes = values + usizex8::splat(offsets[byte]);
+
+                streams ^= u64x8::gather_or_default(&tables.block[..], indexes);
+            }
+        }
+
+        // each stream's register is added to its word in the last block, which is then processed
+        // serially to merge them
+        state = 0;
+        for (register, word) in streams
+            .to_array()
+            .into_iter()
+            .zip(tail[..64].chunks_exact(8))
+        {
+            state = update_word(state ^ register ^ read_word(word, reflected), tables);
+        }
+
+        rest = &tail[64..];
+    }
+
+    let mut words = rest.chunks_exact(8);
+    for word in &mut words {
+        state = update_word(state ^ read_word(word, reflected), tables);
+    }
+
+    // the table for the last byte of a word has nothing after it, so it's the byte-wise table
+    for &byte in words.remainder() {
+        let byte = if reflected { byte } else { byte.reverse_bits() };
+        state = (state >> 8) ^ tables.word[7 * 256 + ((state ^ byte as u64) & 0xff) as usize];
+    }
+
+    state
+}
+
+/// Updates a reflected CRC register, which has already been added to the word, with one word
+#[inline(always)]
+fn update_word(word: u64, tables: &PortableSimdTables) -> u64 {
+    (0..8).fold(0, |state, byte| {
+        state ^ tables.word[byte * 256 + ((word >> (byte * 8)) & 0xff) as usize]
+    })
+}
+
+/// Reads a little-endian word, reflecting each byte if `reflected` is false
+#[inline(always)]
+fn read_word(word: &[u8], reflected: bool) -> u64 {
+    let word = u64::from_le_bytes(word.try_into().unwrap());
+
+    if reflected {
+        word
+    } else {
+        word.reverse_bits().swap_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_portable_simd_matches_reference() {
+        let mut data = vec![0u8; 4096 + 7];
+        rng().fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            for len in (0..600).chain([1024, 4093, 4096]) {
+                let data = &data[7..7 + len];
+
+                let actual = PortableSimdOps.update(params.init, data, params);
+
+                assert_eq!(
+                    actual ^ params.xorout,
+                    config.checksum_with_reference(data),
+                    "Mismatch CRC, {}, length {}",
+                    config.get_name(),
+                    len
+                );
+            }
+        }
+    }
+}
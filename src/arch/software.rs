@@ -4,163 +4,184 @@
 //!
 //! Software fallback is conditionally compiled based on target architecture:
 //! - Always included for non-SIMD architectures (not x86/x86_64/aarch64)
-//! - Included for x86 when SSE4.1/PCLMULQDQ may not be available
-//! - Included for aarch64 for runtime fallback when AES is not detected
-//! - Excluded for x86_64 since SSE4.1/PCLMULQDQ are always available (but included for testing)
+//! - Included for x86 and x86_64 when SSE4.1/PCLMULQDQ may not be available
+//! - Included for aarch64 for runtime fallback when AES is not detected, and always on big-endian
+//! - Excluded when SSE4.1/PCLMULQDQ are enabled at compile time (but included for testing)
 
 #![cfg(any(
     // Non-aarch64/x86/x86_64 architectures always need software fallback
     not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")),
-    // x86 may not have SSE4.1/PCLMULQDQ support
-    all(target_arch = "x86", any(not(target_feature = "sse4.1"), not(target_feature = "pclmulqdq"))),
+    // x86 and x86_64 may not have SSE4.1/PCLMULQDQ support (e.g. virtualized environments which
+    // mask PCLMULQDQ)
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        any(not(target_feature = "sse4.1"), not(target_feature = "pclmulqdq"))
+    ),
     // aarch64 needs software fallback for runtime detection when AES is not available...
     // NEON doesn't guarantee AES, so for rare outlier CPUs this might not work 100%...
     all(target_arch = "aarch64", not(target_feature = "aes")),
+    // ...and big-endian aarch64 always uses it, since the SIMD kernels assume little-endian loads
+    all(target_arch = "aarch64", target_endian = "big"),
     // Include for testing on all architectures
     test
 ))]
 
+use crate::CrcParams;
+
+#[cfg(any(feature = "tiny", test))]
+use crate::bitwise::update as update_bitwise;
+
+#[cfg(not(feature = "tiny"))]
+use crate::cache::{get_or_generate_tables, CrcParamsCacheKey};
+#[cfg(not(feature = "tiny"))]
 use crate::consts::CRC_64_NVME;
+#[cfg(not(feature = "tiny"))]
 use crate::CrcAlgorithm;
-use crate::CrcParams;
-use crc::{Algorithm, Table};
+#[cfg(not(feature = "tiny"))]
+use crc::Table;
+
+/// Slice-by-16 lookup tables for custom parameters, where `tables[k][byte]` is the CRC register
+/// after `byte` followed by `k` zero bytes
+///
+/// Both widths share a 64-bit register: reflected CRCs in its bottom bits, shifting right, and
+/// forward CRCs in its top bits, shifting left, so the same tables and loop handle both.
+#[cfg(not(feature = "tiny"))]
+struct CustomTables {
+    tables: [[u64; 256]; 16],
+}
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC32_AIXM: crc::Crc<u32, Table<16>> =
     crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_AIXM);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC32_AUTOSAR: crc::Crc<u32, Table<16>> =
     crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_AUTOSAR);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC32_BASE91_D: crc::Crc<u32, Table<16>> =
     crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_BASE91_D);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC32_BZIP2: crc::Crc<u32, Table<16>> =
     crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_BZIP2);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC32_CD_ROM_EDC: crc::Crc<u32, Table<16>> =
     crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_CD_ROM_EDC);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC32_CKSUM: crc::Crc<u32, Table<16>> =
     crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_CKSUM);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC32_ISCSI: crc::Crc<u32, Table<16>> =
     crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_ISCSI);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC32_ISO_HDLC: crc::Crc<u32, Table<16>> =
     crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_ISO_HDLC);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC32_JAMCRC: crc::Crc<u32, Table<16>> =
     crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_JAMCRC);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC32_MEF: crc::Crc<u32, Table<16>> = crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_MEF);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC32_MPEG_2: crc::Crc<u32, Table<16>> =
     crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_MPEG_2);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC32_XFER: crc::Crc<u32, Table<16>> =
     crc::Crc::<u32, Table<16>>::new(&crc::CRC_32_XFER);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC64_ECMA_182: crc::Crc<u64, Table<16>> =
     crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_ECMA_182);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC64_GO_ISO: crc::Crc<u64, Table<16>> =
     crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_GO_ISO);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC64_MS: crc::Crc<u64, Table<16>> = crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_MS);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC64_NVME: crc::Crc<u64, Table<16>> = crc::Crc::<u64, Table<16>>::new(&CRC_64_NVME);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC64_REDIS: crc::Crc<u64, Table<16>> =
     crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_REDIS);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC64_WE: crc::Crc<u64, Table<16>> = crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_WE);
 
 #[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 const RUST_CRC64_XZ: crc::Crc<u64, Table<16>> = crc::Crc::<u64, Table<16>>::new(&crc::CRC_64_XZ);
 
+/// Calculates the CRC one bit at a time, with no lookup tables, for the `tiny` feature
 #[allow(unused)]
+#[cfg(feature = "tiny")]
+pub(crate) fn update(state: u64, data: &[u8], params: CrcParams) -> u64 {
+    update_bitwise(state, data, params)
+}
+
+#[allow(unused)]
+#[cfg(not(feature = "tiny"))]
 // Dispatch function that handles the generic case
 pub(crate) fn update(state: u64, data: &[u8], params: CrcParams) -> u64 {
     match params.width {
+        #[cfg(not(feature = "crc64-only"))]
         32 => {
             let params = match params.algorithm {
-                CrcAlgorithm::Crc32Aixm => RUST_CRC32_AIXM,
-                CrcAlgorithm::Crc32Autosar => RUST_CRC32_AUTOSAR,
-                CrcAlgorithm::Crc32Base91D => RUST_CRC32_BASE91_D,
-                CrcAlgorithm::Crc32Bzip2 => RUST_CRC32_BZIP2,
-                CrcAlgorithm::Crc32CdRomEdc => RUST_CRC32_CD_ROM_EDC,
-                CrcAlgorithm::Crc32Cksum => RUST_CRC32_CKSUM,
-                CrcAlgorithm::Crc32Iscsi => RUST_CRC32_ISCSI,
-                CrcAlgorithm::Crc32IsoHdlc => RUST_CRC32_ISO_HDLC,
-                CrcAlgorithm::Crc32Jamcrc => RUST_CRC32_JAMCRC,
-                CrcAlgorithm::Crc32Mef => RUST_CRC32_MEF,
-                CrcAlgorithm::Crc32Mpeg2 => RUST_CRC32_MPEG_2,
-                CrcAlgorithm::Crc32Xfer => RUST_CRC32_XFER,
-                CrcAlgorithm::Crc32Custom => {
-                    let algorithm: Algorithm<u32> = Algorithm {
-                        width: params.width,
-                        poly: params.poly as u32,
-                        init: params.init as u32,
-                        refin: params.refin,
-                        refout: params.refout,
-                        xorout: params.xorout as u32,
-                        check: params.check as u32,
-                        residue: 0x00000000, // unused in this context
-                    };
-
-                    // ugly, but the crc crate is difficult to work with...
-                    let static_algorithm = Box::leak(Box::new(algorithm));
-
-                    crc::Crc::<u32, Table<16>>::new(static_algorithm)
-                }
+                CrcAlgorithm::Crc32Aixm => &RUST_CRC32_AIXM,
+                CrcAlgorithm::Crc32Autosar => &RUST_CRC32_AUTOSAR,
+                CrcAlgorithm::Crc32Base91D => &RUST_CRC32_BASE91_D,
+                CrcAlgorithm::Crc32Bzip2 => &RUST_CRC32_BZIP2,
+                CrcAlgorithm::Crc32CdRomEdc => &RUST_CRC32_CD_ROM_EDC,
+                CrcAlgorithm::Crc32Cksum => &RUST_CRC32_CKSUM,
+                CrcAlgorithm::Crc32Iscsi => &RUST_CRC32_ISCSI,
+                CrcAlgorithm::Crc32IsoHdlc => &RUST_CRC32_ISO_HDLC,
+                CrcAlgorithm::Crc32Jamcrc => &RUST_CRC32_JAMCRC,
+                CrcAlgorithm::Crc32Mef => &RUST_CRC32_MEF,
+                CrcAlgorithm::Crc32Mpeg2 => &RUST_CRC32_MPEG_2,
+                CrcAlgorithm::Crc32Xfer => &RUST_CRC32_XFER,
+                CrcAlgorithm::Crc32Custom => return update_custom(state, data, params),
                 _ => panic!("Invalid algorithm for u32 CRC"),
             };
             update_u32(state as u32, data, params) as u64
         }
         64 => {
             let params = match params.algorithm {
-                CrcAlgorithm::Crc64Ecma182 => RUST_CRC64_ECMA_182,
-                CrcAlgorithm::Crc64GoIso => RUST_CRC64_GO_ISO,
-                CrcAlgorithm::Crc64Ms => RUST_CRC64_MS,
-                CrcAlgorithm::Crc64Nvme => RUST_CRC64_NVME,
-                CrcAlgorithm::Crc64Redis => RUST_CRC64_REDIS,
-                CrcAlgorithm::Crc64We => RUST_CRC64_WE,
-                CrcAlgorithm::Crc64Xz => RUST_CRC64_XZ,
-                CrcAlgorithm::Crc64Custom => {
-                    let algorithm: Algorithm<u64> = Algorithm {
-                        width: params.width,
-                        poly: params.poly,
-                        init: params.init,
-                        refin: params.refin,
-                        refout: params.refout,
-                        xorout: params.xorout,
-                        check: params.check,
-                        residue: 0x0000000000000000, // unused in this context
-                    };
-
-                    // ugly, but the crc crate is difficult to work with...
-                    let static_algorithm = Box::leak(Box::new(algorithm));
-
-                    crc::Crc::<u64, Table<16>>::new(static_algorithm)
-                }
+                CrcAlgorithm::Crc64Ecma182 => &RUST_CRC64_ECMA_182,
+                CrcAlgorithm::Crc64GoIso => &RUST_CRC64_GO_ISO,
+                CrcAlgorithm::Crc64Ms => &RUST_CRC64_MS,
+                CrcAlgorithm::Crc64Nvme => &RUST_CRC64_NVME,
+                CrcAlgorithm::Crc64Redis => &RUST_CRC64_REDIS,
+                CrcAlgorithm::Crc64We => &RUST_CRC64_WE,
+                CrcAlgorithm::Crc64Xz => &RUST_CRC64_XZ,
+                CrcAlgorithm::Crc64Custom => return update_custom(state, data, params),
                 _ => panic!("Invalid algorithm for u64 CRC"),
             };
             update_u64(state, data, params)
@@ -169,8 +190,110 @@ pub(crate) fn update(state: u64, data: &[u8], params: CrcParams) -> u64 {
     }
 }
 
+#[cfg(not(feature = "tiny"))]
+impl CustomTables {
+    /// Generates the tables for a polynomial, which are independent of init and xorout, so they're
+    /// shared by every parameter set with the same polynomial
+    fn new(width: u8, poly: u64, reflected: bool) -> Self {
+        let width = width as u32;
+        let mut tables = [[0u64; 256]; 16];
+
+        if reflected {
+            let poly = poly.reverse_bits() >> (64 - width);
+            for (byte, entry) in tables[0].iter_mut().enumerate() {
+                let mut crc = byte as u64;
+                for _ in 0..8 {
+                    crc = if crc & 1 == 1 {
+                        (crc >> 1) ^ poly
+                    } else {
+                        crc >> 1
+                    };
+                }
+                *entry = crc;
+            }
+
+            for k in 1..16 {
+                for byte in 0..256 {
+                    let crc = tables[k - 1][byte];
+                    tables[k][byte] = (crc >> 8) ^ tables[0][(crc & 0xff) as usize];
+                }
+            }
+        } else {
+            let poly = poly << (64 - width);
+            for (byte, entry) in tables[0].iter_mut().enumerate() {
+                let mut crc = (byte as u64) << 56;
+                for _ in 0..8 {
+                    crc = if crc & (1 << 63) != 0 {
+                        (crc << 1) ^ poly
+                    } else {
+                        crc << 1
+                    };
+                }
+                *entry = crc;
+            }
+
+            for k in 1..16 {
+                for byte in 0..256 {
+                    let crc = tables[k - 1][byte];
+                    tables[k][byte] = (crc << 8) ^ tables[0][(crc >> 56) as usize];
+                }
+            }
+        }
+
+        Self { tables }
+    }
+}
+
+#[cfg(not(feature = "tiny"))]
+/// Updates the state for custom parameters, with lookup tables from the key cache, so they're
+/// bounded and cleared along with the keys
+fn update_custom(state: u64, data: &[u8], params: CrcParams) -> u64 {
+    let tables = get_or_generate_tables(
+        CrcParamsCacheKey::new(params.width, params.poly, params.refin),
+        || CustomTables::new(params.width, params.poly, params.refin),
+    );
+    let tables = &tables.tables;
+
+    // forward CRCs are kept in the top bits of the register
+    let shift = if params.refin {
+        0
+    } else {
+        64 - params.width as u32
+    };
+    let mut crc = state << shift;
+
+    let mut chunks = data.chunks_exact(16);
+    for chunk in &mut chunks {
+        let (head, tail) = chunk.split_at(8);
+        let head: [u8; 8] = head.try_into().unwrap();
+
+        // the register overlaps the first (up to) 8 bytes, in the order they're shifted out
+        let head = if params.refin {
+            (crc ^ u64::from_le_bytes(head)).to_le_bytes()
+        } else {
+            (crc ^ u64::from_be_bytes(head)).to_be_bytes()
+        };
+
+        crc = 0;
+        for (i, &byte) in head.iter().chain(tail).enumerate() {
+            crc ^= tables[15 - i][byte as usize];
+        }
+    }
+
+    for &byte in chunks.remainder() {
+        crc = if params.refin {
+            (crc >> 8) ^ tables[0][((crc ^ byte as u64) & 0xff) as usize]
+        } else {
+            (crc << 8) ^ tables[0][((crc >> 56) ^ byte as u64) as usize]
+        };
+    }
+
+    crc >> shift
+}
+
+#[cfg(all(not(feature = "tiny"), not(feature = "crc64-only")))]
 // Specific implementation for u32
-fn update_u32(state: u32, data: &[u8], params: crc::Crc<u32, Table<16>>) -> u32 {
+fn update_u32(state: u32, data: &[u8], params: &crc::Crc<u32, Table<16>>) -> u32 {
     // apply REFIN if necessary
     let initial = if params.algorithm.refin {
         state.reverse_bits()
@@ -187,8 +310,9 @@ fn update_u32(state: u32, data: &[u8], params: crc::Crc<u32, Table<16>>) -> u32
     checksum ^ params.algorithm.xorout
 }
 
+#[cfg(not(feature = "tiny"))]
 // Specific implementation for u64
-fn update_u64(state: u64, data: &[u8], params: crc::Crc<u64, Table<16>>) -> u64 {
+fn update_u64(state: u64, data: &[u8], params: &crc::Crc<u64, Table<16>>) -> u64 {
     // apply REFIN if necessary
     let initial = if params.algorithm.refin {
         state.reverse_bits()
@@ -202,3 +326,118 @@ fn update_u64(state: u64, data: &[u8], params: crc::Crc<u64, Table<16>>) -> u64
     // remove XOR since this will be applied in the library Digest::finalize() step instead
     digest.finalize() ^ params.algorithm.xorout
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::{TEST_ALL_CONFIGS, TEST_CHECK_STRING};
+
+    #[test]
+    fn test_custom_params_check_value() {
+        for config in TEST_ALL_CONFIGS {
+            let params = CrcParams::new(
+                "CRC/CUSTOM",
+                config.get_width(),
+                config.get_poly(),
+                config.get_init(),
+                config.get_refin(),
+                config.get_xorout(),
+                config.get_check(),
+            );
+
+            let actual = update(params.init, TEST_CHECK_STRING, params) ^ params.xorout;
+
+            assert_eq!(
+                actual,
+                config.get_check(),
+                "Mismatch CRC, {}, expected {:#x}, got {:#x}",
+                config.get_name(),
+                config.get_check(),
+                actual
+            );
+        }
+    }
+
+    #[test]
+    fn test_bitwise_check_value() {
+        for config in TEST_ALL_CONFIGS {
+            let params = CrcParams::new(
+                "CRC/CUSTOM",
+                config.get_width(),
+                config.get_poly(),
+                config.get_init(),
+                config.get_refin(),
+                config.get_xorout(),
+                config.get_check(),
+            );
+
+            let actual = update_bitwise(params.init, TEST_CHECK_STRING, params) ^ params.xorout;
+
+            assert_eq!(
+                actual,
+                config.get_check(),
+                "Mismatch CRC, {}, expected {:#x}, got {:#x}",
+                config.get_name(),
+                config.get_check(),
+                actual
+            );
+        }
+    }
+
+    #[test]
+    fn test_bitwise_matches_tables() {
+        let data: Vec<u8> = (0..=255u8).cycle().take(1031).collect();
+
+        for config in TEST_ALL_CONFIGS {
+            let params = CrcParams::new(
+                "CRC/CUSTOM",
+                config.get_width(),
+                config.get_poly(),
+                config.get_init(),
+                config.get_refin(),
+                config.get_xorout(),
+                config.get_check(),
+            );
+
+            // split, to check the state carries across updates
+            let (head, tail) = data.split_at(517);
+            let state = update_bitwise(params.init, head, params);
+
+            assert_eq!(
+                update_bitwise(state, tail, params),
+                update(params.init, &data, params),
+                "Mismatch CRC, {}",
+                config.get_name()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "tiny"))]
+    fn test_custom_tables() {
+        // polynomials no other test uses, so their cached tables are only generated here
+        for (width, poly, reflected) in [
+            #[cfg(not(feature = "crc64-only"))]
+            (32, 0x2f7a_64c3, true),
+            #[cfg(not(feature = "crc64-only"))]
+            (32, 0x2f7a_64c3, false),
+            #[cfg(not(feature = "crc32-only"))]
+            (64, 0x2f7a_64c3_91d0_5eb7, true),
+            #[cfg(not(feature = "crc32-only"))]
+            (64, 0x2f7a_64c3_91d0_5eb7, false),
+        ] {
+            let params = CrcParams::new("CRC/TABLES", width, poly, 0, reflected, 0, 0);
+            let data: Vec<u8> = (0..1000u32).map(|i| (i * 97 % 256) as u8).collect();
+
+            for len in [0, 1, 15, 16, 17, 100, 1000] {
+                let state = 0x1234_5678_9abc_def0 & (u64::MAX >> (64 - width));
+
+                assert_eq!(
+                    update(state, &data[..len], params),
+                    update_bitwise(state, &data[..len], params),
+                    "Mismatch CRC, width {width}, reflected {reflected}, length {len}"
+                );
+            }
+        }
+    }
+}
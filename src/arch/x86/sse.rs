@@ -20,7 +20,7 @@ use crate::traits::ArchOps;
 
 /// Base x86/x86_64 SSE+PCLMULQDQ implementation - baseline performance for both architectures
 /// Uses SSE4.1 and PCLMULQDQ instructions
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Default, Copy, Clone)]
 pub struct X86SsePclmulqdqOps;
 
 // Base implementation for x86/x86_64 SSE+PCLMULQDQ tier
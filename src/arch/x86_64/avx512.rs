@@ -17,13 +17,13 @@ use crate::traits::ArchOps;
 /// x86_64-only AVX512+PCLMULQDQ tier - delegates to SSE tier and overrides XOR3 operations
 /// Uses AVX512 ternary logic for XOR3 operations with PCLMULQDQ
 #[rustversion::since(1.89)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Default, Copy, Clone)]
 pub struct X86_64Avx512PclmulqdqOps(X86SsePclmulqdqOps);
 
 #[rustversion::since(1.89)]
 impl X86_64Avx512PclmulqdqOps {
     #[inline(always)]
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self(X86SsePclmulqdqOps)
     }
 }
@@ -27,13 +27,13 @@ use std::ops::BitXor;
 /// Implements the ArchOps trait using 512-bit AVX-512 and VPCLMULQDQ instructions at 512 bits.
 /// Delegates to X86SsePclmulqdqOps for standard 128-bit operations
 #[rustversion::since(1.89)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Default, Copy, Clone)]
 pub struct X86_64Avx512VpclmulqdqOps(X86SsePclmulqdqOps);
 
 #[rustversion::since(1.89)]
 impl X86_64Avx512VpclmulqdqOps {
     #[inline(always)]
-    pub fn new() -> Self {
+    pub const fn new() -> Self {
         Self(X86SsePclmulqdqOps)
     }
 }
@@ -115,7 +115,8 @@ impl Simd512 {
 
 #[rustversion::since(1.89)]
 impl X86_64Avx512VpclmulqdqOps {
-    /// Process aligned blocks using VPCLMULQDQ with 4 x 512-bit registers
+    /// Process aligned blocks using VPCLMULQDQ with 8 x 512-bit registers for large inputs, and
+    /// 4 x 512-bit registers otherwise
     ///
     /// Note that #[inline(always)] loses the inlining performance boost, despite no native
     /// target_features being used directly. Odd since that's not how Rust's docs make it sound...
@@ -126,7 +127,7 @@ impl X86_64Avx512VpclmulqdqOps {
         state: &mut CrcState<<X86_64Avx512VpclmulqdqOps as ArchOps>::Vector>,
         first: &[__m128i; 8],
         rest: &[[__m128i; 8]],
-        keys: [u64; 23],
+        keys: [u64; 25],
         reflected: bool,
     ) -> W::Value
     where
@@ -142,22 +143,44 @@ impl X86_64Avx512VpclmulqdqOps {
 
         let reflector = create_reflector512(reflected);
 
-        // Load first 256 bytes (2nd half is rest[0] since these are 128-byte blocks)
-        let first_ptr = first.as_ptr() as *const u8;
-        let first_rest_ptr = rest[0].as_ptr() as *const u8;
+        // 512-byte folding needs at least 1KiB of input (so the main loop runs at least once),
+        // and the 512-byte distance keys, which legacy 23-key params don't have
+        let (mut x, remaining_rest) = if rest.len() >= 7 && keys[23] != 0 && keys[24] != 0 {
+            let x = self.process_512byte_blocks(
+                positioned_state,
+                &reflector,
+                first,
+                rest,
+                keys,
+                reflected,
+            );
 
-        let mut x = [
-            reflect_bytes512(&reflector, Simd512::load_from_ptr(first_ptr)),
-            reflect_bytes512(&reflector, Simd512::load_from_ptr(first_ptr.add(64))),
-            reflect_bytes512(&reflector, Simd512::load_from_ptr(first_rest_ptr)),
-            reflect_bytes512(&reflector, Simd512::load_from_ptr(first_rest_ptr.add(64))),
-        ];
+            // Fold 8 x 512-bit to 4 x 512-bit, and process the rest using 256-byte distances
+            let remaining_rest = &rest[3 + ((rest.len() - 3) / 4) * 4..];
 
-        x[0] = positioned_state.xor(&x[0]);
+            (
+                self.fold_from_8x512_to_4x512(x, keys, reflected),
+                remaining_rest,
+            )
+        } else {
+            // Load first 256 bytes (2nd half is rest[0] since these are 128-byte blocks)
+            let first_ptr = first.as_ptr() as *const u8;
+            let first_rest_ptr = rest[0].as_ptr() as *const u8;
+
+            let mut x = [
+                reflect_bytes512(&reflector, Simd512::load_from_ptr(first_ptr)),
+                reflect_bytes512(&reflector, Simd512::load_from_ptr(first_ptr.add(64))),
+                reflect_bytes512(&reflector, Simd512::load_from_ptr(first_rest_ptr)),
+                reflect_bytes512(&reflector, Simd512::load_from_ptr(first_rest_ptr.add(64))),
+            ];
+
+            x[0] = positioned_state.xor(&x[0]);
+
+            (x, &rest[1..])
+        };
 
         let coeff = self.create_avx512_256byte_coefficient(keys, reflected);
 
-        let remaining_rest = &rest[1..];
         let pair_count = remaining_rest.len() / 2;
 
         for i in 0..pair_count {
@@ -215,11 +238,70 @@ impl X86_64Avx512VpclmulqdqOps {
         W::perform_final_reduction(folded, reflected, keys, self)
     }
 
+    /// Load the first 512 bytes into 8 x 512-bit registers, then fold each subsequent group of
+    /// four 128-byte blocks into them at 512-byte distances
+    ///
+    /// Keeping 8 independent accumulators in flight better hides the VPCLMULQDQ latency on
+    /// cores with multiple carryless multiply ports (Ice Lake, Zen 4, and newer).
+    ///
+    /// Requires `rest` to contain at least 3 blocks. Any trailing blocks which don't fill a
+    /// complete 512-byte group are left for the caller.
+    #[inline]
+    #[target_feature(enable = "avx512vl,avx512bw,vpclmulqdq")]
+    unsafe fn process_512byte_blocks(
+        &self,
+        positioned_state: Simd512,
+        reflector: &Reflector512,
+        first: &[__m128i; 8],
+        rest: &[[__m128i; 8]],
+        keys: [u64; 25],
+        reflected: bool,
+    ) -> [Simd512; 8] {
+        let block_ptrs = [
+            first.as_ptr() as *const u8,
+            rest[0].as_ptr() as *const u8,
+            rest[1].as_ptr() as *const u8,
+            rest[2].as_ptr() as *const u8,
+        ];
+
+        let mut x = [
+            reflect_bytes512(reflector, Simd512::load_from_ptr(block_ptrs[0])),
+            reflect_bytes512(reflector, Simd512::load_from_ptr(block_ptrs[0].add(64))),
+            reflect_bytes512(reflector, Simd512::load_from_ptr(block_ptrs[1])),
+            reflect_bytes512(reflector, Simd512::load_from_ptr(block_ptrs[1].add(64))),
+            reflect_bytes512(reflector, Simd512::load_from_ptr(block_ptrs[2])),
+            reflect_bytes512(reflector, Simd512::load_from_ptr(block_ptrs[2].add(64))),
+            reflect_bytes512(reflector, Simd512::load_from_ptr(block_ptrs[3])),
+            reflect_bytes512(reflector, Simd512::load_from_ptr(block_ptrs[3].add(64))),
+        ];
+
+        x[0] = positioned_state.xor(&x[0]);
+
+        let coeff = self.create_avx512_512byte_coefficient(keys, reflected);
+
+        for blocks in rest[3..].chunks_exact(4) {
+            for (i, block) in blocks.iter().enumerate() {
+                let block_ptr = block.as_ptr() as *const u8;
+
+                x[i * 2] = x[i * 2].fold_64(
+                    &coeff,
+                    &reflect_bytes512(reflector, Simd512::load_from_ptr(block_ptr)),
+                );
+                x[i * 2 + 1] = x[i * 2 + 1].fold_64(
+                    &coeff,
+                    &reflect_bytes512(reflector, Simd512::load_from_ptr(block_ptr.add(64))),
+                );
+            }
+        }
+
+        x
+    }
+
     /// Create a folding coefficient for AVX-512 for 128-byte folding distances
     #[inline(always)]
     unsafe fn create_avx512_128byte_coefficient(
         &self,
-        keys: [u64; 23],
+        keys: [u64; 25],
         reflected: bool,
     ) -> Simd512 {
         let (k1, k2) = if reflected {
@@ -236,7 +318,7 @@ impl X86_64Avx512VpclmulqdqOps {
     #[inline(always)]
     unsafe fn create_avx512_256byte_coefficient(
         &self,
-        keys: [u64; 23],
+        keys: [u64; 25],
         reflected: bool,
     ) -> Simd512 {
         let (k1, k2) = if reflected {
@@ -249,12 +331,49 @@ impl X86_64Avx512VpclmulqdqOps {
         Simd512::new(k1, k2, k1, k2, k1, k2, k1, k2)
     }
 
+    /// Create a folding coefficient for AVX-512 for 512-byte folding distances, from keys 23 and 24
+    /// of the `KeysFold512` format (which are 0 for the 23-key `KeysFold256` format)
+    #[inline(always)]
+    unsafe fn create_avx512_512byte_coefficient(
+        &self,
+        keys: [u64; 25],
+        reflected: bool,
+    ) -> Simd512 {
+        let (k1, k2) = if reflected {
+            (keys[23], keys[24])
+        } else {
+            (keys[24], keys[23])
+        };
+
+        // Replicate the coefficient pair
+        Simd512::new(k1, k2, k1, k2, k1, k2, k1, k2)
+    }
+
+    /// Fold from 8 x 512-bit to 4 x 512-bit
+    #[inline(always)]
+    unsafe fn fold_from_8x512_to_4x512(
+        &self,
+        x: [Simd512; 8],
+        keys: [u64; 25],
+        reflected: bool,
+    ) -> [Simd512; 4] {
+        // This folds registers that are 256 bytes apart (x[0] with x[4], x[1] with x[5], etc.)
+        let coeff = self.create_avx512_256byte_coefficient(keys, reflected);
+
+        [
+            x[0].fold_64(&coeff, &x[4]),
+            x[1].fold_64(&coeff, &x[5]),
+            x[2].fold_64(&coeff, &x[6]),
+            x[3].fold_64(&coeff, &x[7]),
+        ]
+    }
+
     /// Fold from 4 x 512-bit to 1 x 128-bit
     #[inline(always)]
     unsafe fn fold_from_4x512_to_1x128(
         &self,
         x: [Simd512; 4],
-        keys: [u64; 23],
+        keys: [u64; 25],
         reflected: bool,
     ) -> __m128i {
         // Step 1: Fold 4 x 512-bit to 2 x 512-bit
@@ -269,7 +388,7 @@ impl X86_64Avx512VpclmulqdqOps {
     unsafe fn fold_from_4x512_to_2x256(
         &self,
         x: [Simd512; 4],
-        keys: [u64; 23],
+        keys: [u64; 25],
         reflected: bool,
     ) -> [Simd512; 2] {
         // This folds registers that are 128 bytes apart (x[0] with x[2], x[1] with x[3])
@@ -286,7 +405,7 @@ impl X86_64Avx512VpclmulqdqOps {
     unsafe fn fold_from_2x512_to_1x128(
         &self,
         x: [Simd512; 2],
-        keys: [u64; 23],
+        keys: [u64; 25],
         reflected: bool,
     ) -> __m128i {
         // Create the fold coefficients for different distances
@@ -408,7 +527,7 @@ impl ArchOps for X86_64Avx512VpclmulqdqOps {
         first: &[Self::Vector; 8],
         rest: &[[Self::Vector; 8]],
         _reflector: &Reflector<Self::Vector>,
-        keys: [u64; 23],
+        keys: [u64; 25],
     ) -> bool
     where
         Self::Vector: Copy,
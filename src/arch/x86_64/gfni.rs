@@ -0,0 +1,429 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides an experimental x86_64 implementation which uses GFNI affine transforms
+//! rather than carryless multiplication.
+//!
+//! `GF2P8AFFINEQB` multiplies every byte of a vector by an 8x8 bit matrix, and a CRC update is
+//! linear over GF(2), so each byte of the new CRC register is a sum of input-byte-by-matrix
+//! products. One instruction applies the same matrix to every byte of a 64-bit lane, so the input
+//! is split into 8 interleaved streams (the Nth 8-byte word of every 64-byte block belongs to
+//! stream N), and each block is transposed so every byte of a lane comes from a different stream.
+//! The streams are merged serially at the end.
+//!
+//! It only uses 128-bit vectors, for parts which have GFNI but can't sustain AVX-512, such as
+//! Atom and E-core designs. It isn't selected automatically when PCLMULQDQ is available, but can
+//! be forced or selected with auto-tuning.
+
+#![cfg(target_arch = "x86_64")]
+
+#[rustversion::since(1.89)]
+use crate::cache::{get_or_generate_tables, CrcParamsCacheKey};
+#[cfg(not(feature = "crc64-only"))]
+#[rustversion::since(1.89)]
+use crate::crc32::consts::CRC32_ISCSI;
+#[rustversion::since(1.89)]
+use crate::generate::reflected_word_bit_images;
+#[rustversion::since(1.89)]
+use crate::CrcParams;
+#[rustversion::since(1.89)]
+use std::arch::x86_64::*;
+#[rustversion::since(1.89)]
+use std::sync::Arc;
+
+/// Affine matrix which reverses the bits of each byte, for non-reflected parameters
+#[rustversion::since(1.89)]
+const BIT_REVERSE_MATRIX: u64 = 0x8040201008040201;
+
+/// x86_64-only GFNI tier, which doesn't implement the ArchOps folding operations since GFNI
+/// can't do carryless multiplication
+#[rustversion::since(1.89)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct X86_64SseGfniOps {
+    /// Whether SSE4.2 is available, so CRC-32C can use the native CRC-32C instruction instead
+    #[cfg_attr(feature = "crc64-only", allow(dead_code))]
+    native_crc32c: bool,
+}
+
+#[rustversion::since(1.89)]
+impl X86_64SseGfniOps {
+    #[inline(always)]
+    pub const fn new(native_crc32c: bool) -> Self {
+        Self { native_crc32c }
+    }
+
+    /// Returns true if the parameters are calculated with the native CRC-32C instruction rather
+    /// than GFNI, which is only the case for reflected CRC-32C parameters when SSE4.2 is available
+    #[inline(always)]
+    #[cfg(not(feature = "crc64-only"))]
+    pub fn uses_native_crc32c(&self, params: CrcParams) -> bool {
+        self.native_crc32c && params.width == 32 && params.refin && params.poly == CRC32_ISCSI.poly
+    }
+
+    /// Updates a raw CRC state with `data`
+    ///
+    /// # Safety
+    /// Requires GFNI and SSSE3 support, and SSE4.2 if the tier was created with `native_crc32c`
+    #[inline]
+    pub unsafe fn update(&self, state: u64, data: &[u8], params: CrcParams) -> u64 {
+        // the state is the raw CRC register, so any reflected CRC-32C parameters can use it
+        #[cfg(not(feature = "crc64-only"))]
+        if self.uses_native_crc32c(params) {
+            return crate::crc32::native::crc32_iscsi(state as u32, data) as u64;
+        }
+
+        let width = params.width as u32;
+        if width != 32 && width != 64 {
+            panic!("Unsupported CRC width: {}", params.width);
+        }
+
+        let matrices = get_or_generate_matrices(params);
+
+        // the kernel always works on reflected registers, and reflects the input bytes for
+        // non-reflected parameters
+        if params.refin {
+            update_reflected(state, data, &matrices, true)
+        } else {
+            let state = state.reverse_bits() >> (64 - width);
+
+            update_reflected(state, data, &matrices, false).reverse_bits() >> (64 - width)
+        }
+    }
+}
+
+/// Affine matrices for a polynomial, in reflected form
+#[rustversion::since(1.89)]
+struct GfniMatrices {
+    /// The reflected polynomial
+    poly: u64,
+    /// The CRC width, in bytes
+    width: usize,
+    /// Matrices for one word of a stream, followed by the 56 bytes of the other streams, indexed
+    /// by input byte and then output byte
+    block: [[i64; 8]; 8],
+    /// Matrices for a single word, indexed by input byte and then output byte
+    word: [[i64; 8]; 8],
+}
+
+/// Gets the cached matrices for the parameters, or generates and caches them if not present,
+/// in the key cache so they're bounded and cleared along with the keys
+#[rustversion::since(1.89)]
+fn get_or_generate_matrices(params: CrcParams) -> Arc<GfniMatrices> {
+    get_or_generate_tables(
+        CrcParamsCacheKey::new(params.width, params.poly, params.refin),
+        || generate_matrices(params.width, params.poly),
+    )
+}
+
+/// Generates the matrices for a (non-reflected) polynomial
+#[rustversion::since(1.89)]
+fn generate_matrices(width: u8, poly: u64) -> GfniMatrices {
+    // the first word of each stream is followed by the 56 bytes of the other streams
+    let block_images = reflected_word_bit_images(width, poly, 56 * 8);
+    let word_images = reflected_word_bit_images(width, poly, 0);
+
+    GfniMatrices {
+        poly: poly.reverse_bits() >> (64 - width as u32),
+        width: width as usize / 8,
+        block: images_to_matrices(&block_images),
+        word: images_to_matrices(&word_images),
+    }
+}
+
+/// Splits the images of each input bit into 8x8 matrices for each input and output byte, in the
+/// row order used by `GF2P8AFFINEQB`
+#[rustversion::since(1.89)]
+fn images_to_matrices(images: &[u64; 64]) -> [[i64; 8]; 8] {
+    let mut matrices = [[0i64; 8]; 8];
+
+    for (input_byte, row) in matrices.iter_mut().enumerate() {
+        for (output_byte, matrix) in row.iter_mut().enumerate() {
+            for output_bit in 0..8 {
+                let mut mask = 0u64;
+                for input_bit in 0..8 {
+                    let image = images[input_byte * 8 + input_bit];
+                    mask |= ((image >> (output_byte * 8 + output_bit)) & 1) << input_bit;
+                }
+
+                // output bit N is the parity of the input byte ANDed with matrix byte 7 - N
+                *matrix |= (mask << ((7 - output_bit) * 8)) as i64;
+            }
+        }
+    }
+
+    matrices
+}
+
+/// Updates a reflected CRC register, reflecting each byte of `data` first if `reflected` is false
+///
+/// # Safety
+/// Requires GFNI and SSSE3 support
+#[rustversion::since(1.89)]
+#[target_feature(enable = "gfni,ssse3")]
+unsafe fn update_reflected(
+    mut state: u64,
+    data: &[u8],
+    matrices: &GfniMatrices,
+    reflected: bool,
+) -> u64 {
+    // the register is stored as pairs of bytes, one per 64-bit lane
+    let pairs = matrices.width / 2;
+    let mut rest = data;
+
+    // the streams are only worth merging if there's at least one block before the last
+    if data.len() >= 128 {
+        let (bulk, tail) = data.split_at((data.len() / 64 - 1) * 64);
+
+        // lane N holds byte N of each stream's register, and only the first stream continues
+        // the state
+        let mut registers = [0u64; 8];
+        for (byte, register) in registers.iter_mut().take(matrices.width).enumerate() {
+            *register = (state >> (byte * 8)) & 0xff;
+        }
+
+        let mut streams = [_mm_setzero_si128(); 4];
+        for (pair, stream) in streams.iter_mut().take(pairs).enumerate() {
+            *stream = _mm_loadu_si128(registers.as_ptr().add(pair * 2) as *const __m128i);
+        }
+
+        let block_matrices = load_matrices(&matrices.block);
+
+        for block in bulk.chunks_exact(64) {
+            // lane N holds byte N of each stream's word, to which the registers are added
+            let mut words = transpose_block(block, reflected);
+            for pair in 0..pairs {
+                words[pair] = _mm_xor_si128(words[pair], streams[pair]);
+            }
+
+            streams = [_mm_setzero_si128(); 4];
+            for (byte, byte_matrices) in block_matrices.iter().enumerate() {
+                let word = words[byte / 2];
+                let bytes = if byte % 2 == 0 {
+                    _mm_unpacklo_epi64(word, word)
+                } else {
+                    _mm_unpackhi_epi64(word, word)
+                };
+
+                for pair in 0..pairs {
+                    streams[pair] = _mm_xor_si128(
+                        streams[pair],
+                        _mm_gf2p8affine_epi64_epi8::<0>(bytes, byte_matrices[pair]),
+                    );
+                }
+            }
+        }
+
+        for (pair, stream) in streams.iter().take(pairs).enumerate() {
+            _mm_storeu_si128(
+                registers.as_mut_ptr().add(pair * 2) as *mut __m128i,
+                *stream,
+            );
+        }
+
+        // each stream's register is added to its word in the last block, which is then processed
+        // serially to merge them
+        state = 0;
+        for (stream, word) in tail[..64].chunks_exact(8).enumerate() {
+            let register = registers.iter().take(matrices.width).enumerate().fold(
+                0,
+                |register, (byte, bytes)| {
+                    register | (((bytes >> (stream * 8)) & 0xff) << (byte * 8))
+                },
+            );
+
+            state = update_word(state ^ register ^ read_word(word, reflected), matrices);
+        }
+
+        rest = &tail[64..];
+    }
+
+    let mut words = rest.chunks_exact(8);
+    for word in &mut words {
+        state = update_word(state ^ read_word(word, reflected), matrices);
+    }
+
+    for &byte in words.remainder() {
+        state ^= if reflected { byte } else { byte.reverse_bits() } as u64;
+        for _ in 0..8 {
+            state = (state >> 1) ^ ((state & 1) * matrices.poly);
+        }
+    }
+
+    state
+}
+
+/// Updates a reflected CRC register, which has already been added to the word, with one word
+///
+/// # Safety
+/// Requires GFNI and SSSE3 support
+#[rustversion::since(1.89)]
+#[inline]
+#[target_feature(enable = "gfni,ssse3")]
+unsafe fn update_word(word: u64, matrices: &GfniMatrices) -> u64 {
+    let pairs = matrices.width / 2;
+    let word = _mm_cvtsi64_si128(word as i64);
+
+    let mut output = [_mm_setzero_si128(); 4];
+    for (byte, byte_matrices) in matrices.word.iter().enumerate() {
+        // every byte of both lanes is the input byte, so every byte of each output lane is the
+        // same output byte
+        let bytes = _mm_shuffle_epi8(word, _mm_set1_epi8(byte as i8));
+
+        for pair in 0..pairs {
+            let byte_matrices =
+                _mm_set_epi64x(byte_matrices[pair * 2 + 1], byte_matrices[pair * 2]);
+            output[pair] = _mm_xor_si128(
+                output[pair],
+                _mm_gf2p8affine_epi64_epi8::<0>(bytes, byte_matrices),
+            );
+        }
+    }
+
+    let mut registers = [0u64; 8];
+    for (pair, output) in output.iter().take(pairs).enumerate() {
+        _mm_storeu_si128(
+            registers.as_mut_ptr().add(pair * 2) as *mut __m128i,
+            *output,
+        );
+    }
+
+    registers
+        .iter()
+        .take(matrices.width)
+        .enumerate()
+        .fold(0, |register, (byte, bytes)| {
+            register | ((bytes & 0xff) << (byte * 8))
+        })
+}
+
+/// Loads the matrices for each input byte into vectors, with both bytes of an output pair
+#[rustversion::since(1.89)]
+#[inline]
+#[target_feature(enable = "gfni,ssse3")]
+unsafe fn load_matrices(matrices: &[[i64; 8]; 8]) -> [[__m128i; 4]; 8] {
+    matrices.map(|row| [0, 1, 2, 3].map(|pair| _mm_set_epi64x(row[pair * 2 + 1], row[pair * 2])))
+}
+
+/// Transposes a 64-byte block, with 8 words, into 8 vectors of 8 bytes, one from each word, with
+/// two of the vectors in the lanes of each result
+#[rustversion::since(1.89)]
+#[inline]
+#[target_feature(enable = "gfni,ssse3")]
+unsafe fn transpose_block(block: &[u8], reflected: bool) -> [__m128i; 4] {
+    let mut words =
+        [0, 16, 32, 48].map(|offset| _mm_loadu_si128(block.as_ptr().add(offset) as *const __m128i));
+
+    if !reflected {
+        let bit_reverse = _mm_set1_epi64x(BIT_REVERSE_MATRIX as i64);
+        words = words.map(|word| _mm_gf2p8affine_epi64_epi8::<0>(word, bit_reverse));
+    }
+
+    // interleave the bytes of the two words in each vector, then the 16-bit and 32-bit pairs
+    let interleave = _mm_setr_epi8(0, 8, 1, 9, 2, 10, 3, 11, 4, 12, 5, 13, 6, 14, 7, 15);
+    let words = words.map(|word| _mm_shuffle_epi8(word, interleave));
+
+    let low_01 = _mm_unpacklo_epi16(words[0], words[1]);
+    let high_01 = _mm_unpackhi_epi16(words[0], words[1]);
+    let low_23 = _mm_unpacklo_epi16(words[2], words[3]);
+    let high_23 = _mm_unpackhi_epi16(words[2], words[3]);
+
+    [
+        _mm_unpacklo_epi32(low_01, low_23),
+        _mm_unpackhi_epi32(low_01, low_23),
+        _mm_unpacklo_epi32(high_01, high_23),
+        _mm_unpackhi_epi32(high_01, high_23),
+    ]
+}
+
+/// Reads a little-endian word, reflecting each byte if `reflected` is false
+#[rustversion::since(1.89)]
+#[inline(always)]
+fn read_word(word: &[u8], reflected: bool) -> u64 {
+    let word = u64::from_le_bytes(word.try_into().unwrap());
+
+    if reflected {
+        word
+    } else {
+        word.reverse_bits().swap_bytes()
+    }
+}
+
+#[cfg(test)]
+#[rustversion::since(1.89)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_gfni_matches_reference() {
+        if !is_x86_feature_detected!("gfni") || !is_x86_feature_detected!("ssse3") {
+            return;
+        }
+
+        let mut data = vec![0u8; 4096 + 7];
+        rng().fill(&mut data[..]);
+
+        // without the native CRC-32C instruction, so CRC-32/ISCSI goes through GFNI too
+        let ops = X86_64SseGfniOps::new(false);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+            #[cfg(not(feature = "crc64-only"))]
+            assert!(!ops.uses_native_crc32c(params));
+
+            for len in (0..600).chain([1024, 4093, 4096]) {
+                // unaligned, to cover the unaligned loads
+                let data = &data[7..7 + len];
+
+                let actual = unsafe { ops.update(params.init, data, params) };
+
+                assert_eq!(
+                    actual ^ params.xorout,
+                    config.checksum_with_reference(data),
+                    "Mismatch CRC, {}, length {}",
+                    config.get_name(),
+                    len
+                );
+            }
+        }
+    }
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_native_crc32c() {
+        use crate::crc32::consts::{CRC32_BZIP2, CRC32_ISO_HDLC};
+
+        if !is_x86_feature_detected!("gfni")
+            || !is_x86_feature_detected!("ssse3")
+            || !is_x86_feature_detected!("sse4.2")
+        {
+            return;
+        }
+
+        // only reflected CRC-32C uses the native instruction, everything else stays on GFNI
+        let ops = X86_64SseGfniOps::new(true);
+        assert!(ops.uses_native_crc32c(CRC32_ISCSI));
+        assert!(!ops.uses_native_crc32c(CRC32_ISO_HDLC));
+        assert!(!ops.uses_native_crc32c(CRC32_BZIP2));
+
+        let mut data = vec![0u8; 4096 + 7];
+        rng().fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            for len in [0, 1, 15, 64, 600, 4093] {
+                let data = &data[7..7 + len];
+
+                let actual = unsafe { ops.update(params.init, data, params) };
+
+                assert_eq!(
+                    actual ^ params.xorout,
+                    config.checksum_with_reference(data),
+                    "Mismatch CRC, {}, length {}",
+                    config.get_name(),
+                    len
+                );
+            }
+        }
+    }
+}
@@ -6,3 +6,4 @@
 
 pub mod avx512;
 pub mod avx512_vpclmulqdq;
+pub mod gfni;
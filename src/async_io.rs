@@ -0,0 +1,146 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Async I/O adapters for tokio.
+//!
+//! `AsyncHashingReader` checksums every byte read through it, so async pipelines (such as
+//! download → decompress → parse) can verify integrity inline, without buffering the stream twice.
+
+use crate::{CrcAlgorithm, CrcParams, Digest};
+use std::io;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::{AsyncRead, ReadBuf};
+
+/// An `AsyncRead` wrapper which calculates the checksum of all the bytes read through it
+#[derive(Debug)]
+pub struct AsyncHashingReader<R> {
+    /// The wrapped reader
+    inner: R,
+
+    /// The checksum of the bytes read so far
+    digest: Digest,
+}
+
+impl<R> AsyncHashingReader<R> {
+    /// Wraps a reader, checksumming everything read through it with the specified algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{AsyncHashingReader, CrcAlgorithm::Crc32IsoHdlc};
+    /// use tokio::io::AsyncReadExt;
+    ///
+    /// # async fn example() -> std::io::Result<()> {
+    /// let mut reader = AsyncHashingReader::new(&b"123456789"[..], Crc32IsoHdlc);
+    ///
+    /// let mut contents = Vec::new();
+    /// reader.read_to_end(&mut contents).await?;
+    ///
+    /// assert_eq!(reader.checksum(), 0xcbf43926);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(inner: R, algorithm: CrcAlgorithm) -> Self {
+        Self::new_with_digest(inner, Digest::new(algorithm))
+    }
+
+    /// Wraps a reader, checksumming everything read through it with custom CRC parameters.
+    pub fn new_with_params(inner: R, params: CrcParams) -> Self {
+        Self::new_with_digest(inner, Digest::new_with_params(params))
+    }
+
+    /// Wraps a reader, continuing the checksum in an existing Digest, such as one which has
+    /// already been updated with a prefix of the stream.
+    pub fn new_with_digest(inner: R, digest: Digest) -> Self {
+        Self { inner, digest }
+    }
+
+    /// Returns the checksum of the bytes read so far.
+    pub fn checksum(&self) -> u64 {
+        self.digest.finalize()
+    }
+
+    /// Returns the Digest of the bytes read so far.
+    pub fn digest(&self) -> &Digest {
+        &self.digest
+    }
+
+    /// Returns a reference to the wrapped reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the wrapped reader.
+    ///
+    /// Bytes read directly from the wrapped reader aren't checksummed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwraps the reader, returning it and the Digest of the bytes read so far.
+    pub fn into_parts(self) -> (R, Digest) {
+        (self.inner, self.digest)
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for AsyncHashingReader<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        let filled = buf.filled().len();
+
+        let result = Pin::new(&mut this.inner).poll_read(cx, buf);
+
+        if let Poll::Ready(Ok(())) = result {
+            this.digest.update(&buf.filled()[filled..]);
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum;
+    use tokio::io::AsyncReadExt;
+
+    #[tokio::test]
+    async fn test_hashing_reader_matches_checksum() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+        for algorithm in [CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc64Nvme] {
+            // a small buffer, so the data arrives across many reads
+            let inner = tokio::io::BufReader::with_capacity(1_000, &data[..]);
+            let mut reader = AsyncHashingReader::new(inner, algorithm);
+
+            let mut contents = Vec::new();
+            reader.read_to_end(&mut contents).await.unwrap();
+
+            assert_eq!(contents, data);
+            assert_eq!(reader.checksum(), checksum(algorithm, &data));
+            assert_eq!(reader.digest().get_amount(), data.len() as u64);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hashing_reader_continues_digest() {
+        let mut digest = Digest::new(CrcAlgorithm::Crc32IsoHdlc);
+        digest.update(b"1234");
+
+        let mut reader = AsyncHashingReader::new_with_digest(&b"56789"[..], digest);
+
+        let mut first = [0u8; 2];
+        reader.read_exact(&mut first).await.unwrap();
+
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).await.unwrap();
+
+        let (_, digest) = reader.into_parts();
+
+        assert_eq!(digest.finalize(), 0xcbf43926);
+    }
+}
@@ -0,0 +1,153 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Unstable access to the generic folding machinery, for experimenting with new folding schedules
+//! without forking the crate.
+//!
+//! Implement [`ArchOps`] for a new set of SIMD operations (or wrap one of the built-in
+//! implementations and override individual operations), then run it with [`update()`] or register
+//! it for all calculations with [`ArchOpsBackend`] and `register_backend()`.
+//!
+//! Requires the `unstable-backend` feature. Everything in this module may change in any release,
+//! without a MAJOR version bump.
+//!
+//! # Examples
+//!
+//! ```rust
+//! # #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+//! # {
+//! use crc_fast::backend::{update, Width64, X86SsePclmulqdqOps};
+//! use crc_fast::{checksum, CrcAlgorithm, CrcParams};
+//!
+//! if is_x86_feature_detected!("sse4.1") && is_x86_feature_detected!("pclmulqdq") {
+//!     let params = CrcParams::new(
+//!         "CRC-64/NVME",
+//!         64,
+//!         0xad93d23594c93659,
+//!         0xffffffffffffffff,
+//!         true,
+//!         0xffffffffffffffff,
+//!         0xae8b14860a799888,
+//!     );
+//!
+//!     let state = unsafe {
+//!         update::<_, Width64>(params.init, b"123456789", params, &X86SsePclmulqdqOps)
+//!     };
+//!
+//!     assert_eq!(state ^ params.xorout, checksum(CrcAlgorithm::Crc64Nvme, b"123456789"));
+//! }
+//! # }
+//! ```
+
+pub use crate::algorithm::update;
+pub use crate::enums::Reflector;
+pub use crate::structs::{CrcState, Width32, Width64};
+pub use crate::traits::{ArchOps, CrcWidth, EnhancedCrcWidth};
+
+#[cfg(target_arch = "aarch64")]
+pub use crate::arch::aarch64::{aes::Aarch64AesOps, aes_sha3::Aarch64AesSha3Ops};
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use crate::arch::x86::sse::X86SsePclmulqdqOps;
+
+#[rustversion::since(1.89)]
+#[cfg(target_arch = "x86_64")]
+pub use crate::arch::x86_64::{
+    avx512::X86_64Avx512PclmulqdqOps, avx512_vpclmulqdq::X86_64Avx512VpclmulqdqOps,
+};
+
+use crate::{CrcBackend, CrcParams};
+
+/// Adapts an [`ArchOps`] implementation into a [`CrcBackend`], so it can be registered for all
+/// calculations with `register_backend()`.
+#[derive(Debug, Clone, Copy)]
+pub struct ArchOpsBackend<T> {
+    name: &'static str,
+    ops: T,
+}
+
+impl<T: ArchOps> ArchOpsBackend<T> {
+    /// Creates a backend which runs the generic folding algorithm with `ops`.
+    ///
+    /// # Safety
+    ///
+    /// `ops` may use native CPU features, so they must be supported by the CPU it runs on.
+    pub const unsafe fn new(name: &'static str, ops: T) -> Self {
+        Self { name, ops }
+    }
+}
+
+impl<T> CrcBackend for ArchOpsBackend<T>
+where
+    T: ArchOps + Send + Sync,
+    T::Vector: Copy,
+{
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn update(&self, state: u64, data: &[u8], params: CrcParams) -> u64 {
+        // SAFETY: the CPU supports the ops, as promised by `new()`
+        unsafe {
+            match params.width {
+                #[cfg(not(feature = "crc32-only"))]
+                64 => update::<T, Width64>(state, data, params, &self.ops),
+                #[cfg(not(feature = "crc64-only"))]
+                32 => update::<T, Width32>(state as u32, data, params, &self.ops) as u64,
+                _ => panic!("Unsupported CRC width: {}", params.width),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::{TEST_ALL_CONFIGS, TEST_CHECK_STRING};
+
+    #[test]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn test_arch_ops_backend_check_value() {
+        if !is_x86_feature_detected!("sse4.1") || !is_x86_feature_detected!("pclmulqdq") {
+            return;
+        }
+
+        let backend = unsafe { ArchOpsBackend::new("test-sse", X86SsePclmulqdqOps) };
+        assert_eq!(backend.name(), "test-sse");
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+            let actual = backend.update(params.init, TEST_CHECK_STRING, params) ^ params.xorout;
+
+            assert_eq!(
+                actual,
+                config.get_check(),
+                "Mismatch CRC, {}",
+                config.get_name()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(target_arch = "aarch64")]
+    fn test_arch_ops_backend_check_value() {
+        // the AArch64 kernels only support little-endian
+        if cfg!(target_endian = "big") || !std::arch::is_aarch64_feature_detected!("aes") {
+            return;
+        }
+
+        let backend = unsafe { ArchOpsBackend::new("test-aes", Aarch64AesOps) };
+        assert_eq!(backend.name(), "test-aes");
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+            let actual = backend.update(params.init, TEST_CHECK_STRING, params) ^ params.xorout;
+
+            assert_eq!(
+                actual,
+                config.get_check(),
+                "Mismatch CRC, {}",
+                config.get_name()
+            );
+        }
+    }
+}
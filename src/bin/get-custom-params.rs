@@ -204,10 +204,10 @@ fn main() -> ExitCode {
     println!("    refout: {},", config.reflected.unwrap());
     println!("    xorout: 0x{:x},", config.xorout.unwrap());
     println!("    check: 0x{:x},", config.check.unwrap());
-    println!("    keys: CrcKeysStorage::from_keys_fold_256([");
+    println!("    keys: CrcKeysStorage::from_keys_fold_512([");
 
     // Print the keys array
-    for i in 0..23 {
+    for i in 0..params.key_count() {
         let key = params.get_key(i);
         println!("        0x{key:016x},",);
     }
@@ -0,0 +1,68 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides a table-free, bitwise CRC calculation which is usable in const contexts.
+//!
+//! It's used by the `tiny` software fallback, where there's no room for lookup tables, and by
+//! `checksum_const()`, where there's no SIMD (or runtime feature detection) to be had.
+
+use crate::CrcParams;
+
+/// Updates the state one bit at a time, which is slow but has no lookup tables to store or
+/// generate.
+///
+/// Like the table-based fallback, the state is the CRC register (reflected for reflected
+/// parameters) with no XOROUT applied.
+pub(crate) const fn update(state: u64, data: &[u8], params: CrcParams) -> u64 {
+    let width = params.width as u32;
+    let mask = match width {
+        #[cfg(not(feature = "crc64-only"))]
+        32 => u32::MAX as u64,
+        #[cfg(not(feature = "crc32-only"))]
+        64 => u64::MAX,
+        _ => panic!("Unsupported CRC width"),
+    };
+
+    let mut crc = state & mask;
+    let mut i = 0;
+
+    if params.refin {
+        let poly = params.poly.reverse_bits() >> (64 - width);
+
+        while i < data.len() {
+            crc ^= data[i] as u64;
+
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ poly
+                } else {
+                    crc >> 1
+                };
+                bit += 1;
+            }
+
+            i += 1;
+        }
+    } else {
+        let top_bit = 1u64 << (width - 1);
+
+        while i < data.len() {
+            crc ^= (data[i] as u64) << (width - 8);
+
+            let mut bit = 0;
+            while bit < 8 {
+                crc = if crc & top_bit != 0 {
+                    (crc << 1) ^ params.poly
+                } else {
+                    crc << 1
+                };
+                bit += 1;
+            }
+
+            crc &= mask;
+            i += 1;
+        }
+    }
+
+    crc
+}
@@ -4,29 +4,229 @@
 //!
 //! This module provides a thread-safe cache for CRC folding keys to avoid expensive
 //! regeneration when the same CRC parameters are used multiple times. The cache uses
-//! a read-write lock pattern optimized for the common case of cache hits.
+//! a read-write lock pattern optimized for the common case of cache hits, split into shards by
+//! parameters so that generating new keys doesn't block lookups of unrelated ones.
 //!
 //! # Performance Characteristics
 //!
 //! - Cache hits: ~50-100x faster than key generation
 //! - Cache misses: ~100-200ns overhead compared to direct generation
 //! - Memory usage: ~200 bytes per unique parameter set
-//! - Thread safety: Multiple concurrent readers, exclusive writers
+//! - Thread safety: Multiple concurrent readers, exclusive writers per shard
 //!
 //! # Usage
 //!
 //! The cache is used automatically by `CrcParams::new()` and requires no manual management.
 //! The cache is transparent to users and handles all memory management internally.
+//!
+//! It holds about 1024 parameter sets by default, evicting the least recently used one in a shard
+//! when it's full, so processes which generate many distinct custom parameters (such as polynomial
+//! searches) don't grow unboundedly. Use `set_key_cache_capacity()` to change the limit.
+//!
+//! Backends which need their own lookup tables for custom parameters, such as the software
+//! fallback, cache them here too, keyed and bounded in the same way, so the capacity and clearing
+//! functions apply to them as well.
+//!
+//! The `no-key-cache` feature disables the cache at compile time, so keys and tables are always
+//! generated directly, with no global state, for embedded or short-lived processes which prefer
+//! deterministic memory usage.
 
-use crate::generate;
+use crate::{generate, CrcKeysStorage};
+use std::any::{Any, TypeId};
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
-use std::sync::{OnceLock, RwLock};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Whether the cache is compiled in, see the `no-key-cache` feature
+const ENABLED: bool = cfg!(not(feature = "no-key-cache"));
+
+/// Default maximum number of cached parameter sets
+const DEFAULT_CAPACITY: usize = if ENABLED { 1024 } else { 0 };
+
+/// Capacity value which disables eviction
+const UNBOUNDED: usize = usize::MAX;
+
+/// Number of independently locked shards, so that writers only block readers of the same shard
+const SHARDS: usize = 16;
 
 /// Global cache storage for CRC parameter keys
 ///
-/// Uses OnceLock for thread-safe lazy initialization and RwLock for concurrent access.
+/// Uses OnceLock for thread-safe lazy initialization and an RwLock per shard for concurrent access.
 /// The cache maps parameter combinations to their pre-computed folding keys.
-static CACHE: OnceLock<RwLock<HashMap<CrcParamsCacheKey, [u64; 23]>>> = OnceLock::new();
+static CACHE: OnceLock<[RwLock<KeyCache>; SHARDS]> = OnceLock::new();
+
+/// A backend's lookup tables for one parameter set, of whichever type the backend uses
+type Tables = Arc<dyn Any + Send + Sync>;
+
+/// Map of backends' lookup tables, keyed by the type of the tables as well as the parameters, so
+/// that each backend has its own entries
+type TableCache = KeyCache<(TypeId, CrcParamsCacheKey), Tables>;
+
+/// Global cache storage for backends' lookup tables
+static TABLES: OnceLock<[RwLock<TableCache>; SHARDS]> = OnceLock::new();
+
+/// Global maximum number of cached parameter sets
+static CAPACITY: AtomicUsize = AtomicUsize::new(DEFAULT_CAPACITY);
+
+/// Sets the maximum number of CRC parameter sets whose folding keys are cached, or `None` to
+/// cache them without limit. The default is 1024.
+///
+/// When the cache is full, the least recently used parameter set is evicted, and will have its
+/// keys regenerated if it's used again. Backends' lookup tables for custom parameters (up to 32 KiB
+/// each) are cached separately, with the same capacity. The cache is split into 16 shards, each holding a 16th of
+/// the capacity (rounded up), so eviction is per shard. A capacity of `0` disables caching
+/// entirely. Lowering the capacity evicts the excess entries immediately.
+///
+/// This has no effect when the cache is disabled by the `no-key-cache` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::set_key_cache_capacity;
+///
+/// // keep the keys for at most 64 custom parameter sets
+/// set_key_cache_capacity(Some(64));
+/// ```
+pub fn set_key_cache_capacity(capacity: Option<usize>) {
+    if !ENABLED {
+        return;
+    }
+
+    let capacity = capacity.unwrap_or(UNBOUNDED);
+
+    CAPACITY.store(capacity, Ordering::Relaxed);
+
+    // best effort, like every other cache operation
+    for shard in get_cache() {
+        let _ = shard
+            .write()
+            .map(|mut cache| cache.evict_to(shard_capacity(capacity)));
+    }
+
+    for shard in TABLES.get().into_iter().flatten() {
+        let _ = shard
+            .write()
+            .map(|mut cache| cache.evict_to(shard_capacity(capacity)));
+    }
+}
+
+/// Returns the maximum number of cached CRC parameter sets, or `None` if it's unlimited.
+pub fn get_key_cache_capacity() -> Option<usize> {
+    match CAPACITY.load(Ordering::Relaxed) {
+        UNBOUNDED => None,
+        capacity => Some(capacity),
+    }
+}
+
+/// Generates and caches the keys for each `(width, poly, reflected)` parameter set, so
+/// latency-sensitive services can pay the key generation cost at startup, rather than on the
+/// first request to use each set.
+///
+/// Parameter sets beyond the cache's capacity evict the earliest ones, and this has no effect
+/// when the cache is disabled by the `no-key-cache` feature.
+///
+/// # Panics
+///
+/// Panics if a width isn't 32 or 64, like `CrcParams::new()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::prewarm_key_cache;
+///
+/// // CRC-32/CASTAGNOLI variants and Koopman's CRC-32K
+/// prewarm_key_cache(&[(32, 0x1edc6f41, true), (32, 0x741b8cd7, true)]);
+/// ```
+pub fn prewarm_key_cache(params: &[(u8, u64, bool)]) {
+    if !ENABLED {
+        return;
+    }
+
+    for &(width, poly, reflected) in params {
+        get_or_generate_keys(width, poly, reflected);
+    }
+}
+
+/// A cached set of keys (or tables), with the time it was last used for LRU eviction
+#[derive(Debug)]
+struct CacheEntry<V> {
+    keys: V,
+    /// Value of the cache's clock at the last lookup, which is atomic so that hits only need the
+    /// read lock
+    last_used: AtomicU64,
+}
+
+/// Map of parameter combinations to their keys (or tables), which evicts the least recently used
+/// entries
+#[derive(Debug)]
+struct KeyCache<K = CrcParamsCacheKey, V = CrcKeysStorage> {
+    entries: HashMap<K, CacheEntry<V>>,
+    /// Logical clock, incremented on every lookup and insert
+    clock: AtomicU64,
+}
+
+impl<K, V> Default for KeyCache<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+            clock: AtomicU64::new(0),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> KeyCache<K, V> {
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Returns the cached keys, marking them as the most recently used
+    fn get(&self, key: &K) -> Option<V> {
+        let entry = self.entries.get(key)?;
+        entry.last_used.store(self.tick(), Ordering::Relaxed);
+
+        Some(entry.keys.clone())
+    }
+
+    /// Returns the cached keys, without marking them as used
+    fn peek(&self, key: &K) -> Option<V> {
+        self.entries.get(key).map(|entry| entry.keys.clone())
+    }
+
+    /// Caches the keys, first evicting the least recently used entries to keep within capacity
+    fn insert(&mut self, key: K, keys: V, capacity: usize) {
+        if capacity == 0 {
+            return;
+        }
+
+        if !self.entries.contains_key(&key) {
+            self.evict_to(capacity - 1);
+        }
+
+        let last_used = AtomicU64::new(self.tick());
+        self.entries.insert(key, CacheEntry { keys, last_used });
+    }
+
+    /// Evicts the least recently used entries until at most `capacity` remain
+    fn evict_to(&mut self, capacity: usize) {
+        let excess = self.entries.len().saturating_sub(capacity);
+        if excess == 0 {
+            return;
+        }
+
+        let mut by_age: Vec<_> = self
+            .entries
+            .iter()
+            .map(|(key, entry)| (entry.last_used.load(Ordering::Relaxed), key.clone()))
+            .collect();
+        by_age.sort_unstable_by_key(|(last_used, _)| *last_used);
+
+        for (_, key) in by_age.into_iter().take(excess) {
+            self.entries.remove(&key);
+        }
+    }
+}
 
 /// Cache key for storing CRC parameters that affect key generation
 ///
@@ -67,8 +267,29 @@ impl CrcParamsCacheKey {
 ///
 /// Uses OnceLock to ensure thread-safe lazy initialization without requiring
 /// static initialization overhead. The cache is only created when first accessed.
-fn get_cache() -> &'static RwLock<HashMap<CrcParamsCacheKey, [u64; 23]>> {
-    CACHE.get_or_init(|| RwLock::new(HashMap::new()))
+fn get_cache() -> &'static [RwLock<KeyCache>; SHARDS] {
+    CACHE.get_or_init(|| std::array::from_fn(|_| RwLock::default()))
+}
+
+/// Returns the shard which caches the keys for these parameters
+fn get_shard(cache_key: &CrcParamsCacheKey) -> &'static RwLock<KeyCache> {
+    &get_cache()[shard_index(cache_key)]
+}
+
+/// Returns the index of the shard for a cache key
+fn shard_index(cache_key: &impl Hash) -> usize {
+    let mut hasher = DefaultHasher::new();
+    cache_key.hash(&mut hasher);
+
+    hasher.finish() as usize % SHARDS
+}
+
+/// Returns the capacity of each shard for the overall capacity
+fn shard_capacity(capacity: usize) -> usize {
+    match capacity {
+        UNBOUNDED => UNBOUNDED,
+        capacity => capacity.div_ceil(SHARDS),
+    }
 }
 
 /// Get cached keys or generate and cache them if not present
@@ -94,34 +315,69 @@ fn get_cache() -> &'static RwLock<HashMap<CrcParamsCacheKey, [u64; 23]>> {
 ///
 /// # Returns
 ///
-/// Array of 23 pre-computed folding keys for SIMD CRC calculation
-pub fn get_or_generate_keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
+/// Pre-computed folding keys for SIMD CRC calculation, in the widest supported format, which
+/// `CrcKeysStorage::with_key_count()` converts to narrower ones
+pub(crate) fn get_or_generate_key_storage(width: u8, poly: u64, reflected: bool) -> CrcKeysStorage {
+    #[cfg(feature = "key-presets")]
+    if let Some(keys) = crate::presets::lookup(width, poly, reflected) {
+        return keys;
+    }
+
+    if !ENABLED {
+        return CrcKeysStorage::from_keys_fold_512(generate::keys(width, poly, reflected));
+    }
+
     let cache_key = CrcParamsCacheKey::new(width, poly, reflected);
 
     // Try cache read first - multiple threads can read simultaneously
     // If lock is poisoned or read fails, continue to key generation
-    if let Ok(cache) = get_cache().read() {
+    let shard = get_shard(&cache_key);
+    if let Ok(cache) = shard.read() {
         if let Some(keys) = cache.get(&cache_key) {
-            return *keys;
+            return keys;
         }
     }
 
+    #[cfg(feature = "tracing")]
+    tracing::debug!(width, poly, reflected, "key cache miss, generating keys");
+
     // Generate keys outside of write lock to minimize lock hold time
-    let keys = generate::keys(width, poly, reflected);
+    let keys = CrcKeysStorage::from_keys_fold_512(generate::keys(width, poly, reflected));
 
     // Try to cache the result (best effort - if this fails, we still return valid keys)
     // Lock poisoning or write failure doesn't affect functionality
-    let _ = get_cache()
-        .write()
-        .map(|mut cache| cache.insert(cache_key, keys));
+    let _ = shard.write().map(|mut cache| {
+        cache.insert(
+            cache_key,
+            keys,
+            shard_capacity(CAPACITY.load(Ordering::Relaxed)),
+        );
+    });
 
     keys
 }
 
-/// Clear all cached CRC parameter keys
+/// Returns the cached keys for the parameters, if there are any, without generating them or
+/// affecting which keys are evicted next
+pub(crate) fn peek_key_storage(width: u8, poly: u64, reflected: bool) -> Option<CrcKeysStorage> {
+    if !ENABLED {
+        return None;
+    }
+
+    let cache_key = CrcParamsCacheKey::new(width, poly, reflected);
+
+    get_shard(&cache_key).read().ok()?.peek(&cache_key)
+}
+
+/// Get cached keys or generate and cache them if not present, as an array of 25 keys
 ///
-/// This function is primarily intended for testing scenarios where you need to reset
-/// the cache state to ensure test isolation.
+/// See `get_or_generate_key_storage()`.
+pub fn get_or_generate_keys(width: u8, poly: u64, reflected: bool) -> [u64; 25] {
+    get_or_generate_key_storage(width, poly, reflected).to_keys_array_25()
+}
+
+/// Clears all cached CRC parameter keys, and backends' lookup tables, such as after a configuration
+/// reload which removed custom algorithms. Keys are regenerated if their parameters are used again.
 ///
 /// Uses best-effort error handling - lock poisoning or other failures don't cause
 /// panics, ensuring this function never disrupts program execution. If the cache
@@ -132,11 +388,85 @@ pub fn get_or_generate_keys(width: u8, poly: u64, reflected: bool) -> [u64; 23]
 /// This function is thread-safe and can be called concurrently with other cache operations.
 /// However, clearing the cache while other threads are actively using it may temporarily
 /// reduce performance as those threads will need to regenerate keys on their next access.
-#[cfg(test)]
-pub(crate) fn clear_cache() {
+/// `CrcParams` created before clearing keep their own copies of the keys, and are unaffected.
+pub fn clear_key_cache() {
     // Best-effort cache clear - if lock is poisoned or unavailable, silently continue
     // This ensures the function never panics or blocks program execution
-    let _ = get_cache().write().map(|mut cache| cache.clear());
+    if !ENABLED {
+        return;
+    }
+
+    for shard in get_cache() {
+        let _ = shard.write().map(|mut cache| cache.entries.clear());
+    }
+
+    for shard in TABLES.get().into_iter().flatten() {
+        let _ = shard.write().map(|mut cache| cache.entries.clear());
+    }
+}
+
+/// Removes the cached keys, and backends' lookup tables, for a single `(width, poly, reflected)`
+/// parameter set, returning whether the keys were cached.
+///
+/// Like `clear_key_cache()`, this uses best-effort error handling, and doesn't affect `CrcParams`
+/// which were already created.
+pub fn invalidate_key_cache(width: u8, poly: u64, reflected: bool) -> bool {
+    if !ENABLED {
+        return false;
+    }
+
+    let cache_key = CrcParamsCacheKey::new(width, poly, reflected);
+
+    for shard in TABLES.get().into_iter().flatten() {
+        let _ = shard
+            .write()
+            .map(|mut cache| cache.entries.retain(|(_, key), _| *key != cache_key));
+    }
+
+    get_shard(&cache_key)
+        .write()
+        .map(|mut cache| cache.entries.remove(&cache_key).is_some())
+        .unwrap_or(false)
+}
+
+/// Get a backend's cached lookup tables for the parameters, or generate and cache them if not
+/// present
+///
+/// Uses the same read-then-write pattern and best-effort error handling as
+/// `get_or_generate_key_storage()`, and the same capacity, so the tables for many distinct custom
+/// parameters are evicted rather than accumulating. When the cache is disabled by the
+/// `no-key-cache` feature, the tables are generated for every call.
+#[allow(dead_code)] // only used by backends which aren't compiled in on every target
+pub(crate) fn get_or_generate_tables<T: Any + Send + Sync>(
+    cache_key: CrcParamsCacheKey,
+    generate: impl FnOnce() -> T,
+) -> Arc<T> {
+    if !ENABLED {
+        return Arc::new(generate());
+    }
+
+    let cache_key = (TypeId::of::<T>(), cache_key);
+    let shard =
+        &TABLES.get_or_init(|| std::array::from_fn(|_| RwLock::default()))[shard_index(&cache_key)];
+
+    if let Some(tables) = shard.read().ok().and_then(|cache| cache.get(&cache_key)) {
+        if let Ok(tables) = tables.downcast() {
+            return tables;
+        }
+    }
+
+    // Generate tables outside of write lock to minimize lock hold time
+    let tables = Arc::new(generate());
+
+    let _ = shard.write().map(|mut cache| {
+        cache.insert(
+            cache_key,
+            tables.clone(),
+            shard_capacity(CAPACITY.load(Ordering::Relaxed)),
+        );
+    });
+
+    tables
 }
 
 #[cfg(test)]
@@ -151,11 +481,11 @@ mod tests {
 
         assert_eq!(key1.width, 32);
         assert_eq!(key1.poly, 0x04C11DB7);
-        assert_eq!(key1.reflected, true);
+        assert!(key1.reflected);
 
         assert_eq!(key2.width, 64);
         assert_eq!(key2.poly, 0x42F0E1EBA9EA3693);
-        assert_eq!(key2.reflected, false);
+        assert!(!key2.reflected);
     }
 
     #[test]
@@ -206,7 +536,7 @@ mod tests {
 
     #[test]
     fn test_cache_hit_scenarios() {
-        clear_cache();
+        clear_key_cache();
 
         // First call should be a cache miss and generate keys
         let keys1 = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -229,7 +559,7 @@ mod tests {
 
     #[test]
     fn test_cache_miss_scenarios() {
-        clear_cache();
+        clear_key_cache();
 
         // Different width - should be cache miss
         let keys_32 = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -264,7 +594,7 @@ mod tests {
 
     #[test]
     fn test_cached_keys_identical_to_generated_keys() {
-        clear_cache();
+        clear_key_cache();
 
         // Test CRC32 parameters
         let width = 32;
@@ -305,7 +635,7 @@ mod tests {
 
     #[test]
     fn test_multiple_parameter_combinations() {
-        clear_cache();
+        clear_key_cache();
 
         // Test various common CRC parameter combinations
         let test_cases = [
@@ -350,7 +680,7 @@ mod tests {
     #[test]
     fn test_cache_management_utilities() {
         // Clear cache to start with clean state
-        clear_cache();
+        clear_key_cache();
 
         // Generate and cache some keys
         let keys1 = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -364,7 +694,7 @@ mod tests {
         assert_eq!(keys2, cached_keys2);
 
         // Clear cache
-        clear_cache();
+        clear_key_cache();
 
         // Verify cache was cleared by checking that new calls still work
         // (we can't directly verify cache is empty, but we can verify functionality)
@@ -375,12 +705,12 @@ mod tests {
     #[test]
     fn test_cache_error_handling() {
         // Test that cache operations don't panic even if called multiple times
-        clear_cache();
-        clear_cache(); // Should not panic on empty cache
+        clear_key_cache();
+        clear_key_cache(); // Should not panic on empty cache
 
         // Test that get_or_generate_keys works even after multiple clears
         let keys = get_or_generate_keys(32, 0x04C11DB7, true);
-        clear_cache();
+        clear_key_cache();
         let keys2 = get_or_generate_keys(32, 0x04C11DB7, true);
 
         // Keys should be identical (same parameters produce same keys)
@@ -412,7 +742,7 @@ mod tests {
         use std::sync::{Arc, Barrier};
         use std::thread;
 
-        clear_cache();
+        clear_key_cache();
 
         // Pre-populate cache with a known value
         let expected_keys = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -457,7 +787,7 @@ mod tests {
         use std::sync::{Arc, Barrier};
         use std::thread;
 
-        clear_cache();
+        clear_key_cache();
 
         let num_threads = 6;
         let barrier = Arc::new(Barrier::new(num_threads));
@@ -474,9 +804,8 @@ mod tests {
         ];
 
         // Spawn threads that write different cache entries simultaneously
-        for i in 0..num_threads {
+        for (i, &(width, poly, reflected)) in test_params.iter().enumerate().take(num_threads) {
             let barrier_clone = Arc::clone(&barrier);
-            let (width, poly, reflected) = test_params[i];
 
             let handle = thread::spawn(move || {
                 // Wait for all threads to be ready
@@ -523,7 +852,7 @@ mod tests {
         use std::thread;
         use std::time::Duration;
 
-        clear_cache();
+        clear_key_cache();
 
         // Pre-populate cache with some values
         let _keys1 = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -550,8 +879,8 @@ mod tests {
                     let keys2 = get_or_generate_keys(64, 0x42F0E1EBA9EA3693, false);
 
                     // Verify we get consistent results
-                    assert_eq!(keys1.len(), 23);
-                    assert_eq!(keys2.len(), 23);
+                    assert_eq!(keys1.len(), 25);
+                    assert_eq!(keys2.len(), 25);
                     read_count += 1;
                 }
 
@@ -575,7 +904,7 @@ mod tests {
                     let poly = 0x1EDC6F41 + (i as u64 * 0x1000) + (write_count as u64);
                     let keys = get_or_generate_keys(32, poly, true);
 
-                    assert_eq!(keys.len(), 23);
+                    assert_eq!(keys.len(), 25);
                     write_count += 1;
                 }
 
@@ -617,7 +946,7 @@ mod tests {
         use std::sync::{Arc, Barrier};
         use std::thread;
 
-        clear_cache();
+        clear_key_cache();
 
         let num_threads = 10;
         let barrier = Arc::new(Barrier::new(num_threads));
@@ -677,7 +1006,7 @@ mod tests {
         use std::thread;
         use std::time::Duration;
 
-        clear_cache();
+        clear_key_cache();
 
         let num_threads = 8;
         let barrier = Arc::new(Barrier::new(num_threads));
@@ -712,7 +1041,7 @@ mod tests {
                         3 => {
                             // Occasional cache clear (but not too often to avoid disrupting other tests)
                             if operations % 10 == 0 {
-                                clear_cache();
+                                clear_key_cache();
                             }
                             let _keys = get_or_generate_keys(32, 0x04C11DB7, true);
                         }
@@ -759,7 +1088,7 @@ mod tests {
         use std::sync::{Arc, Mutex};
         use std::thread;
 
-        clear_cache();
+        clear_key_cache();
 
         // Pre-populate cache with known values
         let expected_keys = get_or_generate_keys(32, 0x04C11DB7, true);
@@ -790,7 +1119,7 @@ mod tests {
 
         // Test that new cache entries can still be created
         let new_keys = get_or_generate_keys(64, 0x42F0E1EBA9EA3693, false);
-        assert_eq!(new_keys.len(), 23, "New cache entries should still work");
+        assert_eq!(new_keys.len(), 25, "New cache entries should still work");
 
         // Verify the new keys are cached
         let cached_new_keys = get_or_generate_keys(64, 0x42F0E1EBA9EA3693, false);
@@ -799,7 +1128,7 @@ mod tests {
 
     #[test]
     fn test_cache_fallback_to_direct_generation() {
-        clear_cache();
+        clear_key_cache();
 
         // Test that even if cache operations fail, we still get valid keys
         // This tests the fallback mechanism in get_or_generate_keys
@@ -840,7 +1169,7 @@ mod tests {
 
     #[test]
     fn test_cache_operations_under_memory_pressure() {
-        clear_cache();
+        clear_key_cache();
 
         // Simulate memory pressure by creating many cache entries
         // This tests that cache operations remain stable under load
@@ -871,7 +1200,7 @@ mod tests {
         let new_keys = get_or_generate_keys(32, 0x1EDC6F41, true);
         assert_eq!(
             new_keys.len(),
-            23,
+            25,
             "New entries should still work under memory pressure"
         );
 
@@ -880,20 +1209,20 @@ mod tests {
         assert_eq!(new_keys, cached_new_keys, "New entry should be cached");
 
         // Test cache clearing still works
-        clear_cache();
+        clear_key_cache();
 
         // Verify cache was cleared by testing that operations still work
         let post_clear_keys = get_or_generate_keys(32, 0x04C11DB7, true);
         assert_eq!(
             post_clear_keys.len(),
-            23,
+            25,
             "Cache should work after clearing under memory pressure"
         );
     }
 
     #[test]
     fn test_cache_error_recovery_patterns() {
-        clear_cache();
+        clear_key_cache();
 
         // Test various error recovery patterns to ensure robustness
 
@@ -901,7 +1230,7 @@ mod tests {
         for i in 0..50 {
             let poly = 0x04C11DB7 + (i as u64 % 10); // Create some duplicates
             let keys = get_or_generate_keys(32, poly, true);
-            assert_eq!(keys.len(), 23, "Rapid operation {} should succeed", i);
+            assert_eq!(keys.len(), 25, "Rapid operation {} should succeed", i);
         }
 
         // Pattern 2: Interleaved cache hits and misses
@@ -913,7 +1242,7 @@ mod tests {
 
             // Cache miss
             let miss_keys = get_or_generate_keys(32, 0x1EDC6F41 + (i as u64), false);
-            assert_eq!(miss_keys.len(), 23, "Cache miss {} should succeed", i);
+            assert_eq!(miss_keys.len(), 25, "Cache miss {} should succeed", i);
         }
 
         // Pattern 3: Mixed operations with clearing
@@ -922,7 +1251,7 @@ mod tests {
             let keys2 = get_or_generate_keys(64, 0x42F0E1EBA9EA3693, false);
 
             if i % 3 == 0 {
-                clear_cache();
+                clear_key_cache();
             }
 
             // Operations should still work after clearing
@@ -941,7 +1270,7 @@ mod tests {
         use std::thread;
         use std::time::Duration;
 
-        clear_cache();
+        clear_key_cache();
 
         let num_threads = 8;
         let barrier = Arc::new(Barrier::new(num_threads));
@@ -971,7 +1300,7 @@ mod tests {
                         }
                         2 => {
                             // Cache clearing (potential contention point)
-                            clear_cache();
+                            clear_key_cache();
                         }
                         3 => {
                             // Mixed width operations
@@ -1031,7 +1360,7 @@ mod tests {
 
     #[test]
     fn test_cache_memory_allocation_stress() {
-        clear_cache();
+        clear_key_cache();
 
         // Test cache behavior under memory allocation stress
         // Create a large number of unique cache entries to stress memory allocation
@@ -1048,7 +1377,7 @@ mod tests {
             created_entries.push((width, poly, reflected, keys));
 
             // Verify each entry is valid
-            assert_eq!(keys.len(), 23, "Entry {} should have valid keys", i);
+            assert_eq!(keys.len(), 25, "Entry {} should have valid keys", i);
         }
 
         // Verify all entries are still accessible (testing cache integrity)
@@ -1066,18 +1395,18 @@ mod tests {
         let new_keys = get_or_generate_keys(32, 0xFFFFFFFF, true);
         assert_eq!(
             new_keys.len(),
-            23,
+            25,
             "New entries should work after memory stress"
         );
 
         // Test cache clearing works under memory pressure
-        clear_cache();
+        clear_key_cache();
 
         // Verify cache operations still work after clearing
         let post_stress_keys = get_or_generate_keys(32, 0x04C11DB7, true);
         assert_eq!(
             post_stress_keys.len(),
-            23,
+            25,
             "Cache should work after memory stress and clearing"
         );
     }
@@ -1087,7 +1416,7 @@ mod tests {
     fn test_crc_params_new_behavior_unchanged() {
         use crate::CrcParams;
 
-        clear_cache();
+        clear_key_cache();
 
         // Test that CrcParams::new() creates identical instances regardless of caching
         let params1 = CrcParams::new(
@@ -1155,7 +1484,7 @@ mod tests {
     fn test_existing_crc_parameter_combinations() {
         use crate::test::consts::TEST_ALL_CONFIGS;
 
-        clear_cache();
+        clear_key_cache();
 
         // Test all existing CRC parameter combinations work correctly with caching
         for config in TEST_ALL_CONFIGS {
@@ -1194,7 +1523,7 @@ mod tests {
 
     #[test]
     fn test_cached_vs_uncached_results_identical() {
-        clear_cache();
+        clear_key_cache();
 
         // Test parameters that affect key generation
         let test_cases = [
@@ -1211,7 +1540,7 @@ mod tests {
             let uncached_keys = generate::keys(width, poly, reflected);
 
             // Clear cache to ensure first call is cache miss
-            clear_cache();
+            clear_key_cache();
 
             // Create CrcParams instance (first call - cache miss)
             let params1 =
@@ -1242,7 +1571,7 @@ mod tests {
 
     #[test]
     fn test_multiple_crc_params_instances_use_cached_keys() {
-        clear_cache();
+        clear_key_cache();
 
         // Create multiple CrcParams instances with the same parameters
         let width = 32;
@@ -1361,7 +1690,7 @@ mod tests {
     fn test_crc_params_api_compatibility() {
         use crate::{CrcAlgorithm, CrcParams};
 
-        clear_cache();
+        clear_key_cache();
 
         // Test that the CrcParams API remains unchanged
         let params = CrcParams::new(
@@ -1389,6 +1718,7 @@ mod tests {
 
         // Test that CrcParams can be copied and cloned
         let params_copy = params;
+        #[allow(clippy::clone_on_copy)]
         let params_clone = params.clone();
 
         assert_eq!(params.keys, params_copy.keys);
@@ -1404,7 +1734,7 @@ mod tests {
     fn test_crc_params_with_all_standard_algorithms() {
         use crate::test::consts::TEST_ALL_CONFIGS;
 
-        clear_cache();
+        clear_key_cache();
 
         // Test creating CrcParams for all standard CRC algorithms
         for config in TEST_ALL_CONFIGS {
@@ -1460,7 +1790,7 @@ mod tests {
 
     #[test]
     fn test_crc_params_edge_cases() {
-        clear_cache();
+        clear_key_cache();
 
         // Test edge cases for CrcParams creation
 
@@ -1482,10 +1812,10 @@ mod tests {
 
         // Should have different keys due to different reflection
         assert_ne!(params_reflected.keys, params_normal.keys);
-        assert_eq!(params_reflected.refin, true);
-        assert_eq!(params_reflected.refout, true);
-        assert_eq!(params_normal.refin, false);
-        assert_eq!(params_normal.refout, false);
+        assert!(params_reflected.refin);
+        assert!(params_reflected.refout);
+        assert!(!params_normal.refin);
+        assert!(!params_normal.refout);
 
         // Test 64-bit edge cases
         let params64_min = crate::CrcParams::new("CRC64_MIN", 64, 0x1, 0x0, false, 0x0, 0x0);
@@ -1497,13 +1827,13 @@ mod tests {
         assert_eq!(params64_max.width, 64);
         assert_eq!(params64_max.poly, 0xFFFFFFFFFFFFFFFF);
 
-        // Verify all instances have valid 23-element key arrays
-        assert_eq!(params_min_poly.keys.key_count(), 23);
-        assert_eq!(params_max_poly.keys.key_count(), 23);
-        assert_eq!(params_reflected.keys.key_count(), 23);
-        assert_eq!(params_normal.keys.key_count(), 23);
-        assert_eq!(params64_min.keys.key_count(), 23);
-        assert_eq!(params64_max.keys.key_count(), 23);
+        // Verify all instances have valid 25-element key arrays
+        assert_eq!(params_min_poly.keys.key_count(), 25);
+        assert_eq!(params_max_poly.keys.key_count(), 25);
+        assert_eq!(params_reflected.keys.key_count(), 25);
+        assert_eq!(params_normal.keys.key_count(), 25);
+        assert_eq!(params64_min.keys.key_count(), 25);
+        assert_eq!(params64_max.keys.key_count(), 25);
     }
 
     #[test]
@@ -1511,7 +1841,7 @@ mod tests {
         use std::sync::{Arc, Barrier};
         use std::thread;
 
-        clear_cache();
+        clear_key_cache();
 
         let num_threads = 8;
         let barrier = Arc::new(Barrier::new(num_threads));
@@ -1562,8 +1892,8 @@ mod tests {
             assert_eq!(params.width, 32);
             assert_eq!(params.poly, 0x04C11DB7);
             assert_eq!(params.init, 0xFFFFFFFF);
-            assert_eq!(params.refin, true);
-            assert_eq!(params.refout, true);
+            assert!(params.refin);
+            assert!(params.refout);
             assert_eq!(params.xorout, 0xFFFFFFFF);
             assert_eq!(params.check, 0xCBF43926);
         }
@@ -1581,7 +1911,7 @@ mod tests {
         use std::sync::{Arc, Barrier};
         use std::thread;
 
-        clear_cache();
+        clear_key_cache();
 
         // This test is tricky because we need to poison the lock without
         // actually breaking our test. We'll simulate lock poisoning by
@@ -1591,7 +1921,7 @@ mod tests {
 
         // First, verify normal operation
         let keys_before = get_or_generate_keys(32, 0x04C11DB7, true);
-        assert_eq!(keys_before.len(), 23);
+        assert_eq!(keys_before.len(), 25);
 
         // Test that even if internal operations fail, the function still returns valid keys
         // We can't easily poison the lock in a controlled way, but we can verify
@@ -1613,7 +1943,7 @@ mod tests {
                     let keys = get_or_generate_keys(32, poly, true);
                     assert_eq!(
                         keys.len(),
-                        23,
+                        25,
                         "Thread {} iteration {} should return valid keys",
                         i,
                         j
@@ -1621,7 +1951,7 @@ mod tests {
 
                     // Occasional cache clear to increase contention
                     if j % 7 == 0 {
-                        clear_cache();
+                        clear_key_cache();
                     }
                 }
 
@@ -1638,7 +1968,7 @@ mod tests {
 
         // Verify cache is still functional after stress testing
         let keys_after = get_or_generate_keys(32, 0x04C11DB7, true);
-        assert_eq!(keys_after.len(), 23);
+        assert_eq!(keys_after.len(), 25);
 
         // Keys should be mathematically correct regardless of cache state
         let expected_keys = generate::keys(32, 0x04C11DB7, true);
@@ -1649,7 +1979,7 @@ mod tests {
     fn test_cache_behavior_with_thread_local_access() {
         use std::thread;
 
-        clear_cache();
+        clear_key_cache();
 
         // Test that cache works correctly when accessed from different threads
         // in sequence (not concurrently)
@@ -1658,8 +1988,7 @@ mod tests {
 
         let handle = thread::spawn(|| {
             // This thread should see the cached value from the main thread
-            let keys_thread = get_or_generate_keys(32, 0x04C11DB7, true);
-            keys_thread
+            get_or_generate_keys(32, 0x04C11DB7, true)
         });
 
         let keys_from_thread = handle.join().expect("Thread should not panic");
@@ -1689,4 +2018,174 @@ mod tests {
             );
         }
     }
+
+    fn storage(key: u64) -> CrcKeysStorage {
+        CrcKeysStorage::from_keys_fold_512([key; 25])
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let mut cache = KeyCache::default();
+        let key = |poly| CrcParamsCacheKey::new(32, poly, true);
+
+        for poly in 1..=3 {
+            cache.insert(key(poly), storage(poly), 3);
+        }
+
+        // using 1 makes 2 the least recently used
+        assert_eq!(cache.get(&key(1)), Some(storage(1)));
+
+        cache.insert(key(4), storage(4), 3);
+        assert_eq!(cache.entries.len(), 3);
+        assert_eq!(cache.get(&key(2)), None);
+        assert_eq!(cache.get(&key(1)), Some(storage(1)));
+        assert_eq!(cache.get(&key(3)), Some(storage(3)));
+        assert_eq!(cache.get(&key(4)), Some(storage(4)));
+
+        // replacing an existing entry doesn't evict anything
+        cache.insert(key(3), storage(3), 3);
+        assert_eq!(cache.entries.len(), 3);
+        assert_eq!(cache.get(&key(1)), Some(storage(1)));
+    }
+
+    #[test]
+    fn test_lru_evict_to() {
+        let mut cache = KeyCache::default();
+        let key = |poly| CrcParamsCacheKey::new(64, poly, false);
+
+        for poly in 1..=10 {
+            cache.insert(key(poly), storage(poly), UNBOUNDED);
+        }
+        cache.get(&key(1));
+
+        cache.evict_to(2);
+        assert_eq!(cache.entries.len(), 2);
+        assert!(cache.entries.contains_key(&key(1)));
+        assert!(cache.entries.contains_key(&key(10)));
+
+        // a capacity of 0 caches nothing
+        cache.insert(key(11), storage(11), 0);
+        assert!(!cache.entries.contains_key(&key(11)));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-key-cache"))]
+    fn test_key_cache_capacity() {
+        assert_eq!(get_key_cache_capacity(), Some(DEFAULT_CAPACITY));
+
+        set_key_cache_capacity(None);
+        assert_eq!(get_key_cache_capacity(), None);
+
+        set_key_cache_capacity(Some(1));
+        assert_eq!(get_key_cache_capacity(), Some(1));
+
+        // keys are still correct while entries are being evicted
+        assert_eq!(
+            get_or_generate_keys(32, 0x1EDC6F41, true),
+            generate::keys(32, 0x1EDC6F41, true)
+        );
+
+        set_key_cache_capacity(Some(DEFAULT_CAPACITY));
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-key-cache"))]
+    fn test_shards() {
+        assert_eq!(shard_capacity(UNBOUNDED), UNBOUNDED);
+        assert_eq!(shard_capacity(0), 0);
+        assert_eq!(shard_capacity(1), 1);
+        assert_eq!(shard_capacity(DEFAULT_CAPACITY), DEFAULT_CAPACITY / SHARDS);
+        assert_eq!(shard_capacity(1025), 65);
+
+        // the same parameters always map to the same shard, and distinct ones spread out
+        let key = CrcParamsCacheKey::new(32, 0x1EDC6F41, true);
+        assert!(std::ptr::eq(get_shard(&key), get_shard(&key.clone())));
+
+        let used: HashSet<_> = (0..256u64)
+            .map(|poly| get_shard(&CrcParamsCacheKey::new(64, poly, false)) as *const _)
+            .collect();
+        assert!(used.len() > SHARDS / 2);
+    }
+
+    #[test]
+    fn test_invalidate_key_cache() {
+        // a polynomial no other test uses, so concurrent tests can't re-add it (but they can
+        // clear it, so whether the first invalidation finds it isn't checked)
+        let (width, poly, reflected) = (64, 0x0123456789ABCDEF, true);
+        let cache_key = CrcParamsCacheKey::new(width, poly, reflected);
+        get_or_generate_keys(width, poly, reflected);
+
+        invalidate_key_cache(width, poly, reflected);
+        if ENABLED {
+            let shard = get_shard(&cache_key).read().unwrap();
+            assert!(!shard.entries.contains_key(&cache_key));
+        }
+        assert!(!invalidate_key_cache(width, poly, reflected));
+        assert!(!invalidate_key_cache(16, 0x1021, false));
+
+        assert_eq!(
+            get_or_generate_keys(width, poly, reflected),
+            generate::keys(width, poly, reflected)
+        );
+    }
+
+    #[test]
+    fn test_prewarm_key_cache() {
+        let params = [(32, 0x741B8CD7, true), (64, 0xAD93D23594C935A9, false)];
+        prewarm_key_cache(&params);
+
+        for (width, poly, reflected) in params {
+            assert_eq!(
+                get_or_generate_keys(width, poly, reflected),
+                generate::keys(width, poly, reflected)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "no-key-cache"))]
+    #[should_panic(expected = "Unsupported width")]
+    fn test_prewarm_key_cache_unsupported_width() {
+        prewarm_key_cache(&[(16, 0x1021, false)]);
+    }
+
+    #[test]
+    fn test_tables() {
+        // a type private to this test, so no other test's tables share its entries
+        struct TestTables(u64);
+
+        let (width, poly, reflected) = (64, 0x3141_5926_5358_9793, false);
+        let key = || CrcParamsCacheKey::new(width, poly, reflected);
+
+        // other tests may clear the cache in between, so one hit in a few attempts will do
+        let cached = (0..10).any(|_| {
+            let first = get_or_generate_tables(key(), || TestTables(1));
+            let second = get_or_generate_tables(key(), || TestTables(2));
+
+            first.0 == 1 && Arc::ptr_eq(&first, &second)
+        });
+        assert_eq!(cached, ENABLED);
+
+        // invalidating the parameters removes their tables too
+        get_or_generate_tables(key(), || TestTables(1));
+        invalidate_key_cache(width, poly, reflected);
+        assert_eq!(get_or_generate_tables(key(), || TestTables(3)).0, 3);
+
+        clear_key_cache();
+        assert_eq!(get_or_generate_tables(key(), || TestTables(4)).0, 4);
+    }
+
+    #[test]
+    #[cfg(feature = "no-key-cache")]
+    fn test_key_cache_disabled() {
+        set_key_cache_capacity(Some(16));
+        assert_eq!(get_key_cache_capacity(), Some(0));
+
+        assert_eq!(
+            get_or_generate_keys(32, 0x1EDC6F41, true),
+            generate::keys(32, 0x1EDC6F41, true)
+        );
+        assert!(CACHE.get().is_none());
+        assert!(TABLES.get().is_none());
+    }
 }
@@ -50,7 +50,7 @@
      http://reveng.sourceforge.net/crc-catalogue/all.htm
 */
 
-use crate::CrcParams;
+use crate::{CrcAlgorithm, CrcFastError, CrcParams};
 
 /* Multiply the GF(2) vector vec by the GF(2) matrix mat, returning the
 resulting vector.  The vector is stored as bits in a crc_t.  The matrix is
@@ -80,40 +80,37 @@ fn gf2_matrix_square(square: &mut [u64; 64], mat: &[u64; 64]) {
     }
 }
 
+/// Returns an error if the checksum has bits set above the CRC width, which combining would
+/// silently turn into a wrong answer (such as combining a CRC-64 as a CRC-32).
+pub(crate) fn validate_width(checksum: u64, params: CrcParams) -> Result<(), CrcFastError> {
+    if params.width < 64 && checksum >> params.width != 0 {
+        return Err(CrcFastError::ChecksumTooWide {
+            width: params.width,
+            checksum,
+        });
+    }
+
+    Ok(())
+}
+
 /* Combine the CRCs of two successive sequences, where crc1 is the CRC of the
 first sequence of bytes, crc2 is the CRC of the immediately following
 sequence of bytes, and len2 is the length of the second sequence.  The CRC
 of the combined sequence is returned. */
-pub fn checksums(mut crc1: u64, crc2: u64, mut len2: u64, params: CrcParams) -> u64 {
-    let mut col: u64;
+pub fn checksums(crc1: u64, crc2: u64, len2: u64, params: CrcParams) -> u64 {
+    checksums_wide(crc1, crc2, len2 as u128, params)
+}
+
+/* Combine the CRCs of two successive sequences, where the second sequence may be
+longer than u64::MAX bytes, such as for logically-combined exabyte-scale streams. */
+pub fn checksums_wide(mut crc1: u64, crc2: u64, mut len2: u128, params: CrcParams) -> u64 {
     let mut even = [0u64; 64]; /* even-power-of-two zeros operator */
-    let mut odd = [0u64; 64]; /* odd-power-of-two zeros operator */
+    let mut odd = one_zero_bit_operator(params); /* odd-power-of-two zeros operator */
 
     /* exclusive-or the result with len2 zeros applied to the CRC of an empty
     sequence */
     crc1 ^= params.init ^ params.xorout;
 
-    /* construct the operator for one zero bit and put in odd[] */
-    if params.refin && params.refout {
-        // use the reflected POLY
-        odd[0] = reflect_poly(params.poly, params.width as u32);
-        col = 1;
-        for n in 1..params.width {
-            odd[n as usize] = col;
-            col <<= 1;
-        }
-    } else if !params.refin && !params.refout {
-        col = 2;
-        for n in 0..params.width - 1 {
-            odd[n as usize] = col;
-            col <<= 1;
-        }
-        // Put poly at the last valid index (width-1)
-        odd[(params.width - 1) as usize] = params.poly;
-    } else {
-        panic!("Unsupported CRC configuration");
-    }
-
     /* put operator for two zero bits in even */
     gf2_matrix_square(&mut even, &odd);
 
@@ -154,6 +151,138 @@ pub fn checksums(mut crc1: u64, crc2: u64, mut len2: u64, params: CrcParams) ->
     crc1
 }
 
+/* Construct the operator for one zero bit. */
+fn one_zero_bit_operator(params: CrcParams) -> [u64; 64] {
+    let mut op = [0u64; 64];
+    let mut col: u64;
+
+    if params.refin && params.refout {
+        // use the reflected POLY
+        op[0] = reflect_poly(params.poly, params.width as u32);
+        col = 1;
+        for n in 1..params.width {
+            op[n as usize] = col;
+            col <<= 1;
+        }
+    } else if !params.refin && !params.refout {
+        col = 2;
+        for n in 0..params.width - 1 {
+            op[n as usize] = col;
+            col <<= 1;
+        }
+        // Put poly at the last valid index (width-1)
+        op[(params.width - 1) as usize] = params.poly;
+    } else {
+        panic!("Unsupported CRC configuration");
+    }
+
+    op
+}
+
+/* Construct the operator which applies len zero bytes to a CRC, by multiplying
+together the power-of-two zeros operators for each bit of len, the same way
+checksums_wide() applies them to a CRC. */
+fn zeros_operator(mut len: u128, params: CrcParams) -> [u64; 64] {
+    /* start with the identity operator */
+    let mut result = [0u64; 64];
+    for (n, col) in result.iter_mut().take(params.width as usize).enumerate() {
+        *col = 1 << n;
+    }
+
+    let mut op = one_zero_bit_operator(params);
+    let mut square = [0u64; 64];
+
+    /* advance to the operator for one zero byte */
+    for _ in 0..3 {
+        gf2_matrix_square(&mut square, &op);
+        op = square;
+    }
+
+    while len > 0 {
+        if len & 1 == 1 {
+            for col in result.iter_mut() {
+                *col = gf2_matrix_times(&op, *col);
+            }
+        }
+        len >>= 1;
+
+        if len > 0 {
+            gf2_matrix_square(&mut square, &op);
+            op = square;
+        }
+    }
+
+    result
+}
+
+/// A precomputed operator which combines checksums of sequences with a fixed length, using
+/// custom CRC parameters.
+///
+/// `checksum_combine_with_params()` builds the operator for the length of the second sequence on
+/// every call, which dominates the cost. When many checksums are combined with the same length,
+/// such as equal-size parts of a multipart upload, or when building custom combine trees, build
+/// the operator once and `apply()` it, which costs a single GF(2) matrix multiplication.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, CombineOperator, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let part_1 = checksum(Crc32IsoHdlc, b"123");
+/// let part_2 = checksum(Crc32IsoHdlc, b"456");
+/// let part_3 = checksum(Crc32IsoHdlc, b"789");
+///
+/// let operator = CombineOperator::new(Crc32IsoHdlc, 3);
+///
+/// assert_eq!(operator.apply(operator.apply(part_1, part_2), part_3), 0xcbf43926);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct CombineOperator {
+    /// The matrix which applies `len` zero bytes to a CRC.
+    matrix: [u64; 64],
+
+    /// The adjustment for the initial value and final XOR, applied before the matrix.
+    adjust: u64,
+
+    /// The length of the second sequence, in bytes.
+    len: u128,
+}
+
+impl CombineOperator {
+    /// Builds the operator combining checksums of the specified algorithm, where the second
+    /// sequence is `len` bytes long.
+    pub fn new(algorithm: CrcAlgorithm, len: u128) -> Self {
+        Self::new_with_params(crate::get_calculator_params(algorithm).1, len)
+    }
+
+    /// Builds the operator combining checksums with custom CRC parameters, where the second
+    /// sequence is `len` bytes long.
+    pub fn new_with_params(params: CrcParams, len: u128) -> Self {
+        Self {
+            matrix: zeros_operator(len, params),
+            adjust: params.init ^ params.xorout,
+            len,
+        }
+    }
+
+    /// Combines the checksum of a sequence with the checksum of the `len` bytes which follow it,
+    /// returning the checksum of both together.
+    #[inline]
+    pub fn apply(&self, checksum1: u64, checksum2: u64) -> u64 {
+        gf2_matrix_times(&self.matrix, checksum1 ^ self.adjust) ^ checksum2
+    }
+
+    /// Returns the length of the second sequence, in bytes.
+    pub fn len(&self) -> u128 {
+        self.len
+    }
+
+    /// Returns true if the second sequence is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
 fn reflect_poly(poly: u64, width: u32) -> u64 {
     assert!(width <= 64, "Width must be <= 64 bits");
 
@@ -186,3 +315,45 @@ fn bit_reverse(mut forward: u64) -> u64 {
 
     reversed
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+
+    #[test]
+    fn test_combine_operator() {
+        let data: Vec<u8> = (0..2000u32).map(|i| (i * 7 % 251) as u8).collect();
+
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+
+            for len in [0usize, 1, 2, 3, 8, 100, 1000] {
+                let operator = CombineOperator::new(algorithm, len as u128);
+
+                let (first, second) = data[..1000 + len].split_at(1000);
+
+                assert_eq!(
+                    operator.apply(
+                        crate::checksum(algorithm, first),
+                        crate::checksum(algorithm, second)
+                    ),
+                    config.checksum_with_reference(&data[..1000 + len])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_combine_operator_wide() {
+        for config in TEST_ALL_CONFIGS {
+            let params = crate::get_calculator_params(config.get_algorithm()).1;
+            let len = u64::MAX as u128 + 12345;
+
+            assert_eq!(
+                CombineOperator::new_with_params(params, len).apply(0x1234, 0x5678),
+                checksums_wide(0x1234, 0x5678, len, params)
+            );
+        }
+    }
+}
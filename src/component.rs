@@ -0,0 +1,252 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! WebAssembly component bindings
+//!
+//! This module implements the `crc` interface of the `crc-fast` world in `wit/crc-fast.wit`, which
+//! is exported when the crate is built for a component target such as `wasm32-wasip2`, so that
+//! sandboxed plugin runtimes (wasmtime, jco, etc.) can call it.
+
+use crate::{CrcAlgorithm, CrcParams};
+use std::cell::RefCell;
+
+wit_bindgen::generate!({
+    world: "crc-fast",
+    path: "wit",
+});
+
+use exports::awesomized::crc_fast::crc::{
+    Algorithm, Digest as DigestHandle, Error, Guest, GuestDigest, Params,
+};
+
+struct Component;
+
+#[cfg(target_family = "wasm")]
+export!(Component);
+
+impl From<Algorithm> for CrcAlgorithm {
+    fn from(algorithm: Algorithm) -> Self {
+        match algorithm {
+            Algorithm::Crc32Aixm => CrcAlgorithm::Crc32Aixm,
+            Algorithm::Crc32Autosar => CrcAlgorithm::Crc32Autosar,
+            Algorithm::Crc32Base91D => CrcAlgorithm::Crc32Base91D,
+            Algorithm::Crc32Bzip2 => CrcAlgorithm::Crc32Bzip2,
+            Algorithm::Crc32CdRomEdc => CrcAlgorithm::Crc32CdRomEdc,
+            Algorithm::Crc32Cksum => CrcAlgorithm::Crc32Cksum,
+            Algorithm::Crc32Iscsi => CrcAlgorithm::Crc32Iscsi,
+            Algorithm::Crc32IsoHdlc => CrcAlgorithm::Crc32IsoHdlc,
+            Algorithm::Crc32Jamcrc => CrcAlgorithm::Crc32Jamcrc,
+            Algorithm::Crc32Mef => CrcAlgorithm::Crc32Mef,
+            Algorithm::Crc32Mpeg2 => CrcAlgorithm::Crc32Mpeg2,
+            Algorithm::Crc32Xfer => CrcAlgorithm::Crc32Xfer,
+            Algorithm::Crc64Ecma182 => CrcAlgorithm::Crc64Ecma182,
+            Algorithm::Crc64GoIso => CrcAlgorithm::Crc64GoIso,
+            Algorithm::Crc64Ms => CrcAlgorithm::Crc64Ms,
+            Algorithm::Crc64Nvme => CrcAlgorithm::Crc64Nvme,
+            Algorithm::Crc64Redis => CrcAlgorithm::Crc64Redis,
+            Algorithm::Crc64We => CrcAlgorithm::Crc64We,
+            Algorithm::Crc64Xz => CrcAlgorithm::Crc64Xz,
+        }
+    }
+}
+
+impl TryFrom<CrcAlgorithm> for Algorithm {
+    type Error = ();
+
+    fn try_from(algorithm: CrcAlgorithm) -> Result<Self, ()> {
+        Ok(match algorithm {
+            CrcAlgorithm::Crc32Aixm => Algorithm::Crc32Aixm,
+            CrcAlgorithm::Crc32Autosar => Algorithm::Crc32Autosar,
+            CrcAlgorithm::Crc32Base91D => Algorithm::Crc32Base91D,
+            CrcAlgorithm::Crc32Bzip2 => Algorithm::Crc32Bzip2,
+            CrcAlgorithm::Crc32CdRomEdc => Algorithm::Crc32CdRomEdc,
+            CrcAlgorithm::Crc32Cksum => Algorithm::Crc32Cksum,
+            CrcAlgorithm::Crc32Iscsi => Algorithm::Crc32Iscsi,
+            CrcAlgorithm::Crc32IsoHdlc => Algorithm::Crc32IsoHdlc,
+            CrcAlgorithm::Crc32Jamcrc => Algorithm::Crc32Jamcrc,
+            CrcAlgorithm::Crc32Mef => Algorithm::Crc32Mef,
+            CrcAlgorithm::Crc32Mpeg2 => Algorithm::Crc32Mpeg2,
+            CrcAlgorithm::Crc32Xfer => Algorithm::Crc32Xfer,
+            CrcAlgorithm::Crc64Ecma182 => Algorithm::Crc64Ecma182,
+            CrcAlgorithm::Crc64GoIso => Algorithm::Crc64GoIso,
+            CrcAlgorithm::Crc64Ms => Algorithm::Crc64Ms,
+            CrcAlgorithm::Crc64Nvme => Algorithm::Crc64Nvme,
+            CrcAlgorithm::Crc64Redis => Algorithm::Crc64Redis,
+            CrcAlgorithm::Crc64We => Algorithm::Crc64We,
+            CrcAlgorithm::Crc64Xz => Algorithm::Crc64Xz,
+            CrcAlgorithm::Crc32Custom | CrcAlgorithm::Crc64Custom => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<Params> for CrcParams {
+    type Error = Error;
+
+    fn try_from(params: Params) -> Result<Self, Error> {
+        if params.width != 32 && params.width != 64 {
+            return Err(Error::UnsupportedWidth);
+        }
+
+        Ok(CrcParams::new(
+            "custom",
+            params.width,
+            params.poly,
+            params.init,
+            params.reflected,
+            params.xorout,
+            params.check,
+        ))
+    }
+}
+
+impl Guest for Component {
+    type Digest = Digest;
+
+    fn algorithm_name(algorithm: Algorithm) -> String {
+        CrcAlgorithm::from(algorithm).to_string()
+    }
+
+    fn algorithm_from_name(name: String) -> Option<Algorithm> {
+        name.parse::<CrcAlgorithm>()
+            .ok()
+            .and_then(|algorithm| algorithm.try_into().ok())
+    }
+
+    fn checksum(algorithm: Algorithm, data: Vec<u8>) -> u64 {
+        crate::checksum(algorithm.into(), &data)
+    }
+
+    fn checksum_with_params(params: Params, data: Vec<u8>) -> Result<u64, Error> {
+        Ok(crate::checksum_with_params(params.try_into()?, &data))
+    }
+
+    fn checksum_combine(
+        algorithm: Algorithm,
+        checksum1: u64,
+        checksum2: u64,
+        checksum2_len: u64,
+    ) -> u64 {
+        crate::checksum_combine(algorithm.into(), checksum1, checksum2, checksum2_len)
+    }
+
+    fn checksum_combine_with_params(
+        params: Params,
+        checksum1: u64,
+        checksum2: u64,
+        checksum2_len: u64,
+    ) -> Result<u64, Error> {
+        Ok(crate::checksum_combine_with_params(
+            params.try_into()?,
+            checksum1,
+            checksum2,
+            checksum2_len,
+        ))
+    }
+}
+
+/// The `digest` resource, whose methods take `&self`, so the Digest is behind a RefCell
+struct Digest(RefCell<crate::Digest>);
+
+impl GuestDigest for Digest {
+    fn new(algorithm: Algorithm) -> Self {
+        Digest(RefCell::new(crate::Digest::new(algorithm.into())))
+    }
+
+    fn with_params(params: Params) -> Result<DigestHandle, Error> {
+        let digest = crate::Digest::new_with_params(params.try_into()?);
+
+        Ok(DigestHandle::new(Digest(RefCell::new(digest))))
+    }
+
+    fn update(&self, data: Vec<u8>) {
+        self.0.borrow_mut().update(&data);
+    }
+
+    fn finalize(&self) -> u64 {
+        self.0.borrow().finalize()
+    }
+
+    fn finalize_reset(&self) -> u64 {
+        self.0.borrow_mut().finalize_reset()
+    }
+
+    fn reset(&self) {
+        self.0.borrow_mut().reset();
+    }
+
+    fn combine(&self, other: exports::awesomized::crc_fast::crc::DigestBorrow<'_>) {
+        // copy other first, since it may be this same digest
+        let other = *other.get::<Digest>().0.borrow();
+
+        self.0.borrow_mut().combine(&other);
+    }
+
+    fn get_amount(&self) -> u64 {
+        self.0.borrow().get_amount()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_CHECK_STRING;
+
+    #[test]
+    fn test_algorithm_names_round_trip() {
+        for algorithm in crate::enums::PREDEFINED_ALGORITHMS {
+            let converted = Algorithm::try_from(algorithm).unwrap();
+
+            assert_eq!(CrcAlgorithm::from(converted), algorithm);
+            assert_eq!(
+                Component::algorithm_from_name(Component::algorithm_name(converted)),
+                Some(converted)
+            );
+        }
+
+        assert_eq!(Component::algorithm_from_name("CRC-99".to_string()), None);
+    }
+
+    #[test]
+    fn test_checksum_with_params() {
+        let params = Params {
+            width: 32,
+            poly: 0x1edc6f41,
+            init: 0xffffffff,
+            reflected: true,
+            xorout: 0xffffffff,
+            check: 0xe3069283,
+        };
+
+        assert_eq!(
+            Component::checksum_with_params(params, TEST_CHECK_STRING.to_vec()),
+            Ok(Component::checksum(
+                Algorithm::Crc32Iscsi,
+                TEST_CHECK_STRING.to_vec()
+            ))
+        );
+
+        assert_eq!(
+            Component::checksum_with_params(
+                Params {
+                    width: 16,
+                    ..params
+                },
+                vec![]
+            ),
+            Err(Error::UnsupportedWidth)
+        );
+    }
+
+    #[test]
+    fn test_digest() {
+        let digest = <Digest as GuestDigest>::new(Algorithm::Crc64Nvme);
+        digest.update(TEST_CHECK_STRING[..4].to_vec());
+        digest.update(TEST_CHECK_STRING[4..].to_vec());
+
+        assert_eq!(digest.get_amount(), TEST_CHECK_STRING.len() as u64);
+        assert_eq!(
+            digest.finalize_reset(),
+            Component::checksum(Algorithm::Crc64Nvme, TEST_CHECK_STRING.to_vec())
+        );
+        assert_eq!(digest.get_amount(), 0);
+    }
+}
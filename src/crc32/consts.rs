@@ -25,7 +25,7 @@ pub const CRC32_AIXM: CrcParams = CrcParams {
     refout: CRC_32_AIXM.refout, // false
     xorout: CRC_32_AIXM.xorout as u64,
     check: CRC_32_AIXM.check as u64,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_814141AB_FORWARD),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_814141AB_FORWARD),
 };
 
 // width=32 poly=0xf4acfb13 init=0xffffffff refin=true refout=true xorout=0xffffffff check=0x1697d06a residue=0x904cddbf name="CRC-32/AUTOSAR"
@@ -39,7 +39,7 @@ pub const CRC32_AUTOSAR: CrcParams = CrcParams {
     refout: CRC_32_AUTOSAR.refout, // true
     xorout: CRC_32_AUTOSAR.xorout as u64,
     check: CRC_32_AUTOSAR.check as u64,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_F4ACFB13_REFLECTED),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_F4ACFB13_REFLECTED),
 };
 
 // width=32 poly=0xa833982b init=0xffffffff refin=true refout=true xorout=0xffffffff check=0x87315576 residue=0x45270551 name="CRC-32/BASE91-D"
@@ -53,7 +53,7 @@ pub const CRC32_BASE91_D: CrcParams = CrcParams {
     refout: CRC_32_BASE91_D.refout, // true
     xorout: CRC_32_BASE91_D.xorout as u64,
     check: CRC_32_BASE91_D.check as u64,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_A833982B_REFLECTED),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_A833982B_REFLECTED),
 };
 
 // width=32 poly=0x04c11db7 init=0xffffffff refin=false refout=false xorout=0xffffffff check=0xfc891918 residue=0xc704dd7b name="CRC-32/BZIP2"
@@ -67,7 +67,7 @@ pub const CRC32_BZIP2: CrcParams = CrcParams {
     refout: CRC_32_BZIP2.refout, // false
     xorout: CRC_32_BZIP2.xorout as u64,
     check: CRC_32_BZIP2.check as u64,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_04C11DB7_FORWARD),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_04C11DB7_FORWARD),
 };
 
 // width=32 poly=0x8001801b init=0x00000000 refin=true refout=true xorout=0x00000000 check=0x6ec2edc4 residue=0x00000000 name="CRC-32/CD-ROM-EDC"
@@ -81,7 +81,7 @@ pub const CRC32_CD_ROM_EDC: CrcParams = CrcParams {
     refout: CRC_32_CD_ROM_EDC.refout, // true
     xorout: CRC_32_CD_ROM_EDC.xorout as u64,
     check: CRC_32_CD_ROM_EDC.check as u64,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_8001801B_REFLECTED),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_8001801B_REFLECTED),
 };
 
 // width=32 poly=0x04c11db7 init=0x00000000 refin=false refout=false xorout=0xffffffff check=0x765e7680 residue=0xc704dd7b name="CRC-32/CKSUM"
@@ -95,7 +95,7 @@ pub const CRC32_CKSUM: CrcParams = CrcParams {
     refout: CRC_32_CKSUM.refout, // false
     xorout: CRC_32_CKSUM.xorout as u64,
     check: CRC_32_CKSUM.check as u64,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_04C11DB7_FORWARD),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_04C11DB7_FORWARD),
 };
 
 // width=32 poly=0x1edc6f41 init=0xffffffff refin=true refout=true xorout=0xffffffff check=0xe3069283 residue=0xb798b438 name="CRC-32/ISCSI"
@@ -109,7 +109,7 @@ pub const CRC32_ISCSI: CrcParams = CrcParams {
     refout: CRC_32_ISCSI.refout, // true
     xorout: CRC_32_ISCSI.xorout as u64,
     check: CRC_32_ISCSI.check as u64,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_1EDC6F41_REFLECTED),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_1EDC6F41_REFLECTED),
 };
 
 // width=32 poly=0x04c11db7 init=0xffffffff refin=true refout=true xorout=0xffffffff check=0xcbf43926 residue=0xdebb20e3 name="CRC-32/ISO-HDLC"
@@ -123,7 +123,7 @@ pub const CRC32_ISO_HDLC: CrcParams = CrcParams {
     refout: CRC_32_ISO_HDLC.refout, // true
     xorout: CRC_32_ISO_HDLC.xorout as u64,
     check: CRC_32_ISO_HDLC.check as u64,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_04C11DB7_REFLECTED),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_04C11DB7_REFLECTED),
 };
 
 // width=32 poly=0x04c11db7 init=0xffffffff refin=true refout=true xorout=0x00000000 check=0x340bc6d9 residue=0x00000000 name="CRC-32/JAMCRC"
@@ -137,7 +137,7 @@ pub const CRC32_JAMCRC: CrcParams = CrcParams {
     refout: CRC_32_JAMCRC.refout, // true
     xorout: CRC_32_JAMCRC.xorout as u64,
     check: CRC_32_JAMCRC.check as u64,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_04C11DB7_REFLECTED),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_04C11DB7_REFLECTED),
 };
 
 // width=32 poly=0x741b8cd7 init=0xffffffff refin=true refout=true xorout=0x00000000 check=0xd2c22f51 residue=0x00000000 name="CRC-32/MEF"
@@ -151,7 +151,7 @@ pub const CRC32_MEF: CrcParams = CrcParams {
     refout: CRC_32_MEF.refout, // true
     xorout: CRC_32_MEF.xorout as u64,
     check: CRC_32_MEF.check as u64,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_741B8CD7_REFLECTED),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_741B8CD7_REFLECTED),
 };
 
 // width=32 poly=0x04c11db7 init=0xffffffff refin=false refout=false xorout=0x00000000 check=0x0376e6e7 residue=0x00000000 name="CRC-32/MPEG-2"
@@ -165,7 +165,7 @@ pub const CRC32_MPEG_2: CrcParams = CrcParams {
     refout: CRC_32_MPEG_2.refout, // false
     xorout: CRC_32_MPEG_2.xorout as u64,
     check: CRC_32_MPEG_2.check as u64,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_04C11DB7_FORWARD),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_04C11DB7_FORWARD),
 };
 
 // width=32 poly=0x000000af init=0x00000000 refin=false refout=false xorout=0x00000000 check=0xbd0be338 residue=0x00000000 name="CRC-32/XFER"
@@ -179,11 +179,11 @@ pub const CRC32_XFER: CrcParams = CrcParams {
     refout: CRC_32_XFER.refout, // false
     xorout: CRC_32_XFER.xorout as u64,
     check: CRC_32_XFER.check as u64,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_000000AF_FORWARD),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_000000AF_FORWARD),
 };
 
 // CRC-32/AIXM
-pub const KEYS_814141AB_FORWARD: [u64; 23] = [
+pub const KEYS_814141AB_FORWARD: [u64; 25] = [
     0x0000000000000000,
     0x9be9878f00000000,
     0x85b2a6e400000000,
@@ -207,10 +207,12 @@ pub const KEYS_814141AB_FORWARD: [u64; 23] = [
     0xffc42e7700000000,
     0xd12a88300000000,
     0x93a03b8800000000,
+    0x906103ff00000000,
+    0x9fe4d6e700000000,
 ];
 
 // CRC-32/AUTOSAR
-pub const KEYS_F4ACFB13_REFLECTED: [u64; 23] = [
+pub const KEYS_F4ACFB13_REFLECTED: [u64; 25] = [
     0x0000000000000000,
     0x000000016130902a,
     0x0000000050428a9c,
@@ -234,10 +236,12 @@ pub const KEYS_F4ACFB13_REFLECTED: [u64; 23] = [
     0x0000000022919656,
     0x00000001e97b6a9e,
     0x00000000000cbd7c,
+    0x000000010d01fcaa,
+    0x000000005bff600c,
 ];
 
 // CRC-32/BASE91-D
-pub const KEYS_A833982B_REFLECTED: [u64; 23] = [
+pub const KEYS_A833982B_REFLECTED: [u64; 25] = [
     0x0000000000000000,
     0x00000001e065d896,
     0x00000001aca6d990,
@@ -261,10 +265,12 @@ pub const KEYS_A833982B_REFLECTED: [u64; 23] = [
     0x00000001a07ba234,
     0x000000010ffc58e6,
     0x000000015920d7a6,
+    0x0000000182fbbbfa,
+    0x0000000103c9c7ce,
 ];
 
 // CRC-32/CD-ROM-EDC
-pub const KEYS_8001801B_REFLECTED: [u64; 23] = [
+pub const KEYS_8001801B_REFLECTED: [u64; 25] = [
     0x0000000000000000,
     0x00000001d5934102,
     0x000000006c90c100,
@@ -288,10 +294,12 @@ pub const KEYS_8001801B_REFLECTED: [u64; 23] = [
     0x00000000bd01c000,
     0x00000001bcb30820,
     0x000000010d925102,
+    0x0000000147596180,
+    0x0000000099918100,
 ];
 
 // CRC-32/MEF
-pub const KEYS_741B8CD7_REFLECTED: [u64; 23] = [
+pub const KEYS_741B8CD7_REFLECTED: [u64; 25] = [
     0x0000000000000000,
     0x000000014b0602f8,
     0x000000007b4bc878,
@@ -315,10 +323,12 @@ pub const KEYS_741B8CD7_REFLECTED: [u64; 23] = [
     0x000000009c899030,
     0x00000001adf2908e,
     0x00000001f91b48f0,
+    0x0000000049a14d4a,
+    0x000000012608b282,
 ];
 
 // CRC-32/XFER
-pub const KEYS_000000AF_FORWARD: [u64; 23] = [
+pub const KEYS_000000AF_FORWARD: [u64; 25] = [
     0x0000000000000000,
     0x00295f2300000000,
     0xfafa517900000000,
@@ -342,10 +352,12 @@ pub const KEYS_000000AF_FORWARD: [u64; 23] = [
     0xfaebd3d300000000,
     0x25ed382b00000000,
     0x6d2b811a00000000,
+    0x7993632b00000000,
+    0xf77ae7a100000000,
 ];
 
 // CRC-32/ISO-HDLC (aka 'crc32'), CRC-32/JAMCRC
-const KEYS_04C11DB7_REFLECTED: [u64; 23] = [
+const KEYS_04C11DB7_REFLECTED: [u64; 25] = [
     0x0000000000000000, // unused placeholder to match 1-based indexing
     0x00000000ccaa009e, // (2^(32* 3) mod P(x))' << 1
     0x00000001751997d0, // (2^(32* 5) mod P(x))' << 1
@@ -369,10 +381,12 @@ const KEYS_04C11DB7_REFLECTED: [u64; 23] = [
     0x00000000f1da05aa, // (2^(32* 9) mod P(x))' << 1
     0x00000001322d1430,
     0x000000011542778a,
+    0x00000001d741f35c,
+    0x000000001072db28,
 ];
 
 // CRC-32/ISCSI (aka 'crc32c')
-const KEYS_1EDC6F41_REFLECTED: [u64; 23] = [
+const KEYS_1EDC6F41_REFLECTED: [u64; 25] = [
     0x0000000000000000, // unused placeholder to match 1-based indexing
     0x000000014cd00bd6, // (2^(32* 3) mod P(x))' << 1
     0x00000000f20c0dfe, // (2^(32* 5) mod P(x))' << 1
@@ -396,10 +410,12 @@ const KEYS_1EDC6F41_REFLECTED: [u64; 23] = [
     0x00000001384aa63a, // (2^(32* 9) mod P(x))' << 1
     0x00000000b9e02b86,
     0x00000000dcb17aa4,
+    0x00000000dd7e3b0c,
+    0x00000000bd6f81f8,
 ];
 
 // CRC-32/BZIP2, CRC-32/CKSUM, CRC-32/MPEG-2
-const KEYS_04C11DB7_FORWARD: [u64; 23] = [
+const KEYS_04C11DB7_FORWARD: [u64; 25] = [
     0x0000000000000000, // unused placeholder to match 1-based indexing
     0xf200aa6600000000, // 2^(32* 3) mod P(x) << 32
     0x17d3315d00000000, // 2^(32* 5) mod P(x) << 32
@@ -423,6 +439,8 @@ const KEYS_04C11DB7_FORWARD: [u64; 23] = [
     0xab40b71e00000000, // 2^(32* 9) mod P(x) << 32
     0x1851689900000000,
     0xa3dc855100000000,
+    0x759f05d700000000,
+    0x29b69c1000000000,
 ];
 
 pub(crate) const SIMD_CONSTANTS: [[u64; 2]; 4] = [
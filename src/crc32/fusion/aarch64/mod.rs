@@ -10,34 +10,36 @@
 //!
 //! MIT licensed.
 
-#![cfg(target_arch = "aarch64")]
+#![cfg(all(target_arch = "aarch64", target_endian = "little"))]
 
 mod iscsi;
 mod iso_hdlc;
 
+use crate::feature_detection::{get_arch_ops, ArchOpsInstance};
 use std::arch::aarch64::*;
-use std::arch::is_aarch64_feature_detected;
 
 use iscsi::crc_pmull::crc32_iscsi_v12e_v1;
 use iscsi::crc_pmull_sha3::crc32_iscsi_eor3_v9s3x2e_s3;
 use iso_hdlc::crc_pmull::crc32_iso_hdlc_v12e_v1;
 use iso_hdlc::crc_pmull_sha3::crc32_iso_hdlc_eor3_v9s3x2e_s3;
 
+/// The implementation is selected using the cached ArchOps instance, so it always matches the
+/// active (possibly forced) performance tier
 #[inline(always)]
 pub fn crc32_iscsi(crc: u32, data: &[u8]) -> u32 {
-    if is_aarch64_feature_detected!("sha3") {
-        unsafe { crc32_iscsi_aes_sha3(crc, data) }
-    } else {
-        unsafe { crc32_iscsi_aes(crc, data) }
+    match get_arch_ops() {
+        ArchOpsInstance::Aarch64AesSha3(_) => unsafe { crc32_iscsi_aes_sha3(crc, data) },
+        _ => unsafe { crc32_iscsi_aes(crc, data) },
     }
 }
 
+/// The implementation is selected using the cached ArchOps instance, so it always matches the
+/// active (possibly forced) performance tier
 #[inline(always)]
 pub fn crc32_iso_hdlc(crc: u32, data: &[u8]) -> u32 {
-    if is_aarch64_feature_detected!("sha3") {
-        unsafe { crc32_iso_hdlc_aes_sha3(crc, data) }
-    } else {
-        unsafe { crc32_iso_hdlc_aes(crc, data) }
+    match get_arch_ops() {
+        ArchOpsInstance::Aarch64AesSha3(_) => unsafe { crc32_iso_hdlc_aes_sha3(crc, data) },
+        _ => unsafe { crc32_iso_hdlc_aes(crc, data) },
     }
 }
 
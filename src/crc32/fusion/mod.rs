@@ -13,15 +13,16 @@ mod x86;
 
 /// Only AArch64 has native CRC-32/ISO-HDLC instructions
 #[inline(always)]
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_arch = "aarch64", target_endian = "little"))]
 pub(crate) fn crc32_iso_hdlc(state: u32, data: &[u8]) -> u32 {
     aarch64::crc32_iso_hdlc(state, data)
 }
 
-/// Both AArch64 and x86 have native CRC-32/ISCSI instructions
+/// Both AArch64 and x86 have native CRC-32/ISCSI instructions (the AArch64 kernels load 64-bit
+/// words in native byte order, so they're only used on little-endian)
 #[inline(always)]
 pub(crate) fn crc32_iscsi(state: u32, data: &[u8]) -> u32 {
-    #[cfg(target_arch = "aarch64")]
+    #[cfg(all(target_arch = "aarch64", target_endian = "little"))]
     {
         aarch64::crc32_iscsi(state, data)
     }
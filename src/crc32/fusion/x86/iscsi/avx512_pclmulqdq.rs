@@ -17,7 +17,7 @@
 #[inline]
 #[target_feature(enable = "avx512vl,pclmulqdq")]
 pub unsafe fn crc32_iscsi_avx512_v4s3x3(mut crc0: u32, mut buf: *const u8, mut len: usize) -> u32 {
-    use crate::fusion::x86::*;
+    use crate::crc32::fusion::x86::*;
 
     // Align to 8-byte boundary using hardware CRC32C instructions
     while len > 0 && (buf as usize & 7) != 0 {
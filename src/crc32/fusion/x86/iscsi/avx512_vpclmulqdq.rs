@@ -22,7 +22,7 @@ pub unsafe fn crc32_iscsi_avx512_vpclmulqdq_v3x2(
     mut buf: *const u8,
     mut len: usize,
 ) -> u32 {
-    use crate::fusion::x86::*;
+    use crate::crc32::fusion::x86::*;
 
     // Align to 8-byte boundary
     while len > 0 && (buf as usize & 7) != 0 {
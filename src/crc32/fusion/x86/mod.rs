@@ -45,26 +45,25 @@ pub fn crc32_iscsi(crc: u32, data: &[u8]) -> u32 {
 /// (post-AVX-512 support)
 ///
 /// This function is called by the wrapper layer after feature detection has been performed.
-/// The wrapper layer ensures that only the appropriate implementation is called based on
-/// cached feature detection results, removing runtime checks from the hot path.
+/// The implementation is selected using the cached ArchOps instance, so it always matches the
+/// active (possibly forced) performance tier, removing runtime checks from the hot path.
 #[rustversion::since(1.89)]
 #[inline(always)]
 pub fn crc32_iscsi(crc: u32, data: &[u8]) -> u32 {
     #[cfg(target_arch = "x86_64")]
     {
-        // AVX512 + VPCLMULQDQ
+        use crate::feature_detection::{get_arch_ops, ArchOpsInstance};
 
-        if is_x86_feature_detected!("avx512vl") && is_x86_feature_detected!("vpclmulqdq") {
-            unsafe {
+        match get_arch_ops() {
+            // AVX512 + VPCLMULQDQ
+            ArchOpsInstance::X86_64Avx512Vpclmulqdq(_) => unsafe {
                 return crc32_iscsi_avx512_vpclmulqdq_v3x2(crc, data.as_ptr(), data.len());
-            }
-        }
-
-        // AVX512
-        if is_x86_feature_detected!("avx512vl") {
-            unsafe {
+            },
+            // AVX512
+            ArchOpsInstance::X86_64Avx512Pclmulqdq(_) => unsafe {
                 return crc32_iscsi_avx512_v4s3x3(crc, data.as_ptr(), data.len());
-            }
+            },
+            _ => {}
         }
     }
 
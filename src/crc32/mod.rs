@@ -5,5 +5,18 @@
 pub mod algorithm;
 pub mod consts;
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+#[cfg(all(
+    not(feature = "crc64-only"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        all(target_arch = "aarch64", target_endian = "little")
+    )
+))]
 pub(crate) mod fusion;
+
+#[cfg(all(
+    not(feature = "crc64-only"),
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
+pub(crate) mod native;
@@ -0,0 +1,403 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! This module provides CRC-32/ISCSI (and, on aarch64, CRC-32/ISO-HDLC) calculations using only
+//! the native CRC-32 instructions, for CPUs which have them but no carryless multiplication (such
+//! as virtualized x86 guests which mask PCLMULQDQ but keep SSE4.2, or aarch64 cores with the CRC
+//! extension but no PMULL).
+//!
+//! The CRC-32 instructions have a latency of 3 cycles but a throughput of 1 per cycle, so the data
+//! is split into three streams which are calculated in an interleaved loop, and then combined by
+//! shifting the CRCs of the first two streams over the lengths of the streams which follow them,
+//! using lookup tables generated at compile time. This is the same schedule as the Linux kernel's
+//! and ISA-L's CRC-32C, except that they combine with carryless multiplication, which isn't
+//! available here.
+//!
+//! The kernel also calculates the remainders of long inputs in three streams, with a stream length
+//! (and a combine constant) for each remainder length. Each table-based combine needs 4KiB of
+//! tables, so a short ladder of stream lengths is used instead, which still leaves only the last
+//! few words to be calculated serially.
+//!
+//! https://stackoverflow.com/questions/17645167/implementing-sse-4-2s-crc32c-in-software
+
+#![cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+
+use std::ptr;
+
+#[cfg(target_arch = "aarch64")]
+use std::arch::aarch64::*;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Lengths of each of the three streams, from longest to shortest, with a set of shift tables for
+/// each. Longer streams combine less often, and shorter ones leave less to calculate serially.
+const STREAM_LENGTHS: [usize; 3] = [8192, 1024, 128];
+
+/// Reflected CRC-32C (CRC-32/ISCSI) polynomial
+const CRC32C_POLY: u32 = 0x82f63b78;
+
+/// Reflected CRC-32 (CRC-32/ISO-HDLC) polynomial
+#[cfg(target_arch = "aarch64")]
+const CRC32_POLY: u32 = 0xedb88320;
+
+/// Lookup tables which shift a CRC over a fixed number of zero bytes, one per byte of the CRC
+type ShiftTables = [[u32; 256]; 4];
+
+/// Shift tables for each of the `STREAM_LENGTHS`
+type ShiftLadder = [ShiftTables; STREAM_LENGTHS.len()];
+
+/// Shifts a CRC-32C over each of the `STREAM_LENGTHS` in zero bytes
+static CRC32C_SHIFTS: ShiftLadder = shift_ladder(CRC32C_POLY);
+
+/// Shifts a CRC-32 over each of the `STREAM_LENGTHS` in zero bytes
+#[cfg(target_arch = "aarch64")]
+static CRC32_SHIFTS: ShiftLadder = shift_ladder(CRC32_POLY);
+
+/// Calculates the CRC-32/ISCSI state using only the native CRC-32C instructions.
+///
+/// The caller must have detected SSE4.2 (or the CRC extension on aarch64), which the
+/// `X86SseCrc32c` (or `Aarch64Crc`) tier guarantees.
+#[inline(always)]
+pub(crate) fn crc32_iscsi(state: u32, data: &[u8]) -> u32 {
+    unsafe { crc32_iscsi_native(state, data) }
+}
+
+/// Calculates the CRC-32/ISO-HDLC state using only the native CRC-32 instructions.
+///
+/// The caller must have detected the CRC extension, which the `Aarch64Crc` tier guarantees.
+#[inline(always)]
+#[cfg(target_arch = "aarch64")]
+pub(crate) fn crc32_iso_hdlc(state: u32, data: &[u8]) -> u32 {
+    unsafe { crc32_iso_hdlc_native(state, data) }
+}
+
+#[inline]
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[target_feature(enable = "sse4.2")]
+unsafe fn crc32_iscsi_native(state: u32, data: &[u8]) -> u32 {
+    update::<Sse42Crc32c>(state, data, &CRC32C_SHIFTS)
+}
+
+#[inline]
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "crc")]
+unsafe fn crc32_iscsi_native(state: u32, data: &[u8]) -> u32 {
+    update::<ArmCrc32c>(state, data, &CRC32C_SHIFTS)
+}
+
+#[inline]
+#[cfg(target_arch = "aarch64")]
+#[target_feature(enable = "crc")]
+unsafe fn crc32_iso_hdlc_native(state: u32, data: &[u8]) -> u32 {
+    update::<ArmCrc32>(state, data, &CRC32_SHIFTS)
+}
+
+/// The native CRC-32 instructions for one polynomial
+trait NativeCrc32 {
+    /// Updates the reflected CRC state with a byte
+    unsafe fn update_u8(state: u32, value: u8) -> u32;
+
+    /// Updates the reflected CRC state with 8 little-endian bytes
+    unsafe fn update_u64(state: u32, value: u64) -> u32;
+}
+
+/// The SSE4.2 CRC-32C instructions
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+struct Sse42Crc32c;
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+impl NativeCrc32 for Sse42Crc32c {
+    #[inline(always)]
+    unsafe fn update_u8(state: u32, value: u8) -> u32 {
+        _mm_crc32_u8(state, value)
+    }
+
+    #[inline(always)]
+    #[cfg(target_arch = "x86_64")]
+    unsafe fn update_u64(state: u32, value: u64) -> u32 {
+        _mm_crc32_u64(state as u64, value) as u32
+    }
+
+    /// 32-bit x86 has no 64-bit CRC-32C instruction, so process each half in little-endian order
+    #[inline(always)]
+    #[cfg(target_arch = "x86")]
+    unsafe fn update_u64(state: u32, value: u64) -> u32 {
+        _mm_crc32_u32(_mm_crc32_u32(state, value as u32), (value >> 32) as u32)
+    }
+}
+
+/// The aarch64 CRC extension's CRC-32C instructions
+#[cfg(target_arch = "aarch64")]
+struct ArmCrc32c;
+
+#[cfg(target_arch = "aarch64")]
+impl NativeCrc32 for ArmCrc32c {
+    #[inline]
+    #[target_feature(enable = "crc")]
+    unsafe fn update_u8(state: u32, value: u8) -> u32 {
+        __crc32cb(state, value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "crc")]
+    unsafe fn update_u64(state: u32, value: u64) -> u32 {
+        __crc32cd(state, value)
+    }
+}
+
+/// The aarch64 CRC extension's CRC-32 instructions
+#[cfg(target_arch = "aarch64")]
+struct ArmCrc32;
+
+#[cfg(target_arch = "aarch64")]
+impl NativeCrc32 for ArmCrc32 {
+    #[inline]
+    #[target_feature(enable = "crc")]
+    unsafe fn update_u8(state: u32, value: u8) -> u32 {
+        __crc32b(state, value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "crc")]
+    unsafe fn update_u64(state: u32, value: u64) -> u32 {
+        __crc32d(state, value)
+    }
+}
+
+/// Updates the reflected CRC state with the data, in three interleaved streams where it's long
+/// enough
+#[inline(always)]
+unsafe fn update<C: NativeCrc32>(mut state: u32, mut data: &[u8], shifts: &ShiftLadder) -> u32 {
+    for (len, shift) in STREAM_LENGTHS.into_iter().zip(shifts) {
+        state = update_streams::<C>(state, &mut data, len, shift);
+    }
+
+    let mut words = data.chunks_exact(8);
+    for word in &mut words {
+        state = C::update_u64(state, read_u64(word));
+    }
+
+    for &byte in words.remainder() {
+        state = C::update_u8(state, byte);
+    }
+
+    state
+}
+
+/// Consumes as many blocks of three `len`-byte streams from the start of the data as there are,
+/// calculating the streams in an interleaved loop and then combining them
+///
+/// The loop indexes all three streams with one offset, like the kernel's, rather than zipping
+/// three iterators, which leaves only the three loads and CRC instructions in the loop body.
+#[inline(always)]
+unsafe fn update_streams<C: NativeCrc32>(
+    mut state: u32,
+    data: &mut &[u8],
+    len: usize,
+    shift: &ShiftTables,
+) -> u32 {
+    while data.len() >= 3 * len {
+        let ptr = data.as_ptr();
+
+        let mut state1 = 0;
+        let mut state2 = 0;
+
+        // SAFETY: the offsets are all within the first 3 * len bytes of the data
+        let mut offset = 0;
+        while offset < len {
+            state = C::update_u64(state, load_u64(ptr.add(offset)));
+            state1 = C::update_u64(state1, load_u64(ptr.add(len + offset)));
+            state2 = C::update_u64(state2, load_u64(ptr.add(2 * len + offset)));
+            offset += 8;
+        }
+
+        // the streams are contiguous, so shift each CRC over the stream which follows it
+        state = shift_state(shift, state) ^ state1;
+        state = shift_state(shift, state) ^ state2;
+
+        *data = &data[3 * len..];
+    }
+
+    state
+}
+
+/// Loads 8 little-endian bytes, which needn't be aligned
+#[inline(always)]
+unsafe fn load_u64(ptr: *const u8) -> u64 {
+    u64::from_le(ptr::read_unaligned(ptr as *const u64))
+}
+
+#[inline(always)]
+fn read_u64(word: &[u8]) -> u64 {
+    u64::from_le_bytes(word.try_into().unwrap())
+}
+
+/// Shifts the reflected CRC state over the number of zero bytes the tables were generated for
+#[inline(always)]
+fn shift_state(tables: &ShiftTables, state: u32) -> u32 {
+    tables[0][(state & 0xff) as usize]
+        ^ tables[1][((state >> 8) & 0xff) as usize]
+        ^ tables[2][((state >> 16) & 0xff) as usize]
+        ^ tables[3][(state >> 24) as usize]
+}
+
+/// Generates the shift tables for each of the `STREAM_LENGTHS`
+const fn shift_ladder(poly: u32) -> ShiftLadder {
+    let mut ladder = [[[0u32; 256]; 4]; STREAM_LENGTHS.len()];
+
+    let mut i = 0;
+    while i < STREAM_LENGTHS.len() {
+        ladder[i] = shift_tables(poly, STREAM_LENGTHS[i]);
+        i += 1;
+    }
+
+    ladder
+}
+
+/// Generates the tables which shift a reflected CRC state over `len` zero bytes, which is a
+/// multiplication by x^(8 * len) modulo the polynomial, and so is linear in each byte of the state
+const fn shift_tables(poly: u32, len: usize) -> ShiftTables {
+    let op = xpow8n_mod_p(len, poly);
+
+    let mut tables = [[0u32; 256]; 4];
+    let mut k = 0;
+    while k < 4 {
+        let mut byte = 0;
+        while byte < 256 {
+            tables[k][byte] = multiply_mod_p((byte as u32) << (8 * k), op, poly);
+            byte += 1;
+        }
+        k += 1;
+    }
+
+    tables
+}
+
+/// Returns x^(8 * n) modulo the reflected polynomial
+const fn xpow8n_mod_p(n: usize, poly: u32) -> u32 {
+    // x^0, reflected
+    let mut p = 1u32 << 31;
+
+    let mut bit = 0;
+    while bit < 8 * n {
+        p = if p & 1 != 0 { (p >> 1) ^ poly } else { p >> 1 };
+        bit += 1;
+    }
+
+    p
+}
+
+/// Multiplies two reflected polynomials modulo the reflected polynomial
+const fn multiply_mod_p(a: u32, mut b: u32, poly: u32) -> u32 {
+    let mut product = 0;
+
+    let mut m = 1u32 << 31;
+    while m != 0 {
+        if a & m != 0 {
+            product ^= b;
+        }
+
+        b = if b & 1 != 0 { (b >> 1) ^ poly } else { b >> 1 };
+        m >>= 1;
+    }
+
+    product
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hw;
+    use rand::{rng, Rng};
+
+    /// Random data long enough to use every stream length, and still have a remainder
+    fn random_data() -> Vec<u8> {
+        let mut data = vec![0u8; STREAM_LENGTHS.iter().map(|len| 3 * len).sum::<usize>() + 100];
+        rng().fill(&mut data[..]);
+
+        data
+    }
+
+    /// Every length up to a little past the shortest streams, and around the boundaries of each
+    /// of the longer ones
+    fn lengths(max: usize) -> impl Iterator<Item = usize> {
+        let shortest = STREAM_LENGTHS[STREAM_LENGTHS.len() - 1];
+
+        (0..=3 * shortest + 16)
+            .chain(
+                STREAM_LENGTHS
+                    .into_iter()
+                    .flat_map(|len| 3 * len - 8..=3 * len + 8),
+            )
+            .chain([max])
+    }
+
+    #[test]
+    fn test_crc32_iscsi_lengths() {
+        if !hw::has_crc32c() {
+            return;
+        }
+
+        let data = random_data();
+
+        for len in lengths(data.len()) {
+            // unaligned too, to make sure the streams don't depend on alignment
+            for offset in [0, 3] {
+                let data = &data[offset..offset + len.min(data.len() - offset)];
+
+                for state in [0, 0xffffffff, 0x12345678] {
+                    let expected = data
+                        .iter()
+                        .fold(state, |state, byte| hw::crc32c_u8(state, *byte));
+
+                    assert_eq!(
+                        crc32_iscsi(state, data),
+                        expected,
+                        "Failed for length {} at offset {offset}",
+                        data.len()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(target_arch = "aarch64")]
+    fn test_crc32_iso_hdlc_lengths() {
+        if !hw::has_crc32() {
+            return;
+        }
+
+        let data = random_data();
+
+        for len in lengths(data.len()) {
+            let data = &data[..len];
+            let expected = data
+                .iter()
+                .fold(0xffffffff, |state, byte| hw::crc32_u8(state, *byte));
+
+            assert_eq!(
+                crc32_iso_hdlc(0xffffffff, data),
+                expected,
+                "Failed for length {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_shift_tables() {
+        // shifting over zero bytes is the same as calculating them
+        let state = 0xdeadbeef;
+
+        for (len, shift) in STREAM_LENGTHS.into_iter().zip(&CRC32C_SHIFTS) {
+            assert_eq!(
+                shift_state(shift, state),
+                vec![0u8; len]
+                    .iter()
+                    .fold(state, |state, byte| hw::crc32c_u8(state, *byte)),
+                "Failed for length {len}"
+            );
+        }
+    }
+}
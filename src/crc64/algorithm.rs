@@ -146,7 +146,7 @@ impl EnhancedCrcWidth for crate::structs::Width64 {
     unsafe fn perform_final_reduction<T: ArchOps>(
         state: T::Vector,
         reflected: bool,
-        keys: [u64; 23],
+        keys: [u64; 25],
         ops: &T,
     ) -> Self::Value
     where
@@ -213,7 +213,7 @@ pub(crate) unsafe fn process_0_to_15<T: ArchOps, W: EnhancedCrcWidth>(
     data: &[u8],
     state: &mut CrcState<T::Vector>,
     reflector: &Reflector<T::Vector>,
-    keys: [u64; 23],
+    keys: [u64; 25],
     ops: &T,
 ) -> W::Value
 where
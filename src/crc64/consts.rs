@@ -18,7 +18,7 @@ pub const CRC64_ECMA_182: CrcParams = CrcParams {
     refout: CRC_64_ECMA_182.refout, // false
     xorout: CRC_64_ECMA_182.xorout,
     check: CRC_64_ECMA_182.check,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_42F0E1EBA9EA3693_FORWARD),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_42F0E1EBA9EA3693_FORWARD),
 };
 
 // width=64 poly=0x000000000000001b init=0xffffffffffffffff refin=true refout=true xorout=0xffffffffffffffff check=0xb90956c775a41001 residue=0x5300000000000000 name="CRC-64/GO-ISO"
@@ -32,7 +32,7 @@ pub const CRC64_GO_ISO: CrcParams = CrcParams {
     refout: CRC_64_GO_ISO.refout, // true
     xorout: CRC_64_GO_ISO.xorout,
     check: CRC_64_GO_ISO.check,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_000000000000001B_REFLECTED),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_000000000000001B_REFLECTED),
 };
 
 // width=64 poly=0x259c84cba6426349 init=0xffffffffffffffff refin=true refout=true xorout=0x0000000000000000 check=0x75d4b74f024eceea residue=0x0000000000000000 name="CRC-64/MS"
@@ -46,7 +46,7 @@ pub const CRC64_MS: CrcParams = CrcParams {
     refout: CRC_64_MS.refout, // true
     xorout: CRC_64_MS.xorout,
     check: CRC_64_MS.check,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_259C84CBA6426349_REFLECTED),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_259C84CBA6426349_REFLECTED),
 };
 
 // https://reveng.sourceforge.io/crc-catalogue/all.htm#crc.cat.crc-64-nvme
@@ -61,7 +61,7 @@ pub const CRC64_NVME: CrcParams = CrcParams {
     refout: CRC_64_NVME.refout, // true
     xorout: CRC_64_NVME.xorout,
     check: CRC_64_NVME.check,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_AD93D23594C93659_REFLECTED),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_AD93D23594C93659_REFLECTED),
 };
 
 // width=64 poly=0xad93d23594c935a9 init=0x0000000000000000 refin=true refout=true xorout=0x0000000000000000 check=0xe9c6d914c4b8d9ca residue=0x0000000000000000 name="CRC-64/REDIS"
@@ -75,7 +75,7 @@ pub const CRC64_REDIS: CrcParams = CrcParams {
     refout: CRC_64_REDIS.refout, // true
     xorout: CRC_64_REDIS.xorout,
     check: CRC_64_REDIS.check,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_AD93D23594C935A9_REFLECTED),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_AD93D23594C935A9_REFLECTED),
 };
 
 // width=64 poly=0x42f0e1eba9ea3693 init=0xffffffffffffffff refin=false refout=false xorout=0xffffffffffffffff check=0x62ec59e3f1a4f00a residue=0xfcacbebd5931a992 name="CRC-64/WE"
@@ -89,7 +89,7 @@ pub const CRC64_WE: CrcParams = CrcParams {
     refout: CRC_64_WE.refout, // false
     xorout: CRC_64_WE.xorout,
     check: CRC_64_WE.check,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_42F0E1EBA9EA3693_FORWARD),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_42F0E1EBA9EA3693_FORWARD),
 };
 
 // width=64 poly=0x42f0e1eba9ea3693 init=0xffffffffffffffff refin=true refout=true xorout=0xffffffffffffffff check=0x995dc9bbdf1939fa residue=0x49958c9abd7d353f name="CRC-64/XZ"
@@ -103,11 +103,11 @@ pub const CRC64_XZ: CrcParams = CrcParams {
     refout: CRC_64_XZ.refout, // true
     xorout: CRC_64_XZ.xorout,
     check: CRC_64_XZ.check,
-    keys: crate::CrcKeysStorage::from_keys_fold_256(KEYS_42F0E1EBA9EA3693_REFLECTED),
+    keys: crate::CrcKeysStorage::from_keys_fold_512(KEYS_42F0E1EBA9EA3693_REFLECTED),
 };
 
 // CRC-64/MS
-const KEYS_259C84CBA6426349_REFLECTED: [u64; 23] = [
+const KEYS_259C84CBA6426349_REFLECTED: [u64; 25] = [
     0x0000000000000000,
     0xcef05cca14bbf4df,
     0xfd5d7a0700b5ba38,
@@ -131,10 +131,12 @@ const KEYS_259C84CBA6426349_REFLECTED: [u64; 23] = [
     0x2188097f5687b43c,
     0xb7c2f9fa47c4fe55,
     0x8dccaf9d6169d0fa,
+    0x6e94c3e6ceb173bb,
+    0x36f00b55ece1f124,
 ];
 
 // CRC-64/REDIS
-const KEYS_AD93D23594C935A9_REFLECTED: [u64; 23] = [
+const KEYS_AD93D23594C935A9_REFLECTED: [u64; 25] = [
     0x0000000000000000,
     0x381d0015c96f4444,
     0xd9d7be7d505da32c,
@@ -158,10 +160,12 @@ const KEYS_AD93D23594C935A9_REFLECTED: [u64; 23] = [
     0x6ba4d760ab38201e,
     0x9471a5389095fe44,
     0x9a8908341a6d6d52,
+    0x517c365ad57671f6,
+    0x5f76ba258de12275,
 ];
 
 // CRC-64/ECMA-182, CRC-64/WE
-const KEYS_42F0E1EBA9EA3693_FORWARD: [u64; 23] = [
+const KEYS_42F0E1EBA9EA3693_FORWARD: [u64; 25] = [
     0x0000000000000000, // unused placeholder to match 1-based indexing
     0x05f5c3c7eb52fab6, // 2^(64* 2) mod P(x)
     0x4eb938a7d257740e, // 2^(64* 3) mod P(x)
@@ -185,10 +189,12 @@ const KEYS_42F0E1EBA9EA3693_FORWARD: [u64; 23] = [
     0x44bef2a201b5200c, // 2^(64* 5) mod P(x)
     0x7f52691a60ddc70d,
     0x7036b0389f6a0c82,
+    0x1c6ffb45e31ea184,
+    0x04df74f1878d4dac,
 ];
 
 // CRC-64/XZ
-const KEYS_42F0E1EBA9EA3693_REFLECTED: [u64; 23] = [
+const KEYS_42F0E1EBA9EA3693_REFLECTED: [u64; 25] = [
     0x0000000000000000, // unused placeholder to match 1-based indexing
     0xdabe95afc7875f40, // 2^((64* 2)-1) mod P(x)
     0xe05dd497ca393ae4, // 2^((64* 3)-1) mod P(x)
@@ -212,10 +218,12 @@ const KEYS_42F0E1EBA9EA3693_REFLECTED: [u64; 23] = [
     0x60095b008a9efa44, // 2^((64* 5)-1) mod P(x)
     0xf31fd9271e228b79,
     0x8260adf2381ad81c,
+    0x430af18f45bfec70,
+    0x6b6563c31e5df640,
 ];
 
 // CRC-64/GO-ISO
-const KEYS_000000000000001B_REFLECTED: [u64; 23] = [
+const KEYS_000000000000001B_REFLECTED: [u64; 25] = [
     0x0000000000000000, // unused placeholder to match 1-based indexing
     0xf500000000000001, // 2^((64* 2)-1) mod P(x)
     0x6b70000000000001, // 2^((64* 3)-1) mod P(x)
@@ -239,10 +247,12 @@ const KEYS_000000000000001B_REFLECTED: [u64; 23] = [
     0x1b1ab00000000001, // 2^((64* 5)-1) mod P(x)
     0x45000000b0000000,
     0x6b700000f5000000,
+    0x7b61000000000000,
+    0x4e0bb00000000000,
 ];
 
 // CRC-64/NVME
-const KEYS_AD93D23594C93659_REFLECTED: [u64; 23] = [
+const KEYS_AD93D23594C93659_REFLECTED: [u64; 25] = [
     0x0000000000000000, // unused placeholder to match 1-based indexing
     0x21e9_761e_2526_21ac,
     0xeadc_41fd_2ba3_d420,
@@ -266,6 +276,8 @@ const KEYS_AD93D23594C93659_REFLECTED: [u64; 23] = [
     0xb0bc_2e58_9204_f500,
     0xa043_808c_0f78_2663,
     0x37cc_d3e1_4069_cabc,
+    0xc240_9e25_37aa_5eb1,
+    0xd0b3_aa0e_d6d5_4ae0,
 ];
 
 pub const SIMD_CONSTANTS: [[u64; 2]; 4] = [
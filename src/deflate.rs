@@ -0,0 +1,281 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Integrity verification for the zlib and gzip wrappers around a deflate stream.
+//!
+//! The decompressed data is fed through a `DeflateVerifier` as it's inflated, and the wrapper's
+//! trailer is checked once the deflate stream ends, reporting exactly which integrity field didn't
+//! match, which is the first question when debugging a corrupt archive.
+//!
+//! - zlib (RFC 1950) ends with the Adler-32 of the decompressed data, big-endian.
+//! - gzip (RFC 1952) ends with the CRC-32/ISO-HDLC of the decompressed data, then its length
+//!   modulo 2^32 (ISIZE), both little-endian.
+
+use crate::{adler32, CrcAlgorithm, Digest};
+use std::fmt;
+use std::io::Write;
+
+/// The wrapper around a deflate stream, which determines the trailer's integrity fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeflateWrapper {
+    /// zlib (RFC 1950), with an Adler-32 trailer
+    Zlib,
+
+    /// gzip (RFC 1952), with a CRC-32 and ISIZE trailer
+    Gzip,
+}
+
+impl DeflateWrapper {
+    /// Returns the length of the wrapper's trailer, in bytes.
+    pub fn trailer_len(self) -> usize {
+        match self {
+            DeflateWrapper::Zlib => 4,
+            DeflateWrapper::Gzip => 8,
+        }
+    }
+}
+
+/// The integrity field of a deflate wrapper's trailer which didn't match the decompressed data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeflateIntegrityError {
+    /// The trailer isn't the wrapper's trailer length, so the stream is probably truncated
+    TrailerLength { expected: usize, actual: usize },
+
+    /// The zlib Adler-32 doesn't match the decompressed data
+    Adler32 { expected: u32, actual: u32 },
+
+    /// The gzip CRC-32 doesn't match the decompressed data
+    Crc32 { expected: u32, actual: u32 },
+
+    /// The gzip ISIZE doesn't match the length of the decompressed data (modulo 2^32)
+    Isize { expected: u32, actual: u32 },
+}
+
+impl fmt::Display for DeflateIntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeflateIntegrityError::TrailerLength { expected, actual } => write!(
+                f,
+                "trailer is {actual} bytes, expected {expected} (truncated stream?)"
+            ),
+            DeflateIntegrityError::Adler32 { expected, actual } => write!(
+                f,
+                "zlib Adler-32 mismatch: trailer has {expected:#010x}, data is {actual:#010x}"
+            ),
+            DeflateIntegrityError::Crc32 { expected, actual } => write!(
+                f,
+                "gzip CRC-32 mismatch: trailer has {expected:#010x}, data is {actual:#010x}"
+            ),
+            DeflateIntegrityError::Isize { expected, actual } => write!(
+                f,
+                "gzip ISIZE mismatch: trailer has {expected} bytes, data is {actual} bytes"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DeflateIntegrityError {}
+
+/// Checksums decompressed data as it's fed incrementally, and verifies it against a zlib or gzip
+/// trailer
+#[derive(Debug, Clone, Copy)]
+pub struct DeflateVerifier {
+    /// The wrapper whose trailer is verified
+    wrapper: DeflateWrapper,
+
+    /// The CRC-32 of the decompressed data, for gzip
+    crc32: Digest,
+
+    /// The Adler-32 of the decompressed data, for zlib
+    adler32: adler32::Digest,
+}
+
+impl DeflateVerifier {
+    /// Creates a verifier for a deflate stream in the specified wrapper.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{DeflateIntegrityError, DeflateVerifier, DeflateWrapper};
+    ///
+    /// let mut verifier = DeflateVerifier::new(DeflateWrapper::Gzip);
+    ///
+    /// // feed the decompressed data as it's inflated
+    /// verifier.update(b"1234");
+    /// verifier.update(b"56789");
+    ///
+    /// // the 8-byte gzip trailer: CRC-32 then ISIZE, little-endian
+    /// let trailer = [0x26, 0x39, 0xf4, 0xcb, 0x09, 0x00, 0x00, 0x00];
+    /// assert_eq!(verifier.verify(&trailer), Ok(()));
+    ///
+    /// let truncated = [0x26, 0x39, 0xf4, 0xcb, 0x0a, 0x00, 0x00, 0x00];
+    /// assert_eq!(
+    ///     verifier.verify(&truncated),
+    ///     Err(DeflateIntegrityError::Isize { expected: 10, actual: 9 })
+    /// );
+    /// ```
+    pub fn new(wrapper: DeflateWrapper) -> Self {
+        Self {
+            wrapper,
+            crc32: Digest::new(CrcAlgorithm::Crc32IsoHdlc),
+            adler32: adler32::Digest::new(),
+        }
+    }
+
+    /// Updates the checksum with decompressed data.
+    #[inline(always)]
+    pub fn update(&mut self, data: &[u8]) {
+        match self.wrapper {
+            DeflateWrapper::Zlib => self.adler32.update(data),
+            DeflateWrapper::Gzip => self.crc32.update(data),
+        }
+    }
+
+    /// Returns the wrapper whose trailer is verified.
+    pub fn wrapper(&self) -> DeflateWrapper {
+        self.wrapper
+    }
+
+    /// Returns the amount of decompressed data so far.
+    pub fn get_amount(&self) -> u64 {
+        match self.wrapper {
+            DeflateWrapper::Zlib => self.adler32.get_amount(),
+            DeflateWrapper::Gzip => self.crc32.get_amount(),
+        }
+    }
+
+    /// Verifies the decompressed data so far against the wrapper's trailer (the bytes immediately
+    /// following the end of the deflate stream).
+    ///
+    /// For gzip, a mismatched ISIZE is reported ahead of a mismatched CRC-32, since a length
+    /// mismatch (usually truncation) also breaks the CRC-32 and is the more useful diagnosis.
+    pub fn verify(&self, trailer: &[u8]) -> Result<(), DeflateIntegrityError> {
+        if trailer.len() != self.wrapper.trailer_len() {
+            return Err(DeflateIntegrityError::TrailerLength {
+                expected: self.wrapper.trailer_len(),
+                actual: trailer.len(),
+            });
+        }
+
+        match self.wrapper {
+            DeflateWrapper::Zlib => {
+                let expected = u32::from_be_bytes(trailer.try_into().unwrap());
+                let actual = self.adler32.finalize();
+
+                if expected != actual {
+                    return Err(DeflateIntegrityError::Adler32 { expected, actual });
+                }
+            }
+            DeflateWrapper::Gzip => {
+                let expected = u32::from_le_bytes(trailer[4..].try_into().unwrap());
+                let actual = self.crc32.get_amount() as u32;
+
+                if expected != actual {
+                    return Err(DeflateIntegrityError::Isize { expected, actual });
+                }
+
+                let expected = u32::from_le_bytes(trailer[..4].try_into().unwrap());
+                let actual = self.crc32.finalize() as u32;
+
+                if expected != actual {
+                    return Err(DeflateIntegrityError::Crc32 { expected, actual });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Write for DeflateVerifier {
+    #[inline(always)]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `zlib.compress(b"123456789")`
+    const ZLIB_STREAM: &[u8] = &[
+        0x78, 0x9c, 0x33, 0x34, 0x32, 0x36, 0x31, 0x35, 0x33, 0xb7, 0xb0, 0x04, 0x00, 0x09, 0x1e,
+        0x01, 0xde,
+    ];
+
+    /// `gzip.compress(b"123456789", mtime=0)`
+    const GZIP_STREAM: &[u8] = &[
+        0x1f, 0x8b, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02, 0x03, 0x33, 0x34, 0x32, 0x36, 0x31,
+        0x35, 0x33, 0xb7, 0xb0, 0x04, 0x00, 0x26, 0x39, 0xf4, 0xcb, 0x09, 0x00, 0x00, 0x00,
+    ];
+
+    /// Verifies the data, fed in small pieces, against the trailer of a complete stream
+    fn verify(
+        wrapper: DeflateWrapper,
+        data: &[u8],
+        stream: &[u8],
+    ) -> Result<(), DeflateIntegrityError> {
+        let mut verifier = DeflateVerifier::new(wrapper);
+        for chunk in data.chunks(2) {
+            verifier.update(chunk);
+        }
+
+        assert_eq!(verifier.get_amount(), data.len() as u64);
+
+        verifier.verify(&stream[stream.len().saturating_sub(wrapper.trailer_len())..])
+    }
+
+    #[test]
+    fn test_zlib() {
+        assert_eq!(
+            verify(DeflateWrapper::Zlib, b"123456789", ZLIB_STREAM),
+            Ok(())
+        );
+        assert_eq!(
+            verify(DeflateWrapper::Zlib, b"123456780", ZLIB_STREAM),
+            Err(DeflateIntegrityError::Adler32 {
+                expected: 0x091e01de,
+                actual: adler32::checksum(b"123456780"),
+            })
+        );
+    }
+
+    #[test]
+    fn test_gzip() {
+        assert_eq!(
+            verify(DeflateWrapper::Gzip, b"123456789", GZIP_STREAM),
+            Ok(())
+        );
+        assert_eq!(
+            verify(DeflateWrapper::Gzip, b"123456780", GZIP_STREAM),
+            Err(DeflateIntegrityError::Crc32 {
+                expected: 0xcbf43926,
+                actual: crate::checksum(CrcAlgorithm::Crc32IsoHdlc, b"123456780") as u32,
+            })
+        );
+        assert_eq!(
+            verify(DeflateWrapper::Gzip, b"12345678", GZIP_STREAM),
+            Err(DeflateIntegrityError::Isize {
+                expected: 9,
+                actual: 8
+            })
+        );
+        assert_eq!(
+            verify(
+                DeflateWrapper::Gzip,
+                b"123456789",
+                &GZIP_STREAM[GZIP_STREAM.len() - 4..]
+            ),
+            Err(DeflateIntegrityError::TrailerLength {
+                expected: 8,
+                actual: 4
+            })
+        );
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
 
 use crate::consts::*;
-use crate::CrcAlgorithm;
+use crate::{CrcAlgorithm, KeySource};
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
@@ -62,9 +62,143 @@ impl Display for CrcAlgorithm {
     }
 }
 
+/// The predefined algorithms, in `CrcAlgorithm` order without the custom ones (which have no
+/// parameters of their own), for enumerating them or selecting them by index over FFI
+#[allow(unused)]
+pub(crate) const PREDEFINED_ALGORITHMS: [CrcAlgorithm; 19] = [
+    CrcAlgorithm::Crc32Aixm,
+    CrcAlgorithm::Crc32Autosar,
+    CrcAlgorithm::Crc32Base91D,
+    CrcAlgorithm::Crc32Bzip2,
+    CrcAlgorithm::Crc32CdRomEdc,
+    CrcAlgorithm::Crc32Cksum,
+    CrcAlgorithm::Crc32Iscsi,
+    CrcAlgorithm::Crc32IsoHdlc,
+    CrcAlgorithm::Crc32Jamcrc,
+    CrcAlgorithm::Crc32Mef,
+    CrcAlgorithm::Crc32Mpeg2,
+    CrcAlgorithm::Crc32Xfer,
+    CrcAlgorithm::Crc64Ecma182,
+    CrcAlgorithm::Crc64GoIso,
+    CrcAlgorithm::Crc64Ms,
+    CrcAlgorithm::Crc64Nvme,
+    CrcAlgorithm::Crc64Redis,
+    CrcAlgorithm::Crc64We,
+    CrcAlgorithm::Crc64Xz,
+];
+
+/// Common names for the predefined algorithms, as other tools and ecosystems spell them, matched
+/// case-insensitively by `CrcAlgorithm::from_alias()`.
+///
+/// Ambiguous names (such as "crc64", which is XZ, ECMA-182, or NVME depending on who's asking) are
+/// deliberately left out. Beware that PHP's `hash("crc32")` is CRC-32/BZIP2, while its
+/// `hash("crc32b")` and `crc32()` are CRC-32/ISO-HDLC.
+const ALGORITHM_ALIASES: [(&str, CrcAlgorithm); 24] = [
+    // zlib, gzip, PNG, Ethernet, Python's zlib.crc32, Java's CRC32, Go's crc32.IEEE
+    ("crc32", CrcAlgorithm::Crc32IsoHdlc),
+    ("crc32b", CrcAlgorithm::Crc32IsoHdlc),
+    ("crc-32", CrcAlgorithm::Crc32IsoHdlc),
+    ("ieee", CrcAlgorithm::Crc32IsoHdlc),
+    ("zlib", CrcAlgorithm::Crc32IsoHdlc),
+    ("gzip", CrcAlgorithm::Crc32IsoHdlc),
+    // iSCSI, SCTP, ext4, Btrfs, Java's CRC32C, Go's crc32.Castagnoli, SSE4.2's crc32 instruction
+    ("crc32c", CrcAlgorithm::Crc32Iscsi),
+    ("crc-32c", CrcAlgorithm::Crc32Iscsi),
+    ("castagnoli", CrcAlgorithm::Crc32Iscsi),
+    // POSIX cksum
+    ("cksum", CrcAlgorithm::Crc32Cksum),
+    ("posix", CrcAlgorithm::Crc32Cksum),
+    // bzip2, and PHP's hash("crc32")
+    ("bzip2", CrcAlgorithm::Crc32Bzip2),
+    ("crc32-bzip2", CrcAlgorithm::Crc32Bzip2),
+    ("jamcrc", CrcAlgorithm::Crc32Jamcrc),
+    ("mpeg2", CrcAlgorithm::Crc32Mpeg2),
+    // XZ, and Go's crc64.ECMA table (which uses XZ's init and xorout)
+    ("xz64", CrcAlgorithm::Crc64Xz),
+    ("crc64-xz", CrcAlgorithm::Crc64Xz),
+    ("go-ecma", CrcAlgorithm::Crc64Xz),
+    // Go's crc64.ISO table
+    ("go-iso", CrcAlgorithm::Crc64GoIso),
+    ("crc64-iso", CrcAlgorithm::Crc64GoIso),
+    // NVMe, and the AWS S3 full-object CRC64NVME checksum
+    ("nvme", CrcAlgorithm::Crc64Nvme),
+    ("crc64nvme", CrcAlgorithm::Crc64Nvme),
+    ("crc64-nvme", CrcAlgorithm::Crc64Nvme),
+    ("redis", CrcAlgorithm::Crc64Redis),
+];
+
+impl CrcAlgorithm {
+    /// Looks up a predefined algorithm by a common alias (such as "crc32c", "cksum", or "xz64") or
+    /// by its catalogue name (such as "CRC-32/ISCSI"), ignoring ASCII case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::CrcAlgorithm;
+    ///
+    /// assert_eq!(CrcAlgorithm::from_alias("crc32c"), Some(CrcAlgorithm::Crc32Iscsi));
+    /// assert_eq!(CrcAlgorithm::from_alias("crc-64/nvme"), Some(CrcAlgorithm::Crc64Nvme));
+    /// assert_eq!(CrcAlgorithm::from_alias("crc64"), None);
+    /// ```
+    pub fn from_alias(name: &str) -> Option<Self> {
+        ALGORITHM_ALIASES
+            .iter()
+            .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+            .map(|(_, algorithm)| *algorithm)
+            .or_else(|| {
+                PREDEFINED_ALGORITHMS
+                    .into_iter()
+                    .find(|algorithm| algorithm.to_string().eq_ignore_ascii_case(name))
+            })
+    }
+
+    /// Returns the curated aliases and the algorithms they resolve to, see `from_alias()`.
+    pub fn aliases() -> &'static [(&'static str, CrcAlgorithm)] {
+        &ALGORITHM_ALIASES
+    }
+
+    /// Returns true if the algorithm's width is compiled in, see the `crc32-only` and `crc64-only`
+    /// features
+    pub(crate) const fn is_compiled_in(self) -> bool {
+        let is_crc32 = matches!(
+            self,
+            CrcAlgorithm::Crc32Aixm
+                | CrcAlgorithm::Crc32Autosar
+                | CrcAlgorithm::Crc32Base91D
+                | CrcAlgorithm::Crc32Bzip2
+                | CrcAlgorithm::Crc32CdRomEdc
+                | CrcAlgorithm::Crc32Cksum
+                | CrcAlgorithm::Crc32Custom
+                | CrcAlgorithm::Crc32Iscsi
+                | CrcAlgorithm::Crc32IsoHdlc
+                | CrcAlgorithm::Crc32Jamcrc
+                | CrcAlgorithm::Crc32Mef
+                | CrcAlgorithm::Crc32Mpeg2
+                | CrcAlgorithm::Crc32Xfer
+        );
+
+        const CRC32_COMPILED_IN: bool = cfg!(not(feature = "crc64-only"));
+        const CRC64_COMPILED_IN: bool = cfg!(not(feature = "crc32-only"));
+
+        if is_crc32 {
+            CRC32_COMPILED_IN
+        } else {
+            CRC64_COMPILED_IN
+        }
+    }
+}
+
+/// The predefined algorithms which are compiled in, see `CrcAlgorithm::is_compiled_in()`
+#[allow(unused)]
+pub(crate) fn compiled_algorithms() -> impl Iterator<Item = CrcAlgorithm> {
+    PREDEFINED_ALGORITHMS
+        .into_iter()
+        .filter(|algorithm| algorithm.is_compiled_in())
+}
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
 #[derive(Debug, Copy, Clone)]
-pub(crate) enum Reflector<T> {
+pub enum Reflector<T> {
     NoReflector,
     ForwardReflector { smask: T },
 }
@@ -91,3 +225,13 @@ impl DataChunkProcessor {
         }
     }
 }
+
+impl Display for KeySource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeySource::Const => write!(f, "const"),
+            KeySource::Cached => write!(f, "cached"),
+            KeySource::Generated => write!(f, "generated"),
+        }
+    }
+}
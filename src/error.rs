@@ -0,0 +1,101 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! The error type returned by this crate's fallible functions.
+
+/// The reason a fallible function failed
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum CrcFastError {
+    /// Reading the input failed, or the file changed in a way the function can't continue from
+    #[cfg(feature = "std")]
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The custom CRC parameters are invalid, such as not producing their own check value
+    #[error("invalid CRC parameters: {0}")]
+    InvalidParams(&'static str),
+
+    /// The CRC width isn't 32 or 64
+    #[error("unsupported CRC width: {0}")]
+    UnsupportedWidth(u8),
+
+    /// The operation was cancelled by the caller
+    #[error("the operation was cancelled")]
+    Cancelled,
+
+    /// A checksum has bits set above the CRC width, such as a CRC-64 combined as a CRC-32
+    #[error("checksum {checksum:#x} is too wide for a {width}-bit CRC")]
+    ChecksumTooWide {
+        /// The CRC width of the combine
+        width: u8,
+        /// The checksum which doesn't fit in it
+        checksum: u64,
+    },
+
+    /// The Digests being combined use different CRC parameters
+    #[error("the digests use different CRC parameters")]
+    ParamsMismatch,
+}
+
+/// Keeps `?` working in functions which return `std::io::Result`
+#[cfg(feature = "std")]
+impl From<CrcFastError> for std::io::Error {
+    fn from(error: CrcFastError) -> Self {
+        use std::io::ErrorKind;
+
+        match error {
+            CrcFastError::Io(error) => error,
+            CrcFastError::Cancelled => std::io::Error::new(ErrorKind::Interrupted, error),
+            _ => std::io::Error::new(ErrorKind::InvalidInput, error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            CrcFastError::UnsupportedWidth(16).to_string(),
+            "unsupported CRC width: 16"
+        );
+        assert_eq!(
+            CrcFastError::InvalidParams("check mismatch").to_string(),
+            "invalid CRC parameters: check mismatch"
+        );
+        assert_eq!(
+            CrcFastError::Cancelled.to_string(),
+            "the operation was cancelled"
+        );
+        assert_eq!(
+            CrcFastError::ChecksumTooWide {
+                width: 32,
+                checksum: 0x1_0000_0000
+            }
+            .to_string(),
+            "checksum 0x100000000 is too wide for a 32-bit CRC"
+        );
+    }
+
+    #[test]
+    fn test_io_conversions() {
+        use std::error::Error;
+        use std::io::ErrorKind;
+
+        let error = CrcFastError::from(std::io::Error::from(ErrorKind::NotFound));
+        assert!(matches!(&error, CrcFastError::Io(io) if io.kind() == ErrorKind::NotFound));
+        assert!(error.source().is_some());
+        assert_eq!(std::io::Error::from(error).kind(), ErrorKind::NotFound);
+
+        assert_eq!(
+            std::io::Error::from(CrcFastError::UnsupportedWidth(8)).kind(),
+            ErrorKind::InvalidInput
+        );
+        assert_eq!(
+            std::io::Error::from(CrcFastError::Cancelled).kind(),
+            ErrorKind::Interrupted
+        );
+    }
+}
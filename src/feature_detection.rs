@@ -3,47 +3,203 @@
 //! Feature detection system for safe and efficient hardware acceleration across different
 //! platforms.
 
+use crate::CrcParams;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::OnceLock;
 
 /// Global ArchOps instance cache - initialized once based on feature detection results
 static ARCH_OPS_INSTANCE: OnceLock<ArchOpsInstance> = OnceLock::new();
 
+/// Environment variable which forces a specific performance tier, using its target string (e.g.
+/// `x86_64-sse-pclmulqdq`)
+const FORCE_TIER_ENV_VAR: &str = "CRC_FAST_FORCE_TIER";
+
+/// `CRC_FAST_FORCE_TIER` value which selects the fastest tier using a micro-benchmark
+const FORCE_TIER_AUTO: &str = "auto";
+
+/// Environment variable which masks individual CPU features during detection, as a
+/// comma-separated list of feature names (e.g. `vpclmulqdq,sha3`)
+const DISABLE_FEATURES_ENV_VAR: &str = "CRC_FAST_DISABLE_FEATURES";
+
+/// CPU features which can be disabled, and their bits in the disabled features mask. The native
+/// CRC-32 instructions are `crc` on aarch64 and `sse4.2` on x86, and `pmull` is provided by `aes`.
+const DISABLEABLE_FEATURES: [(&str, u32); 10] = [
+    ("aes", 1 << 0),
+    ("pmull", 1 << 0),
+    ("sha3", 1 << 1),
+    ("sse4.1", 1 << 2),
+    ("pclmulqdq", 1 << 3),
+    ("avx512vl", 1 << 4),
+    ("vpclmulqdq", 1 << 5),
+    ("gfni", 1 << 6),
+    ("crc", 1 << 7),
+    ("sse4.2", 1 << 7),
+];
+
+/// Bit for the native CRC-32 instructions in the disabled features mask
+const NATIVE_CRC_FEATURE: u32 = 1 << 7;
+
+/// Features disabled by `set_disabled_features()`
+static DISABLED_FEATURES: AtomicU32 = AtomicU32::new(0);
+
+/// Features disabled by the `CRC_FAST_DISABLE_FEATURES` environment variable
+static DISABLED_FEATURES_ENV: OnceLock<u32> = OnceLock::new();
+
+/// Size of the sample buffer used to time each tier when auto-tuning
+const AUTO_TUNE_SAMPLE_SIZE: usize = 64 * 1024;
+
+/// Number of timed runs per tier when auto-tuning, the fastest of which is used
+const AUTO_TUNE_ITERATIONS: usize = 8;
+
+/// Every performance tier, from highest to lowest
+pub(crate) const ALL_TIERS: [PerformanceTier; 12] = [
+    PerformanceTier::AArch64AesSha3,
+    PerformanceTier::AArch64Aes,
+    PerformanceTier::AArch64Crc,
+    PerformanceTier::X86_64Avx512Vpclmulqdq,
+    PerformanceTier::X86_64Avx512Pclmulqdq,
+    PerformanceTier::X86_64SsePclmulqdq,
+    PerformanceTier::X86_64SseGfni,
+    PerformanceTier::X86_64SseCrc32c,
+    PerformanceTier::X86SsePclmulqdq,
+    PerformanceTier::X86SseCrc32c,
+    PerformanceTier::PortableSimd,
+    PerformanceTier::SoftwareTable,
+];
+
 /// Performance tiers representing different hardware capability levels
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[allow(dead_code)] // Some variants may not be constructed on all target architectures
+#[non_exhaustive]
 pub enum PerformanceTier {
     // AArch64 tiers
     AArch64AesSha3,
     AArch64Aes,
+    /// Only the CRC extension's CRC-32 and CRC-32C instructions, for CRC-32/ISCSI and
+    /// CRC-32/ISO-HDLC on cores without PMULL, with the other algorithms using the software
+    /// fallback
+    AArch64Crc,
 
     // x86_64 tiers
     X86_64Avx512Vpclmulqdq,
     X86_64Avx512Pclmulqdq,
     X86_64SsePclmulqdq,
+    /// Experimental, uses GFNI affine transforms rather than carryless multiplication
+    X86_64SseGfni,
+    /// Only the SSE4.2 CRC-32C instruction, for CRC-32/ISCSI on CPUs (usually virtualized) without
+    /// PCLMULQDQ, with the other algorithms using the software fallback
+    X86_64SseCrc32c,
 
     // x86 tiers
     X86SsePclmulqdq,
+    /// Only the SSE4.2 CRC-32C instruction, like `X86_64SseCrc32c`
+    X86SseCrc32c,
+
+    // Portable `std::simd`, with the `portable-simd` feature on nightly
+    PortableSimd,
 
     // Fallback
     SoftwareTable,
+
+    // Registered via `register_backend()`
+    Custom,
 }
 
-/// Architecture-specific capabilities
-#[derive(Debug, Clone, Copy)]
-#[allow(dead_code)] // Some fields may not be read on all target architectures
+/// Architecture-specific capabilities, as detected at runtime (see [`capabilities()`])
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ArchCapabilities {
     // AArch64 features
-    pub has_aes: bool, // provides PMULL support for CRC calculations (NEON is implicit)
-    pub has_sha3: bool, // requires 'aes', provides EOR3 for XOR3 operations
+    pub(crate) has_aes: bool, // provides PMULL support for CRC calculations (NEON is implicit)
+    pub(crate) has_sha3: bool, // requires 'aes', provides EOR3 for XOR3 operations
 
     // x86/x86_64 features
-    pub has_sse41: bool,
-    pub has_pclmulqdq: bool,
-    pub has_avx512vl: bool, // implicitly enables avx512f, has XOR3 operations
-    pub has_vpclmulqdq: bool,
+    pub(crate) has_sse41: bool,
+    pub(crate) has_pclmulqdq: bool,
+    pub(crate) has_avx512vl: bool, // implicitly enables avx512f, has XOR3 operations
+    pub(crate) has_vpclmulqdq: bool,
+    pub(crate) has_gfni: bool, // requires 'ssse3', used by the experimental GFNI tier
+
+    // Native CRC-32 instructions: 'crc' on aarch64, 'sse4.2' on x86/x86_64 (CRC-32C only)
+    pub(crate) has_crc: bool,
 
     // Rust version gates
-    pub rust_version_supports_avx512: bool,
+    pub(crate) rust_version_supports_avx512: bool,
+}
+
+impl ArchCapabilities {
+    /// Whether AES (and so PMULL) is available, on aarch64
+    pub fn has_aes(&self) -> bool {
+        self.has_aes
+    }
+
+    /// Whether SHA3 (and so EOR3) is available, on aarch64
+    pub fn has_sha3(&self) -> bool {
+        self.has_sha3
+    }
+
+    /// Whether SSE4.1 is available, on x86 and x86_64
+    pub fn has_sse41(&self) -> bool {
+        self.has_sse41
+    }
+
+    /// Whether PCLMULQDQ is available, on x86 and x86_64
+    pub fn has_pclmulqdq(&self) -> bool {
+        self.has_pclmulqdq
+    }
+
+    /// Whether AVX-512VL is available and usable by this Rust version, on x86 and x86_64
+    pub fn has_avx512vl(&self) -> bool {
+        self.has_avx512vl
+    }
+
+    /// Whether VPCLMULQDQ is available and usable by this Rust version, on x86 and x86_64
+    pub fn has_vpclmulqdq(&self) -> bool {
+        self.has_vpclmulqdq
+    }
+
+    /// Whether GFNI is available and usable by this Rust version, on x86_64
+    pub fn has_gfni(&self) -> bool {
+        self.has_gfni
+    }
+
+    /// Whether the native CRC-32 instructions are available: `crc` on aarch64, or `sse4.2` on x86
+    /// and x86_64 (which only has CRC-32C instructions)
+    pub fn has_crc(&self) -> bool {
+        self.has_crc
+    }
+
+    /// Whether this crate was built with a Rust version which supports the AVX-512 intrinsics
+    /// (1.89 or later)
+    pub fn rust_version_supports_avx512(&self) -> bool {
+        self.rust_version_supports_avx512
+    }
+
+    /// Returns the names of the available features, in the same format as
+    /// [`set_disabled_features()`], for logging.
+    pub fn features(&self) -> Vec<&'static str> {
+        [
+            (self.has_aes, "aes"),
+            (self.has_sha3, "sha3"),
+            (self.has_sse41, "sse4.1"),
+            (self.has_pclmulqdq, "pclmulqdq"),
+            (self.has_avx512vl, "avx512vl"),
+            (self.has_vpclmulqdq, "vpclmulqdq"),
+            (self.has_gfni, "gfni"),
+            (
+                self.has_crc,
+                if cfg!(target_arch = "aarch64") {
+                    "crc"
+                } else {
+                    "sse4.2"
+                },
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(available, name)| available.then_some(name))
+        .collect()
+    }
 }
 
 /// Helper function to convert a performance tier to a human-readable target string
@@ -53,20 +209,337 @@ fn tier_to_target_string(tier: PerformanceTier) -> String {
     match tier {
         PerformanceTier::AArch64AesSha3 => "aarch64-neon-pmull-sha3".to_string(),
         PerformanceTier::AArch64Aes => "aarch64-neon-pmull".to_string(),
+        PerformanceTier::AArch64Crc => "aarch64-crc".to_string(),
         PerformanceTier::X86_64Avx512Vpclmulqdq => "x86_64-avx512-vpclmulqdq".to_string(),
         PerformanceTier::X86_64Avx512Pclmulqdq => "x86_64-avx512-pclmulqdq".to_string(),
         PerformanceTier::X86_64SsePclmulqdq => "x86_64-sse-pclmulqdq".to_string(),
+        PerformanceTier::X86_64SseGfni => "x86_64-sse-gfni".to_string(),
+        PerformanceTier::X86_64SseCrc32c => "x86_64-sse-crc32c".to_string(),
         PerformanceTier::X86SsePclmulqdq => "x86-sse-pclmulqdq".to_string(),
+        PerformanceTier::X86SseCrc32c => "x86-sse-crc32c".to_string(),
+        PerformanceTier::PortableSimd => "portable-simd-u64x8".to_string(),
         PerformanceTier::SoftwareTable => "software-fallback-tables".to_string(),
+        PerformanceTier::Custom => "custom".to_string(),
     }
 }
 
-/// Detect architecture-specific capabilities combining compile-time and runtime checks
+impl Display for PerformanceTier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", tier_to_target_string(*self))
+    }
+}
+
+impl PerformanceTier {
+    /// Whether this is one of the built-in SIMD tiers, as opposed to the software fallback (with or
+    /// without the native CRC-32 instructions) or a custom backend
+    #[inline(always)]
+    pub(crate) fn is_builtin_simd(self) -> bool {
+        !matches!(
+            self,
+            PerformanceTier::AArch64Crc
+                | PerformanceTier::X86_64SseCrc32c
+                | PerformanceTier::X86SseCrc32c
+                | PerformanceTier::SoftwareTable
+                | PerformanceTier::Custom
+        )
+    }
+
+    /// Whether the native CRC-32 instructions can be fused with this tier's folding, which the
+    /// GFNI and portable SIMD tiers don't do since they have no carryless multiplication, and no
+    /// tier does if the native CRC-32 instructions have been disabled
+    #[inline(always)]
+    pub(crate) fn supports_fusion(self) -> bool {
+        self.is_builtin_simd()
+            && !matches!(
+                self,
+                PerformanceTier::X86_64SseGfni | PerformanceTier::PortableSimd
+            )
+            && !is_native_crc_disabled()
+    }
+}
+
+impl FromStr for PerformanceTier {
+    type Err = ();
+
+    /// Parses a target string, as returned by `get_calculator_target()`, into a performance tier
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "aarch64-neon-pmull-sha3" => Ok(PerformanceTier::AArch64AesSha3),
+            "aarch64-neon-pmull" => Ok(PerformanceTier::AArch64Aes),
+            "aarch64-crc" => Ok(PerformanceTier::AArch64Crc),
+            "x86_64-avx512-vpclmulqdq" => Ok(PerformanceTier::X86_64Avx512Vpclmulqdq),
+            "x86_64-avx512-pclmulqdq" => Ok(PerformanceTier::X86_64Avx512Pclmulqdq),
+            "x86_64-sse-pclmulqdq" => Ok(PerformanceTier::X86_64SsePclmulqdq),
+            "x86_64-sse-gfni" => Ok(PerformanceTier::X86_64SseGfni),
+            "x86_64-sse-crc32c" => Ok(PerformanceTier::X86_64SseCrc32c),
+            "x86-sse-pclmulqdq" => Ok(PerformanceTier::X86SsePclmulqdq),
+            "x86-sse-crc32c" => Ok(PerformanceTier::X86SseCrc32c),
+            "portable-simd-u64x8" => Ok(PerformanceTier::PortableSimd),
+            "software-fallback-tables" => Ok(PerformanceTier::SoftwareTable),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Helper function to get the CPU features used by a performance tier
+#[inline(always)]
+fn tier_to_features(tier: PerformanceTier) -> &'static [&'static str] {
+    match tier {
+        PerformanceTier::AArch64AesSha3 => &["neon", "aes", "sha3"],
+        PerformanceTier::AArch64Aes => &["neon", "aes"],
+        PerformanceTier::AArch64Crc => &["crc"],
+        PerformanceTier::X86_64Avx512Vpclmulqdq => {
+            &["sse4.1", "pclmulqdq", "avx512f", "avx512vl", "vpclmulqdq"]
+        }
+        PerformanceTier::X86_64Avx512Pclmulqdq => &["sse4.1", "pclmulqdq", "avx512f", "avx512vl"],
+        PerformanceTier::X86_64SsePclmulqdq | PerformanceTier::X86SsePclmulqdq => {
+            &["sse4.1", "pclmulqdq"]
+        }
+        PerformanceTier::X86_64SseGfni => &["ssse3", "gfni"],
+        PerformanceTier::X86_64SseCrc32c | PerformanceTier::X86SseCrc32c => &["sse4.2"],
+        PerformanceTier::PortableSimd
+        | PerformanceTier::SoftwareTable
+        | PerformanceTier::Custom => &[],
+    }
+}
+
+/// Helper function to get the SIMD register width, in bits, used by a performance tier
+#[inline(always)]
+fn tier_to_simd_width(tier: PerformanceTier) -> u16 {
+    match tier {
+        PerformanceTier::X86_64Avx512Vpclmulqdq | PerformanceTier::PortableSimd => 512,
+        PerformanceTier::AArch64Crc
+        | PerformanceTier::X86_64SseCrc32c
+        | PerformanceTier::X86SseCrc32c
+        | PerformanceTier::SoftwareTable
+        | PerformanceTier::Custom => 0,
+        _ => 128,
+    }
+}
+
+/// Structured description of the hardware acceleration used for CRC calculations, for monitoring
+/// and support tooling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AcceleratorInfo {
+    /// Target architecture, such as `x86_64` or `aarch64`
+    pub arch: &'static str,
+    /// Active performance tier
+    pub tier: PerformanceTier,
+    /// CPU features used by the active implementation, such as `pclmulqdq` or `sha3`
+    pub features: Vec<&'static str>,
+    /// Width of the SIMD registers used, in bits (0 for the software fallback and custom backends)
+    pub simd_width: u16,
+    /// Whether native CRC instructions are fused with the carryless multiplication folding
+    pub uses_fusion: bool,
+}
+
+impl AcceleratorInfo {
+    /// Describes the specified performance tier, with or without fusion
+    pub(crate) fn new(tier: PerformanceTier, uses_fusion: bool) -> Self {
+        let uses_fusion = uses_fusion && tier.supports_fusion();
+
+        let mut features = tier_to_features(tier).to_vec();
+        if uses_fusion {
+            // fusion uses the native CRC-32 instructions
+            features.push(if cfg!(target_arch = "aarch64") {
+                "crc"
+            } else {
+                "sse4.2"
+            });
+        }
+
+        Self {
+            arch: std::env::consts::ARCH,
+            tier,
+            features,
+            simd_width: tier_to_simd_width(tier),
+            uses_fusion,
+        }
+    }
+}
+
+/// Errors that can occur when forcing a performance tier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForceTierError {
+    /// The active tier was already selected (by a previous calculation, a previous call, the
+    /// `CRC_FAST_FORCE_TIER` environment variable, or `static-dispatch`) and can't be changed
+    AlreadySelected(PerformanceTier),
+    /// The requested tier isn't supported by this CPU, architecture, or Rust version, or a custom
+    /// backend failed its self-check
+    Unsupported(PerformanceTier),
+}
+
+impl Display for ForceTierError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ForceTierError::AlreadySelected(tier) => {
+                write!(f, "performance tier already selected: {tier}")
+            }
+            ForceTierError::Unsupported(tier) => {
+                write!(f, "performance tier not supported on this system: {tier}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ForceTierError {}
+
+/// A custom CRC implementation, such as a proprietary accelerator or a DPU offload, which can be
+/// registered with `register_backend()` to replace the built-in implementations.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{CrcBackend, CrcParams};
+///
+/// struct Offload;
+///
+/// impl CrcBackend for Offload {
+///     fn name(&self) -> &'static str {
+///         "dpu-offload"
+///     }
+///
+///     fn update(&self, state: u64, data: &[u8], params: CrcParams) -> u64 {
+///         // hand off to the accelerator here
+///         # let _ = (data, params);
+///         state
+///     }
+/// }
+/// ```
+pub trait CrcBackend: Send + Sync {
+    /// Human-readable name, returned by `get_calculator_target()` while the backend is active
+    fn name(&self) -> &'static str;
+
+    /// Updates a raw, non-finalized CRC state with `data`, returning the new state.
+    ///
+    /// Must support every valid `params`, in both 32 and 64 bit widths and in both reflected and
+    /// non-reflected forms. `state` is the same raw state as used by the built-in implementations,
+    /// so `init` has already been applied and `xorout` is applied by the caller.
+    fn update(&self, state: u64, data: &[u8], params: CrcParams) -> u64;
+}
+
+impl std::fmt::Debug for dyn CrcBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("CrcBackend").field(&self.name()).finish()
+    }
+}
+
+/// Converts a comma-separated list of feature names to a disabled features mask, ignoring unknown
+/// names so a typo can't break CRC calculations
+fn parse_disabled_features<'a>(features: impl IntoIterator<Item = &'a str>) -> u32 {
+    features
+        .into_iter()
+        .map(str::trim)
+        .filter_map(|feature| {
+            DISABLEABLE_FEATURES
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(feature))
+        })
+        .fold(0, |mask, (_, bit)| mask | bit)
+}
+
+/// Returns the mask of features disabled by `set_disabled_features()` or the
+/// `CRC_FAST_DISABLE_FEATURES` environment variable
+#[inline(always)]
+fn disabled_features() -> u32 {
+    let env = *DISABLED_FEATURES_ENV.get_or_init(|| {
+        std::env::var(DISABLE_FEATURES_ENV_VAR)
+            .map(|value| parse_disabled_features(value.split(',')))
+            .unwrap_or(0)
+    });
+
+    env | DISABLED_FEATURES.load(Ordering::Relaxed)
+}
+
+/// Returns whether the native CRC-32 instructions have been disabled, in which case the fusion
+/// implementations aren't used
+#[inline(always)]
+fn is_native_crc_disabled() -> bool {
+    disabled_features() & NATIVE_CRC_FEATURE != 0
+}
+
+/// Disables individual CPU features for all subsequent CRC calculations, in addition to any
+/// disabled by the `CRC_FAST_DISABLE_FEATURES` environment variable (e.g. `vpclmulqdq,sha3`).
+///
+/// Separately from forcing a tier, this masks capabilities during detection, which is handy when a
+/// specific microarchitecture has an erratum or downclocking issue with one instruction. The best
+/// tier which doesn't need a disabled feature is selected, and tiers which need one can't be
+/// forced. Disabling the native CRC-32 instructions (`crc` on aarch64, `sse4.2` on x86) disables
+/// fusion.
+///
+/// The recognized names are `aes` (or `pmull`), `sha3`, `crc`, `sse4.1`, `sse4.2`, `pclmulqdq`,
+/// `avx512vl`, `vpclmulqdq`, and `gfni`, and are case-insensitive. Unknown names are ignored, and
+/// features can't be re-enabled once they're disabled.
+///
+/// The tier is selected once, so this must be called before the first CRC calculation. A tier
+/// selected at compile time via the `static-dispatch` feature ignores disabled features.
+///
+/// # Errors
+///
+/// Returns [`ForceTierError::AlreadySelected`] if the tier has already been selected.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::set_disabled_features;
+///
+/// // fails if a calculation has already happened, in which case nothing is disabled
+/// let _ = set_disabled_features(&["vpclmulqdq"]);
+/// ```
+pub fn set_disabled_features(features: &[&str]) -> Result<(), ForceTierError> {
+    if let Some(selected) = get_selected_tier() {
+        return Err(ForceTierError::AlreadySelected(selected));
+    }
+
+    DISABLED_FEATURES.fetch_or(
+        parse_disabled_features(features.iter().copied()),
+        Ordering::Relaxed,
+    );
+
+    Ok(())
+}
+
+/// Clears the features in the disabled features mask from the capabilities, along with the
+/// features which depend on them
+fn mask_capabilities(capabilities: ArchCapabilities, disabled: u32) -> ArchCapabilities {
+    let enabled = |name: &str| {
+        DISABLEABLE_FEATURES
+            .iter()
+            .any(|(feature, bit)| *feature == name && disabled & bit == 0)
+    };
+
+    let has_aes = capabilities.has_aes && enabled("aes");
+    let has_sse41 = capabilities.has_sse41 && enabled("sse4.1");
+    let has_pclmulqdq = capabilities.has_pclmulqdq && has_sse41 && enabled("pclmulqdq");
+    let has_avx512vl = capabilities.has_avx512vl && has_pclmulqdq && enabled("avx512vl");
+
+    ArchCapabilities {
+        has_aes,
+        has_sha3: capabilities.has_sha3 && has_aes && enabled("sha3"),
+        has_sse41,
+        has_pclmulqdq,
+        has_avx512vl,
+        has_vpclmulqdq: capabilities.has_vpclmulqdq && has_avx512vl && enabled("vpclmulqdq"),
+        has_gfni: capabilities.has_gfni && enabled("gfni"),
+        has_crc: capabilities.has_crc && enabled("crc"),
+        rust_version_supports_avx512: capabilities.rust_version_supports_avx512,
+    }
+}
+
+/// Detect architecture-specific capabilities, excluding any disabled features
 ///
 /// # Safety
 /// Uses runtime feature detection which may access CPU-specific registers
 unsafe fn detect_arch_capabilities() -> ArchCapabilities {
-    #[cfg(target_arch = "aarch64")]
+    mask_capabilities(detect_all_arch_capabilities(), disabled_features())
+}
+
+/// Detect architecture-specific capabilities combining compile-time and runtime checks
+///
+/// # Safety
+/// Uses runtime feature detection which may access CPU-specific registers
+unsafe fn detect_all_arch_capabilities() -> ArchCapabilities {
+    #[cfg(all(target_arch = "aarch64", target_endian = "little"))]
     {
         detect_aarch64_features()
     }
@@ -76,7 +549,14 @@ unsafe fn detect_arch_capabilities() -> ArchCapabilities {
         detect_x86_features()
     }
 
-    #[cfg(not(any(target_arch = "aarch64", target_arch = "x86", target_arch = "x86_64")))]
+    // The AArch64 folding and fused kernels load 64-bit lanes in native byte order, which is only
+    // correct on little-endian, so big-endian AArch64 uses the software fallback like other
+    // architectures
+    #[cfg(not(any(
+        all(target_arch = "aarch64", target_endian = "little"),
+        target_arch = "x86",
+        target_arch = "x86_64"
+    )))]
     {
         // Other architectures use software fallback
         ArchCapabilities {
@@ -86,6 +566,8 @@ unsafe fn detect_arch_capabilities() -> ArchCapabilities {
             has_pclmulqdq: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         }
     }
@@ -96,7 +578,7 @@ unsafe fn detect_arch_capabilities() -> ArchCapabilities {
 /// Note: NEON is always available on AArch64 and is implicitly enabled by AES support.
 /// AES support provides the PMULL instructions needed for CRC calculations.
 #[inline(always)]
-#[cfg(target_arch = "aarch64")]
+#[cfg(all(target_arch = "aarch64", target_endian = "little"))]
 unsafe fn detect_aarch64_features() -> ArchCapabilities {
     use std::arch::is_aarch64_feature_detected;
 
@@ -107,6 +589,9 @@ unsafe fn detect_aarch64_features() -> ArchCapabilities {
     // XOR3 operations.
     let has_sha3 = is_aarch64_feature_detected!("sha3");
 
+    // The CRC extension provides the native CRC-32 and CRC-32C instructions
+    let has_crc = is_aarch64_feature_detected!("crc");
+
     ArchCapabilities {
         has_aes,
         has_sha3,
@@ -114,6 +599,8 @@ unsafe fn detect_aarch64_features() -> ArchCapabilities {
         has_pclmulqdq: false,
         has_avx512vl: false,
         has_vpclmulqdq: false,
+        has_gfni: false,
+        has_crc,
         rust_version_supports_avx512: false,
     }
 }
@@ -137,6 +624,16 @@ unsafe fn detect_x86_features() -> ArchCapabilities {
     let has_vpclmulqdq =
         has_avx512vl && rust_version_supports_avx512 && is_x86_feature_detected!("vpclmulqdq");
 
+    // GFNI intrinsics stabilized alongside AVX-512, and are only used by the x86_64 GFNI tier
+    let has_gfni = cfg!(target_arch = "x86_64")
+        && rust_version_supports_avx512
+        && is_x86_feature_detected!("ssse3")
+        && is_x86_feature_detected!("gfni");
+
+    // SSE4.2 provides the native CRC-32C instruction, which some virtualized environments keep
+    // while masking PCLMULQDQ
+    let has_crc = is_x86_feature_detected!("sse4.2");
+
     ArchCapabilities {
         has_aes: false,
         has_sha3: false,
@@ -144,6 +641,8 @@ unsafe fn detect_x86_features() -> ArchCapabilities {
         has_pclmulqdq,
         has_avx512vl,
         has_vpclmulqdq,
+        has_gfni,
+        has_crc,
         rust_version_supports_avx512,
     }
 }
@@ -179,6 +678,10 @@ pub(crate) fn select_performance_tier(capabilities: &ArchCapabilities) -> Perfor
         if capabilities.has_aes {
             return PerformanceTier::AArch64Aes;
         }
+
+        if capabilities.has_crc {
+            return PerformanceTier::AArch64Crc;
+        }
     }
 
     #[cfg(target_arch = "x86_64")]
@@ -192,6 +695,13 @@ pub(crate) fn select_performance_tier(capabilities: &ArchCapabilities) -> Perfor
         if capabilities.has_pclmulqdq {
             return PerformanceTier::X86_64SsePclmulqdq;
         }
+        // the GFNI tier still uses the native CRC-32C instruction for CRC-32/ISCSI, if it can
+        if capabilities.has_gfni {
+            return PerformanceTier::X86_64SseGfni;
+        }
+        if capabilities.has_crc {
+            return PerformanceTier::X86_64SseCrc32c;
+        }
     }
 
     #[cfg(target_arch = "x86")]
@@ -199,12 +709,59 @@ pub(crate) fn select_performance_tier(capabilities: &ArchCapabilities) -> Perfor
         if capabilities.has_pclmulqdq {
             return PerformanceTier::X86SsePclmulqdq;
         }
+        if capabilities.has_crc {
+            return PerformanceTier::X86SseCrc32c;
+        }
     }
 
+    // Portable SIMD, if enabled, is still faster than the tables
+    #[cfg(feature = "portable-simd")]
+    return PerformanceTier::PortableSimd;
+
     // Fallback to software implementation
+    #[allow(unreachable_code)]
     PerformanceTier::SoftwareTable
 }
 
+/// Check whether a performance tier can be safely used with the detected capabilities
+///
+/// The software fallback is only compiled in where it may be needed, so it's only supported when
+/// it would have been selected anyway.
+#[inline(always)]
+#[allow(unused)]
+pub(crate) fn is_tier_supported(tier: PerformanceTier, capabilities: &ArchCapabilities) -> bool {
+    match tier {
+        #[cfg(target_arch = "aarch64")]
+        PerformanceTier::AArch64AesSha3 => capabilities.has_aes && capabilities.has_sha3,
+        #[cfg(target_arch = "aarch64")]
+        PerformanceTier::AArch64Aes => capabilities.has_aes,
+        #[cfg(target_arch = "aarch64")]
+        PerformanceTier::AArch64Crc => capabilities.has_crc,
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64Avx512Vpclmulqdq => capabilities.has_vpclmulqdq,
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64Avx512Pclmulqdq => capabilities.has_avx512vl,
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64SsePclmulqdq => capabilities.has_pclmulqdq,
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64SseGfni => capabilities.has_gfni,
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64SseCrc32c => capabilities.has_crc,
+        #[cfg(target_arch = "x86")]
+        PerformanceTier::X86SsePclmulqdq => capabilities.has_pclmulqdq,
+        #[cfg(target_arch = "x86")]
+        PerformanceTier::X86SseCrc32c => capabilities.has_crc,
+        #[cfg(feature = "portable-simd")]
+        PerformanceTier::PortableSimd => true,
+        PerformanceTier::SoftwareTable => {
+            select_performance_tier(capabilities) == PerformanceTier::SoftwareTable
+        }
+        // Tiers for other architectures are never supported
+        #[allow(unreachable_patterns)]
+        _ => false,
+    }
+}
+
 /// Enum that holds the different ArchOps implementations for compile-time dispatch
 /// This avoids the need for trait objects while still providing factory-based selection
 #[rustversion::since(1.89)]
@@ -214,14 +771,27 @@ pub enum ArchOpsInstance {
     Aarch64Aes(crate::arch::aarch64::aes::Aarch64AesOps),
     #[cfg(target_arch = "aarch64")]
     Aarch64AesSha3(crate::arch::aarch64::aes_sha3::Aarch64AesSha3Ops),
+    /// Software fallback with the CRC extension's instructions - no ArchOps struct needed
+    #[cfg(target_arch = "aarch64")]
+    Aarch64Crc,
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     X86SsePclmulqdq(crate::arch::x86::sse::X86SsePclmulqdqOps),
     #[cfg(target_arch = "x86_64")]
     X86_64Avx512Pclmulqdq(crate::arch::x86_64::avx512::X86_64Avx512PclmulqdqOps),
     #[cfg(target_arch = "x86_64")]
     X86_64Avx512Vpclmulqdq(crate::arch::x86_64::avx512_vpclmulqdq::X86_64Avx512VpclmulqdqOps),
+    #[cfg(target_arch = "x86_64")]
+    X86_64SseGfni(crate::arch::x86_64::gfni::X86_64SseGfniOps),
+    /// Portable `std::simd` - no ArchOps struct needed
+    #[cfg(feature = "portable-simd")]
+    PortableSimd(crate::arch::portable_simd::PortableSimdOps),
+    /// Software fallback with the SSE4.2 CRC-32C instruction - no ArchOps struct needed
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    X86SseCrc32c,
     /// Software fallback - no ArchOps struct needed
     SoftwareFallback,
+    /// Custom backend registered via `register_backend()`
+    Custom(&'static dyn CrcBackend),
 }
 
 #[rustversion::before(1.89)]
@@ -231,10 +801,21 @@ pub enum ArchOpsInstance {
     Aarch64Aes(crate::arch::aarch64::aes::Aarch64AesOps),
     #[cfg(target_arch = "aarch64")]
     Aarch64AesSha3(crate::arch::aarch64::aes_sha3::Aarch64AesSha3Ops),
+    /// Software fallback with the CRC extension's instructions - no ArchOps struct needed
+    #[cfg(target_arch = "aarch64")]
+    Aarch64Crc,
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     X86SsePclmulqdq(crate::arch::x86::sse::X86SsePclmulqdqOps),
+    /// Portable `std::simd` - no ArchOps struct needed
+    #[cfg(feature = "portable-simd")]
+    PortableSimd(crate::arch::portable_simd::PortableSimdOps),
+    /// Software fallback with the SSE4.2 CRC-32C instruction - no ArchOps struct needed
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    X86SseCrc32c,
     /// Software fallback - no ArchOps struct needed
     SoftwareFallback,
+    /// Custom backend registered via `register_backend()`
+    Custom(&'static dyn CrcBackend),
 }
 
 impl ArchOpsInstance {
@@ -246,13 +827,26 @@ impl ArchOpsInstance {
             ArchOpsInstance::Aarch64Aes(_) => PerformanceTier::AArch64Aes,
             #[cfg(target_arch = "aarch64")]
             ArchOpsInstance::Aarch64AesSha3(_) => PerformanceTier::AArch64AesSha3,
-            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[cfg(target_arch = "aarch64")]
+            ArchOpsInstance::Aarch64Crc => PerformanceTier::AArch64Crc,
+            #[cfg(target_arch = "x86_64")]
+            ArchOpsInstance::X86SsePclmulqdq(_) => PerformanceTier::X86_64SsePclmulqdq,
+            #[cfg(target_arch = "x86")]
             ArchOpsInstance::X86SsePclmulqdq(_) => PerformanceTier::X86SsePclmulqdq,
             #[cfg(target_arch = "x86_64")]
             ArchOpsInstance::X86_64Avx512Pclmulqdq(_) => PerformanceTier::X86_64Avx512Pclmulqdq,
             #[cfg(target_arch = "x86_64")]
             ArchOpsInstance::X86_64Avx512Vpclmulqdq(_) => PerformanceTier::X86_64Avx512Vpclmulqdq,
+            #[cfg(target_arch = "x86_64")]
+            ArchOpsInstance::X86_64SseGfni(_) => PerformanceTier::X86_64SseGfni,
+            #[cfg(target_arch = "x86_64")]
+            ArchOpsInstance::X86SseCrc32c => PerformanceTier::X86_64SseCrc32c,
+            #[cfg(target_arch = "x86")]
+            ArchOpsInstance::X86SseCrc32c => PerformanceTier::X86SseCrc32c,
+            #[cfg(feature = "portable-simd")]
+            ArchOpsInstance::PortableSimd(_) => PerformanceTier::PortableSimd,
             ArchOpsInstance::SoftwareFallback => PerformanceTier::SoftwareTable,
+            ArchOpsInstance::Custom(_) => PerformanceTier::Custom,
         }
     }
 
@@ -264,16 +858,30 @@ impl ArchOpsInstance {
             ArchOpsInstance::Aarch64Aes(_) => PerformanceTier::AArch64Aes,
             #[cfg(target_arch = "aarch64")]
             ArchOpsInstance::Aarch64AesSha3(_) => PerformanceTier::AArch64AesSha3,
-            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            #[cfg(target_arch = "aarch64")]
+            ArchOpsInstance::Aarch64Crc => PerformanceTier::AArch64Crc,
+            #[cfg(target_arch = "x86_64")]
+            ArchOpsInstance::X86SsePclmulqdq(_) => PerformanceTier::X86_64SsePclmulqdq,
+            #[cfg(target_arch = "x86")]
             ArchOpsInstance::X86SsePclmulqdq(_) => PerformanceTier::X86SsePclmulqdq,
+            #[cfg(target_arch = "x86_64")]
+            ArchOpsInstance::X86SseCrc32c => PerformanceTier::X86_64SseCrc32c,
+            #[cfg(target_arch = "x86")]
+            ArchOpsInstance::X86SseCrc32c => PerformanceTier::X86SseCrc32c,
+            #[cfg(feature = "portable-simd")]
+            ArchOpsInstance::PortableSimd(_) => PerformanceTier::PortableSimd,
             ArchOpsInstance::SoftwareFallback => PerformanceTier::SoftwareTable,
+            ArchOpsInstance::Custom(_) => PerformanceTier::Custom,
         }
     }
 
     /// Get a human-readable target string describing the active configuration
     #[inline(always)]
     pub fn get_target_string(&self) -> String {
-        tier_to_target_string(self.get_tier())
+        match self {
+            ArchOpsInstance::Custom(backend) => backend.name().to_string(),
+            _ => tier_to_target_string(self.get_tier()),
+        }
     }
 }
 
@@ -282,18 +890,394 @@ impl ArchOpsInstance {
 /// This function provides access to the cached ArchOps instance that was selected based on
 /// feature detection results at library initialization time, eliminating runtime feature
 /// detection overhead from hot paths.
+///
+/// With the `static-dispatch` feature enabled, and the target features for a hardware-accelerated
+/// tier enabled at compile time (e.g. via `-C target-cpu=native`), the instance is selected at
+/// compile time instead, which removes the `OnceLock` and the dispatch match from the hot path
+/// entirely.
+#[inline(always)]
 pub fn get_arch_ops() -> &'static ArchOpsInstance {
+    #[cfg(feature = "static-dispatch")]
+    if let Some(instance) = &STATIC_ARCH_OPS_INSTANCE {
+        return instance;
+    }
+
     ARCH_OPS_INSTANCE.get_or_init(create_arch_ops)
 }
 
+/// Compile-time selected ArchOps instance, or None if the enabled target features don't support
+/// any hardware-accelerated tier (in which case runtime feature detection is used)
+#[cfg(feature = "static-dispatch")]
+const STATIC_ARCH_OPS_INSTANCE: Option<ArchOpsInstance> = select_static_arch_ops();
+
+/// Select the ArchOps instance from the target features enabled at compile time for Rust 1.89+
+/// (when AVX512 stabilized)
+#[rustversion::since(1.89)]
+#[cfg(feature = "static-dispatch")]
+const fn select_static_arch_ops() -> Option<ArchOpsInstance> {
+    #[cfg(target_arch = "x86_64")]
+    {
+        use crate::arch::x86_64::avx512::X86_64Avx512PclmulqdqOps;
+        use crate::arch::x86_64::avx512_vpclmulqdq::X86_64Avx512VpclmulqdqOps;
+
+        if cfg!(all(
+            target_feature = "sse4.1",
+            target_feature = "pclmulqdq",
+            target_feature = "avx512vl",
+            target_feature = "vpclmulqdq"
+        )) {
+            return Some(ArchOpsInstance::X86_64Avx512Vpclmulqdq(
+                X86_64Avx512VpclmulqdqOps::new(),
+            ));
+        }
+
+        if cfg!(all(
+            target_feature = "sse4.1",
+            target_feature = "pclmulqdq",
+            target_feature = "avx512vl"
+        )) {
+            return Some(ArchOpsInstance::X86_64Avx512Pclmulqdq(
+                X86_64Avx512PclmulqdqOps::new(),
+            ));
+        }
+    }
+
+    select_static_baseline_arch_ops()
+}
+
+/// Select the ArchOps instance from the target features enabled at compile time for Rust <1.89
+/// (before AVX512 stabilized)
+#[rustversion::before(1.89)]
+#[cfg(feature = "static-dispatch")]
+const fn select_static_arch_ops() -> Option<ArchOpsInstance> {
+    select_static_baseline_arch_ops()
+}
+
+/// Select the baseline (non-AVX512) ArchOps instance from the target features enabled at compile
+/// time
+#[cfg(feature = "static-dispatch")]
+const fn select_static_baseline_arch_ops() -> Option<ArchOpsInstance> {
+    #[cfg(target_arch = "aarch64")]
+    {
+        use crate::arch::aarch64::aes::Aarch64AesOps;
+        use crate::arch::aarch64::aes_sha3::Aarch64AesSha3Ops;
+
+        if cfg!(all(target_feature = "aes", target_feature = "sha3")) {
+            return Some(ArchOpsInstance::Aarch64AesSha3(Aarch64AesSha3Ops::new()));
+        }
+
+        if cfg!(target_feature = "aes") {
+            return Some(ArchOpsInstance::Aarch64Aes(Aarch64AesOps));
+        }
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        use crate::arch::x86::sse::X86SsePclmulqdqOps;
+
+        if cfg!(all(target_feature = "sse4.1", target_feature = "pclmulqdq")) {
+            return Some(ArchOpsInstance::X86SsePclmulqdq(X86SsePclmulqdqOps));
+        }
+    }
+
+    None
+}
+
+/// Forces the performance tier used for all subsequent CRC calculations.
+///
+/// Useful for pinning or downgrading the implementation when debugging wrong-result or
+/// illegal-instruction reports, or on heterogeneous fleets. The same can be accomplished without
+/// code changes by setting the `CRC_FAST_FORCE_TIER` environment variable to a target string, such
+/// as `x86_64-sse-pclmulqdq`.
+///
+/// The tier is selected once, so this must be called before the first CRC calculation. Only tiers
+/// supported by the current CPU can be forced, so it can't be used to enable unsupported
+/// instructions.
+///
+/// # Errors
+///
+/// Returns [`ForceTierError::AlreadySelected`] if the tier has already been selected (including at
+/// compile time via the `static-dispatch` feature), or [`ForceTierError::Unsupported`] if the tier
+/// isn't supported on this system.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{set_forced_tier, PerformanceTier};
+///
+/// // fails if unsupported or a calculation has already happened, in which case the tier is unchanged
+/// let _ = set_forced_tier(PerformanceTier::X86_64SsePclmulqdq);
+/// ```
+pub fn set_forced_tier(tier: PerformanceTier) -> Result<(), ForceTierError> {
+    if let Some(selected) = get_selected_tier() {
+        return Err(ForceTierError::AlreadySelected(selected));
+    }
+
+    let capabilities = unsafe { detect_arch_capabilities() };
+    if !is_tier_supported(tier, &capabilities) {
+        return Err(ForceTierError::Unsupported(tier));
+    }
+
+    ARCH_OPS_INSTANCE
+        .set(create_arch_ops_from_tier(tier))
+        .map_err(|_| ForceTierError::AlreadySelected(get_arch_ops().get_tier()))?;
+
+    #[cfg(feature = "tracing")]
+    tracing::info!(tier = %tier, "forced CRC performance tier");
+
+    Ok(())
+}
+
+/// Returns the ArchOps instance for the tier, if it's supported on this system, for calculations
+/// which bypass the selected tier. The selected tier is always supported, including a custom
+/// backend.
+pub(crate) fn arch_ops_for_tier(tier: PerformanceTier) -> Result<ArchOpsInstance, ForceTierError> {
+    if let Some(instance) = ARCH_OPS_INSTANCE.get().filter(|i| i.get_tier() == tier) {
+        return Ok(*instance);
+    }
+
+    let capabilities = unsafe { detect_arch_capabilities() };
+    if !is_tier_supported(tier, &capabilities) {
+        return Err(ForceTierError::Unsupported(tier));
+    }
+
+    Ok(create_arch_ops_from_tier(tier))
+}
+
+/// Returns every performance tier which can be used on this system, from highest to lowest.
+///
+/// Any of these can be passed to `set_forced_tier()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::available_tiers;
+///
+/// for tier in available_tiers() {
+///     println!("Available performance tier: {}", tier);
+/// }
+/// ```
+pub fn available_tiers() -> Vec<PerformanceTier> {
+    let capabilities = unsafe { detect_arch_capabilities() };
+
+    ALL_TIERS
+        .into_iter()
+        .filter(|tier| is_tier_supported(*tier, &capabilities))
+        .collect()
+}
+
+/// Returns the CPU capabilities detected at runtime, excluding any disabled by
+/// `set_disabled_features()` or the `CRC_FAST_DISABLE_FEATURES` environment variable, for
+/// including in logs and performance reports.
+///
+/// These are what the performance tier is selected from, not necessarily what it uses: see
+/// [`get_accelerator_info()`](crate::get_accelerator_info) for the active implementation.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::capabilities;
+///
+/// let capabilities = capabilities();
+///
+/// println!("Detected CPU features: {}", capabilities.features().join(", "));
+///
+/// if capabilities.has_vpclmulqdq() {
+///     println!("VPCLMULQDQ is available");
+/// }
+/// ```
+pub fn capabilities() -> ArchCapabilities {
+    unsafe { detect_arch_capabilities() }
+}
+
+/// Times each available performance tier on a sample buffer and forces the fastest one.
+///
+/// The static tier ordering isn't always right (e.g. AVX-512 downclocking on some Xeons), so this
+/// can be used at startup to pick the tier which is actually fastest on this system. The same can
+/// be accomplished without code changes by setting the `CRC_FAST_FORCE_TIER` environment variable
+/// to `auto`.
+///
+/// This takes a few milliseconds, and must be called before the first CRC calculation.
+///
+/// # Errors
+///
+/// Returns [`ForceTierError::AlreadySelected`] if the tier has already been selected.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::auto_tune_tier;
+///
+/// if let Ok(tier) = auto_tune_tier() {
+///     println!("Fastest performance tier: {}", tier);
+/// }
+/// ```
+pub fn auto_tune_tier() -> Result<PerformanceTier, ForceTierError> {
+    if let Some(selected) = get_selected_tier() {
+        return Err(ForceTierError::AlreadySelected(selected));
+    }
+
+    let capabilities = unsafe { detect_arch_capabilities() };
+    let tier = select_fastest_tier(&capabilities);
+
+    ARCH_OPS_INSTANCE
+        .set(create_arch_ops_from_tier(tier))
+        .map_err(|_| ForceTierError::AlreadySelected(get_arch_ops().get_tier()))?;
+
+    Ok(tier)
+}
+
+/// Registers a custom backend which is used for all subsequent CRC calculations, instead of the
+/// built-in implementations.
+///
+/// The backend is checked against the check values of a reflected and non-reflected algorithm for
+/// each width before it's registered, and then replaces every built-in implementation, including
+/// the fusion kernels. Like `set_forced_tier()`, this must be called before the first CRC
+/// calculation.
+///
+/// # Errors
+///
+/// Returns [`ForceTierError::AlreadySelected`] if the tier has already been selected, or
+/// [`ForceTierError::Unsupported`] if the backend failed its self-check.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{register_backend, CrcBackend, CrcParams};
+///
+/// struct Offload;
+///
+/// impl CrcBackend for Offload {
+///     fn name(&self) -> &'static str {
+///         "dpu-offload"
+///     }
+///
+///     fn update(&self, state: u64, data: &[u8], params: CrcParams) -> u64 {
+///         // hand off to the accelerator here
+///         # let _ = (data, params);
+///         state
+///     }
+/// }
+///
+/// static OFFLOAD: Offload = Offload;
+///
+/// // fails if the self-check fails or a calculation has already happened
+/// let _ = register_backend(&OFFLOAD);
+/// ```
+pub fn register_backend(backend: &'static dyn CrcBackend) -> Result<(), ForceTierError> {
+    if !backend_passes_self_check(backend) {
+        return Err(ForceTierError::Unsupported(PerformanceTier::Custom));
+    }
+
+    if let Some(selected) = get_selected_tier() {
+        return Err(ForceTierError::AlreadySelected(selected));
+    }
+
+    ARCH_OPS_INSTANCE
+        .set(ArchOpsInstance::Custom(backend))
+        .map_err(|_| ForceTierError::AlreadySelected(get_arch_ops().get_tier()))
+}
+
+/// Checks a custom backend against the check values of reflected and non-reflected CRC-32 and
+/// CRC-64 algorithms
+fn backend_passes_self_check(backend: &dyn CrcBackend) -> bool {
+    use crate::crc32::consts::{CRC32_BZIP2, CRC32_ISO_HDLC};
+    use crate::crc64::consts::{CRC64_ECMA_182, CRC64_NVME};
+
+    [CRC32_ISO_HDLC, CRC32_BZIP2, CRC64_NVME, CRC64_ECMA_182]
+        .into_iter()
+        .all(|params| {
+            backend.update(params.init, b"123456789", params) ^ params.xorout == params.check
+        })
+}
+
+/// Returns the performance tier if it has already been selected, at compile time or runtime
+fn get_selected_tier() -> Option<PerformanceTier> {
+    #[cfg(feature = "static-dispatch")]
+    if let Some(instance) = &STATIC_ARCH_OPS_INSTANCE {
+        return Some(instance.get_tier());
+    }
+
+    ARCH_OPS_INSTANCE.get().map(ArchOpsInstance::get_tier)
+}
+
+/// Times each supported performance tier on a sample buffer and returns the fastest one
+///
+/// Ties go to the higher tier, and the timing is skipped entirely if there's only one choice.
+fn select_fastest_tier(capabilities: &ArchCapabilities) -> PerformanceTier {
+    #[cfg(feature = "crc32-only")]
+    use crate::crc32::consts::CRC32_ISCSI as SAMPLE_PARAMS;
+    #[cfg(not(feature = "crc32-only"))]
+    use crate::crc64::consts::CRC64_NVME as SAMPLE_PARAMS;
+    use std::hint::black_box;
+    use std::time::{Duration, Instant};
+
+    let tiers: Vec<PerformanceTier> = ALL_TIERS
+        .into_iter()
+        .filter(|tier| is_tier_supported(*tier, capabilities))
+        .collect();
+
+    if tiers.len() < 2 {
+        return select_performance_tier(capabilities);
+    }
+
+    let sample: Vec<u8> = (0..AUTO_TUNE_SAMPLE_SIZE).map(|i| i as u8).collect();
+
+    tiers
+        .into_iter()
+        .min_by_key(|tier| {
+            let arch_ops = create_arch_ops_from_tier(*tier);
+
+            // warm up, then keep the best run to reduce noise
+            (0..=AUTO_TUNE_ITERATIONS)
+                .map(|_| {
+                    let start = Instant::now();
+                    black_box(unsafe {
+                        crate::arch::update_with_arch_ops(
+                            SAMPLE_PARAMS.init,
+                            black_box(&sample),
+                            SAMPLE_PARAMS,
+                            &arch_ops,
+                        )
+                    });
+                    start.elapsed()
+                })
+                .skip(1)
+                .min()
+                .unwrap_or(Duration::MAX)
+        })
+        .unwrap_or_else(|| select_performance_tier(capabilities))
+}
+
 /// Factory function that creates the appropriate ArchOps struct based on cached feature detection
 ///
 /// This function uses the cached feature detection results to select the optimal
 /// architecture-specific implementation at library initialization time, eliminating
-/// runtime feature detection overhead from hot paths.
+/// runtime feature detection overhead from hot paths. A tier forced via the `CRC_FAST_FORCE_TIER`
+/// environment variable is used instead, if it's supported, and `auto` selects the fastest tier
+/// using `select_fastest_tier()`. Unknown values are ignored, so a typo can't break CRC
+/// calculations.
 fn create_arch_ops() -> ArchOpsInstance {
     let capabilities = unsafe { detect_arch_capabilities() };
-    let tier = select_performance_tier(&capabilities);
+
+    let forced = std::env::var(FORCE_TIER_ENV_VAR).ok();
+
+    let tier = match forced.as_deref().map(str::trim) {
+        Some(FORCE_TIER_AUTO) => select_fastest_tier(&capabilities),
+        Some(value) => match value.parse() {
+            Ok(tier) if is_tier_supported(tier, &capabilities) => tier,
+            _ => select_performance_tier(&capabilities),
+        },
+        None => select_performance_tier(&capabilities),
+    };
+
+    #[cfg(feature = "tracing")]
+    tracing::info!(
+        tier = %tier,
+        forced = forced.as_deref(),
+        ?capabilities,
+        "selected CRC performance tier"
+    );
 
     create_arch_ops_from_tier(tier)
 }
@@ -313,6 +1297,8 @@ fn create_arch_ops_from_tier(tier: PerformanceTier) -> ArchOpsInstance {
             use crate::arch::aarch64::aes::Aarch64AesOps;
             ArchOpsInstance::Aarch64Aes(Aarch64AesOps)
         }
+        #[cfg(target_arch = "aarch64")]
+        PerformanceTier::AArch64Crc => ArchOpsInstance::Aarch64Crc,
         #[cfg(target_arch = "x86_64")]
         PerformanceTier::X86_64Avx512Vpclmulqdq => {
             use crate::arch::x86_64::avx512_vpclmulqdq::X86_64Avx512VpclmulqdqOps;
@@ -323,10 +1309,27 @@ fn create_arch_ops_from_tier(tier: PerformanceTier) -> ArchOpsInstance {
             use crate::arch::x86_64::avx512::X86_64Avx512PclmulqdqOps;
             ArchOpsInstance::X86_64Avx512Pclmulqdq(X86_64Avx512PclmulqdqOps::new())
         }
+        #[cfg(target_arch = "x86_64")]
+        PerformanceTier::X86_64SseGfni => {
+            use crate::arch::x86_64::gfni::X86_64SseGfniOps;
+
+            // SAFETY: only reads the CPU's feature flags
+            let has_crc = unsafe { detect_arch_capabilities() }.has_crc;
+            ArchOpsInstance::X86_64SseGfni(X86_64SseGfniOps::new(has_crc))
+        }
         #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
         PerformanceTier::X86_64SsePclmulqdq | PerformanceTier::X86SsePclmulqdq => {
             create_x86_sse_pclmulqdq_ops()
         }
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        PerformanceTier::X86_64SseCrc32c | PerformanceTier::X86SseCrc32c => {
+            ArchOpsInstance::X86SseCrc32c
+        }
+        #[cfg(feature = "portable-simd")]
+        PerformanceTier::PortableSimd => {
+            use crate::arch::portable_simd::PortableSimdOps;
+            ArchOpsInstance::PortableSimd(PortableSimdOps::new())
+        }
         PerformanceTier::SoftwareTable => {
             // Use software fallback
             ArchOpsInstance::SoftwareFallback
@@ -355,6 +1358,8 @@ fn create_arch_ops_from_tier(tier: PerformanceTier) -> ArchOpsInstance {
             use crate::arch::aarch64::aes::Aarch64AesOps;
             ArchOpsInstance::Aarch64Aes(Aarch64AesOps)
         }
+        #[cfg(target_arch = "aarch64")]
+        PerformanceTier::AArch64Crc => ArchOpsInstance::Aarch64Crc,
         #[cfg(target_arch = "x86_64")]
         PerformanceTier::X86_64Avx512Vpclmulqdq => {
             // VPCLMULQDQ and AVX512 not available in older Rust versions, fall back to SSE
@@ -369,6 +1374,15 @@ fn create_arch_ops_from_tier(tier: PerformanceTier) -> ArchOpsInstance {
         PerformanceTier::X86_64SsePclmulqdq | PerformanceTier::X86SsePclmulqdq => {
             create_x86_sse_pclmulqdq_ops()
         }
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        PerformanceTier::X86_64SseCrc32c | PerformanceTier::X86SseCrc32c => {
+            ArchOpsInstance::X86SseCrc32c
+        }
+        #[cfg(feature = "portable-simd")]
+        PerformanceTier::PortableSimd => {
+            use crate::arch::portable_simd::PortableSimdOps;
+            ArchOpsInstance::PortableSimd(PortableSimdOps::new())
+        }
         PerformanceTier::SoftwareTable => {
             // Use software fallback
             ArchOpsInstance::SoftwareFallback
@@ -420,6 +1434,21 @@ pub fn select_performance_tier_for_test(capabilities: &ArchCapabilities) -> Perf
         return PerformanceTier::X86_64SsePclmulqdq;
     }
 
+    // GFNI is only selected when PCLMULQDQ isn't available
+    if capabilities.has_gfni {
+        return PerformanceTier::X86_64SseGfni;
+    }
+
+    // The native CRC-32 instructions alone are only selected when nothing faster is available,
+    // and which tier they map to depends on the architecture
+    if capabilities.has_crc {
+        return if cfg!(target_arch = "aarch64") {
+            PerformanceTier::AArch64Crc
+        } else {
+            PerformanceTier::X86_64SseCrc32c
+        };
+    }
+
     // Fallback to software implementation
     PerformanceTier::SoftwareTable
 }
@@ -437,6 +1466,244 @@ mod tests {
         let _ = supports_vpclmulqdq;
     }
 
+    #[test]
+    fn test_tier_target_string_round_trip() {
+        for tier in ALL_TIERS {
+            assert_eq!(tier.to_string().parse::<PerformanceTier>(), Ok(tier));
+        }
+
+        assert!("x86_64-sse".parse::<PerformanceTier>().is_err());
+    }
+
+    #[test]
+    fn test_parse_disabled_features() {
+        assert_eq!(parse_disabled_features([]), 0);
+        assert_eq!(parse_disabled_features("".split(',')), 0);
+        assert_eq!(
+            parse_disabled_features(" VPCLMULQDQ, sha3,typo".split(',')),
+            (1 << 5) | (1 << 1)
+        );
+        assert_eq!(
+            parse_disabled_features(["pmull"]),
+            parse_disabled_features(["aes"])
+        );
+        assert_eq!(
+            parse_disabled_features(["crc"]),
+            parse_disabled_features(["sse4.2"])
+        );
+    }
+
+    #[test]
+    fn test_capabilities() {
+        let capabilities = capabilities();
+
+        for tier in available_tiers() {
+            assert!(is_tier_supported(tier, &capabilities), "{tier}");
+        }
+
+        // the feature names are the ones set_disabled_features() recognizes
+        for feature in capabilities.features() {
+            assert_ne!(parse_disabled_features([feature]), 0, "{feature}");
+        }
+
+        assert_eq!(
+            capabilities.has_aes(),
+            capabilities.features().contains(&"aes")
+        );
+        assert_eq!(
+            capabilities.has_vpclmulqdq(),
+            capabilities.features().contains(&"vpclmulqdq")
+        );
+    }
+
+    #[test]
+    fn test_mask_capabilities() {
+        let all = ArchCapabilities {
+            has_aes: true,
+            has_sha3: true,
+            has_sse41: true,
+            has_pclmulqdq: true,
+            has_avx512vl: true,
+            has_vpclmulqdq: true,
+            has_gfni: true,
+            has_crc: true,
+            rust_version_supports_avx512: true,
+        };
+
+        let masked = mask_capabilities(all, 0);
+        assert!(masked.has_vpclmulqdq && masked.has_sha3 && masked.has_gfni && masked.has_crc);
+
+        let masked = mask_capabilities(all, parse_disabled_features(["sse4.2"]));
+        assert!(!masked.has_crc && masked.has_pclmulqdq);
+
+        // dependent features are disabled along with the feature they need
+        let masked = mask_capabilities(all, parse_disabled_features(["pclmulqdq", "aes"]));
+        assert!(masked.has_sse41 && masked.has_gfni);
+        assert!(!masked.has_pclmulqdq && !masked.has_avx512vl && !masked.has_vpclmulqdq);
+        assert!(!masked.has_aes && !masked.has_sha3);
+
+        let masked = mask_capabilities(all, parse_disabled_features(["vpclmulqdq"]));
+        assert!(masked.has_avx512vl && !masked.has_vpclmulqdq);
+
+        #[cfg(target_arch = "x86_64")]
+        assert_eq!(
+            select_performance_tier(&masked),
+            PerformanceTier::X86_64Avx512Pclmulqdq
+        );
+    }
+
+    #[test]
+    fn test_detected_tier_is_supported() {
+        let capabilities = unsafe { detect_arch_capabilities() };
+        let tier = select_performance_tier(&capabilities);
+
+        assert!(is_tier_supported(tier, &capabilities));
+    }
+
+    #[test]
+    #[cfg(target_arch = "x86_64")]
+    fn test_foreign_tiers_are_unsupported() {
+        let capabilities = unsafe { detect_arch_capabilities() };
+
+        assert!(!is_tier_supported(
+            PerformanceTier::AArch64Aes,
+            &capabilities
+        ));
+        assert!(!is_tier_supported(
+            PerformanceTier::X86SsePclmulqdq,
+            &capabilities
+        ));
+        assert!(!is_tier_supported(
+            PerformanceTier::SoftwareTable,
+            &capabilities
+        ));
+        assert!(set_forced_tier(PerformanceTier::AArch64AesSha3).is_err());
+    }
+
+    #[test]
+    fn test_accelerator_info() {
+        let info = AcceleratorInfo::new(PerformanceTier::X86_64Avx512Vpclmulqdq, true);
+        assert_eq!(info.simd_width, 512);
+        assert_eq!(info.uses_fusion, !is_native_crc_disabled());
+        assert!(info.features.contains(&"vpclmulqdq"));
+
+        let info = AcceleratorInfo::new(PerformanceTier::AArch64Aes, false);
+        assert_eq!(info.simd_width, 128);
+        assert!(!info.uses_fusion);
+        assert_eq!(info.features, ["neon", "aes"]);
+
+        // the software fallback never uses fusion or SIMD
+        let info = AcceleratorInfo::new(PerformanceTier::SoftwareTable, true);
+        assert_eq!(info.simd_width, 0);
+        assert!(!info.uses_fusion);
+        assert!(info.features.is_empty());
+    }
+
+    #[test]
+    fn test_available_tiers() {
+        let capabilities = unsafe { detect_arch_capabilities() };
+        let tiers = available_tiers();
+
+        // the default tier is always the highest available one
+        assert_eq!(tiers.first(), Some(&select_performance_tier(&capabilities)));
+        assert!(tiers.contains(&select_fastest_tier(&capabilities)));
+    }
+
+    #[test]
+    fn test_set_forced_tier_after_selection() {
+        let tier = get_arch_ops().get_tier();
+
+        assert_eq!(
+            set_forced_tier(tier),
+            Err(ForceTierError::AlreadySelected(tier))
+        );
+        assert_eq!(auto_tune_tier(), Err(ForceTierError::AlreadySelected(tier)));
+    }
+
+    /// Delegates to the built-in implementation for the detected tier
+    struct DelegatingBackend;
+
+    impl CrcBackend for DelegatingBackend {
+        fn name(&self) -> &'static str {
+            "test-delegating"
+        }
+
+        fn update(&self, state: u64, data: &[u8], params: CrcParams) -> u64 {
+            let capabilities = unsafe { detect_arch_capabilities() };
+            let arch_ops = create_arch_ops_from_tier(select_performance_tier(&capabilities));
+
+            unsafe { crate::arch::update_with_arch_ops(state, data, params, &arch_ops) }
+        }
+    }
+
+    /// Always returns the initial state, so fails the self-check
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    struct BrokenBackend;
+
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    impl CrcBackend for BrokenBackend {
+        fn name(&self) -> &'static str {
+            "test-broken"
+        }
+
+        fn update(&self, state: u64, _data: &[u8], _params: CrcParams) -> u64 {
+            state
+        }
+    }
+
+    static DELEGATING_BACKEND: DelegatingBackend = DelegatingBackend;
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    static BROKEN_BACKEND: BrokenBackend = BrokenBackend;
+
+    #[test]
+    fn test_custom_backend_instance() {
+        use crate::test::consts::{TEST_ALL_CONFIGS, TEST_CHECK_STRING};
+
+        let arch_ops = ArchOpsInstance::Custom(&DELEGATING_BACKEND);
+        assert_eq!(arch_ops.get_tier(), PerformanceTier::Custom);
+        assert_eq!(arch_ops.get_target_string(), "test-delegating");
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+            let actual = unsafe {
+                crate::arch::update_with_arch_ops(params.init, TEST_CHECK_STRING, params, &arch_ops)
+            };
+
+            assert_eq!(actual ^ params.xorout, config.get_check());
+        }
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_register_backend() {
+        assert!(backend_passes_self_check(&DELEGATING_BACKEND));
+
+        assert_eq!(
+            register_backend(&BROKEN_BACKEND),
+            Err(ForceTierError::Unsupported(PerformanceTier::Custom))
+        );
+
+        let tier = get_arch_ops().get_tier();
+        assert_eq!(
+            register_backend(&DELEGATING_BACKEND),
+            Err(ForceTierError::AlreadySelected(tier))
+        );
+
+        // custom backends can't be forced as a tier
+        assert!("custom".parse::<PerformanceTier>().is_err());
+        assert!(!available_tiers().contains(&PerformanceTier::Custom));
+    }
+
+    #[test]
+    #[cfg(feature = "static-dispatch")]
+    fn test_static_dispatch_matches_compile_time_selection() {
+        // The compile-time selection should win over runtime detection whenever it's available
+        if let Some(instance) = &STATIC_ARCH_OPS_INSTANCE {
+            assert_eq!(get_arch_ops().get_tier(), instance.get_tier());
+            assert_ne!(instance.get_tier(), PerformanceTier::SoftwareTable);
+        }
+    }
+
     #[test]
     fn test_aarch64_tier_selection() {
         // Test that aarch64 tier selection follows the expected hierarchy
@@ -449,6 +1716,8 @@ mod tests {
             has_pclmulqdq: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
         assert_eq!(
@@ -464,6 +1733,8 @@ mod tests {
             has_pclmulqdq: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
         assert_eq!(
@@ -479,12 +1750,28 @@ mod tests {
             has_pclmulqdq: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
         assert_eq!(
             select_performance_tier_for_test(&capabilities_no_aes),
             PerformanceTier::SoftwareTable
         );
+
+        // Test missing AES with the CRC extension (should use the native CRC-32 instructions,
+        // though the same capabilities select the SSE4.2 tier on x86)
+        #[cfg(target_arch = "aarch64")]
+        {
+            let capabilities_crc = ArchCapabilities {
+                has_crc: true,
+                ..capabilities_no_aes
+            };
+            assert_eq!(
+                select_performance_tier_for_test(&capabilities_crc),
+                PerformanceTier::AArch64Crc
+            );
+        }
     }
 
     #[test]
@@ -501,6 +1788,8 @@ mod tests {
             has_pclmulqdq: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
 
@@ -515,6 +1804,8 @@ mod tests {
             has_pclmulqdq: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
 
@@ -535,6 +1826,8 @@ mod tests {
             has_pclmulqdq: true,
             has_avx512vl: true,
             has_vpclmulqdq: true,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: true,
         };
         assert_eq!(
@@ -550,6 +1843,8 @@ mod tests {
             has_pclmulqdq: true,
             has_avx512vl: true,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: true,
         };
         assert_eq!(
@@ -565,6 +1860,8 @@ mod tests {
             has_pclmulqdq: true,
             has_avx512vl: true,
             has_vpclmulqdq: true,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
         assert_eq!(
@@ -580,6 +1877,8 @@ mod tests {
             has_pclmulqdq: true,
             has_avx512vl: true,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
         assert_eq!(
@@ -595,6 +1894,8 @@ mod tests {
             has_pclmulqdq: true,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
         assert_eq!(
@@ -610,12 +1911,53 @@ mod tests {
             has_pclmulqdq: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
         assert_eq!(
             select_performance_tier_for_test(&capabilities_no_pclmul),
             PerformanceTier::SoftwareTable
         );
+
+        // Test missing PCLMULQDQ with SSE4.2 (should use the native CRC-32C instruction)
+        let capabilities_crc32c = ArchCapabilities {
+            has_crc: true,
+            ..capabilities_no_pclmul
+        };
+        assert_eq!(
+            select_performance_tier_for_test(&capabilities_crc32c),
+            PerformanceTier::X86_64SseCrc32c
+        );
+
+        // Test GFNI and SSE4.2 without PCLMULQDQ (GFNI, which keeps the native instruction for
+        // CRC-32/ISCSI)
+        let capabilities_gfni_crc32c = ArchCapabilities {
+            has_gfni: true,
+            ..capabilities_crc32c
+        };
+        assert_eq!(
+            select_performance_tier_for_test(&capabilities_gfni_crc32c),
+            PerformanceTier::X86_64SseGfni
+        );
+        assert_eq!(
+            select_performance_tier(&capabilities_gfni_crc32c),
+            PerformanceTier::X86_64SseGfni
+        );
+
+        // Test GFNI alone
+        let capabilities_gfni = ArchCapabilities {
+            has_gfni: true,
+            ..capabilities_no_pclmul
+        };
+        assert_eq!(
+            select_performance_tier_for_test(&capabilities_gfni),
+            PerformanceTier::X86_64SseGfni
+        );
+        assert_eq!(
+            select_performance_tier(&capabilities_gfni),
+            PerformanceTier::X86_64SseGfni
+        );
     }
 
     #[test]
@@ -631,6 +1973,8 @@ mod tests {
             has_pclmulqdq: true,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
         assert_eq!(
@@ -647,6 +1991,8 @@ mod tests {
             has_pclmulqdq: true,
             has_avx512vl: false, // No AVX512 on 32-bit x86
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
         // This should select x86_64 tier since we're testing the general case
@@ -663,6 +2009,8 @@ mod tests {
             has_pclmulqdq: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
         assert_eq!(
@@ -686,6 +2034,8 @@ mod tests {
             has_pclmulqdq: true,
             has_avx512vl: true,
             has_vpclmulqdq: true,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: true,
         };
 
@@ -699,6 +2049,7 @@ mod tests {
 
     #[test]
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[allow(clippy::bool_comparison, clippy::overly_complex_bool_expr)]
     fn test_rust_version_gating() {
         // Test that VPCLMULQDQ is properly gated by Rust version
         let rust_support = check_rust_version_supports_avx512();
@@ -723,7 +2074,9 @@ mod tests {
                 has_sse41: true,
                 has_pclmulqdq: true,
                 has_avx512vl: true,
-                has_vpclmulqdq: true,                // Hardware supports it
+                has_vpclmulqdq: true, // Hardware supports it
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false, // But Rust version is too old
             };
 
@@ -746,6 +2099,8 @@ mod tests {
                 has_pclmulqdq: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false,
             };
 
@@ -763,6 +2118,8 @@ mod tests {
                 has_pclmulqdq: true,
                 has_avx512vl: false,  // Missing required dependency
                 has_vpclmulqdq: true, // This should be impossible in real detection
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: true,
             };
 
@@ -790,6 +2147,8 @@ mod tests {
                 has_pclmulqdq: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false,
             };
             assert_eq!(
@@ -805,6 +2164,8 @@ mod tests {
                 has_pclmulqdq: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false,
             };
             assert_eq!(
@@ -820,6 +2181,8 @@ mod tests {
                 has_pclmulqdq: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false,
             };
             assert_eq!(
@@ -840,6 +2203,8 @@ mod tests {
                 has_pclmulqdq: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false,
             };
             assert_eq!(
@@ -855,6 +2220,8 @@ mod tests {
                 has_pclmulqdq: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false,
             };
             assert_eq!(
@@ -870,6 +2237,8 @@ mod tests {
                 has_pclmulqdq: true,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false,
             };
             assert_eq!(
@@ -885,6 +2254,8 @@ mod tests {
                 has_pclmulqdq: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false, // Old Rust version
             };
             assert_eq!(
@@ -900,6 +2271,8 @@ mod tests {
                 has_pclmulqdq: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: true, // New Rust version
             };
             assert_eq!(
@@ -915,6 +2288,8 @@ mod tests {
                 has_pclmulqdq: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: true,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false, // Old Rust version
             };
             assert_eq!(
@@ -930,6 +2305,8 @@ mod tests {
                 has_pclmulqdq: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: true,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: true, // New Rust version
             };
             assert_eq!(
@@ -950,6 +2327,8 @@ mod tests {
                 has_pclmulqdq: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false,
             };
             assert_eq!(
@@ -966,6 +2345,8 @@ mod tests {
                 has_pclmulqdq: true,
                 has_avx512vl: false, // AVX512 not available on 32-bit x86
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false,
             };
             // The test function will return x86_64 tier since it doesn't distinguish architectures
@@ -982,6 +2363,7 @@ mod tests {
             let test_cases = [
                 (PerformanceTier::AArch64AesSha3, "aarch64-neon-pmull-sha3"),
                 (PerformanceTier::AArch64Aes, "aarch64-neon-pmull"),
+                (PerformanceTier::AArch64Crc, "aarch64-crc"),
                 (
                     PerformanceTier::X86_64Avx512Vpclmulqdq,
                     "x86_64-avx512-vpclmulqdq",
@@ -991,7 +2373,11 @@ mod tests {
                     "x86_64-avx512-pclmulqdq",
                 ),
                 (PerformanceTier::X86_64SsePclmulqdq, "x86_64-sse-pclmulqdq"),
+                (PerformanceTier::X86_64SseGfni, "x86_64-sse-gfni"),
+                (PerformanceTier::X86_64SseCrc32c, "x86_64-sse-crc32c"),
                 (PerformanceTier::X86SsePclmulqdq, "x86-sse-pclmulqdq"),
+                (PerformanceTier::X86SseCrc32c, "x86-sse-crc32c"),
+                (PerformanceTier::PortableSimd, "portable-simd-u64x8"),
                 (PerformanceTier::SoftwareTable, "software-fallback-tables"),
             ];
 
@@ -1017,6 +2403,8 @@ mod tests {
                 has_pclmulqdq: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false,
             };
 
@@ -1053,6 +2441,8 @@ mod tests {
                 has_pclmulqdq: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: true,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: true,
             };
 
@@ -1102,6 +2492,8 @@ mod tests {
                 has_pclmulqdq: true,
                 has_avx512vl: true,
                 has_vpclmulqdq: true,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false, // Old Rust version
             };
 
@@ -1124,6 +2516,8 @@ mod tests {
                 has_pclmulqdq: false,
                 has_avx512vl: false,
                 has_vpclmulqdq: false,
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: false,
             };
             // Should fall back to software since AES is required for SHA3
@@ -1140,6 +2534,8 @@ mod tests {
                 has_pclmulqdq: true,
                 has_avx512vl: false,
                 has_vpclmulqdq: true, // This would be impossible in real detection
+                has_gfni: false,
+                has_crc: false,
                 rust_version_supports_avx512: true,
             };
             // Should fall back to SSE tier since AVX512VL is required for VPCLMULQDQ
@@ -1164,6 +2560,8 @@ mod software_fallback_tests {
             has_pclmulqdq: false,
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
 
@@ -1185,6 +2583,8 @@ mod software_fallback_tests {
             has_pclmulqdq: false, // But PCLMULQDQ not available
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
 
@@ -1203,6 +2603,8 @@ mod software_fallback_tests {
             has_pclmulqdq: false, // PCLMULQDQ requires SSE4.1
             has_avx512vl: false,
             has_vpclmulqdq: false,
+            has_gfni: false,
+            has_crc: false,
             rust_version_supports_avx512: false,
         };
 
@@ -4,71 +4,207 @@
 //!
 //! This module provides a C-compatible interface for the Rust library, allowing
 //! C programs to use the library's functionality.
+//!
+//! Checksums, states, and parameters are passed as `uint64_t` for every CRC width, with the bits
+//! above the width always zero, so narrower widths can be added without changing the ABI.
 
 #![cfg(any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86"))]
 
+use crate::enums::PREDEFINED_ALGORITHMS;
+use crate::feature_detection::{get_arch_ops, ALL_TIERS};
 use crate::CrcAlgorithm;
 use crate::CrcParams;
-use crate::{get_calculator_target, Digest};
-use std::collections::HashMap;
+use crate::{get_calculator_target, Digest, ForceTierError, PerformanceTier};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use std::ffi::CStr;
-use std::os::raw::c_char;
+use std::ffi::CString;
+use std::ops::{Deref, DerefMut};
+use std::os::raw::{c_char, c_int, c_void};
+use std::path::Path;
 use std::slice;
-use std::sync::Mutex;
 use std::sync::OnceLock;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
-// Global storage for stable key pointers to ensure they remain valid across FFI boundary
-static STABLE_KEY_STORAGE: OnceLock<Mutex<HashMap<u64, Box<[u64]>>>> = OnceLock::new();
+// Global storage for stable key pointers to ensure they remain valid across FFI boundary, with the
+// number of CrcFastParams referencing each key set, so they can be freed
+static STABLE_KEY_STORAGE: OnceLock<Mutex<HashMap<Box<[u64]>, usize>>> = OnceLock::new();
 
 /// Creates a stable pointer to the keys for FFI usage.
-/// The keys are stored in global memory to ensure the pointer remains valid.
+/// The keys are stored in global memory to ensure the pointer remains valid, until every reference
+/// to them has been released with `release_stable_key_pointer()`.
 fn create_stable_key_pointer(keys: &crate::CrcKeysStorage) -> (*const u64, u32) {
     let storage = STABLE_KEY_STORAGE.get_or_init(|| Mutex::new(HashMap::new()));
 
-    // Create a unique hash for this key set to avoid duplicates
-    let key_hash = match keys {
-        crate::CrcKeysStorage::KeysFold256(keys) => {
-            let mut hasher = std::collections::hash_map::DefaultHasher::new();
-            use std::hash::{Hash, Hasher};
-            keys.hash(&mut hasher);
-            hasher.finish()
+    let keys: &[u64] = match keys {
+        crate::CrcKeysStorage::KeysFold256(keys) => keys,
+        crate::CrcKeysStorage::KeysFold512(keys) => keys,
+    };
+
+    let mut storage_map = storage.lock().unwrap();
+
+    // Reuse the key set if we already have it stored, since the boxed keys never move
+    let ptr = match storage_map.entry(Box::from(keys)) {
+        Entry::Occupied(mut entry) => {
+            *entry.get_mut() += 1;
+            entry.key().as_ptr()
         }
-        crate::CrcKeysStorage::KeysFutureTest(keys) => {
-            let mut hasher = std::collections::hash_map::DefaultHasher::new();
-            use std::hash::{Hash, Hasher};
-            keys.hash(&mut hasher);
-            hasher.finish()
+        Entry::Vacant(entry) => {
+            let ptr = entry.key().as_ptr();
+            entry.insert(1);
+            ptr
         }
     };
 
+    (ptr, keys.len() as u32)
+}
+
+/// Releases a reference to keys created by `create_stable_key_pointer()`, freeing them once there
+/// are no references left. Keys which weren't created by it are ignored.
+///
+/// # Safety
+///
+/// `keys` must point to `key_count` readable keys.
+unsafe fn release_stable_key_pointer(keys: *const u64, key_count: u32) {
+    let Some(storage) = STABLE_KEY_STORAGE.get() else {
+        return;
+    };
+
+    // copied, since the keys may be freed below
+    let lookup = slice::from_raw_parts(keys, key_count as usize).to_vec();
+
     let mut storage_map = storage.lock().unwrap();
 
-    // Check if we already have this key set stored
-    if let Some(stored_keys) = storage_map.get(&key_hash) {
-        return (stored_keys.as_ptr(), stored_keys.len() as u32);
+    if let Entry::Occupied(mut entry) = storage_map.entry(lookup.into_boxed_slice()) {
+        if entry.key().as_ptr() != keys {
+            return;
+        }
+
+        *entry.get_mut() -= 1;
+        if *entry.get() == 0 {
+            entry.remove();
+        }
     }
+}
 
-    // Store the keys in stable memory
-    let key_vec: Vec<u64> = match keys {
-        crate::CrcKeysStorage::KeysFold256(keys) => keys.to_vec(),
-        crate::CrcKeysStorage::KeysFutureTest(keys) => keys.to_vec(),
-    };
+// Global storage for the names of the predefined algorithms, so they remain valid across FFI
+// boundary
+static ALGORITHM_NAMES: OnceLock<Vec<CString>> = OnceLock::new();
 
-    let boxed_keys = key_vec.into_boxed_slice();
-    let ptr = boxed_keys.as_ptr();
-    let count = boxed_keys.len() as u32;
+// Global storage for the target strings of the performance tiers, so they remain valid across FFI
+// boundary
+static TIER_NAMES: OnceLock<Vec<(PerformanceTier, CString)>> = OnceLock::new();
 
-    storage_map.insert(key_hash, boxed_keys);
+// Global storage for the calculator target strings, interned so that they remain valid across FFI
+// boundary without leaking a new string on every call (there's one per algorithm and tier)
+static CALCULATOR_TARGETS: OnceLock<Mutex<HashSet<CString>>> = OnceLock::new();
 
-    (ptr, count)
+/// A handle to the Digest object, with a lock if it was created by one of the
+/// `crc_fast_digest_new_threadsafe*` functions
+#[repr(C)]
+pub struct CrcFastDigestHandle(*mut Digest, *mut CrcFastDigestLock);
+
+/// The lock which serializes calls on a thread-safe Digest handle
+pub struct CrcFastDigestLock(Mutex<()>);
+
+impl CrcFastDigestHandle {
+    /// Boxes the Digest behind a new handle, with a lock if it's to be thread-safe
+    fn into_raw(digest: Digest, thread_safe: bool) -> *mut Self {
+        let lock = match thread_safe {
+            true => Box::into_raw(Box::new(CrcFastDigestLock(Mutex::new(())))),
+            false => std::ptr::null_mut(),
+        };
+
+        Box::into_raw(Box::new(Self(Box::into_raw(Box::new(digest)), lock)))
+    }
+}
+
+/// The Digest behind a handle, which holds the handle's lock (if it has one) until it's dropped
+struct LockedDigest<'a> {
+    digest: &'a mut Digest,
+    _guard: Option<MutexGuard<'a, ()>>,
 }
 
-/// A handle to the Digest object
+impl Deref for LockedDigest<'_> {
+    type Target = Digest;
+
+    fn deref(&self) -> &Digest {
+        self.digest
+    }
+}
+
+impl DerefMut for LockedDigest<'_> {
+    fn deref_mut(&mut self) -> &mut Digest {
+        self.digest
+    }
+}
+
+/// The result of the `crc_fast_*_ex` functions
 #[repr(C)]
-pub struct CrcFastDigestHandle(*mut Digest);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrcFastError {
+    /// The operation succeeded, and any out-pointers were written
+    Success = 0,
+    /// A required pointer argument was null
+    NullPointer,
+    /// The custom parameters have a key count other than 23 or 25, or null keys
+    InvalidKeyCount,
+    /// The CRC width isn't 32 or 64
+    UnsupportedWidth,
+    /// A string argument wasn't valid UTF-8
+    InvalidUtf8,
+    /// The file couldn't be opened or read
+    IoError,
+    /// The algorithm name isn't one of the predefined algorithms
+    UnknownAlgorithm,
+    /// The operation was cancelled by the caller
+    Cancelled,
+    /// The custom parameters' keys weren't generated from their polynomial
+    KeyMismatch,
+    /// The custom parameters don't produce their own check value
+    CheckMismatch,
+    /// An unexpected internal error occurred
+    Panic,
+    /// The self-test produced an incorrect checksum on this CPU
+    SelfTestFailed,
+    /// The performance tier name isn't one of the tier target strings
+    UnknownTier,
+    /// The performance tier isn't supported on this system
+    UnsupportedTier,
+    /// The performance tier was already selected, by a previous calculation or call
+    TierAlreadySelected,
+    /// The checksums (or Digests) being combined don't have the same width (or parameters)
+    IncompatibleChecksums,
+    /// A count argument was negative
+    InvalidCount,
+}
+
+impl From<ForceTierError> for CrcFastError {
+    fn from(error: ForceTierError) -> Self {
+        match error {
+            ForceTierError::AlreadySelected(_) => CrcFastError::TierAlreadySelected,
+            ForceTierError::Unsupported(_) => CrcFastError::UnsupportedTier,
+        }
+    }
+}
+
+impl From<crate::CrcFastError> for CrcFastError {
+    fn from(error: crate::CrcFastError) -> Self {
+        match error {
+            crate::CrcFastError::Io(_) => CrcFastError::IoError,
+            crate::CrcFastError::InvalidParams(_) => CrcFastError::CheckMismatch,
+            crate::CrcFastError::UnsupportedWidth(_) => CrcFastError::UnsupportedWidth,
+            crate::CrcFastError::Cancelled => CrcFastError::Cancelled,
+            crate::CrcFastError::ChecksumTooWide { .. } | crate::CrcFastError::ParamsMismatch => {
+                CrcFastError::IncompatibleChecksums
+            }
+        }
+    }
+}
 
 /// The supported CRC algorithms
 #[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CrcFastAlgorithm {
     Crc32Aixm,
     Crc32Autosar,
@@ -122,6 +258,61 @@ impl From<CrcFastAlgorithm> for CrcAlgorithm {
     }
 }
 
+// Convert from internal enum to FFI enum
+impl From<CrcAlgorithm> for CrcFastAlgorithm {
+    fn from(value: CrcAlgorithm) -> Self {
+        match value {
+            CrcAlgorithm::Crc32Aixm => CrcFastAlgorithm::Crc32Aixm,
+            CrcAlgorithm::Crc32Autosar => CrcFastAlgorithm::Crc32Autosar,
+            CrcAlgorithm::Crc32Base91D => CrcFastAlgorithm::Crc32Base91D,
+            CrcAlgorithm::Crc32Bzip2 => CrcFastAlgorithm::Crc32Bzip2,
+            CrcAlgorithm::Crc32CdRomEdc => CrcFastAlgorithm::Crc32CdRomEdc,
+            CrcAlgorithm::Crc32Cksum => CrcFastAlgorithm::Crc32Cksum,
+            CrcAlgorithm::Crc32Custom => CrcFastAlgorithm::Crc32Custom,
+            CrcAlgorithm::Crc32Iscsi => CrcFastAlgorithm::Crc32Iscsi,
+            CrcAlgorithm::Crc32IsoHdlc => CrcFastAlgorithm::Crc32IsoHdlc,
+            CrcAlgorithm::Crc32Jamcrc => CrcFastAlgorithm::Crc32Jamcrc,
+            CrcAlgorithm::Crc32Mef => CrcFastAlgorithm::Crc32Mef,
+            CrcAlgorithm::Crc32Mpeg2 => CrcFastAlgorithm::Crc32Mpeg2,
+            CrcAlgorithm::Crc32Xfer => CrcFastAlgorithm::Crc32Xfer,
+            CrcAlgorithm::Crc64Custom => CrcFastAlgorithm::Crc64Custom,
+            CrcAlgorithm::Crc64Ecma182 => CrcFastAlgorithm::Crc64Ecma182,
+            CrcAlgorithm::Crc64GoIso => CrcFastAlgorithm::Crc64GoIso,
+            CrcAlgorithm::Crc64Ms => CrcFastAlgorithm::Crc64Ms,
+            CrcAlgorithm::Crc64Nvme => CrcFastAlgorithm::Crc64Nvme,
+            CrcAlgorithm::Crc64Redis => CrcFastAlgorithm::Crc64Redis,
+            CrcAlgorithm::Crc64We => CrcFastAlgorithm::Crc64We,
+            CrcAlgorithm::Crc64Xz => CrcFastAlgorithm::Crc64Xz,
+        }
+    }
+}
+
+/// Progress callback for file checksums, called after each chunk with the number of bytes
+/// processed so far, the total file size (or 0 if it's unknown), and the caller's user data
+pub type CrcFastProgressCallback =
+    Option<unsafe extern "C" fn(bytes_processed: u64, total_bytes: u64, user_data: *mut c_void)>;
+
+/// Read callback for stream checksums, which reads up to `len` bytes into `buf` and returns the
+/// number of bytes read, 0 at the end of the stream, or a negative value on error
+pub type CrcFastReadCallback =
+    Option<unsafe extern "C" fn(buf: *mut u8, len: usize, user_data: *mut c_void) -> isize>;
+
+/// A memory region, laid out like POSIX's `struct iovec`, which is left out of the header in favor
+/// of the platform's own
+#[cfg(all(unix, not(target_os = "windows")))]
+#[repr(C)]
+#[allow(non_camel_case_types)]
+pub struct iovec {
+    pub iov_base: *mut c_void,
+    pub iov_len: usize,
+}
+
+/// A UTF-16 code unit of a Windows wide-character string, which is left out of the header in favor
+/// of the platform's own `wchar_t`
+#[cfg(target_os = "windows")]
+#[allow(non_camel_case_types)]
+pub type wchar_t = u16;
+
 /// Custom CRC parameters
 #[repr(C)]
 pub struct CrcFastParams {
@@ -137,6 +328,62 @@ pub struct CrcFastParams {
     pub keys: *const u64,
 }
 
+/// The CRC parameters which determine the folding keys, for pre-warming the key cache
+#[repr(C)]
+pub struct CrcFastKeyCacheEntry {
+    pub width: u8,
+    pub poly: u64,
+    pub reflected: bool,
+}
+
+/// Custom CRC parameters as plain Rocksoft values, with no keys to manage and only blittable
+/// fields (`reflected` is 0 or 1), so they can be passed from C# P/Invoke without custom
+/// marshaling. The keys are generated (and cached) by the library.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrcFastRocksoftParams {
+    pub width: u8,
+    pub reflected: u8,
+    pub poly: u64,
+    pub init: u64,
+    pub xorout: u64,
+    pub check: u64,
+}
+
+/// A checksum, or the error which prevented it (with a checksum of 0), returned by value from the
+/// `crc_fast_simple_*` functions
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrcFastResult {
+    pub checksum: u64,
+    pub error: CrcFastError,
+}
+
+impl CrcFastResult {
+    /// Runs an `_ex` function with a local out-pointer, returning its checksum and error together
+    fn from_ex(ex: impl FnOnce(*mut u64) -> CrcFastError) -> Self {
+        let mut checksum = 0;
+        let error = ex(&mut checksum);
+
+        Self {
+            checksum: match error {
+                CrcFastError::Success => checksum,
+                _ => 0,
+            },
+            error,
+        }
+    }
+}
+
+/// The first vector which failed a self-test
+#[repr(C)]
+pub struct CrcFastSelfTestFailure {
+    pub algorithm: CrcFastAlgorithm,
+    pub len: usize,
+    pub expected: u64,
+    pub actual: u64,
+}
+
 // Convert from FFI struct to internal struct
 impl From<CrcFastParams> for CrcParams {
     fn from(value: CrcFastParams) -> Self {
@@ -147,8 +394,8 @@ impl From<CrcFastParams> for CrcParams {
             23 => crate::CrcKeysStorage::from_keys_fold_256(
                 keys.try_into().expect("Invalid key count for fold_256"),
             ),
-            25 => crate::CrcKeysStorage::from_keys_fold_future_test(
-                keys.try_into().expect("Invalid key count for future_test"),
+            25 => crate::CrcKeysStorage::from_keys_fold_512(
+                keys.try_into().expect("Invalid key count for fold_512"),
             ),
             _ => panic!("Unsupported key count: {}", value.key_count),
         };
@@ -175,29 +422,7 @@ impl From<CrcParams> for CrcFastParams {
         let (keys_ptr, key_count) = create_stable_key_pointer(&params.keys);
 
         CrcFastParams {
-            algorithm: match params.algorithm {
-                CrcAlgorithm::Crc32Aixm => CrcFastAlgorithm::Crc32Aixm,
-                CrcAlgorithm::Crc32Autosar => CrcFastAlgorithm::Crc32Autosar,
-                CrcAlgorithm::Crc32Base91D => CrcFastAlgorithm::Crc32Base91D,
-                CrcAlgorithm::Crc32Bzip2 => CrcFastAlgorithm::Crc32Bzip2,
-                CrcAlgorithm::Crc32CdRomEdc => CrcFastAlgorithm::Crc32CdRomEdc,
-                CrcAlgorithm::Crc32Cksum => CrcFastAlgorithm::Crc32Cksum,
-                CrcAlgorithm::Crc32Custom => CrcFastAlgorithm::Crc32Custom,
-                CrcAlgorithm::Crc32Iscsi => CrcFastAlgorithm::Crc32Iscsi,
-                CrcAlgorithm::Crc32IsoHdlc => CrcFastAlgorithm::Crc32IsoHdlc,
-                CrcAlgorithm::Crc32Jamcrc => CrcFastAlgorithm::Crc32Jamcrc,
-                CrcAlgorithm::Crc32Mef => CrcFastAlgorithm::Crc32Mef,
-                CrcAlgorithm::Crc32Mpeg2 => CrcFastAlgorithm::Crc32Mpeg2,
-                CrcAlgorithm::Crc32Xfer => CrcFastAlgorithm::Crc32Xfer,
-                CrcAlgorithm::Crc64Custom => CrcFastAlgorithm::Crc64Custom,
-                CrcAlgorithm::Crc64Ecma182 => CrcFastAlgorithm::Crc64Ecma182,
-                CrcAlgorithm::Crc64GoIso => CrcFastAlgorithm::Crc64GoIso,
-                CrcAlgorithm::Crc64Ms => CrcFastAlgorithm::Crc64Ms,
-                CrcAlgorithm::Crc64Nvme => CrcFastAlgorithm::Crc64Nvme,
-                CrcAlgorithm::Crc64Redis => CrcFastAlgorithm::Crc64Redis,
-                CrcAlgorithm::Crc64We => CrcFastAlgorithm::Crc64We,
-                CrcAlgorithm::Crc64Xz => CrcFastAlgorithm::Crc64Xz,
-            },
+            algorithm: params.algorithm.into(),
             width: params.width,
             poly: params.poly,
             init: params.init,
@@ -214,9 +439,7 @@ impl From<CrcParams> for CrcFastParams {
 /// Creates a new Digest to compute CRC checksums using algorithm
 #[no_mangle]
 pub extern "C" fn crc_fast_digest_new(algorithm: CrcFastAlgorithm) -> *mut CrcFastDigestHandle {
-    let digest = Box::new(Digest::new(algorithm.into()));
-    let handle = Box::new(CrcFastDigestHandle(Box::into_raw(digest)));
-    Box::into_raw(handle)
+    CrcFastDigestHandle::into_raw(Digest::new(algorithm.into()), false)
 }
 
 /// Creates a new Digest with a custom initial state
@@ -225,9 +448,10 @@ pub extern "C" fn crc_fast_digest_new_with_init_state(
     algorithm: CrcFastAlgorithm,
     init_state: u64,
 ) -> *mut CrcFastDigestHandle {
-    let digest = Box::new(Digest::new_with_init_state(algorithm.into(), init_state));
-    let handle = Box::new(CrcFastDigestHandle(Box::into_raw(digest)));
-    Box::into_raw(handle)
+    CrcFastDigestHandle::into_raw(
+        Digest::new_with_init_state(algorithm.into(), init_state),
+        false,
+    )
 }
 
 /// Creates a new Digest to compute CRC checksums using custom parameters
@@ -235,9 +459,39 @@ pub extern "C" fn crc_fast_digest_new_with_init_state(
 pub extern "C" fn crc_fast_digest_new_with_params(
     params: CrcFastParams,
 ) -> *mut CrcFastDigestHandle {
-    let digest = Box::new(Digest::new_with_params(params.into()));
-    let handle = Box::new(CrcFastDigestHandle(Box::into_raw(digest)));
-    Box::into_raw(handle)
+    CrcFastDigestHandle::into_raw(Digest::new_with_params(params.into()), false)
+}
+
+/// Creates a new Digest to compute CRC checksums using custom parameters and a custom initial
+/// state
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_new_with_params_and_state(
+    params: CrcFastParams,
+    state: u64,
+) -> *mut CrcFastDigestHandle {
+    CrcFastDigestHandle::into_raw(
+        Digest::new_with_params_and_state(params.into(), state),
+        false,
+    )
+}
+
+/// Creates a new Digest to compute CRC checksums using algorithm, whose handle can be used from
+/// multiple threads at once. Calls on it are serialized by an internal lock, except
+/// `crc_fast_digest_free()`, which must not race with any other call.
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_new_threadsafe(
+    algorithm: CrcFastAlgorithm,
+) -> *mut CrcFastDigestHandle {
+    CrcFastDigestHandle::into_raw(Digest::new(algorithm.into()), true)
+}
+
+/// Creates a new Digest to compute CRC checksums using custom parameters, whose handle can be used
+/// from multiple threads at once, see `crc_fast_digest_new_threadsafe()`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_new_threadsafe_with_params(
+    params: CrcFastParams,
+) -> *mut CrcFastDigestHandle {
+    CrcFastDigestHandle::into_raw(Digest::new_with_params(params.into()), true)
 }
 
 /// Updates the Digest with data
@@ -247,30 +501,23 @@ pub extern "C" fn crc_fast_digest_update(
     data: *const c_char,
     len: usize,
 ) {
-    if handle.is_null() || data.is_null() {
+    if data.is_null() {
         return;
     }
 
     unsafe {
-        let digest = &mut *(*handle).0;
-
-        #[allow(clippy::unnecessary_cast)]
-        let bytes = slice::from_raw_parts(data as *const u8, len);
-        digest.update(bytes);
+        if let Ok(mut digest) = digest_mut(handle) {
+            #[allow(clippy::unnecessary_cast)]
+            let bytes = slice::from_raw_parts(data as *const u8, len);
+            digest.update(bytes);
+        }
     }
 }
 
 /// Calculates the CRC checksum for data that's been written to the Digest
 #[no_mangle]
 pub extern "C" fn crc_fast_digest_finalize(handle: *mut CrcFastDigestHandle) -> u64 {
-    if handle.is_null() {
-        return 0;
-    }
-
-    unsafe {
-        let digest = &*(*handle).0;
-        digest.finalize()
-    }
+    unsafe { digest_mut(handle).map_or(0, |digest| digest.finalize()) }
 }
 
 /// Free the Digest resources without finalizing
@@ -283,110 +530,92 @@ pub extern "C" fn crc_fast_digest_free(handle: *mut CrcFastDigestHandle) {
     unsafe {
         let handle = Box::from_raw(handle);
         let _ = Box::from_raw(handle.0); // This drops the digest
+        if !handle.1.is_null() {
+            let _ = Box::from_raw(handle.1);
+        }
     }
 }
 
 /// Reset the Digest state
 #[no_mangle]
 pub extern "C" fn crc_fast_digest_reset(handle: *mut CrcFastDigestHandle) {
-    if handle.is_null() {
-        return;
-    }
-
     unsafe {
-        let digest = &mut *(*handle).0;
-
-        digest.reset();
+        if let Ok(mut digest) = digest_mut(handle) {
+            digest.reset();
+        }
     }
 }
 
 /// Finalize and reset the Digest in one operation
 #[no_mangle]
 pub extern "C" fn crc_fast_digest_finalize_reset(handle: *mut CrcFastDigestHandle) -> u64 {
-    if handle.is_null() {
-        return 0;
-    }
-
-    unsafe {
-        let digest = &mut *(*handle).0;
-
-        digest.finalize_reset()
-    }
+    unsafe { digest_mut(handle).map_or(0, |mut digest| digest.finalize_reset()) }
 }
 
-/// Combine two Digest checksums
+/// Combine two Digest checksums, leaving the first unchanged if they use different parameters
+/// (`crc_fast_digest_combine_ex` reports that as an error)
 #[no_mangle]
 pub extern "C" fn crc_fast_digest_combine(
     handle1: *mut CrcFastDigestHandle,
     handle2: *mut CrcFastDigestHandle,
 ) {
-    if handle1.is_null() || handle2.is_null() {
-        return;
+    unsafe {
+        let _ = combine_digests(handle1, handle2);
     }
+}
 
+/// Creates a new Digest with a copy of the state of an existing one, which can be finalized (or
+/// updated) independently, and is thread-safe if the existing one is. Returns null if the handle
+/// is null.
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_clone(
+    handle: *mut CrcFastDigestHandle,
+) -> *mut CrcFastDigestHandle {
     unsafe {
-        let digest1 = &mut *(*handle1).0;
-        let digest2 = &*(*handle2).0;
-        digest1.combine(digest2);
+        match digest_mut(handle) {
+            Ok(digest) => CrcFastDigestHandle::into_raw(*digest, !(*handle).1.is_null()),
+            Err(_) => std::ptr::null_mut(),
+        }
     }
 }
 
 /// Gets the amount of data processed by the Digest so far
 #[no_mangle]
 pub extern "C" fn crc_fast_digest_get_amount(handle: *mut CrcFastDigestHandle) -> u64 {
-    if handle.is_null() {
-        return 0;
-    }
-
-    unsafe {
-        let digest = &*(*handle).0;
-        digest.get_amount()
-    }
+    unsafe { digest_mut(handle).map_or(0, |digest| digest.get_amount()) }
 }
 
 /// Gets the current state of the Digest
 #[no_mangle]
 pub extern "C" fn crc_fast_digest_get_state(handle: *mut CrcFastDigestHandle) -> u64 {
-    if handle.is_null() {
-        return 0;
-    }
-    unsafe {
-        let digest = &*(*handle).0;
-        digest.get_state()
-    }
+    unsafe { digest_mut(handle).map_or(0, |digest| digest.get_state()) }
 }
 
-/// Helper method to calculate a CRC checksum directly for a string using algorithm
+/// Helper method to calculate a CRC checksum directly for a string using algorithm. `data` may
+/// only be null if `len` is 0, which is the checksum of empty input.
 #[no_mangle]
 pub extern "C" fn crc_fast_checksum(
     algorithm: CrcFastAlgorithm,
     data: *const c_char,
     len: usize,
 ) -> u64 {
-    if data.is_null() {
-        return 0;
-    }
-    unsafe {
-        #[allow(clippy::unnecessary_cast)]
-        let bytes = slice::from_raw_parts(data as *const u8, len);
-        crate::checksum(algorithm.into(), bytes)
+    match unsafe { bytes_from_raw(data, len) } {
+        Ok(bytes) => crate::checksum(algorithm.into(), bytes),
+        Err(_) => 0,
     }
 }
 
-/// Helper method to calculate a CRC checksum directly for data using custom parameters
+/// Helper method to calculate a CRC checksum directly for data using custom parameters. `data`
+/// may only be null if `len` is 0, which is the checksum of empty input.
 #[no_mangle]
 pub extern "C" fn crc_fast_checksum_with_params(
     params: CrcFastParams,
     data: *const c_char,
     len: usize,
 ) -> u64 {
-    if data.is_null() {
-        return 0;
-    }
-    unsafe {
-        #[allow(clippy::unnecessary_cast)]
-        let bytes = slice::from_raw_parts(data as *const u8, len);
-        crate::checksum_with_params(params.into(), bytes)
+    match unsafe { bytes_from_raw(data, len) } {
+        Ok(bytes) => crate::checksum_with_params(params.into(), bytes),
+        Err(_) => 0,
     }
 }
 
@@ -432,7 +661,7 @@ pub extern "C" fn crc_fast_checksum_file_with_params(
     }
 }
 
-/// Combine two CRC checksums using algorithm
+/// Combine two CRC checksums using algorithm, returning 0 if either checksum is too wide for it
 #[no_mangle]
 pub extern "C" fn crc_fast_checksum_combine(
     algorithm: CrcFastAlgorithm,
@@ -440,10 +669,11 @@ pub extern "C" fn crc_fast_checksum_combine(
     checksum2: u64,
     checksum2_len: u64,
 ) -> u64 {
-    crate::checksum_combine(algorithm.into(), checksum1, checksum2, checksum2_len)
+    crate::try_checksum_combine(algorithm.into(), checksum1, checksum2, checksum2_len).unwrap_or(0)
 }
 
-/// Combine two CRC checksums using custom parameters
+/// Combine two CRC checksums using custom parameters, returning 0 if either checksum is too wide
+/// for them
 #[no_mangle]
 pub extern "C" fn crc_fast_checksum_combine_with_params(
     params: CrcFastParams,
@@ -451,10 +681,22 @@ pub extern "C" fn crc_fast_checksum_combine_with_params(
     checksum2: u64,
     checksum2_len: u64,
 ) -> u64 {
-    crate::checksum_combine_with_params(params.into(), checksum1, checksum2, checksum2_len)
+    crate::try_checksum_combine_with_params(params.into(), checksum1, checksum2, checksum2_len)
+        .unwrap_or(0)
+}
+
+/// Returns whether value is the algorithm's checksum of empty (zero-length) input, which is
+/// `init ^ xorout` and isn't always 0
+#[no_mangle]
+pub extern "C" fn crc_fast_is_empty_crc(algorithm: CrcFastAlgorithm, value: u64) -> bool {
+    crate::is_empty_crc(algorithm.into(), value)
 }
 
 /// Returns the custom CRC parameters for a given set of Rocksoft CRC parameters
+///
+/// The keys are shared by every CrcFastParams with the same parameters, and are kept until each of
+/// them has been released with `crc_fast_free_params()`. The name is unused, since CrcFastParams
+/// has no name field.
 #[no_mangle]
 pub extern "C" fn crc_fast_get_custom_params(
     name_ptr: *const c_char,
@@ -465,22 +707,12 @@ pub extern "C" fn crc_fast_get_custom_params(
     xorout: u64,
     check: u64,
 ) -> CrcFastParams {
-    let name = if name_ptr.is_null() {
-        "custom"
-    } else {
-        unsafe { CStr::from_ptr(name_ptr).to_str().unwrap_or("custom") }
-    };
+    let _ = name_ptr;
 
     // Get the custom params from the library
     let params = CrcParams::new(
-        // We need to use a static string for the name field
-        Box::leak(name.to_string().into_boxed_str()),
-        width,
-        poly,
-        init,
-        reflected,
-        xorout,
-        check,
+        "custom", // C interface doesn't need the name field
+        width, poly, init, reflected, xorout, check,
     );
 
     // Create stable key pointer for FFI usage
@@ -505,12 +737,182 @@ pub extern "C" fn crc_fast_get_custom_params(
     }
 }
 
-/// Gets the target build properties (CPU architecture and fine-tuning parameters) for this algorithm
+/// Releases the keys of custom CRC parameters returned by this library, freeing them once no other
+/// CrcFastParams with the same parameters uses them. The struct's keys are cleared, so it can't be
+/// used afterwards, but Digests created from it are unaffected.
+#[no_mangle]
+pub extern "C" fn crc_fast_free_params(params: *mut CrcFastParams) {
+    if params.is_null() {
+        return;
+    }
+
+    unsafe {
+        let params = &mut *params;
+        if params.keys.is_null() {
+            return;
+        }
+
+        release_stable_key_pointer(params.keys, params.key_count);
+
+        params.keys = std::ptr::null();
+        params.key_count = 0;
+    }
+}
+
+/// Generates and caches the keys for `count` parameter sets at startup, so the first custom
+/// parameters created with each of them don't pay the key generation cost. Nothing is cached if
+/// any width is unsupported.
+#[no_mangle]
+pub extern "C" fn crc_fast_prewarm_key_cache(
+    entries: *const CrcFastKeyCacheEntry,
+    count: usize,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let entries = match entries.is_null() {
+            true if count == 0 => &[],
+            true => return Err(CrcFastError::NullPointer),
+            false => slice::from_raw_parts(entries, count),
+        };
+
+        if entries.iter().any(|entry| !matches!(entry.width, 32 | 64)) {
+            return Err(CrcFastError::UnsupportedWidth);
+        }
+
+        let params: Vec<_> = entries
+            .iter()
+            .map(|entry| (entry.width, entry.poly, entry.reflected))
+            .collect();
+        crate::prewarm_key_cache(&params);
+
+        Ok(())
+    })
+}
+
+/// Clears all cached keys, such as after a configuration reload which removed custom algorithms.
+/// CrcFastParams which were already created are unaffected.
+#[no_mangle]
+pub extern "C" fn crc_fast_clear_key_cache() {
+    crate::clear_key_cache();
+}
+
+/// Removes the cached keys for a single parameter set, returning whether they were cached
+#[no_mangle]
+pub extern "C" fn crc_fast_invalidate_key_cache(width: u8, poly: u64, reflected: bool) -> bool {
+    crate::invalidate_key_cache(width, poly, reflected)
+}
+
+/// Runs the check string and longer vectors for every algorithm through the currently selected
+/// target, returning `SelfTestFailed` and writing the first failing vector to `out_failure` (if
+/// it's not null) if any checksum is incorrect
+#[no_mangle]
+pub extern "C" fn crc_fast_self_test(out_failure: *mut CrcFastSelfTestFailure) -> CrcFastError {
+    catch_panic(|| match crate::self_test() {
+        Ok(_) => Ok(()),
+        Err(failure) => {
+            if !out_failure.is_null() {
+                unsafe {
+                    *out_failure = CrcFastSelfTestFailure {
+                        algorithm: failure.algorithm.into(),
+                        len: failure.len,
+                        expected: failure.expected,
+                        actual: failure.actual,
+                    };
+                }
+            }
+
+            Err(CrcFastError::SelfTestFailed)
+        }
+    })
+}
+
+/// Gets the target build properties (CPU architecture and fine-tuning parameters) for this
+/// algorithm. The string is owned by the library and must not be freed.
 #[no_mangle]
 pub extern "C" fn crc_fast_get_calculator_target(algorithm: CrcFastAlgorithm) -> *const c_char {
-    let target = get_calculator_target(algorithm.into());
+    let target = CString::new(get_calculator_target(algorithm.into())).unwrap();
+
+    let mut targets = CALCULATOR_TARGETS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    // the string's heap buffer doesn't move when the set grows, so the pointer stays valid
+    match targets.get(&target) {
+        Some(interned) => interned.as_ptr(),
+        None => {
+            let ptr = target.as_ptr();
+            targets.insert(target);
+            ptr
+        }
+    }
+}
+
+/// Gets the target string of the active performance tier (such as "x86_64-avx512-vpclmulqdq"),
+/// selecting it if no calculation has happened yet. The string is owned by the library and must not
+/// be freed.
+#[no_mangle]
+pub extern "C" fn crc_fast_get_tier() -> *const c_char {
+    tier_name(get_arch_ops().get_tier())
+}
+
+/// Writes the target strings of the performance tiers available on this system, from highest to
+/// lowest, to `out_tiers` (up to `capacity` of them), and returns how many are available, so a
+/// null `out_tiers` with a `capacity` of 0 gets the count. The strings are owned by the library
+/// and must not be freed.
+#[no_mangle]
+pub extern "C" fn crc_fast_list_tiers(out_tiers: *mut *const c_char, capacity: usize) -> usize {
+    let tiers = crate::available_tiers();
+
+    if !out_tiers.is_null() {
+        for (index, tier) in tiers.iter().take(capacity).enumerate() {
+            unsafe { *out_tiers.add(index) = tier_name(*tier) };
+        }
+    }
+
+    tiers.len()
+}
+
+/// Forces the performance tier used for all subsequent calculations by its target string (such as
+/// "x86_64-sse-pclmulqdq"), or selects the fastest one with "auto". Must be called before the
+/// first calculation.
+#[no_mangle]
+pub extern "C" fn crc_fast_force_tier(tier_ptr: *const c_char) -> CrcFastError {
+    catch_panic(|| unsafe {
+        if tier_ptr.is_null() {
+            return Err(CrcFastError::NullPointer);
+        }
+
+        let tier = CStr::from_ptr(tier_ptr)
+            .to_str()
+            .map_err(|_| CrcFastError::InvalidUtf8)?;
+
+        if tier == "auto" {
+            crate::auto_tune_tier()?;
+            return Ok(());
+        }
+
+        let tier = tier
+            .parse::<PerformanceTier>()
+            .map_err(|_| CrcFastError::UnknownTier)?;
 
-    std::ffi::CString::new(target).unwrap().into_raw()
+        Ok(crate::set_forced_tier(tier)?)
+    })
+}
+
+/// Gets the interned target string of a performance tier
+fn tier_name(tier: PerformanceTier) -> *const c_char {
+    let names = TIER_NAMES.get_or_init(|| {
+        ALL_TIERS
+            .into_iter()
+            .chain([PerformanceTier::Custom])
+            .map(|tier| (tier, CString::new(tier.to_string()).unwrap()))
+            .collect()
+    });
+
+    names
+        .iter()
+        .find(|(candidate, _)| *candidate == tier)
+        .map_or(std::ptr::null(), |(_, name)| name.as_ptr())
 }
 
 /// Gets the version of this library
@@ -525,14 +927,2142 @@ pub extern "C" fn crc_fast_get_version() -> *const c_char {
     VERSION.as_ptr()
 }
 
-unsafe fn convert_to_string(data: *const u8, len: usize) -> String {
-    if data.is_null() {
-        return String::new();
-    }
+/// Gets the number of predefined algorithms, which can be enumerated with
+/// `crc_fast_algorithm_name()`
+#[no_mangle]
+pub extern "C" fn crc_fast_algorithm_count() -> usize {
+    PREDEFINED_ALGORITHMS.len()
+}
 
-    // Safely construct string slice from raw parts
-    match std::str::from_utf8(slice::from_raw_parts(data, len)) {
-        Ok(s) => s.to_string(),
-        Err(_) => panic!("Invalid UTF-8 string"),
+/// Gets the name of the predefined algorithm at index (such as "CRC-32/ISCSI"), or null if the
+/// index is out of range. The string is owned by the library and must not be freed.
+#[no_mangle]
+pub extern "C" fn crc_fast_algorithm_name(index: usize) -> *const c_char {
+    let names = ALGORITHM_NAMES.get_or_init(|| {
+        PREDEFINED_ALGORITHMS
+            .iter()
+            .map(|algorithm| CString::new(algorithm.to_string()).unwrap())
+            .collect()
+    });
+
+    names
+        .get(index)
+        .map_or(std::ptr::null(), |name| name.as_ptr())
+}
+
+/// Looks up a predefined algorithm by name (such as "CRC-32/ISCSI"), writing it to
+/// `out_algorithm`
+#[no_mangle]
+pub extern "C" fn crc_fast_algorithm_from_name(
+    name_ptr: *const c_char,
+    out_algorithm: *mut CrcFastAlgorithm,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        if name_ptr.is_null() {
+            return Err(CrcFastError::NullPointer);
+        }
+
+        let name = CStr::from_ptr(name_ptr)
+            .to_str()
+            .map_err(|_| CrcFastError::InvalidUtf8)?;
+        let out_algorithm = out_mut(out_algorithm)?;
+
+        *out_algorithm = name
+            .parse::<CrcAlgorithm>()
+            .map_err(|_| CrcFastError::UnknownAlgorithm)?
+            .into();
+        Ok(())
+    })
+}
+
+/// Looks up a predefined algorithm by a common alias (such as "crc32c", "cksum", or "xz64") or by
+/// name (such as "CRC-32/ISCSI"), ignoring ASCII case, writing it to `out_algorithm`
+#[no_mangle]
+pub extern "C" fn crc_fast_algorithm_from_alias(
+    alias_ptr: *const c_char,
+    out_algorithm: *mut CrcFastAlgorithm,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        if alias_ptr.is_null() {
+            return Err(CrcFastError::NullPointer);
+        }
+
+        let alias = CStr::from_ptr(alias_ptr)
+            .to_str()
+            .map_err(|_| CrcFastError::InvalidUtf8)?;
+        let out_algorithm = out_mut(out_algorithm)?;
+
+        *out_algorithm = CrcAlgorithm::from_alias(alias)
+            .ok_or(CrcFastError::UnknownAlgorithm)?
+            .into();
+        Ok(())
+    })
+}
+
+/// Creates a new Digest to compute CRC checksums using algorithm, writing it to `out_handle`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_new_ex(
+    algorithm: CrcFastAlgorithm,
+    out_handle: *mut *mut CrcFastDigestHandle,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        *out_mut(out_handle)? = crc_fast_digest_new(algorithm);
+        Ok(())
+    })
+}
+
+/// Creates a new Digest with a custom initial state, writing it to `out_handle`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_new_with_init_state_ex(
+    algorithm: CrcFastAlgorithm,
+    init_state: u64,
+    out_handle: *mut *mut CrcFastDigestHandle,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        *out_mut(out_handle)? = crc_fast_digest_new_with_init_state(algorithm, init_state);
+        Ok(())
+    })
+}
+
+/// Creates a new Digest to compute CRC checksums using custom parameters, writing it to
+/// `out_handle`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_new_with_params_ex(
+    params: CrcFastParams,
+    out_handle: *mut *mut CrcFastDigestHandle,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let out_handle = out_mut(out_handle)?;
+        validate_params(&params)?;
+        *out_handle = crc_fast_digest_new_with_params(params);
+        Ok(())
+    })
+}
+
+/// Creates a new Digest to compute CRC checksums using custom parameters and a custom initial
+/// state, writing it to `out_handle`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_new_with_params_and_state_ex(
+    params: CrcFastParams,
+    state: u64,
+    out_handle: *mut *mut CrcFastDigestHandle,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let out_handle = out_mut(out_handle)?;
+        validate_params(&params)?;
+        *out_handle = crc_fast_digest_new_with_params_and_state(params, state);
+        Ok(())
+    })
+}
+
+/// Creates a new Digest to compute CRC checksums using algorithm, whose handle can be used from
+/// multiple threads at once, writing it to `out_handle`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_new_threadsafe_ex(
+    algorithm: CrcFastAlgorithm,
+    out_handle: *mut *mut CrcFastDigestHandle,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        *out_mut(out_handle)? = crc_fast_digest_new_threadsafe(algorithm);
+        Ok(())
+    })
+}
+
+/// Creates a new Digest to compute CRC checksums using custom parameters, whose handle can be used
+/// from multiple threads at once, writing it to `out_handle`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_new_threadsafe_with_params_ex(
+    params: CrcFastParams,
+    out_handle: *mut *mut CrcFastDigestHandle,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let out_handle = out_mut(out_handle)?;
+        validate_params(&params)?;
+        *out_handle = crc_fast_digest_new_threadsafe_with_params(params);
+        Ok(())
+    })
+}
+
+/// Updates the Digest with data, which may only be null if `len` is 0
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_update_ex(
+    handle: *mut CrcFastDigestHandle,
+    data: *const c_char,
+    len: usize,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let bytes = bytes_from_raw(data, len)?;
+        digest_mut(handle)?.update(bytes);
+        Ok(())
+    })
+}
+
+/// Calculates the CRC checksum for data that's been written to the Digest, writing it to
+/// `out_checksum`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_finalize_ex(
+    handle: *mut CrcFastDigestHandle,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let digest = digest_mut(handle)?;
+        *out_mut(out_checksum)? = digest.finalize();
+        Ok(())
+    })
+}
+
+/// Reset the Digest state
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_reset_ex(handle: *mut CrcFastDigestHandle) -> CrcFastError {
+    catch_panic(|| unsafe {
+        digest_mut(handle)?.reset();
+        Ok(())
+    })
+}
+
+/// Finalize and reset the Digest in one operation, writing the checksum to `out_checksum`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_finalize_reset_ex(
+    handle: *mut CrcFastDigestHandle,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let out_checksum = out_mut(out_checksum)?;
+        *out_checksum = digest_mut(handle)?.finalize_reset();
+        Ok(())
+    })
+}
+
+/// Combine two Digest checksums
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_combine_ex(
+    handle1: *mut CrcFastDigestHandle,
+    handle2: *mut CrcFastDigestHandle,
+) -> CrcFastError {
+    catch_panic(|| unsafe { combine_digests(handle1, handle2) })
+}
+
+/// Creates a new Digest with a copy of the state of an existing one, writing it to `out_handle`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_clone_ex(
+    handle: *mut CrcFastDigestHandle,
+    out_handle: *mut *mut CrcFastDigestHandle,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let out_handle = out_mut(out_handle)?;
+        digest_mut(handle)?;
+        *out_handle = crc_fast_digest_clone(handle);
+        Ok(())
+    })
+}
+
+/// Gets the amount of data processed by the Digest so far, writing it to `out_amount`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_get_amount_ex(
+    handle: *mut CrcFastDigestHandle,
+    out_amount: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let digest = digest_mut(handle)?;
+        *out_mut(out_amount)? = digest.get_amount();
+        Ok(())
+    })
+}
+
+/// Gets the current state of the Digest, writing it to `out_state`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_get_state_ex(
+    handle: *mut CrcFastDigestHandle,
+    out_state: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let digest = digest_mut(handle)?;
+        *out_mut(out_state)? = digest.get_state();
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum directly for data using algorithm, writing it to `out_checksum`
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_ex(
+    algorithm: CrcFastAlgorithm,
+    data: *const c_char,
+    len: usize,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let bytes = bytes_from_raw(data, len)?;
+        *out_mut(out_checksum)? = crate::checksum(algorithm.into(), bytes);
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum directly for data using custom parameters, writing it to
+/// `out_checksum`
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_with_params_ex(
+    params: CrcFastParams,
+    data: *const c_char,
+    len: usize,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let bytes = bytes_from_raw(data, len)?;
+        let out_checksum = out_mut(out_checksum)?;
+        validate_params(&params)?;
+        *out_checksum = crate::checksum_with_params(params.into(), bytes);
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum directly for a file using algorithm, writing it to `out_checksum`
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_file_ex(
+    algorithm: CrcFastAlgorithm,
+    path_ptr: *const u8,
+    path_len: usize,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let path = try_convert_to_string(path_ptr, path_len)?;
+        let out_checksum = out_mut(out_checksum)?;
+        *out_checksum = crate::checksum_file(algorithm.into(), &path, None)?;
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum directly for a file using custom parameters, writing it to
+/// `out_checksum`
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_file_with_params_ex(
+    params: CrcFastParams,
+    path_ptr: *const u8,
+    path_len: usize,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let path = try_convert_to_string(path_ptr, path_len)?;
+        let out_checksum = out_mut(out_checksum)?;
+        validate_params(&params)?;
+        *out_checksum = crate::checksum_file_with_params(params.into(), &path, None)?;
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum directly for a file using algorithm, writing it to `out_checksum`.
+///
+/// A `chunk_size` of 0 uses the default (512KiB). The optional `progress` callback is called with
+/// `user_data` after each chunk, and the checksum stops with `CrcFastError::Cancelled` as soon as
+/// the optional `cancel` flag is set (which may be done from another thread).
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_file_progress(
+    algorithm: CrcFastAlgorithm,
+    path_ptr: *const u8,
+    path_len: usize,
+    chunk_size: usize,
+    progress: CrcFastProgressCallback,
+    user_data: *mut c_void,
+    cancel: *const bool,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let path = try_convert_to_string(path_ptr, path_len)?;
+        let out_checksum = out_mut(out_checksum)?;
+        *out_checksum = checksum_file_with_progress(
+            Digest::new(algorithm.into()),
+            Path::new(&path),
+            chunk_size,
+            progress,
+            user_data,
+            cancel,
+        )?;
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum directly for a file using custom parameters, writing it to
+/// `out_checksum`, with the same chunk size, progress, and cancellation handling as
+/// `crc_fast_checksum_file_progress()`
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_file_with_params_progress(
+    params: CrcFastParams,
+    path_ptr: *const u8,
+    path_len: usize,
+    chunk_size: usize,
+    progress: CrcFastProgressCallback,
+    user_data: *mut c_void,
+    cancel: *const bool,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let path = try_convert_to_string(path_ptr, path_len)?;
+        let out_checksum = out_mut(out_checksum)?;
+        validate_params(&params)?;
+        *out_checksum = checksum_file_with_progress(
+            Digest::new_with_params(params.into()),
+            Path::new(&path),
+            chunk_size,
+            progress,
+            user_data,
+            cancel,
+        )?;
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum directly for an open file descriptor using algorithm, from the start of
+/// the file regardless of its offset, which is left unchanged. The descriptor isn't closed.
+///
+/// Returns 0 on error, see `crc_fast_checksum_fd_ex()` for error handling.
+// not(target_os = "windows") is redundant for rustc, but guards the header with !defined(_WIN32)
+#[cfg(all(unix, not(target_os = "windows")))]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_fd(algorithm: CrcFastAlgorithm, fd: c_int) -> u64 {
+    let mut checksum = 0;
+    crc_fast_checksum_fd_ex(algorithm, fd, &mut checksum);
+
+    checksum
+}
+
+/// Calculates a CRC checksum directly for an open file descriptor using algorithm, writing it to
+/// `out_checksum`, with the same behavior as `crc_fast_checksum_fd()`
+#[cfg(all(unix, not(target_os = "windows")))]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_fd_ex(
+    algorithm: CrcFastAlgorithm,
+    fd: c_int,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    use std::os::fd::FromRawFd;
+
+    catch_panic(|| unsafe {
+        let out_checksum = out_mut(out_checksum)?;
+        if fd < 0 {
+            return Err(CrcFastError::IoError);
+        }
+
+        // borrowed from the caller, so it mustn't be closed when dropped
+        let file = std::mem::ManuallyDrop::new(std::fs::File::from_raw_fd(fd));
+        *out_checksum = checksum_open_file(Digest::new(algorithm.into()), &file)?;
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum directly for an open file HANDLE using algorithm, from the start of
+/// the file. The file pointer is moved, and the HANDLE isn't closed.
+///
+/// Returns 0 on error, see `crc_fast_checksum_handle_ex()` for error handling.
+#[cfg(target_os = "windows")]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_handle(
+    algorithm: CrcFastAlgorithm,
+    handle: *mut c_void,
+) -> u64 {
+    let mut checksum = 0;
+    crc_fast_checksum_handle_ex(algorithm, handle, &mut checksum);
+
+    checksum
+}
+
+/// Calculates a CRC checksum directly for an open file HANDLE using algorithm, writing it to
+/// `out_checksum`, with the same behavior as `crc_fast_checksum_handle()`
+#[cfg(target_os = "windows")]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_handle_ex(
+    algorithm: CrcFastAlgorithm,
+    handle: *mut c_void,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    use std::os::windows::io::FromRawHandle;
+
+    catch_panic(|| unsafe {
+        let out_checksum = out_mut(out_checksum)?;
+        if handle.is_null() {
+            return Err(CrcFastError::NullPointer);
+        }
+
+        // borrowed from the caller, so it mustn't be closed when dropped
+        let file = std::mem::ManuallyDrop::new(std::fs::File::from_raw_handle(handle));
+        *out_checksum = checksum_open_file(Digest::new(algorithm.into()), &file)?;
+        Ok(())
+    })
+}
+
+/// Helper method to calculate a CRC checksum directly for a file with a UTF-16 path using
+/// algorithm, for Windows paths which can't be represented in UTF-8. The path length is in
+/// `wchar_t` units.
+///
+/// Returns 0 on error, see `crc_fast_checksum_file_w_ex()` for error handling.
+#[cfg(target_os = "windows")]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_file_w(
+    algorithm: CrcFastAlgorithm,
+    path_ptr: *const wchar_t,
+    path_len: usize,
+) -> u64 {
+    let mut checksum = 0;
+    crc_fast_checksum_file_w_ex(algorithm, path_ptr, path_len, &mut checksum);
+
+    checksum
+}
+
+/// Calculates a CRC checksum directly for a file with a UTF-16 path using algorithm, writing it to
+/// `out_checksum`
+#[cfg(target_os = "windows")]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_file_w_ex(
+    algorithm: CrcFastAlgorithm,
+    path_ptr: *const wchar_t,
+    path_len: usize,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let path = try_convert_to_wide_path(path_ptr, path_len)?;
+        let out_checksum = out_mut(out_checksum)?;
+        *out_checksum = checksum_file_with_progress(
+            Digest::new(algorithm.into()),
+            &path,
+            0,
+            None,
+            std::ptr::null_mut(),
+            std::ptr::null(),
+        )?;
+        Ok(())
+    })
+}
+
+/// Helper method to calculate a CRC checksum directly for a file with a UTF-16 path using custom
+/// parameters. The path length is in `wchar_t` units.
+///
+/// Returns 0 on error, see `crc_fast_checksum_file_with_params_w_ex()` for error handling.
+#[cfg(target_os = "windows")]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_file_with_params_w(
+    params: CrcFastParams,
+    path_ptr: *const wchar_t,
+    path_len: usize,
+) -> u64 {
+    let mut checksum = 0;
+    crc_fast_checksum_file_with_params_w_ex(params, path_ptr, path_len, &mut checksum);
+
+    checksum
+}
+
+/// Calculates a CRC checksum directly for a file with a UTF-16 path using custom parameters,
+/// writing it to `out_checksum`
+#[cfg(target_os = "windows")]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_file_with_params_w_ex(
+    params: CrcFastParams,
+    path_ptr: *const wchar_t,
+    path_len: usize,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let path = try_convert_to_wide_path(path_ptr, path_len)?;
+        let out_checksum = out_mut(out_checksum)?;
+        validate_params(&params)?;
+        *out_checksum = checksum_file_with_progress(
+            Digest::new_with_params(params.into()),
+            &path,
+            0,
+            None,
+            std::ptr::null_mut(),
+            std::ptr::null(),
+        )?;
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum for a stream using algorithm, repeatedly calling `read` with
+/// `user_data` to fill an internal buffer until it returns 0 at the end of the stream, so embedders
+/// can use their own I/O (sockets, archives, etc) without managing a Digest.
+///
+/// Returns 0 on error, see `crc_fast_checksum_stream_ex()` for error handling.
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_stream(
+    algorithm: CrcFastAlgorithm,
+    read: CrcFastReadCallback,
+    user_data: *mut c_void,
+) -> u64 {
+    let mut checksum = 0;
+    crc_fast_checksum_stream_ex(algorithm, read, user_data, &mut checksum);
+
+    checksum
+}
+
+/// Calculates a CRC checksum for a stream using algorithm, writing it to `out_checksum`. Returns
+/// `CrcFastError::IoError` if `read` returns a negative value, or more bytes than requested.
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_stream_ex(
+    algorithm: CrcFastAlgorithm,
+    read: CrcFastReadCallback,
+    user_data: *mut c_void,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let out_checksum = out_mut(out_checksum)?;
+        *out_checksum = checksum_stream(Digest::new(algorithm.into()), read, user_data)?;
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum for a stream using custom parameters, with the same callback
+/// behavior as `crc_fast_checksum_stream()`
+///
+/// Returns 0 on error, see `crc_fast_checksum_stream_with_params_ex()` for error handling.
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_stream_with_params(
+    params: CrcFastParams,
+    read: CrcFastReadCallback,
+    user_data: *mut c_void,
+) -> u64 {
+    let mut checksum = 0;
+    crc_fast_checksum_stream_with_params_ex(params, read, user_data, &mut checksum);
+
+    checksum
+}
+
+/// Calculates a CRC checksum for a stream using custom parameters, writing it to `out_checksum`
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_stream_with_params_ex(
+    params: CrcFastParams,
+    read: CrcFastReadCallback,
+    user_data: *mut c_void,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let out_checksum = out_mut(out_checksum)?;
+        validate_params(&params)?;
+        *out_checksum = checksum_stream(Digest::new_with_params(params.into()), read, user_data)?;
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum directly for a list of memory regions using algorithm, as if they
+/// were one contiguous buffer, so network stacks can hash scattered packet fragments in one call.
+/// `iov` may only be null if `iovcnt` is 0, and each region's base may only be null if its length
+/// is 0.
+///
+/// Returns 0 on error, see `crc_fast_checksum_iovec_ex()` for error handling.
+#[cfg(all(unix, not(target_os = "windows")))]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_iovec(
+    algorithm: CrcFastAlgorithm,
+    iov: *const iovec,
+    iovcnt: c_int,
+) -> u64 {
+    let mut checksum = 0;
+    crc_fast_checksum_iovec_ex(algorithm, iov, iovcnt, &mut checksum);
+
+    checksum
+}
+
+/// Calculates a CRC checksum directly for a list of memory regions using algorithm, writing it to
+/// `out_checksum`. Returns `CrcFastError::InvalidCount` if `iovcnt` is negative.
+#[cfg(all(unix, not(target_os = "windows")))]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_iovec_ex(
+    algorithm: CrcFastAlgorithm,
+    iov: *const iovec,
+    iovcnt: c_int,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let out_checksum = out_mut(out_checksum)?;
+        *out_checksum = checksum_iovec(Digest::new(algorithm.into()), iov, iovcnt)?;
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum directly for a list of memory regions using custom parameters, with
+/// the same behavior as `crc_fast_checksum_iovec()`
+///
+/// Returns 0 on error, see `crc_fast_checksum_iovec_with_params_ex()` for error handling.
+#[cfg(all(unix, not(target_os = "windows")))]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_iovec_with_params(
+    params: CrcFastParams,
+    iov: *const iovec,
+    iovcnt: c_int,
+) -> u64 {
+    let mut checksum = 0;
+    crc_fast_checksum_iovec_with_params_ex(params, iov, iovcnt, &mut checksum);
+
+    checksum
+}
+
+/// Calculates a CRC checksum directly for a list of memory regions using custom parameters,
+/// writing it to `out_checksum`
+#[cfg(all(unix, not(target_os = "windows")))]
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_iovec_with_params_ex(
+    params: CrcFastParams,
+    iov: *const iovec,
+    iovcnt: c_int,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let out_checksum = out_mut(out_checksum)?;
+        validate_params(&params)?;
+        *out_checksum = checksum_iovec(Digest::new_with_params(params.into()), iov, iovcnt)?;
+        Ok(())
+    })
+}
+
+/// Combine two CRC checksums using algorithm, writing the result to `out_checksum`
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_combine_ex(
+    algorithm: CrcFastAlgorithm,
+    checksum1: u64,
+    checksum2: u64,
+    checksum2_len: u64,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        *out_mut(out_checksum)? =
+            crate::try_checksum_combine(algorithm.into(), checksum1, checksum2, checksum2_len)?;
+        Ok(())
+    })
+}
+
+/// Combine two CRC checksums using custom parameters, writing the result to `out_checksum`
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_combine_with_params_ex(
+    params: CrcFastParams,
+    checksum1: u64,
+    checksum2: u64,
+    checksum2_len: u64,
+    out_checksum: *mut u64,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let out_checksum = out_mut(out_checksum)?;
+        validate_params(&params)?;
+        *out_checksum = crate::try_checksum_combine_with_params(
+            params.into(),
+            checksum1,
+            checksum2,
+            checksum2_len,
+        )?;
+        Ok(())
+    })
+}
+
+/// Gets the custom CRC parameters for a given set of Rocksoft CRC parameters, writing them to
+/// `out_params`. A null `name_ptr` uses "custom" as the name.
+#[allow(clippy::too_many_arguments)]
+#[no_mangle]
+pub extern "C" fn crc_fast_get_custom_params_ex(
+    name_ptr: *const c_char,
+    width: u8,
+    poly: u64,
+    init: u64,
+    reflected: bool,
+    xorout: u64,
+    check: u64,
+    out_params: *mut CrcFastParams,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let out_params = out_mut(out_params)?;
+
+        if width != 32 && width != 64 {
+            return Err(CrcFastError::UnsupportedWidth);
+        }
+
+        if !name_ptr.is_null() && CStr::from_ptr(name_ptr).to_str().is_err() {
+            return Err(CrcFastError::InvalidUtf8);
+        }
+
+        *out_params =
+            crc_fast_get_custom_params(name_ptr, width, poly, init, reflected, xorout, check);
+        Ok(())
+    })
+}
+
+/// Validates custom parameters before use, checking the width, that the keys were generated from
+/// the polynomial, and that the checksum of "123456789" matches the check value, so hand-written
+/// parameter structs which would silently produce wrong results can be caught
+#[no_mangle]
+pub extern "C" fn crc_fast_validate_params(params: *const CrcFastParams) -> CrcFastError {
+    catch_panic(|| unsafe {
+        let params = params.as_ref().ok_or(CrcFastError::NullPointer)?;
+        validate_params(params)?;
+
+        let expected_keys =
+            crate::cache::get_or_generate_keys(params.width, params.poly, params.refin);
+        let keys = slice::from_raw_parts(params.keys, params.key_count as usize);
+        if keys != &expected_keys[..keys.len()] {
+            return Err(CrcFastError::KeyMismatch);
+        }
+
+        let params = CrcParams::from(std::ptr::read(params));
+        if crate::checksum_with_params(params, b"123456789") != params.check {
+            return Err(CrcFastError::CheckMismatch);
+        }
+
+        Ok(())
+    })
+}
+
+/// Gets the target build properties (CPU architecture and fine-tuning parameters) for this
+/// algorithm, writing the string to `out_target`
+#[no_mangle]
+pub extern "C" fn crc_fast_get_calculator_target_ex(
+    algorithm: CrcFastAlgorithm,
+    out_target: *mut *const c_char,
+) -> CrcFastError {
+    catch_panic(|| unsafe {
+        *out_mut(out_target)? = crc_fast_get_calculator_target(algorithm);
+        Ok(())
+    })
+}
+
+/// Calculates a CRC checksum directly for data using algorithm, returning the checksum and error
+/// together by value.
+///
+/// The `crc_fast_simple_*` functions are for callers which handle out-pointers and callbacks
+/// poorly, such as Go via cgo: they take only values and a data pointer, return everything by
+/// value, and keep no state between calls (errors aren't stored anywhere, thread-local or
+/// otherwise). `data` may only be null if `len` is 0.
+#[no_mangle]
+pub extern "C" fn crc_fast_simple_checksum(
+    algorithm: CrcFastAlgorithm,
+    data: *const c_char,
+    len: usize,
+) -> CrcFastResult {
+    CrcFastResult::from_ex(|out| crc_fast_checksum_ex(algorithm, data, len, out))
+}
+
+/// Calculates a CRC checksum directly for data using custom parameters, returning the checksum
+/// and error together by value, see `crc_fast_simple_checksum()`
+#[no_mangle]
+pub extern "C" fn crc_fast_simple_checksum_with_params(
+    params: CrcFastParams,
+    data: *const c_char,
+    len: usize,
+) -> CrcFastResult {
+    CrcFastResult::from_ex(|out| crc_fast_checksum_with_params_ex(params, data, len, out))
+}
+
+/// Combines two CRC checksums using algorithm, returning the result and error together by value,
+/// see `crc_fast_simple_checksum()`
+#[no_mangle]
+pub extern "C" fn crc_fast_simple_checksum_combine(
+    algorithm: CrcFastAlgorithm,
+    checksum1: u64,
+    checksum2: u64,
+    checksum2_len: u64,
+) -> CrcFastResult {
+    CrcFastResult::from_ex(|out| {
+        crc_fast_checksum_combine_ex(algorithm, checksum1, checksum2, checksum2_len, out)
+    })
+}
+
+/// Calculates the CRC checksum for data that's been written to the Digest, returning the checksum
+/// and error together by value, see `crc_fast_simple_checksum()`
+#[no_mangle]
+pub extern "C" fn crc_fast_simple_digest_finalize(
+    handle: *mut CrcFastDigestHandle,
+) -> CrcFastResult {
+    CrcFastResult::from_ex(|out| crc_fast_digest_finalize_ex(handle, out))
+}
+
+/// Calculates a CRC checksum directly for `len` bytes at `bytes` using algorithm, returning the
+/// checksum and error together by value.
+///
+/// The `*_bytes` functions take an untyped pointer, which Swift imports as `UnsafeRawPointer`, so
+/// the bytes of a `Data` or `[UInt8]` can be passed from `withUnsafeBytes` without rebinding them
+/// to `CChar`. `bytes` may only be null if `len` is 0.
+#[no_mangle]
+pub extern "C" fn crc_fast_checksum_bytes(
+    algorithm: CrcFastAlgorithm,
+    bytes: *const c_void,
+    len: usize,
+) -> CrcFastResult {
+    crc_fast_simple_checksum(algorithm, bytes as *const c_char, len)
+}
+
+/// Updates the Digest with `len` bytes at `bytes`, see `crc_fast_checksum_bytes()`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_update_bytes(
+    handle: *mut CrcFastDigestHandle,
+    bytes: *const c_void,
+    len: usize,
+) -> CrcFastError {
+    crc_fast_digest_update_ex(handle, bytes as *const c_char, len)
+}
+
+/// Creates a new Digest to compute CRC checksums using algorithm, returning null if it couldn't be
+/// created.
+///
+/// The `crc_fast_digest_create*` and `crc_fast_digest_destroy()` functions have explicit-lifetime
+/// semantics for wrappers such as .NET's `SafeHandle`: creation returns null rather than an
+/// invalid handle, and destruction is NULL-tolerant and idempotent. The handle is used with the
+/// other `crc_fast_digest_*` functions.
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_create(algorithm: CrcFastAlgorithm) -> *mut CrcFastDigestHandle {
+    let mut handle = std::ptr::null_mut();
+    crc_fast_digest_new_ex(algorithm, &mut handle);
+
+    handle
+}
+
+/// Creates a new Digest to compute CRC checksums using custom Rocksoft parameters, returning null
+/// if the parameters aren't supported, see `crc_fast_digest_create()`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_create_with_params(
+    params: CrcFastRocksoftParams,
+) -> *mut CrcFastDigestHandle {
+    let mut handle = std::ptr::null_mut();
+    let _ = catch_panic(|| {
+        if params.width != 32 && params.width != 64 {
+            return Err(CrcFastError::UnsupportedWidth);
+        }
+
+        let params = CrcParams::new(
+            "custom", // C interface doesn't need the name field
+            params.width,
+            params.poly,
+            params.init,
+            params.reflected != 0,
+            params.xorout,
+            params.check,
+        );
+
+        handle = CrcFastDigestHandle::into_raw(Digest::new_with_params(params), false);
+        Ok(())
+    });
+
+    handle
+}
+
+/// Frees the Digest behind `*handle` and sets `*handle` to null, so destroying the same handle
+/// again (or a null one) does nothing, see `crc_fast_digest_create()`
+#[no_mangle]
+pub extern "C" fn crc_fast_digest_destroy(handle: *mut *mut CrcFastDigestHandle) {
+    if let Some(handle) = unsafe { handle.as_mut() } {
+        crc_fast_digest_free(std::mem::replace(handle, std::ptr::null_mut()));
+    }
+}
+
+/// Reads the file in chunks into the Digest, reporting progress and checking for cancellation
+/// after each chunk
+unsafe fn checksum_file_with_progress(
+    mut digest: Digest,
+    path: &Path,
+    chunk_size: usize,
+    progress: CrcFastProgressCallback,
+    user_data: *mut c_void,
+    cancel: *const bool,
+) -> Result<u64, CrcFastError> {
+    use std::io::{ErrorKind, Read};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    // the flag may be set from another thread, so it's read atomically
+    let cancel = (!cancel.is_null()).then(|| AtomicBool::from_ptr(cancel as *mut bool));
+    let is_cancelled = || cancel.is_some_and(|cancel| cancel.load(Ordering::Relaxed));
+
+    let mut file = std::fs::File::open(path).map_err(|_| CrcFastError::IoError)?;
+    let total_bytes = file.metadata().map_or(0, |metadata| metadata.len());
+
+    let chunk_size = match chunk_size {
+        0 => crate::consts::CRC_FILE_CHUNK_SIZE,
+        chunk_size => chunk_size,
+    };
+    let mut buf = vec![0; chunk_size];
+    let mut bytes_processed = 0;
+
+    loop {
+        if is_cancelled() {
+            return Err(CrcFastError::Cancelled);
+        }
+
+        let n = match file.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+            Err(_) => return Err(CrcFastError::IoError),
+        };
+
+        digest.update(&buf[..n]);
+        bytes_processed += n as u64;
+
+        if let Some(progress) = progress {
+            progress(bytes_processed, total_bytes, user_data);
+        }
+    }
+
+    Ok(digest.finalize())
+}
+
+/// Reads an open file from the start into the Digest, using positioned reads so a shared file
+/// descriptor's offset is left unchanged (Windows has no such reads, so its file pointer moves)
+#[cfg(any(unix, target_os = "windows"))]
+fn checksum_open_file(mut digest: Digest, file: &std::fs::File) -> Result<u64, CrcFastError> {
+    use std::io::ErrorKind;
+    #[cfg(unix)]
+    use std::os::unix::fs::FileExt;
+    #[cfg(target_os = "windows")]
+    use std::os::windows::fs::FileExt;
+
+    let mut buf = vec![0; crate::consts::CRC_FILE_CHUNK_SIZE];
+    let mut offset = 0;
+
+    loop {
+        #[cfg(unix)]
+        let result = file.read_at(&mut buf, offset);
+        #[cfg(target_os = "windows")]
+        let result = file.seek_read(&mut buf, offset);
+
+        let n = match result {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+            Err(_) => return Err(CrcFastError::IoError),
+        };
+
+        digest.update(&buf[..n]);
+        offset += n as u64;
+    }
+
+    Ok(digest.finalize())
+}
+
+/// Reads a stream into the Digest using the read callback, until it reports the end of the stream
+unsafe fn checksum_stream(
+    mut digest: Digest,
+    read: CrcFastReadCallback,
+    user_data: *mut c_void,
+) -> Result<u64, CrcFastError> {
+    let read = read.ok_or(CrcFastError::NullPointer)?;
+
+    let mut buf = vec![0; crate::consts::CRC_FILE_CHUNK_SIZE];
+
+    loop {
+        let n = match usize::try_from(read(buf.as_mut_ptr(), buf.len(), user_data)) {
+            Ok(0) => break,
+            Ok(n) if n <= buf.len() => n,
+            _ => return Err(CrcFastError::IoError),
+        };
+
+        digest.update(&buf[..n]);
+    }
+
+    Ok(digest.finalize())
+}
+
+/// Checksums each memory region in turn, like `Digest::write_vectored()`
+#[cfg(all(unix, not(target_os = "windows")))]
+unsafe fn checksum_iovec(
+    mut digest: Digest,
+    iov: *const iovec,
+    iovcnt: c_int,
+) -> Result<u64, CrcFastError> {
+    let count = usize::try_from(iovcnt).map_err(|_| CrcFastError::InvalidCount)?;
+
+    let regions = match (iov.is_null(), count) {
+        (true, 0) => &[],
+        (true, _) => return Err(CrcFastError::NullPointer),
+        (false, _) => slice::from_raw_parts(iov, count),
+    };
+
+    for region in regions {
+        digest.update(bytes_from_raw(
+            region.iov_base as *const c_char,
+            region.iov_len,
+        )?);
+    }
+
+    Ok(digest.finalize())
+}
+
+/// Runs the body of an `_ex` function, converting any panic into `CrcFastError::Panic` so it never
+/// unwinds across the FFI boundary
+fn catch_panic(body: impl FnOnce() -> Result<(), CrcFastError>) -> CrcFastError {
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(body)) {
+        Ok(Ok(())) => CrcFastError::Success,
+        Ok(Err(error)) => error,
+        Err(_) => CrcFastError::Panic,
+    }
+}
+
+/// Checks that custom parameters can be converted without panicking
+fn validate_params(params: &CrcFastParams) -> Result<(), CrcFastError> {
+    if params.width != 32 && params.width != 64 {
+        return Err(CrcFastError::UnsupportedWidth);
+    }
+
+    if params.keys.is_null() || !matches!(params.key_count, 23 | 25) {
+        return Err(CrcFastError::InvalidKeyCount);
+    }
+
+    Ok(())
+}
+
+/// Converts an out-pointer to a mutable reference, failing if it's null
+unsafe fn out_mut<'a, T>(out: *mut T) -> Result<&'a mut T, CrcFastError> {
+    out.as_mut().ok_or(CrcFastError::NullPointer)
+}
+
+/// Gets the Digest behind a handle, locking it if it's thread-safe, failing if it's null
+///
+/// A panic while the lock is held can't leave the Digest in an unusable state, so the lock is
+/// recovered if it's poisoned.
+unsafe fn digest_mut<'a>(
+    handle: *mut CrcFastDigestHandle,
+) -> Result<LockedDigest<'a>, CrcFastError> {
+    let handle = handle.as_ref().ok_or(CrcFastError::NullPointer)?;
+
+    let guard = handle
+        .1
+        .as_ref()
+        .map(|lock| lock.0.lock().unwrap_or_else(PoisonError::into_inner));
+
+    Ok(LockedDigest {
+        digest: handle.0.as_mut().ok_or(CrcFastError::NullPointer)?,
+        _guard: guard,
+    })
+}
+
+/// Combines the second handle's Digest into the first's
+///
+/// Thread-safe handles are locked in address order, so concurrent combines in opposite directions
+/// can't deadlock, and a Digest combined with itself is combined with a copy.
+unsafe fn combine_digests(
+    handle1: *mut CrcFastDigestHandle,
+    handle2: *mut CrcFastDigestHandle,
+) -> Result<(), CrcFastError> {
+    if handle1 == handle2 {
+        let mut digest = digest_mut(handle1)?;
+        let other = *digest;
+
+        return Ok(digest.try_combine(&other)?);
+    }
+
+    let (mut digest1, digest2) = if handle1 < handle2 {
+        let digest1 = digest_mut(handle1)?;
+        (digest1, digest_mut(handle2)?)
+    } else {
+        let digest2 = digest_mut(handle2)?;
+        (digest_mut(handle1)?, digest2)
+    };
+
+    Ok(digest1.try_combine(&digest2)?)
+}
+
+/// Converts a data pointer to a slice, allowing a null pointer only for empty data
+unsafe fn bytes_from_raw<'a>(data: *const c_char, len: usize) -> Result<&'a [u8], CrcFastError> {
+    if data.is_null() {
+        return match len {
+            0 => Ok(&[]),
+            _ => Err(CrcFastError::NullPointer),
+        };
+    }
+
+    #[allow(clippy::unnecessary_cast)]
+    Ok(slice::from_raw_parts(data as *const u8, len))
+}
+
+/// Converts a path pointer to a string, failing if it's null or not valid UTF-8
+unsafe fn try_convert_to_string(data: *const u8, len: usize) -> Result<String, CrcFastError> {
+    if data.is_null() {
+        return Err(CrcFastError::NullPointer);
+    }
+
+    std::str::from_utf8(slice::from_raw_parts(data, len))
+        .map(str::to_string)
+        .map_err(|_| CrcFastError::InvalidUtf8)
+}
+
+/// Converts a UTF-16 path pointer to a path, failing if it's null. Unpaired surrogates, which are
+/// valid in Windows paths, are preserved.
+#[cfg(target_os = "windows")]
+unsafe fn try_convert_to_wide_path(
+    data: *const wchar_t,
+    len: usize,
+) -> Result<std::path::PathBuf, CrcFastError> {
+    use std::os::windows::ffi::OsStringExt;
+
+    if data.is_null() {
+        return Err(CrcFastError::NullPointer);
+    }
+
+    Ok(std::ffi::OsString::from_wide(slice::from_raw_parts(data, len)).into())
+}
+
+unsafe fn convert_to_string(data: *const u8, len: usize) -> String {
+    if data.is_null() {
+        return String::new();
+    }
+
+    // Safely construct string slice from raw parts
+    match std::str::from_utf8(slice::from_raw_parts(data, len)) {
+        Ok(s) => s.to_string(),
+        Err(_) => panic!("Invalid UTF-8 string"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::TEST_CHECK_STRING;
+    use std::ptr;
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_checksum_ex() {
+        let mut checksum = 0;
+        let result = crc_fast_checksum_ex(
+            CrcFastAlgorithm::Crc32IsoHdlc,
+            TEST_CHECK_STRING.as_ptr() as *const c_char,
+            TEST_CHECK_STRING.len(),
+            &mut checksum,
+        );
+
+        assert_eq!(result, CrcFastError::Success);
+        assert_eq!(checksum, 0xcbf43926);
+
+        let result = crc_fast_checksum_ex(
+            CrcFastAlgorithm::Crc32IsoHdlc,
+            ptr::null(),
+            TEST_CHECK_STRING.len(),
+            &mut checksum,
+        );
+        assert_eq!(result, CrcFastError::NullPointer);
+
+        let result = crc_fast_checksum_ex(
+            CrcFastAlgorithm::Crc32IsoHdlc,
+            TEST_CHECK_STRING.as_ptr() as *const c_char,
+            TEST_CHECK_STRING.len(),
+            ptr::null_mut(),
+        );
+        assert_eq!(result, CrcFastError::NullPointer);
+    }
+
+    #[test]
+    fn test_checksums_masked_to_width() {
+        let data = [0xffu8; 64];
+
+        for algorithm in crate::enums::compiled_algorithms() {
+            let width = crate::get_calculator_params(algorithm).1.width;
+            let checksum = crc_fast_checksum(algorithm.into(), data.as_ptr() as _, data.len());
+
+            assert_eq!(
+                checksum.checked_shr(width as u32).unwrap_or(0),
+                0,
+                "{algorithm}"
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_simple_checksum() {
+        let result = crc_fast_simple_checksum(
+            CrcFastAlgorithm::Crc32IsoHdlc,
+            TEST_CHECK_STRING.as_ptr() as *const c_char,
+            TEST_CHECK_STRING.len(),
+        );
+        assert_eq!(
+            result,
+            CrcFastResult {
+                checksum: 0xcbf43926,
+                error: CrcFastError::Success
+            }
+        );
+
+        let checksum1 =
+            crc_fast_simple_checksum(CrcFastAlgorithm::Crc32IsoHdlc, b"1234".as_ptr() as _, 4);
+        let checksum2 =
+            crc_fast_simple_checksum(CrcFastAlgorithm::Crc32IsoHdlc, b"56789".as_ptr() as _, 5);
+        assert_eq!(
+            crc_fast_simple_checksum_combine(
+                CrcFastAlgorithm::Crc32IsoHdlc,
+                checksum1.checksum,
+                checksum2.checksum,
+                5
+            )
+            .checksum,
+            0xcbf43926
+        );
+
+        // errors come back with a zero checksum
+        assert_eq!(
+            crc_fast_simple_checksum(CrcFastAlgorithm::Crc32IsoHdlc, ptr::null(), 1),
+            CrcFastResult {
+                checksum: 0,
+                error: CrcFastError::NullPointer
+            }
+        );
+        assert_eq!(
+            crc_fast_simple_digest_finalize(ptr::null_mut()).error,
+            CrcFastError::NullPointer
+        );
+
+        let handle = crc_fast_digest_new(CrcFastAlgorithm::Crc64Nvme);
+        crc_fast_digest_update(
+            handle,
+            TEST_CHECK_STRING.as_ptr() as _,
+            TEST_CHECK_STRING.len(),
+        );
+        assert_eq!(
+            crc_fast_simple_digest_finalize(handle).checksum,
+            0xae8b14860a799888
+        );
+        crc_fast_digest_free(handle);
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_digest_create_destroy() {
+        let mut handle = crc_fast_digest_create(CrcFastAlgorithm::Crc32IsoHdlc);
+        assert!(!handle.is_null());
+
+        crc_fast_digest_update(
+            handle,
+            TEST_CHECK_STRING.as_ptr() as _,
+            TEST_CHECK_STRING.len(),
+        );
+        assert_eq!(crc_fast_digest_finalize(handle), 0xcbf43926);
+
+        // destroying is idempotent, and tolerates null
+        crc_fast_digest_destroy(&mut handle);
+        assert!(handle.is_null());
+        crc_fast_digest_destroy(&mut handle);
+        crc_fast_digest_destroy(ptr::null_mut());
+
+        // the same as CRC-64/NVME
+        let params = CrcFastRocksoftParams {
+            width: 64,
+            reflected: 1,
+            poly: 0xad93d23594c93659,
+            init: 0xffffffffffffffff,
+            xorout: 0xffffffffffffffff,
+            check: 0xae8b14860a799888,
+        };
+
+        let mut handle = crc_fast_digest_create_with_params(params);
+        assert!(!handle.is_null());
+
+        crc_fast_digest_update(
+            handle,
+            TEST_CHECK_STRING.as_ptr() as _,
+            TEST_CHECK_STRING.len(),
+        );
+        assert_eq!(crc_fast_digest_finalize(handle), 0xae8b14860a799888);
+        crc_fast_digest_destroy(&mut handle);
+
+        assert!(crc_fast_digest_create_with_params(CrcFastRocksoftParams {
+            width: 16,
+            ..params
+        })
+        .is_null());
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_bytes() {
+        let bytes = TEST_CHECK_STRING.as_ptr() as *const c_void;
+
+        assert_eq!(
+            crc_fast_checksum_bytes(CrcFastAlgorithm::Crc32IsoHdlc, bytes, 9).checksum,
+            0xcbf43926
+        );
+        assert_eq!(
+            crc_fast_checksum_bytes(CrcFastAlgorithm::Crc32IsoHdlc, ptr::null(), 0).checksum,
+            0
+        );
+
+        let mut handle = crc_fast_digest_create(CrcFastAlgorithm::Crc32IsoHdlc);
+        assert_eq!(
+            crc_fast_digest_update_bytes(handle, bytes, 9),
+            CrcFastError::Success
+        );
+        assert_eq!(
+            crc_fast_digest_update_bytes(handle, ptr::null(), 1),
+            CrcFastError::NullPointer
+        );
+        assert_eq!(crc_fast_digest_finalize(handle), 0xcbf43926);
+        crc_fast_digest_destroy(&mut handle);
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_empty_input() {
+        for algorithm in [CrcFastAlgorithm::Crc32IsoHdlc, CrcFastAlgorithm::Crc32Mpeg2] {
+            let empty = crate::checksum(algorithm.into(), &[]);
+            assert!(crc_fast_is_empty_crc(algorithm, empty));
+
+            // a null pointer is only accepted for empty input
+            assert_eq!(crc_fast_checksum(algorithm, ptr::null(), 0), empty);
+
+            let mut checksum = 0;
+            assert_eq!(
+                crc_fast_checksum_ex(algorithm, ptr::null(), 0, &mut checksum),
+                CrcFastError::Success
+            );
+            assert_eq!(checksum, empty);
+
+            let handle = crc_fast_digest_new(algorithm);
+            crc_fast_digest_update(handle, ptr::null(), 0);
+            assert_eq!(crc_fast_digest_finalize(handle), empty);
+            crc_fast_digest_free(handle);
+
+            let check = crate::checksum(algorithm.into(), TEST_CHECK_STRING);
+            assert_eq!(crc_fast_checksum_combine(algorithm, check, empty, 0), check);
+        }
+
+        assert!(!crc_fast_is_empty_crc(CrcFastAlgorithm::Crc32Mpeg2, 0));
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc32-only"))]
+    fn test_digest_ex() {
+        let mut handle = ptr::null_mut();
+        assert_eq!(
+            crc_fast_digest_new_ex(CrcFastAlgorithm::Crc64Nvme, &mut handle),
+            CrcFastError::Success
+        );
+
+        // empty updates may use a null pointer
+        assert_eq!(
+            crc_fast_digest_update_ex(handle, ptr::null(), 0),
+            CrcFastError::Success
+        );
+        assert_eq!(
+            crc_fast_digest_update_ex(
+                handle,
+                TEST_CHECK_STRING.as_ptr() as *const c_char,
+                TEST_CHECK_STRING.len()
+            ),
+            CrcFastError::Success
+        );
+
+        let mut checksum = 0;
+        assert_eq!(
+            crc_fast_digest_finalize_ex(handle, &mut checksum),
+            CrcFastError::Success
+        );
+        assert_eq!(checksum, 0xae8b14860a799888);
+
+        crc_fast_digest_free(handle);
+
+        assert_eq!(
+            crc_fast_digest_finalize_ex(ptr::null_mut(), &mut checksum),
+            CrcFastError::NullPointer
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_digest_clone() {
+        let handle = crc_fast_digest_new(CrcFastAlgorithm::Crc32IsoHdlc);
+        crc_fast_digest_update(handle, b"1234".as_ptr() as _, 4);
+
+        // finalize an intermediate checksum while continuing to stream into the original
+        let mut clone = ptr::null_mut();
+        assert_eq!(
+            crc_fast_digest_clone_ex(handle, &mut clone),
+            CrcFastError::Success
+        );
+        crc_fast_digest_update(handle, b"56789".as_ptr() as _, 5);
+
+        assert_eq!(
+            crc_fast_digest_finalize(clone),
+            crc_fast_checksum(CrcFastAlgorithm::Crc32IsoHdlc, b"1234".as_ptr() as _, 4)
+        );
+        assert_eq!(crc_fast_digest_finalize(handle), 0xcbf43926);
+
+        crc_fast_digest_free(clone);
+        crc_fast_digest_free(handle);
+
+        assert!(crc_fast_digest_clone(ptr::null_mut()).is_null());
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_digest_threadsafe() {
+        const THREADS: usize = 8;
+        const UPDATES: usize = 1000;
+
+        let mut handle = ptr::null_mut();
+        assert_eq!(
+            crc_fast_digest_new_threadsafe_ex(CrcFastAlgorithm::Crc32Iscsi, &mut handle),
+            CrcFastError::Success
+        );
+
+        // every update is the same, so the order they're serialized in doesn't matter
+        let address = handle as usize;
+        std::thread::scope(|s| {
+            for _ in 0..THREADS {
+                s.spawn(move || {
+                    let handle = address as *mut CrcFastDigestHandle;
+                    for _ in 0..UPDATES {
+                        crc_fast_digest_update(handle, b"a".as_ptr() as _, 1);
+                    }
+                });
+            }
+        });
+
+        let expected = crate::checksum(CrcAlgorithm::Crc32Iscsi, &[b'a'; THREADS * UPDATES]);
+        assert_eq!(
+            crc_fast_digest_get_amount(handle),
+            (THREADS * UPDATES) as u64
+        );
+        assert_eq!(crc_fast_digest_finalize(handle), expected);
+
+        // clones are thread-safe too, and combining a Digest with itself doesn't deadlock
+        let clone = crc_fast_digest_clone(handle);
+        assert!(!unsafe { (*clone).1 }.is_null());
+        assert_eq!(
+            crc_fast_digest_combine_ex(clone, clone),
+            CrcFastError::Success
+        );
+        assert_eq!(
+            crc_fast_digest_finalize(clone),
+            crate::checksum(CrcAlgorithm::Crc32Iscsi, &[b'a'; 2 * THREADS * UPDATES])
+        );
+
+        // and combining with a non-thread-safe Digest works in either direction
+        let plain = crc_fast_digest_new(CrcFastAlgorithm::Crc32Iscsi);
+        crc_fast_digest_combine(plain, handle);
+        crc_fast_digest_combine(handle, plain);
+        assert_eq!(
+            crc_fast_digest_get_amount(handle),
+            2 * (THREADS * UPDATES) as u64
+        );
+
+        crc_fast_digest_free(plain);
+        crc_fast_digest_free(clone);
+        crc_fast_digest_free(handle);
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_checksum_combine_with_params() {
+        // a custom polynomial, the same as CRC-32/BZIP2
+        let custom = || {
+            crc_fast_get_custom_params(
+                ptr::null(),
+                32,
+                0x04c11db7,
+                0xffffffff,
+                false,
+                0xffffffff,
+                0xfc891918,
+            )
+        };
+
+        let checksum1 = crc_fast_checksum_with_params(custom(), b"1234".as_ptr() as _, 4);
+        let checksum2 = crc_fast_checksum_with_params(custom(), b"56789".as_ptr() as _, 5);
+
+        assert_eq!(
+            crc_fast_checksum_combine_with_params(custom(), checksum1, checksum2, 5),
+            0xfc891918
+        );
+
+        let mut checksum = 0;
+        assert_eq!(
+            crc_fast_checksum_combine_with_params_ex(
+                custom(),
+                checksum1,
+                checksum2,
+                5,
+                &mut checksum
+            ),
+            CrcFastError::Success
+        );
+        assert_eq!(checksum, 0xfc891918);
+
+        // a CRC-64 checksum doesn't fit
+        assert_eq!(
+            crc_fast_checksum_combine_with_params_ex(
+                custom(),
+                checksum1,
+                u64::MAX,
+                5,
+                &mut checksum
+            ),
+            CrcFastError::IncompatibleChecksums
+        );
+        assert_eq!(
+            crc_fast_checksum_combine_with_params(custom(), checksum1, u64::MAX, 5),
+            0
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_digest_with_params_and_state() {
+        // a custom polynomial, the same as CRC-32/BZIP2
+        let custom = || {
+            crc_fast_get_custom_params(
+                ptr::null(),
+                32,
+                0x04c11db7,
+                0xffffffff,
+                false,
+                0xffffffff,
+                0xfc891918,
+            )
+        };
+
+        let prefix = crc_fast_digest_new_with_params(custom());
+        crc_fast_digest_update(prefix, b"1234".as_ptr() as _, 4);
+
+        let mut handle = ptr::null_mut();
+        assert_eq!(
+            crc_fast_digest_new_with_params_and_state_ex(
+                custom(),
+                crc_fast_digest_get_state(prefix),
+                &mut handle
+            ),
+            CrcFastError::Success
+        );
+        crc_fast_digest_update(handle, b"56789".as_ptr() as _, 5);
+
+        assert_eq!(crc_fast_digest_finalize(handle), 0xfc891918);
+
+        crc_fast_digest_free(handle);
+        crc_fast_digest_free(prefix);
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc32-only"))]
+    fn test_free_params() {
+        // a polynomial no other test uses, so the reference counts aren't shared
+        let new_params = || crc_fast_get_custom_params(ptr::null(), 64, 0x1234567, 0, true, 0, 0);
+        let is_stored = |keys: *const u64| {
+            STABLE_KEY_STORAGE
+                .get()
+                .unwrap()
+                .lock()
+                .unwrap()
+                .keys()
+                .any(|stored| stored.as_ptr() == keys)
+        };
+
+        let mut params1 = new_params();
+        let mut params2 = new_params();
+        let keys = params1.keys;
+        assert_eq!(keys, params2.keys);
+
+        let handle = crc_fast_digest_new_with_params(CrcFastParams { ..params1 });
+
+        crc_fast_free_params(&mut params1);
+        assert!(params1.keys.is_null());
+        assert_eq!(params1.key_count, 0);
+        assert!(is_stored(keys));
+
+        // freeing twice is harmless
+        crc_fast_free_params(&mut params1);
+        assert!(is_stored(keys));
+
+        crc_fast_free_params(&mut params2);
+        assert!(!is_stored(keys));
+
+        // the Digest has its own copy of the keys
+        crc_fast_digest_update(
+            handle,
+            TEST_CHECK_STRING.as_ptr() as _,
+            TEST_CHECK_STRING.len(),
+        );
+        crc_fast_digest_free(handle);
+
+        crc_fast_free_params(ptr::null_mut());
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc32-only"))]
+    fn test_validate_params() {
+        let params = || {
+            crc_fast_get_custom_params(
+                ptr::null(),
+                64,
+                0x42f0e1eba9ea3693,
+                0,
+                false,
+                0,
+                0x6c40df5f0b497347,
+            )
+        };
+
+        assert_eq!(crc_fast_validate_params(&params()), CrcFastError::Success);
+
+        let mut invalid = params();
+        invalid.check ^= 1;
+        assert_eq!(
+            crc_fast_validate_params(&invalid),
+            CrcFastError::CheckMismatch
+        );
+
+        let mut invalid = params();
+        invalid.poly ^= 2;
+        assert_eq!(
+            crc_fast_validate_params(&invalid),
+            CrcFastError::KeyMismatch
+        );
+
+        let mut invalid = params();
+        invalid.width = 16;
+        assert_eq!(
+            crc_fast_validate_params(&invalid),
+            CrcFastError::UnsupportedWidth
+        );
+
+        let mut invalid = params();
+        invalid.keys = ptr::null();
+        assert_eq!(
+            crc_fast_validate_params(&invalid),
+            CrcFastError::InvalidKeyCount
+        );
+
+        assert_eq!(
+            crc_fast_validate_params(ptr::null()),
+            CrcFastError::NullPointer
+        );
+    }
+
+    #[test]
+    fn test_invalid_params_ex() {
+        let mut params = crc_fast_get_custom_params(
+            ptr::null(),
+            32,
+            0x04c11db7,
+            0xffffffff,
+            true,
+            0xffffffff,
+            0xcbf43926,
+        );
+        params.key_count = 7;
+
+        let mut checksum = 0;
+        let result = crc_fast_checksum_with_params_ex(
+            params,
+            TEST_CHECK_STRING.as_ptr() as *const c_char,
+            TEST_CHECK_STRING.len(),
+            &mut checksum,
+        );
+        assert_eq!(result, CrcFastError::InvalidKeyCount);
+
+        let mut params = unsafe { std::mem::zeroed() };
+        let result = crc_fast_get_custom_params_ex(
+            ptr::null(),
+            16,
+            0x1021,
+            0xffff,
+            false,
+            0,
+            0x29b1,
+            &mut params,
+        );
+        assert_eq!(result, CrcFastError::UnsupportedWidth);
+    }
+
+    #[test]
+    fn test_prewarm_key_cache() {
+        let entries = [
+            CrcFastKeyCacheEntry {
+                width: 32,
+                poly: 0x741b8cd7,
+                reflected: true,
+            },
+            CrcFastKeyCacheEntry {
+                width: 64,
+                poly: 0xad93d23594c935a9,
+                reflected: false,
+            },
+        ];
+
+        assert_eq!(
+            crc_fast_prewarm_key_cache(entries.as_ptr(), entries.len()),
+            CrcFastError::Success
+        );
+        assert_eq!(
+            crc_fast_prewarm_key_cache(ptr::null(), 0),
+            CrcFastError::Success
+        );
+        assert_eq!(
+            crc_fast_prewarm_key_cache(ptr::null(), 1),
+            CrcFastError::NullPointer
+        );
+
+        let unsupported = [CrcFastKeyCacheEntry {
+            width: 16,
+            poly: 0x1021,
+            reflected: false,
+        }];
+        assert_eq!(
+            crc_fast_prewarm_key_cache(unsupported.as_ptr(), unsupported.len()),
+            CrcFastError::UnsupportedWidth
+        );
+    }
+
+    #[test]
+    fn test_invalidate_key_cache() {
+        // concurrent tests may clear the cache, so only the second invalidation is predictable
+        crc_fast_invalidate_key_cache(32, 0x0badc0de, true);
+        assert!(!crc_fast_invalidate_key_cache(32, 0x0badc0de, true));
+        assert!(!crc_fast_invalidate_key_cache(16, 0x1021, false));
+
+        crc_fast_clear_key_cache();
+    }
+
+    #[test]
+    fn test_self_test() {
+        assert_eq!(crc_fast_self_test(ptr::null_mut()), CrcFastError::Success);
+    }
+
+    #[test]
+    fn test_calculator_target_interned() {
+        let first = crc_fast_get_calculator_target(CrcFastAlgorithm::Crc32Iscsi);
+        let second = crc_fast_get_calculator_target(CrcFastAlgorithm::Crc32Iscsi);
+
+        assert_eq!(first, second);
+        assert_eq!(
+            unsafe { CStr::from_ptr(first) }.to_str().unwrap(),
+            get_calculator_target(CrcAlgorithm::Crc32Iscsi)
+        );
+    }
+
+    #[test]
+    fn test_tiers() {
+        let count = crc_fast_list_tiers(ptr::null_mut(), 0);
+        assert_eq!(count, crate::available_tiers().len());
+
+        let mut tiers = vec![ptr::null(); count + 1];
+        assert_eq!(crc_fast_list_tiers(tiers.as_mut_ptr(), count + 1), count);
+        assert!(tiers[count].is_null());
+
+        for (name, expected) in tiers.iter().zip(crate::available_tiers()) {
+            let name = unsafe { CStr::from_ptr(*name) }.to_str().unwrap();
+            assert_eq!(name.parse::<PerformanceTier>(), Ok(expected));
+        }
+
+        let active = crc_fast_get_tier();
+        assert!(!active.is_null());
+
+        // the tier is selected once, by crc_fast_get_tier() if nothing else
+        assert_eq!(
+            crc_fast_force_tier(active),
+            CrcFastError::TierAlreadySelected
+        );
+        assert_eq!(
+            crc_fast_force_tier(c"x86_64-nope".as_ptr()),
+            CrcFastError::UnknownTier
+        );
+        assert_eq!(crc_fast_force_tier(ptr::null()), CrcFastError::NullPointer);
+    }
+
+    #[test]
+    fn test_algorithm_names() {
+        assert_eq!(crc_fast_algorithm_count(), PREDEFINED_ALGORITHMS.len());
+        assert!(crc_fast_algorithm_name(crc_fast_algorithm_count()).is_null());
+
+        for (index, expected) in PREDEFINED_ALGORITHMS.into_iter().enumerate() {
+            let name = crc_fast_algorithm_name(index);
+            assert!(!name.is_null());
+
+            let mut algorithm = CrcFastAlgorithm::Crc32Custom;
+            assert_eq!(
+                crc_fast_algorithm_from_name(name, &mut algorithm),
+                CrcFastError::Success
+            );
+            assert_eq!(algorithm, expected.into());
+        }
+
+        let mut algorithm = CrcFastAlgorithm::Crc32Custom;
+        assert_eq!(
+            crc_fast_algorithm_from_name(c"CRC-32/NOPE".as_ptr(), &mut algorithm),
+            CrcFastError::UnknownAlgorithm
+        );
+        assert_eq!(
+            crc_fast_algorithm_from_name(ptr::null(), &mut algorithm),
+            CrcFastError::NullPointer
+        );
+    }
+
+    #[test]
+    fn test_algorithm_aliases() {
+        let mut algorithm = CrcFastAlgorithm::Crc32Custom;
+
+        for (alias, expected) in [
+            (c"crc32", CrcFastAlgorithm::Crc32IsoHdlc),
+            (c"CRC32B", CrcFastAlgorithm::Crc32IsoHdlc),
+            (c"crc32c", CrcFastAlgorithm::Crc32Iscsi),
+            (c"cksum", CrcFastAlgorithm::Crc32Cksum),
+            (c"xz64", CrcFastAlgorithm::Crc64Xz),
+            (c"go-iso", CrcFastAlgorithm::Crc64GoIso),
+            (c"crc-32/bzip2", CrcFastAlgorithm::Crc32Bzip2),
+        ] {
+            assert_eq!(
+                crc_fast_algorithm_from_alias(alias.as_ptr(), &mut algorithm),
+                CrcFastError::Success
+            );
+            assert_eq!(algorithm, expected);
+        }
+
+        // every curated alias resolves to a predefined algorithm
+        for (alias, expected) in CrcAlgorithm::aliases() {
+            assert_eq!(CrcAlgorithm::from_alias(alias), Some(*expected));
+            assert!(PREDEFINED_ALGORITHMS.contains(expected));
+        }
+
+        assert_eq!(
+            crc_fast_algorithm_from_alias(c"crc64".as_ptr(), &mut algorithm),
+            CrcFastError::UnknownAlgorithm
+        );
+        assert_eq!(
+            crc_fast_algorithm_from_alias(ptr::null(), &mut algorithm),
+            CrcFastError::NullPointer
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_checksum_file_progress() {
+        unsafe extern "C" fn record(
+            bytes_processed: u64,
+            total_bytes: u64,
+            user_data: *mut c_void,
+        ) {
+            let calls = &mut *(user_data as *mut Vec<(u64, u64)>);
+            calls.push((bytes_processed, total_bytes));
+        }
+
+        let path = b"crc-check.txt";
+        let mut calls: Vec<(u64, u64)> = Vec::new();
+        let mut checksum = 0;
+
+        let result = crc_fast_checksum_file_progress(
+            CrcFastAlgorithm::Crc32IsoHdlc,
+            path.as_ptr(),
+            path.len(),
+            4,
+            Some(record),
+            &mut calls as *mut _ as *mut c_void,
+            ptr::null(),
+            &mut checksum,
+        );
+
+        assert_eq!(result, CrcFastError::Success);
+        assert_eq!(checksum, 0xcbf43926);
+        assert_eq!(calls, [(4, 9), (8, 9), (9, 9)]);
+
+        let cancel = true;
+        let result = crc_fast_checksum_file_progress(
+            CrcFastAlgorithm::Crc32IsoHdlc,
+            path.as_ptr(),
+            path.len(),
+            0,
+            None,
+            ptr::null_mut(),
+            &cancel,
+            &mut checksum,
+        );
+        assert_eq!(result, CrcFastError::Cancelled);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_checksum_fd() {
+        use std::io::{Seek, SeekFrom};
+        use std::os::fd::AsRawFd;
+
+        let mut file = std::fs::File::open("crc-check.txt").unwrap();
+        file.seek(SeekFrom::Start(4)).unwrap();
+
+        assert_eq!(
+            crc_fast_checksum_fd(CrcFastAlgorithm::Crc32IsoHdlc, file.as_raw_fd()),
+            0xcbf43926
+        );
+
+        // the offset is unchanged, and the file is still open
+        assert_eq!(file.stream_position().unwrap(), 4);
+
+        let mut checksum = 0;
+        assert_eq!(
+            crc_fast_checksum_fd_ex(CrcFastAlgorithm::Crc32IsoHdlc, -1, &mut checksum),
+            CrcFastError::IoError
+        );
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn test_checksum_file_w() {
+        let path: Vec<wchar_t> = "crc-check.txt".encode_utf16().collect();
+
+        assert_eq!(
+            crc_fast_checksum_file_w(CrcFastAlgorithm::Crc32IsoHdlc, path.as_ptr(), path.len()),
+            0xcbf43926
+        );
+
+        let mut checksum = 0;
+        assert_eq!(
+            crc_fast_checksum_file_w_ex(
+                CrcFastAlgorithm::Crc32IsoHdlc,
+                ptr::null(),
+                0,
+                &mut checksum
+            ),
+            CrcFastError::NullPointer
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_checksum_stream() {
+        // reads the remaining data a few bytes at a time
+        unsafe extern "C" fn read(buf: *mut u8, len: usize, user_data: *mut c_void) -> isize {
+            let remaining = &mut *(user_data as *mut &[u8]);
+            let n = remaining.len().min(len).min(4);
+
+            ptr::copy_nonoverlapping(remaining.as_ptr(), buf, n);
+            *remaining = &remaining[n..];
+
+            n as isize
+        }
+
+        unsafe extern "C" fn fail(_: *mut u8, _: usize, _: *mut c_void) -> isize {
+            -1
+        }
+
+        let mut remaining: &[u8] = TEST_CHECK_STRING;
+        assert_eq!(
+            crc_fast_checksum_stream(
+                CrcFastAlgorithm::Crc32IsoHdlc,
+                Some(read),
+                &mut remaining as *mut _ as *mut c_void
+            ),
+            0xcbf43926
+        );
+
+        let mut checksum = 0;
+        assert_eq!(
+            crc_fast_checksum_stream_ex(
+                CrcFastAlgorithm::Crc32IsoHdlc,
+                Some(fail),
+                ptr::null_mut(),
+                &mut checksum
+            ),
+            CrcFastError::IoError
+        );
+        assert_eq!(
+            crc_fast_checksum_stream_ex(
+                CrcFastAlgorithm::Crc32IsoHdlc,
+                None,
+                ptr::null_mut(),
+                &mut checksum
+            ),
+            CrcFastError::NullPointer
+        );
+    }
+
+    #[test]
+    #[cfg(all(unix, not(target_os = "windows")))]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_checksum_iovec() {
+        let region = |data: &[u8]| iovec {
+            iov_base: data.as_ptr() as *mut c_void,
+            iov_len: data.len(),
+        };
+
+        // empty regions may have a null base
+        let regions = [
+            region(b"1234"),
+            iovec {
+                iov_base: ptr::null_mut(),
+                iov_len: 0,
+            },
+            region(b"56789"),
+        ];
+
+        assert_eq!(
+            crc_fast_checksum_iovec(CrcFastAlgorithm::Crc32IsoHdlc, regions.as_ptr(), 3),
+            0xcbf43926
+        );
+
+        // a custom polynomial, the same as CRC-64/NVME
+        let params = crc_fast_get_custom_params(
+            ptr::null(),
+            64,
+            0xad93d23594c93659,
+            0xffffffffffffffff,
+            true,
+            0xffffffffffffffff,
+            0xae8b14860a799888,
+        );
+        assert_eq!(
+            crc_fast_checksum_iovec_with_params(params, regions.as_ptr(), 3),
+            0xae8b14860a799888
+        );
+
+        // no regions is the checksum of empty input
+        let mut checksum = u64::MAX;
+        assert_eq!(
+            crc_fast_checksum_iovec_ex(
+                CrcFastAlgorithm::Crc32IsoHdlc,
+                ptr::null(),
+                0,
+                &mut checksum
+            ),
+            CrcFastError::Success
+        );
+        assert_eq!(checksum, 0);
+
+        assert_eq!(
+            crc_fast_checksum_iovec_ex(
+                CrcFastAlgorithm::Crc32IsoHdlc,
+                regions.as_ptr(),
+                -1,
+                &mut checksum
+            ),
+            CrcFastError::InvalidCount
+        );
+        assert_eq!(
+            crc_fast_checksum_iovec_ex(
+                CrcFastAlgorithm::Crc32IsoHdlc,
+                ptr::null(),
+                1,
+                &mut checksum
+            ),
+            CrcFastError::NullPointer
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_checksum_file_ex() {
+        let mut checksum = 0;
+        let path = b"crc-check.txt";
+        let result = crc_fast_checksum_file_ex(
+            CrcFastAlgorithm::Crc32IsoHdlc,
+            path.as_ptr(),
+            path.len(),
+            &mut checksum,
+        );
+        assert_eq!(result, CrcFastError::Success);
+        assert_eq!(checksum, 0xcbf43926);
+
+        let path = b"does-not-exist.txt";
+        let result = crc_fast_checksum_file_ex(
+            CrcFastAlgorithm::Crc32IsoHdlc,
+            path.as_ptr(),
+            path.len(),
+            &mut checksum,
+        );
+        assert_eq!(result, CrcFastError::IoError);
+
+        let path = [0xffu8, 0xfe];
+        let result = crc_fast_checksum_file_ex(
+            CrcFastAlgorithm::Crc32IsoHdlc,
+            path.as_ptr(),
+            path.len(),
+            &mut checksum,
+        );
+        assert_eq!(result, CrcFastError::InvalidUtf8);
     }
 }
@@ -0,0 +1,142 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Low-level folding API for expert integrators.
+//!
+//! Filesystems, storage engines, and other pipelined I/O loops can select the folding
+//! implementation once with [`fold_ops()`], and then call [`fold_block()`] (or [`fold()`]) for each
+//! block without the per-call dispatch of `Digest::update()` or `checksum()`.
+//!
+//! All states are raw, non-finalized CRC states: start with `params.init`, and apply
+//! `params.xorout` to the final state to get the checksum.
+//!
+//! These always use the generic carryless multiplication folding kernel, even for algorithms
+//! which otherwise use fusion (such as CRC-32/ISCSI), so the results are identical but the
+//! performance may differ.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::fold::{fold_block, fold_ops};
+//! use crc_fast::{checksum, CrcAlgorithm, CrcParams};
+//!
+//! let params = CrcParams::new(
+//!     "CRC-32/ISCSI",
+//!     32,
+//!     0x1edc6f41,
+//!     0xffffffff,
+//!     true,
+//!     0xffffffff,
+//!     0xe3069283,
+//! );
+//! let ops = fold_ops();
+//!
+//! let blocks = [[0xabu8; 4096]; 4];
+//! let mut state = params.init;
+//! for block in &blocks {
+//!     state = unsafe { fold_block(state, block, params, &ops) };
+//! }
+//!
+//! let expected = checksum(CrcAlgorithm::Crc32Iscsi, blocks.as_flattened());
+//! assert_eq!(state ^ params.xorout, expected);
+//! ```
+
+use crate::feature_detection::{get_arch_ops, ArchOpsInstance, PerformanceTier};
+use crate::CrcParams;
+
+/// Opaque handle to the folding implementation selected for this CPU.
+///
+/// Obtained once with [`fold_ops()`] and then passed to every [`fold_block()`] or [`fold()`] call.
+#[derive(Debug, Clone, Copy)]
+pub struct FoldOps(ArchOpsInstance);
+
+impl FoldOps {
+    /// Returns the performance tier of this folding implementation.
+    #[inline(always)]
+    pub fn tier(&self) -> PerformanceTier {
+        self.0.get_tier()
+    }
+}
+
+/// Returns the folding implementation selected for this CPU, which is the same one used by the
+/// rest of the library (including any forced tier).
+#[inline(always)]
+pub fn fold_ops() -> FoldOps {
+    FoldOps(*get_arch_ops())
+}
+
+/// Folds a fixed-size block into a raw CRC state, returning the updated state.
+///
+/// Because the block size is known at compile time, the length-dependent branches of the folding
+/// kernel can be resolved by the compiler, which makes this ideal for fixed-size I/O blocks.
+///
+/// # Safety
+///
+/// `ops` must come from [`fold_ops()`] in this process, since it may use CPU features which are
+/// only available on the CPU it was selected for. `params` must be valid (e.g. created with
+/// `CrcParams::new()` or one of the predefined algorithms), and `state` must be a raw CRC state
+/// for the same `params`.
+#[inline(always)]
+pub unsafe fn fold_block<const N: usize>(
+    state: u64,
+    block: &[u8; N],
+    params: CrcParams,
+    ops: &FoldOps,
+) -> u64 {
+    crate::arch::update_with_arch_ops(state, block, params, &ops.0)
+}
+
+/// Folds a slice of any length into a raw CRC state, returning the updated state.
+///
+/// # Safety
+///
+/// Same requirements as [`fold_block()`].
+#[inline(always)]
+pub unsafe fn fold(state: u64, data: &[u8], params: CrcParams, ops: &FoldOps) -> u64 {
+    crate::arch::update_with_arch_ops(state, data, params, &ops.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::consts::{TEST_ALL_CONFIGS, TEST_CHECK_STRING};
+
+    #[test]
+    fn test_fold_check_value() {
+        let ops = fold_ops();
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+            let actual = unsafe { fold(params.init, TEST_CHECK_STRING, params, &ops) };
+
+            assert_eq!(actual ^ params.xorout, config.get_check());
+        }
+    }
+
+    #[test]
+    fn test_fold_block_matches_checksum() {
+        let ops = fold_ops();
+        let data: Vec<u8> = (0..4096u32).map(|i| (i * 31) as u8).collect();
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            let state = data
+                .chunks_exact(512)
+                .fold(params.init, |state, block| unsafe {
+                    fold_block::<512>(state, block.try_into().unwrap(), params, &ops)
+                });
+
+            assert_eq!(
+                state ^ params.xorout,
+                crate::checksum_with_params(params, &data),
+                "Mismatch CRC, {}",
+                config.get_name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_fold_ops_matches_active_tier() {
+        assert_eq!(fold_ops().tier(), get_arch_ops().get_tier());
+    }
+}
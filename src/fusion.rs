@@ -0,0 +1,91 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Direct access to the fused CRC-32 kernels, which interleave the native CRC-32 instructions with
+//! carryless multiplication, for callers (such as kernel-bypass network stacks) which manage the
+//! CRC state themselves and want the fastest kernel with no `Digest` or parameter handling.
+//!
+//! # State
+//!
+//! The functions take and return the raw, reflected 32-bit CRC register, exactly like the
+//! [`hw`](crate::hw) instruction wrappers: no initial value or final XOR is applied. So:
+//!
+//! - To calculate a standard checksum, start with a state of `0xffffffff` and invert the result.
+//! - To continue a finished checksum with more data, invert it, update, and invert again.
+//! - To chain raw states (e.g. per-packet partial CRCs), pass each result straight back in.
+//!
+//! The kernel is picked the same way as for `checksum()`, from the active (possibly forced)
+//! performance tier. Where fusion isn't available (no native CRC-32 instructions, a tier with no
+//! carryless multiplication, or a registered custom backend), the regular folding kernel (or the
+//! backend) is used instead, with identical results. `get_calculator_target()` reports a `-fusion`
+//! suffix when the fused kernel is in use.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::fusion;
+//!
+//! let checksum = !fusion::crc32c(0xffffffff, b"123456789");
+//! assert_eq!(checksum, 0xe3069283);
+//!
+//! // continue a finished checksum
+//! let prefix = !fusion::crc32c(0xffffffff, b"1234");
+//! assert_eq!(!fusion::crc32c(!prefix, b"56789"), checksum);
+//! ```
+
+use crate::crc32::consts::{CRC32_ISCSI, CRC32_ISO_HDLC};
+use crate::{crc32_iscsi_calculator, crc32_iso_hdlc_calculator};
+
+/// Updates a raw CRC-32C (CRC-32/ISCSI) state with data, using the fused kernel where available.
+#[inline(always)]
+pub fn crc32c(state: u32, data: &[u8]) -> u32 {
+    crc32_iscsi_calculator(state as u64, data, CRC32_ISCSI) as u32
+}
+
+/// Updates a raw CRC-32 (CRC-32/ISO-HDLC) state with data, using the fused kernel where available
+/// (only `aarch64` has native CRC-32/ISO-HDLC instructions to fuse with).
+#[inline(always)]
+pub fn crc32(state: u32, data: &[u8]) -> u32 {
+    crc32_iso_hdlc_calculator(state as u64, data, CRC32_ISO_HDLC) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{checksum, hw, CrcAlgorithm};
+
+    #[test]
+    fn test_matches_checksum() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+        for len in (0..300).chain([1_024, 4_096, 4_097, data.len()]) {
+            let data = &data[..len];
+
+            assert_eq!(
+                !crc32c(0xffffffff, data) as u64,
+                checksum(CrcAlgorithm::Crc32Iscsi, data)
+            );
+            assert_eq!(
+                !crc32(0xffffffff, data) as u64,
+                checksum(CrcAlgorithm::Crc32IsoHdlc, data)
+            );
+        }
+    }
+
+    #[test]
+    fn test_raw_state_matches_instructions() {
+        let data: Vec<u8> = (0..1_000u32).map(|i| (i * 7 % 253) as u8).collect();
+
+        // arbitrary raw states, including ones which aren't a standard init value
+        for state in [0, 1, 0x12345678, 0xffffffff] {
+            let expected = data
+                .iter()
+                .fold(state, |crc, byte| hw::crc32c_u8(crc, *byte));
+
+            assert_eq!(crc32c(state, &data), expected);
+
+            // chaining raw states is the same as one update
+            let (left, right) = data.split_at(333);
+            assert_eq!(crc32c(crc32c(state, left), right), expected);
+        }
+    }
+}
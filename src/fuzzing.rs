@@ -0,0 +1,172 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Differential fuzzing harnesses, used by the cargo-fuzz targets in the `fuzz` directory.
+//!
+//! Each harness decodes the algorithm, alignment, and update boundaries from the fuzzer's input,
+//! checksums the rest of the input with the currently selected acceleration target, and panics if
+//! the result differs from the table-based reference implementation.
+//!
+//! Only one target is selected per process, so set the `CRC_FAST_FORCE_TIER` environment variable
+//! to fuzz a specific tier.
+
+use crate::enums::compiled_algorithms;
+use crate::self_test::reference_checksum;
+use crate::{checksum, CrcAlgorithm, Digest};
+
+/// The largest misalignment applied to the checksummed data, which covers every SIMD width
+const MAX_ALIGNMENT: usize = 64;
+
+/// The most update lengths decoded from a single input
+const MAX_UPDATE_LENGTHS: usize = 16;
+
+/// Compares a single `checksum()` call against the reference implementation.
+///
+/// The first byte selects the algorithm, the second the misalignment of the data, and the rest of
+/// the input is the data.
+///
+/// # Panics
+///
+/// Panics if the checksums differ.
+pub fn checksum_differential(input: &[u8]) {
+    let Some((algorithm, input)) = split_algorithm(input) else {
+        return;
+    };
+    let Some((&alignment, data)) = input.split_first() else {
+        return;
+    };
+
+    let alignment = alignment as usize % MAX_ALIGNMENT;
+
+    // copy the data so it starts `alignment` bytes past a MAX_ALIGNMENT boundary
+    let mut buf = vec![0u8; data.len() + 2 * MAX_ALIGNMENT];
+    let start = buf.as_ptr().align_offset(MAX_ALIGNMENT) + alignment;
+    buf[start..start + data.len()].copy_from_slice(data);
+
+    assert_eq!(
+        checksum(algorithm, &buf[start..start + data.len()]),
+        reference_checksum(algorithm, data),
+        "{algorithm} checksum mismatch for {} bytes at alignment {}",
+        data.len(),
+        alignment
+    );
+}
+
+/// Compares a sequence of `Digest::update()` calls, and the combination of the Digests for each
+/// half of the data, against the reference implementation.
+///
+/// The first byte selects the algorithm, the second the number of update lengths (up to 16), which
+/// follow as little-endian `u16`s and are cycled through until the rest of the input, the data, is
+/// consumed, with zero treated as one. The first update length is also where the data is split for combining.
+///
+/// # Panics
+///
+/// Panics if the checksums differ.
+pub fn digest_differential(input: &[u8]) {
+    let Some((algorithm, input)) = split_algorithm(input) else {
+        return;
+    };
+    let Some((&count, input)) = input.split_first() else {
+        return;
+    };
+
+    let count = count as usize % (MAX_UPDATE_LENGTHS + 1);
+    if input.len() < count * 2 {
+        return;
+    }
+
+    let (lengths, data) = input.split_at(count * 2);
+    let mut lengths: Vec<usize> = lengths
+        .chunks_exact(2)
+        .map(|length| u16::from_le_bytes([length[0], length[1]]).max(1) as usize)
+        .collect();
+    if lengths.is_empty() {
+        lengths.push(data.len());
+    }
+
+    let expected = reference_checksum(algorithm, data);
+
+    let mut digest = Digest::new(algorithm);
+    let mut remaining = data;
+    for &length in lengths.iter().cycle() {
+        if remaining.is_empty() {
+            break;
+        }
+
+        let (chunk, rest) = remaining.split_at(length.min(remaining.len()));
+        digest.update(chunk);
+        remaining = rest;
+    }
+
+    assert_eq!(
+        digest.finalize(),
+        expected,
+        "{algorithm} Digest mismatch for {} bytes with update lengths {lengths:?}",
+        data.len()
+    );
+
+    let (head, tail) = data.split_at(lengths[0].min(data.len()));
+
+    let mut digest = Digest::new(algorithm);
+    digest.update(head);
+
+    let mut other = Digest::new(algorithm);
+    other.update(tail);
+
+    digest.combine(&other);
+
+    assert_eq!(
+        digest.finalize(),
+        expected,
+        "{algorithm} combine mismatch for {} + {} bytes",
+        head.len(),
+        tail.len()
+    );
+}
+
+/// Selects a compiled-in predefined algorithm from the first byte of the input
+fn split_algorithm(input: &[u8]) -> Option<(CrcAlgorithm, &[u8])> {
+    let (&selector, rest) = input.split_first()?;
+    let algorithms: Vec<CrcAlgorithm> = compiled_algorithms().collect();
+
+    Some((algorithms[selector as usize % algorithms.len()], rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_checksum_differential() {
+        let mut rng = rng();
+
+        for len in 0..1200 {
+            let mut input = vec![0u8; len];
+            rng.fill(&mut input[..]);
+
+            checksum_differential(&input);
+        }
+    }
+
+    #[test]
+    fn test_digest_differential() {
+        let mut rng = rng();
+
+        for _ in 0..1000 {
+            let mut input = vec![0u8; rng.random_range(0..4096)];
+            rng.fill(&mut input[..]);
+
+            // keep the update lengths short enough to exercise the chunked paths
+            if input.len() > 3 {
+                input[1] %= 8;
+                let end = (2 + input[1] as usize * 2).min(input.len());
+                input[3..end.max(3)]
+                    .iter_mut()
+                    .step_by(2)
+                    .for_each(|high| *high = 0);
+            }
+
+            digest_differential(&input);
+        }
+    }
+}
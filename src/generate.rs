@@ -44,7 +44,7 @@
 //! - This key folds together data chunks that are 12 bytes apart
 //!
 //! The larger distances (32*63, 32*65) handle 256-byte chunks for very high throughput, such as
-//! using AVX-512 VPCLMULQDQ.
+//! using AVX-512 VPCLMULQDQ, and the largest (32*127, 32*129) handle 512-byte chunks.
 
 //! # Why CRC-32 and CRC-64 Implementations Differ So Greatly
 //!
@@ -133,7 +133,11 @@ use std::ops::{BitAnd, BitOr, Shl, Shr};
 ///   - 32*63 = 2016 bits (252 bytes)
 ///   - 32*65 = 2080 bits (260 bytes)
 ///   - These enable very high-throughput processing of large buffers
-const CRC32_EXPONENTS: [u64; 23] = [
+/// - Indices 23-24: Larger distances for 512-byte chunk processing using AVX-512 VPCLMULQDQ
+///   - 32*127 = 4064 bits (508 bytes)
+///   - 32*129 = 4128 bits (516 bytes)
+///   - These keep more folds in flight to better hide carryless multiply latency
+const CRC32_EXPONENTS: [u64; 25] = [
     0, // unused, just aligns indexes with the literature
     32 * 3,
     32 * 5,
@@ -155,8 +159,10 @@ const CRC32_EXPONENTS: [u64; 23] = [
     32 * 13,
     32 * 7,
     32 * 9,
-    32 * 63, // for 256 byte distances (2048 - 32)
-    32 * 65, // for 256 byte distances (2048 + 32)
+    32 * 63,  // for 256 byte distances (2048 - 32)
+    32 * 65,  // for 256 byte distances (2048 + 32)
+    32 * 127, // for 512 byte distances (4096 - 32)
+    32 * 129, // for 512 byte distances (4096 + 32)
 ];
 
 /// Exponents (bit distances) for CRC-64 key generation.
@@ -177,9 +183,10 @@ const CRC32_EXPONENTS: [u64; 23] = [
 /// | 1     | 96 bits (12B)   | 128 bits (16B)  | Primary fold         |
 /// | 2     | 160 bits (20B)  | 192 bits (24B)  | Primary fold         |
 /// | 21    | 2016 bits (252B)| 2048 bits (256B)| Large chunk folding  |
+/// | 23    | 4064 bits (508B)| 4096 bits (512B)| Larger chunk folding |
 ///
 /// The smaller multipliers (2, 3 vs 3, 5) reflect the larger base unit (64 vs 32 bits).
-const CRC64_EXPONENTS: [u64; 23] = [
+const CRC64_EXPONENTS: [u64; 25] = [
     0, // unused, just aligns indexes with the literature
     64 * 2,
     64 * 3,
@@ -203,12 +210,14 @@ const CRC64_EXPONENTS: [u64; 23] = [
     64 * 5,
     64 * 32, // for 256 byte distances (2048)
     64 * 33, // for 256 byte distances (2048 + 64)
+    64 * 64, // for 512 byte distances (4096)
+    64 * 65, // for 512 byte distances (4096 + 64)
 ];
 
-/// Generates the 23 keys needed to calculate CRCs for a given polynomial using PCLMULQDQ when
+/// Generates the 25 keys needed to calculate CRCs for a given polynomial using PCLMULQDQ when
 /// folding by 8.
-pub fn keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
-    let mut keys: [u64; 23] = [0; 23];
+pub fn keys(width: u8, poly: u64, reflected: bool) -> [u64; 25] {
+    let mut keys: [u64; 25] = [0; 25];
 
     let exponents = if 32 == width {
         CRC32_EXPONENTS
@@ -224,7 +233,7 @@ pub fn keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
         poly
     };
 
-    for i in 1..23 {
+    for i in 1..25 {
         keys[i] = key(width, poly, reflected, exponents[i]);
     }
 
@@ -234,6 +243,29 @@ pub fn keys(width: u8, poly: u64, reflected: bool) -> [u64; 23] {
     keys
 }
 
+/// Generates the image of each bit of a 64-bit word in a reflected CRC register, after the word and
+/// then `trailing_bits` zero bits are processed, for implementations which calculate the CRC as a
+/// linear map of each word (e.g. with tables or affine transforms) rather than by folding.
+///
+/// `poly` is the non-reflected polynomial, and every image is x^(64 - bit + trailing_bits) mod P
+/// in reflected form, since the bit is shifted through the register once for each bit after it.
+pub(crate) fn reflected_word_bit_images(width: u8, poly: u64, trailing_bits: u32) -> [u64; 64] {
+    let poly = poly.reverse_bits() >> (64 - width as u32);
+
+    let mut images = [0u64; 64];
+
+    let mut crc = 1u64;
+    for shifts in 1..=64 + trailing_bits {
+        crc = (crc >> 1) ^ ((crc & 1) * poly);
+
+        if shifts > trailing_bits {
+            images[(64 + trailing_bits - shifts) as usize] = crc;
+        }
+    }
+
+    images
+}
+
 fn key(width: u8, poly: u64, reflected: bool, exponent: u64) -> u64 {
     if width == 32 {
         crc32_key(exponent, reflected, poly)
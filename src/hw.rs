@@ -0,0 +1,349 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Raw hardware CRC-32 instruction wrappers.
+//!
+//! These expose the native CRC-32 instructions directly, for hashing and database users who want
+//! to checksum tiny fixed-size keys without constructing a `Digest`.
+//!
+//! The functions mirror the instructions exactly: `state` is the raw, reflected CRC register, and
+//! no initial value or final XOR is applied. To calculate a standard checksum, start with a state
+//! of `0xffffffff` and invert the result.
+//!
+//! - CRC-32C (CRC-32/ISCSI) instructions are available on `x86`/`x86_64` (SSE4.2) and `aarch64`
+//!   (CRC extension).
+//! - CRC-32 (CRC-32/ISO-HDLC) instructions are only available on `aarch64` (CRC extension).
+//!
+//! When the CPU doesn't provide the instruction, a (much slower) bitwise software implementation
+//! with identical results is used instead, so these are always safe to call. Use
+//! [`has_crc32c()`] and [`has_crc32()`] to check whether the instructions are available.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::hw::crc32c_u64;
+//!
+//! let key: u64 = 0x0123456789abcdef;
+//! let hash = !crc32c_u64(0xffffffff, key);
+//! ```
+
+/// Reflected CRC-32C (CRC-32/ISCSI) polynomial, for the software implementation
+const CRC32C_POLY_REFLECTED: u32 = 0x82f63b78;
+
+/// Reflected CRC-32 (CRC-32/ISO-HDLC) polynomial, for the software implementation
+const CRC32_POLY_REFLECTED: u32 = 0xedb88320;
+
+/// Returns whether the CPU provides native CRC-32C (CRC-32/ISCSI) instructions.
+#[inline(always)]
+pub fn has_crc32c() -> bool {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        is_x86_feature_detected!("sse4.2")
+    }
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        std::arch::is_aarch64_feature_detected!("crc")
+    }
+
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+    {
+        false
+    }
+}
+
+/// Returns whether the CPU provides native CRC-32 (CRC-32/ISO-HDLC) instructions.
+#[inline(always)]
+pub fn has_crc32() -> bool {
+    #[cfg(target_arch = "aarch64")]
+    {
+        std::arch::is_aarch64_feature_detected!("crc")
+    }
+
+    #[cfg(not(target_arch = "aarch64"))]
+    {
+        false
+    }
+}
+
+/// Updates a raw CRC-32C (CRC-32/ISCSI) state with a single byte.
+#[inline(always)]
+pub fn crc32c_u8(state: u32, value: u8) -> u32 {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+    if has_crc32c() {
+        return unsafe { native::crc32c_u8(state, value) };
+    }
+
+    software_update(state, value as u64, 8, CRC32C_POLY_REFLECTED)
+}
+
+/// Updates a raw CRC-32C (CRC-32/ISCSI) state with a little-endian `u16`.
+#[inline(always)]
+pub fn crc32c_u16(state: u32, value: u16) -> u32 {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+    if has_crc32c() {
+        return unsafe { native::crc32c_u16(state, value) };
+    }
+
+    software_update(state, value as u64, 16, CRC32C_POLY_REFLECTED)
+}
+
+/// Updates a raw CRC-32C (CRC-32/ISCSI) state with a little-endian `u32`.
+#[inline(always)]
+pub fn crc32c_u32(state: u32, value: u32) -> u32 {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+    if has_crc32c() {
+        return unsafe { native::crc32c_u32(state, value) };
+    }
+
+    software_update(state, value as u64, 32, CRC32C_POLY_REFLECTED)
+}
+
+/// Updates a raw CRC-32C (CRC-32/ISCSI) state with a little-endian `u64`.
+#[inline(always)]
+pub fn crc32c_u64(state: u32, value: u64) -> u32 {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+    if has_crc32c() {
+        return unsafe { native::crc32c_u64(state, value) };
+    }
+
+    software_update(state, value, 64, CRC32C_POLY_REFLECTED)
+}
+
+/// Updates a raw CRC-32 (CRC-32/ISO-HDLC) state with a single byte.
+#[inline(always)]
+pub fn crc32_u8(state: u32, value: u8) -> u32 {
+    #[cfg(target_arch = "aarch64")]
+    if has_crc32() {
+        return unsafe { native::crc32_u8(state, value) };
+    }
+
+    software_update(state, value as u64, 8, CRC32_POLY_REFLECTED)
+}
+
+/// Updates a raw CRC-32 (CRC-32/ISO-HDLC) state with a little-endian `u16`.
+#[inline(always)]
+pub fn crc32_u16(state: u32, value: u16) -> u32 {
+    #[cfg(target_arch = "aarch64")]
+    if has_crc32() {
+        return unsafe { native::crc32_u16(state, value) };
+    }
+
+    software_update(state, value as u64, 16, CRC32_POLY_REFLECTED)
+}
+
+/// Updates a raw CRC-32 (CRC-32/ISO-HDLC) state with a little-endian `u32`.
+#[inline(always)]
+pub fn crc32_u32(state: u32, value: u32) -> u32 {
+    #[cfg(target_arch = "aarch64")]
+    if has_crc32() {
+        return unsafe { native::crc32_u32(state, value) };
+    }
+
+    software_update(state, value as u64, 32, CRC32_POLY_REFLECTED)
+}
+
+/// Updates a raw CRC-32 (CRC-32/ISO-HDLC) state with a little-endian `u64`.
+#[inline(always)]
+pub fn crc32_u64(state: u32, value: u64) -> u32 {
+    #[cfg(target_arch = "aarch64")]
+    if has_crc32() {
+        return unsafe { native::crc32_u64(state, value) };
+    }
+
+    software_update(state, value, 64, CRC32_POLY_REFLECTED)
+}
+
+/// Bitwise reflected CRC-32 update, matching the hardware instructions, for CPUs without them
+#[inline]
+fn software_update(mut state: u32, value: u64, bits: u32, poly: u32) -> u32 {
+    for i in 0..bits {
+        let bit = ((state as u64 ^ (value >> i)) & 1) as u32;
+        state = (state >> 1) ^ (poly & bit.wrapping_neg());
+    }
+
+    state
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod native {
+    #[cfg(target_arch = "x86")]
+    use std::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use std::arch::x86_64::*;
+
+    #[inline]
+    #[target_feature(enable = "sse4.2")]
+    pub(super) unsafe fn crc32c_u8(state: u32, value: u8) -> u32 {
+        _mm_crc32_u8(state, value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse4.2")]
+    pub(super) unsafe fn crc32c_u16(state: u32, value: u16) -> u32 {
+        _mm_crc32_u16(state, value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse4.2")]
+    pub(super) unsafe fn crc32c_u32(state: u32, value: u32) -> u32 {
+        _mm_crc32_u32(state, value)
+    }
+
+    #[inline]
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "sse4.2")]
+    pub(super) unsafe fn crc32c_u64(state: u32, value: u64) -> u32 {
+        _mm_crc32_u64(state as u64, value) as u32
+    }
+
+    /// 32-bit x86 has no 64-bit CRC-32C instruction, so process each half in little-endian order
+    #[inline]
+    #[cfg(target_arch = "x86")]
+    #[target_feature(enable = "sse4.2")]
+    pub(super) unsafe fn crc32c_u64(state: u32, value: u64) -> u32 {
+        _mm_crc32_u32(_mm_crc32_u32(state, value as u32), (value >> 32) as u32)
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod native {
+    use std::arch::aarch64::*;
+
+    #[inline]
+    #[target_feature(enable = "crc")]
+    pub(super) unsafe fn crc32c_u8(state: u32, value: u8) -> u32 {
+        __crc32cb(state, value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "crc")]
+    pub(super) unsafe fn crc32c_u16(state: u32, value: u16) -> u32 {
+        __crc32ch(state, value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "crc")]
+    pub(super) unsafe fn crc32c_u32(state: u32, value: u32) -> u32 {
+        __crc32cw(state, value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "crc")]
+    pub(super) unsafe fn crc32c_u64(state: u32, value: u64) -> u32 {
+        __crc32cd(state, value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "crc")]
+    pub(super) unsafe fn crc32_u8(state: u32, value: u8) -> u32 {
+        __crc32b(state, value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "crc")]
+    pub(super) unsafe fn crc32_u16(state: u32, value: u16) -> u32 {
+        __crc32h(state, value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "crc")]
+    pub(super) unsafe fn crc32_u32(state: u32, value: u32) -> u32 {
+        __crc32w(state, value)
+    }
+
+    #[inline]
+    #[target_feature(enable = "crc")]
+    pub(super) unsafe fn crc32_u64(state: u32, value: u64) -> u32 {
+        __crc32d(state, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "crc64-only"))]
+    use crate::test::consts::TEST_CHECK_STRING;
+    #[cfg(not(feature = "crc64-only"))]
+    use crate::{checksum, CrcAlgorithm};
+    use rand::{rng, Rng};
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_crc32c_check_value() {
+        let state = TEST_CHECK_STRING
+            .iter()
+            .fold(0xffffffff, |state, byte| crc32c_u8(state, *byte));
+
+        assert_eq!(
+            !state as u64,
+            checksum(CrcAlgorithm::Crc32Iscsi, TEST_CHECK_STRING)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_crc32_check_value() {
+        let state = TEST_CHECK_STRING
+            .iter()
+            .fold(0xffffffff, |state, byte| crc32_u8(state, *byte));
+
+        assert_eq!(
+            !state as u64,
+            checksum(CrcAlgorithm::Crc32IsoHdlc, TEST_CHECK_STRING)
+        );
+    }
+
+    #[test]
+    fn test_widths_match_bytes() {
+        let mut rng = rng();
+
+        for _ in 0..1000 {
+            let value: u64 = rng.random();
+            let state: u32 = rng.random();
+            let bytes = value.to_le_bytes();
+
+            let crc32c = bytes.iter().fold(state, |s, b| crc32c_u8(s, *b));
+            let crc32 = bytes.iter().fold(state, |s, b| crc32_u8(s, *b));
+
+            assert_eq!(crc32c_u64(state, value), crc32c);
+            assert_eq!(crc32_u64(state, value), crc32);
+
+            let low = value as u32;
+            assert_eq!(
+                crc32c_u32(state, low),
+                bytes[..4].iter().fold(state, |s, b| crc32c_u8(s, *b))
+            );
+            assert_eq!(
+                crc32_u16(state, low as u16),
+                bytes[..2].iter().fold(state, |s, b| crc32_u8(s, *b))
+            );
+            assert_eq!(
+                crc32c_u16(state, low as u16),
+                bytes[..2].iter().fold(state, |s, b| crc32c_u8(s, *b))
+            );
+            assert_eq!(
+                crc32_u32(state, low),
+                bytes[..4].iter().fold(state, |s, b| crc32_u8(s, *b))
+            );
+        }
+    }
+
+    #[test]
+    fn test_native_matches_software() {
+        if !has_crc32c() {
+            return;
+        }
+
+        let mut rng = rng();
+
+        for _ in 0..1000 {
+            let value: u64 = rng.random();
+            let state: u32 = rng.random();
+
+            assert_eq!(
+                crc32c_u64(state, value),
+                software_update(state, value, 64, CRC32C_POLY_REFLECTED)
+            );
+        }
+    }
+}
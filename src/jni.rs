@@ -0,0 +1,295 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! JNI bindings for Java, Kotlin, and other JVM languages
+//!
+//! This module exports the native methods of the `com.awesomized.crcfast.CrcFast` class in the
+//! `java` directory, which wraps them with a `java.util.zip.Checksum` implementation. Algorithms
+//! are passed as the ordinal of its `Algorithm` enum, and Digests as `long` handles.
+
+use crate::enums::PREDEFINED_ALGORITHMS;
+use crate::{CrcAlgorithm, Digest};
+use jni::objects::{JByteArray, JByteBuffer, JClass, ReleaseMode};
+use jni::sys::{jint, jlong};
+use jni::JNIEnv;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+/// Failures which are thrown as Java exceptions
+enum JavaError {
+    IllegalArgument(&'static str),
+    NullPointer(&'static str),
+    Jni(jni::errors::Error),
+    Panic,
+}
+
+impl From<jni::errors::Error> for JavaError {
+    fn from(error: jni::errors::Error) -> Self {
+        JavaError::Jni(error)
+    }
+}
+
+/// Runs the body of a native method, throwing any failure (including a panic, which must not
+/// unwind into the JVM) as a Java exception and returning the default value
+fn run<T: Default>(env: &mut JNIEnv, body: impl FnOnce(&mut JNIEnv) -> Result<T, JavaError>) -> T {
+    let error = match catch_unwind(AssertUnwindSafe(|| body(env))) {
+        Ok(Ok(value)) => return value,
+        Ok(Err(error)) => error,
+        Err(_) => JavaError::Panic,
+    };
+
+    // a failed JNI call may have already thrown
+    if env.exception_check().unwrap_or(true) {
+        return T::default();
+    }
+
+    let _ = match error {
+        JavaError::IllegalArgument(message) => {
+            env.throw_new("java/lang/IllegalArgumentException", message)
+        }
+        JavaError::NullPointer(message) => env.throw_new("java/lang/NullPointerException", message),
+        JavaError::Jni(error) => env.throw_new("java/lang/RuntimeException", error.to_string()),
+        JavaError::Panic => env.throw_new("java/lang/RuntimeException", "crc-fast panicked"),
+    };
+
+    T::default()
+}
+
+/// Converts an `Algorithm` ordinal to the algorithm
+fn algorithm(ordinal: jint) -> Result<CrcAlgorithm, JavaError> {
+    usize::try_from(ordinal)
+        .ok()
+        .and_then(|ordinal| PREDEFINED_ALGORITHMS.get(ordinal).copied())
+        .ok_or(JavaError::IllegalArgument("unknown algorithm"))
+}
+
+/// Converts a Digest handle to the Digest
+///
+/// # Safety
+///
+/// The handle must be 0, or come from `digestNew()` and not have been freed.
+unsafe fn digest<'a>(handle: jlong) -> Result<&'a mut Digest, JavaError> {
+    (handle as *mut Digest)
+        .as_mut()
+        .ok_or(JavaError::NullPointer("digest was closed"))
+}
+
+/// Checks that offset and length are within a buffer of the given size, returning the range
+fn range(offset: jint, length: jint, size: usize) -> Result<std::ops::Range<usize>, JavaError> {
+    let start = usize::try_from(offset).ok();
+    let end = start
+        .zip(usize::try_from(length).ok())
+        .map(|(start, length)| start + length);
+
+    match (start, end) {
+        (Some(start), Some(end)) if end <= size => Ok(start..end),
+        _ => Err(JavaError::IllegalArgument(
+            "offset and length out of bounds",
+        )),
+    }
+}
+
+/// Calls f with the bytes of the array range, without copying them
+fn with_array<T>(
+    env: &mut JNIEnv,
+    array: &JByteArray,
+    offset: jint,
+    length: jint,
+    f: impl FnOnce(&[u8]) -> T,
+) -> Result<T, JavaError> {
+    if array.is_null() {
+        return Err(JavaError::NullPointer("data is null"));
+    }
+
+    let range = range(offset, length, env.get_array_length(array)? as usize)?;
+
+    // SAFETY: no JNI calls are made while the critical section is held
+    let elements = unsafe { env.get_array_elements_critical(array, ReleaseMode::NoCopyBack)? };
+    let bytes =
+        unsafe { std::slice::from_raw_parts(elements.as_ptr() as *const u8, elements.len()) };
+
+    Ok(f(&bytes[range]))
+}
+
+/// Calls f with the bytes of the direct buffer range, without copying them
+fn with_direct_buffer<T>(
+    env: &mut JNIEnv,
+    buffer: &JByteBuffer,
+    offset: jint,
+    length: jint,
+    f: impl FnOnce(&[u8]) -> T,
+) -> Result<T, JavaError> {
+    if buffer.is_null() {
+        return Err(JavaError::NullPointer("buffer is null"));
+    }
+
+    let address = env
+        .get_direct_buffer_address(buffer)
+        .map_err(|_| JavaError::IllegalArgument("buffer isn't direct"))?;
+    let range = range(offset, length, env.get_direct_buffer_capacity(buffer)?)?;
+
+    // SAFETY: the JVM guarantees the address is valid for the buffer's capacity
+    let bytes = unsafe { std::slice::from_raw_parts(address, range.end) };
+
+    Ok(f(&bytes[range]))
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_awesomized_crcfast_CrcFast_checksum(
+    mut env: JNIEnv,
+    _class: JClass,
+    algorithm_ordinal: jint,
+    data: JByteArray,
+    offset: jint,
+    length: jint,
+) -> jlong {
+    run(&mut env, |env| {
+        let algorithm = algorithm(algorithm_ordinal)?;
+
+        with_array(env, &data, offset, length, |bytes| {
+            crate::checksum(algorithm, bytes) as jlong
+        })
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_awesomized_crcfast_CrcFast_checksumDirect(
+    mut env: JNIEnv,
+    _class: JClass,
+    algorithm_ordinal: jint,
+    buffer: JByteBuffer,
+    offset: jint,
+    length: jint,
+) -> jlong {
+    run(&mut env, |env| {
+        let algorithm = algorithm(algorithm_ordinal)?;
+
+        with_direct_buffer(env, &buffer, offset, length, |bytes| {
+            crate::checksum(algorithm, bytes) as jlong
+        })
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_awesomized_crcfast_CrcFast_checksumCombine(
+    mut env: JNIEnv,
+    _class: JClass,
+    algorithm_ordinal: jint,
+    checksum1: jlong,
+    checksum2: jlong,
+    checksum2_len: jlong,
+) -> jlong {
+    run(&mut env, |_| {
+        let checksum2_len = u64::try_from(checksum2_len)
+            .map_err(|_| JavaError::IllegalArgument("negative length"))?;
+
+        crate::try_checksum_combine(
+            algorithm(algorithm_ordinal)?,
+            checksum1 as u64,
+            checksum2 as u64,
+            checksum2_len,
+        )
+        .map(|checksum| checksum as jlong)
+        .map_err(|_| JavaError::IllegalArgument("checksum is too wide for the algorithm"))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_awesomized_crcfast_CrcFast_digestNew(
+    mut env: JNIEnv,
+    _class: JClass,
+    algorithm_ordinal: jint,
+) -> jlong {
+    run(&mut env, |_| {
+        let digest = Box::new(Digest::new(algorithm(algorithm_ordinal)?));
+
+        Ok(Box::into_raw(digest) as jlong)
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_awesomized_crcfast_CrcFast_digestUpdate(
+    mut env: JNIEnv,
+    _class: JClass,
+    handle: jlong,
+    data: JByteArray,
+    offset: jint,
+    length: jint,
+) {
+    run(&mut env, |env| {
+        let digest = unsafe { digest(handle)? };
+
+        with_array(env, &data, offset, length, |bytes| digest.update(bytes))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_awesomized_crcfast_CrcFast_digestUpdateDirect(
+    mut env: JNIEnv,
+    _class: JClass,
+    handle: jlong,
+    buffer: JByteBuffer,
+    offset: jint,
+    length: jint,
+) {
+    run(&mut env, |env| {
+        let digest = unsafe { digest(handle)? };
+
+        with_direct_buffer(env, &buffer, offset, length, |bytes| digest.update(bytes))
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_awesomized_crcfast_CrcFast_digestFinalize(
+    mut env: JNIEnv,
+    _class: JClass,
+    handle: jlong,
+) -> jlong {
+    run(&mut env, |_| {
+        Ok(unsafe { digest(handle)? }.finalize() as jlong)
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_awesomized_crcfast_CrcFast_digestReset(
+    mut env: JNIEnv,
+    _class: JClass,
+    handle: jlong,
+) {
+    run(&mut env, |_| {
+        unsafe { digest(handle)? }.reset();
+        Ok(())
+    })
+}
+
+#[no_mangle]
+pub extern "system" fn Java_com_awesomized_crcfast_CrcFast_digestFree(
+    _env: JNIEnv,
+    _class: JClass,
+    handle: jlong,
+) {
+    if handle != 0 {
+        // SAFETY: the handle came from digestNew(), and the Java wrapper only frees it once
+        drop(unsafe { Box::from_raw(handle as *mut Digest) });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_ordinals() {
+        assert_eq!(algorithm(0).ok(), Some(CrcAlgorithm::Crc32Aixm));
+        assert_eq!(algorithm(18).ok(), Some(CrcAlgorithm::Crc64Xz));
+        assert!(algorithm(19).is_err());
+        assert!(algorithm(-1).is_err());
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(range(2, 3, 5).ok(), Some(2..5));
+        assert_eq!(range(0, 0, 0).ok(), Some(0..0));
+        assert!(range(2, 4, 5).is_err());
+        assert!(range(-1, 1, 5).is_err());
+        assert!(range(0, -1, 5).is_err());
+    }
+}
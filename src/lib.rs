@@ -1,6 +1,7 @@
 // Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
 // Future proofing for no_std support
 #![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 
 //! `crc-fast`
 //! ===========
@@ -73,6 +74,10 @@
 //!
 //! assert_eq!(checksum, 0xcbf43926);
 //! ```
+//!
+//! `&mut Digest` is also a writer, so it can be passed to APIs which take an `impl Write` by value.
+//! Where the writer has to be owned (e.g. handed to another thread), `DigestWriter` is a cheap,
+//! cloneable handle whose clones all update the same Digest.
 //! ## checksum
 //!```rust
 //! use crc_fast::{checksum, CrcAlgorithm::Crc32IsoHdlc};
@@ -132,19 +137,23 @@
 //! assert_eq!(checksum, 0xcbf43926);
 //! ```
 
+#[cfg(not(feature = "crc64-only"))]
 use crate::crc32::consts::{
     CRC32_AIXM, CRC32_AUTOSAR, CRC32_BASE91_D, CRC32_BZIP2, CRC32_CD_ROM_EDC, CRC32_CKSUM,
     CRC32_ISCSI, CRC32_ISO_HDLC, CRC32_JAMCRC, CRC32_MEF, CRC32_MPEG_2, CRC32_XFER,
 };
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
-use crate::crc32::fusion;
-
+#[cfg(not(feature = "crc32-only"))]
 use crate::crc64::consts::{
     CRC64_ECMA_182, CRC64_GO_ISO, CRC64_MS, CRC64_NVME, CRC64_REDIS, CRC64_WE, CRC64_XZ,
 };
-use crate::structs::Calculator;
+use crate::perf::{record_calculation, record_staged};
+use crate::structs::{Calculator, StagingBuffer};
 use crate::traits::CrcCalculator;
+use core::any::Any;
+use core::hash::{Hash, Hasher};
+use core::mem::MaybeUninit;
+use core::slice;
 use digest::{DynDigest, InvalidBufferSize};
 
 #[cfg(feature = "std")]
@@ -152,20 +161,114 @@ use std::fs::File;
 #[cfg(feature = "std")]
 use std::io::{Read, Write};
 
+#[cfg(all(feature = "crc32-only", feature = "crc64-only"))]
+compile_error!("the crc32-only and crc64-only features are mutually exclusive");
+
+#[cfg(all(
+    feature = "staticlib-ffi",
+    any(feature = "jni", feature = "wasm-component")
+))]
+compile_error!("the staticlib-ffi feature only exports the crc_fast_* symbols, so it can't be combined with jni or wasm-component");
+
+#[cfg(feature = "adler32")]
+pub mod adler32;
 mod algorithm;
+mod aligned;
 mod arch;
+#[cfg(feature = "tokio")]
+mod async_io;
+#[cfg(all(
+    feature = "unstable-backend",
+    any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")
+))]
+pub mod backend;
+mod bitwise;
 mod cache;
 mod combine;
+#[cfg(feature = "wasm-component")]
+mod component;
 mod consts;
 mod crc32;
 mod crc64;
+#[cfg(all(feature = "adler32", feature = "std", not(feature = "crc64-only")))]
+mod deflate;
 mod enums;
+mod error;
 mod feature_detection;
 mod ffi;
+pub mod fold;
+#[cfg(not(feature = "crc64-only"))]
+pub mod fusion;
+#[cfg(feature = "fuzzing")]
+#[doc(hidden)]
+pub mod fuzzing;
 mod generate;
+pub mod hw;
+#[cfg(feature = "jni")]
+mod jni;
+#[cfg(feature = "std")]
+mod manifest;
+#[cfg(feature = "std")]
+mod multipart;
+mod non_temporal;
+mod parallel;
+mod perf;
+mod pool;
+#[cfg(feature = "key-presets")]
+mod presets;
+pub mod reference;
+#[cfg(feature = "std")]
+mod self_test;
+#[cfg(feature = "std")]
+mod shared;
 mod structs;
 mod test;
+pub mod test_vectors;
 mod traits;
+#[cfg(feature = "std")]
+mod tree;
+#[cfg(feature = "std")]
+mod writer;
+
+pub use crate::aligned::CHECKSUM_ALIGNMENT;
+#[cfg(feature = "tokio")]
+pub use crate::async_io::AsyncHashingReader;
+pub use crate::cache::{
+    clear_key_cache, get_key_cache_capacity, invalidate_key_cache, prewarm_key_cache,
+    set_key_cache_capacity,
+};
+pub use crate::combine::CombineOperator;
+#[cfg(all(feature = "adler32", feature = "std", not(feature = "crc64-only")))]
+pub use crate::deflate::{DeflateIntegrityError, DeflateVerifier, DeflateWrapper};
+pub use crate::error::CrcFastError;
+pub use crate::feature_detection::{
+    auto_tune_tier, available_tiers, capabilities, register_backend, set_disabled_features,
+    set_forced_tier, AcceleratorInfo, ArchCapabilities, CrcBackend, ForceTierError,
+    PerformanceTier,
+};
+#[cfg(feature = "std")]
+pub use crate::manifest::{
+    checksum_file_blocks, checksum_file_blocks_with_params, diff_file_blocks, diff_reader_blocks,
+    BlockManifest,
+};
+#[cfg(feature = "std")]
+pub use crate::multipart::{
+    compose_object_crc, compose_object_crc_with_params, decode_object_crc, encode_object_crc,
+    verify_object_crc, verify_object_crc_with_params, MultipartHasher,
+};
+pub use crate::parallel::{get_parallel_threshold, set_parallel_threshold};
+#[cfg(feature = "std")]
+pub use crate::perf::estimate_throughput;
+pub use crate::perf::PerfStats;
+pub use crate::pool::DigestPool;
+#[cfg(feature = "std")]
+pub use crate::self_test::{self_test, SelfTestFailure, SelfTestReport};
+#[cfg(feature = "std")]
+pub use crate::shared::{SharedDigest, SharedDigestPart};
+#[cfg(feature = "std")]
+pub use crate::tree::ChecksumTree;
+#[cfg(feature = "std")]
+pub use crate::writer::DigestWriter;
 
 /// Supported CRC-32 and CRC-64 variants
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -197,11 +300,14 @@ pub enum CrcAlgorithm {
 /// This enum allows future expansion to support larger folding distances while maintaining
 /// backwards compatibility with existing const definitions.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
 pub enum CrcKeysStorage {
-    /// Current 23-key format for existing algorithms (supports up to 256-byte folding distances)
+    /// 23-key format (supports up to 256-byte folding distances)
     KeysFold256([u64; 23]),
-    /// Future 25-key format for potential expanded folding distances (testing purposes only)
-    KeysFutureTest([u64; 25]),
+    /// 25-key format (supports up to 512-byte folding distances), used by the built-in algorithms.
+    /// The first 23 keys are the same as `KeysFold256`, and keys 23 and 24 are the 512-byte
+    /// folding distance pair.
+    KeysFold512([u64; 25]),
 }
 
 impl CrcKeysStorage {
@@ -216,7 +322,7 @@ impl CrcKeysStorage {
                     0
                 }
             }
-            CrcKeysStorage::KeysFutureTest(keys) => {
+            CrcKeysStorage::KeysFold512(keys) => {
                 if index < 25 {
                     keys[index]
                 } else {
@@ -231,21 +337,36 @@ impl CrcKeysStorage {
     const fn key_count(self) -> usize {
         match self {
             CrcKeysStorage::KeysFold256(_) => 23,
-            CrcKeysStorage::KeysFutureTest(_) => 25,
+            CrcKeysStorage::KeysFold512(_) => 25,
         }
     }
 
-    /// Const constructor for 23-key arrays (current format).
+    /// Const constructor for 23-key arrays (legacy format).
+    #[allow(unused)]
     #[inline(always)]
     const fn from_keys_fold_256(keys: [u64; 23]) -> Self {
         CrcKeysStorage::KeysFold256(keys)
     }
 
-    /// Const constructor for 25-key arrays (future expansion testing).
+    /// Const constructor for 25-key arrays (current format).
+    #[inline(always)]
+    #[cfg(not(all(
+        feature = "tiny",
+        not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))
+    )))]
+    const fn from_keys_fold_512(keys: [u64; 25]) -> Self {
+        CrcKeysStorage::KeysFold512(keys)
+    }
+
+    /// Const constructor for 25-key arrays (current format), which drops the keys for the `tiny`
+    /// feature on targets with no SIMD implementation to use them, so they aren't compiled in.
     #[inline(always)]
-    #[allow(dead_code)] // Reserved for future expansion
-    const fn from_keys_fold_future_test(keys: [u64; 25]) -> Self {
-        CrcKeysStorage::KeysFutureTest(keys)
+    #[cfg(all(
+        feature = "tiny",
+        not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))
+    ))]
+    const fn from_keys_fold_512(_keys: [u64; 25]) -> Self {
+        CrcKeysStorage::KeysFold512([0; 25])
     }
 
     /// Extracts keys as a [u64; 23] array for FFI compatibility.
@@ -255,13 +376,41 @@ impl CrcKeysStorage {
     pub fn to_keys_array_23(self) -> [u64; 23] {
         match self {
             CrcKeysStorage::KeysFold256(keys) => keys,
-            CrcKeysStorage::KeysFutureTest(keys) => {
+            CrcKeysStorage::KeysFold512(keys) => {
                 let mut result = [0u64; 23];
                 result.copy_from_slice(&keys[..23]);
                 result
             }
         }
     }
+
+    /// Extracts keys as a [u64; 25] array.
+    /// For variants with fewer keys, remaining slots are filled with 0.
+    #[inline(always)]
+    pub fn to_keys_array_25(self) -> [u64; 25] {
+        match self {
+            CrcKeysStorage::KeysFold256(keys) => {
+                let mut result = [0u64; 25];
+                result[..23].copy_from_slice(&keys);
+                result
+            }
+            CrcKeysStorage::KeysFold512(keys) => keys,
+        }
+    }
+
+    /// Converts to the storage format with the given number of keys, or `None` if it's not a
+    /// supported format or there aren't enough keys to fill it.
+    pub(crate) fn with_key_count(self, key_count: usize) -> Option<Self> {
+        if key_count > self.key_count() {
+            return None;
+        }
+
+        match key_count {
+            23 => Some(CrcKeysStorage::KeysFold256(self.to_keys_array_23())),
+            25 => Some(CrcKeysStorage::KeysFold512(self.to_keys_array_25())),
+            _ => None,
+        }
+    }
 }
 
 // Implement PartialEq between CrcKeysStorage and [u64; 23] for test compatibility
@@ -277,8 +426,47 @@ impl PartialEq<CrcKeysStorage> for [u64; 23] {
     }
 }
 
+// Implement PartialEq between CrcKeysStorage and [u64; 25] for test compatibility
+impl PartialEq<[u64; 25]> for CrcKeysStorage {
+    fn eq(&self, other: &[u64; 25]) -> bool {
+        self.key_count() == 25
+            && other
+                .iter()
+                .enumerate()
+                .all(|(i, key)| self.get_key(i) == *key)
+    }
+}
+
+impl PartialEq<CrcKeysStorage> for [u64; 25] {
+    fn eq(&self, other: &CrcKeysStorage) -> bool {
+        other == self
+    }
+}
+
+/// Where a `CrcParams` instance's folding keys came from, as reported by `CrcParams::key_source()`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KeySource {
+    /// Compiled in, for the predefined algorithms (and, with the `key-presets` feature, custom
+    /// parameters which share a preset's polynomial)
+    Const,
+    /// Shared with the key cache, so other custom parameters with the same polynomial get them
+    /// from a cache hit
+    Cached,
+    /// Generated for these parameters, and not (or no longer) in the key cache, such as with the
+    /// `no-key-cache` feature or after `clear_key_cache()`
+    Generated,
+}
+
 /// Parameters for CRC computation, including polynomial, initial value, and other settings.
-#[derive(Clone, Copy, Debug)]
+///
+/// `Display` shows them in the CRC catalogue's notation, along with where the folding keys came
+/// from, which helps when custom parameters produce unexpected checksums.
+///
+/// Equality and hashing only consider the parameters which determine the checksums (`width`,
+/// `poly`, `init`, `refin`, `refout`, and `xorout`), so custom parameters equal the built-in
+/// algorithm they describe, whatever their name or key format.
+#[derive(Clone, Copy)]
 pub struct CrcParams {
     pub algorithm: CrcAlgorithm,
     pub name: &'static str,
@@ -292,6 +480,30 @@ pub struct CrcParams {
     pub keys: CrcKeysStorage,
 }
 
+impl PartialEq for CrcParams {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width
+            && self.poly == other.poly
+            && self.init == other.init
+            && self.refin == other.refin
+            && self.refout == other.refout
+            && self.xorout == other.xorout
+    }
+}
+
+impl Eq for CrcParams {}
+
+impl Hash for CrcParams {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.poly.hash(state);
+        self.init.hash(state);
+        self.refin.hash(state);
+        self.refout.hash(state);
+        self.xorout.hash(state);
+    }
+}
+
 /// Type alias for a function pointer that represents a CRC calculation function.
 ///
 /// The function takes the following parameters:
@@ -316,14 +528,28 @@ pub struct Digest {
     /// The current state of the CRC computation.
     state: u64,
 
-    /// The total amount of data processed so far.
-    amount: u64,
+    /// The state the CRC computation started from, which `reset_keep_params()` returns to.
+    init_state: u64,
+
+    /// The total amount of data processed so far, which is wider than u64 since combining
+    /// Digests can logically describe more data than could ever be processed directly.
+    amount: u128,
 
     /// The parameters for the CRC computation, such as polynomial, initial value, etc.
     params: CrcParams,
 
     /// The function used to perform the CRC calculation.
     calculator: CalculatorFn,
+
+    /// The input size at or above which updates are split across cores, overriding the global
+    /// threshold if set.
+    parallel_threshold: Option<usize>,
+
+    /// Accumulates tiny updates before they're calculated, if enabled.
+    staging: Option<StagingBuffer>,
+
+    /// Counts the calculations made, if enabled.
+    perf_stats: Option<PerfStats>,
 }
 
 impl DynDigest for Digest {
@@ -338,13 +564,7 @@ impl DynDigest for Digest {
             return Err(InvalidBufferSize);
         }
 
-        let result = self.finalize();
-        let bytes = if self.output_size() == 4 {
-            result.to_be_bytes()[4..].to_vec() // Take last 4 bytes for 32-bit CRC
-        } else {
-            result.to_be_bytes().to_vec() // Use all 8 bytes for 64-bit CRC
-        };
-        buf.copy_from_slice(&bytes[..self.output_size()]);
+        write_checksum_bytes(self.finalize(), self.params.width, buf);
 
         Ok(())
     }
@@ -354,14 +574,10 @@ impl DynDigest for Digest {
         if out.len() != self.output_size() {
             return Err(InvalidBufferSize);
         }
-        let result = self.finalize();
+
+        write_checksum_bytes(self.finalize(), self.params.width, out);
         self.reset();
-        let bytes = if self.output_size() == 4 {
-            result.to_be_bytes()[4..].to_vec() // Take last 4 bytes for 32-bit CRC
-        } else {
-            result.to_be_bytes().to_vec() // Use all 8 bytes for 64-bit CRC
-        };
-        out.copy_from_slice(&bytes[..self.output_size()]);
+
         Ok(())
     }
 
@@ -372,7 +588,7 @@ impl DynDigest for Digest {
 
     #[inline(always)]
     fn output_size(&self) -> usize {
-        self.params.width as usize / 8
+        checksum_byte_len(self.params.width)
     }
 
     fn box_clone(&self) -> Box<dyn DynDigest> {
@@ -380,6 +596,73 @@ impl DynDigest for Digest {
     }
 }
 
+/// A `DynDigest` which can be downcast to its concrete type.
+///
+/// `DynDigest` has no `Any` bound, so a `Box<dyn DynDigest>` can't recover the `Digest` behind it.
+/// Generic checksum registries can hold `Box<dyn DynDigestAny>` instead, which is still usable as a
+/// `DynDigest`, and downcast it to read the algorithm and amount.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{Digest, DynDigestAny, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let mut hasher: Box<dyn DynDigestAny> = Box::new(Digest::new(Crc32IsoHdlc));
+/// hasher.update(b"123456789");
+///
+/// let digest = hasher.as_any().downcast_ref::<Digest>().unwrap();
+///
+/// assert_eq!(digest.algorithm(), Crc32IsoHdlc);
+/// assert_eq!(digest.get_amount(), 9);
+/// ```
+pub trait DynDigestAny: DynDigest {
+    /// Returns this digest as `Any`, for downcasting by reference.
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns this digest as `Any`, for downcasting by mutable reference.
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Converts this boxed digest to `Any`, for downcasting by value.
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<T: DynDigest + Any> DynDigestAny for T {
+    #[inline(always)]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[inline(always)]
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    #[inline(always)]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+/// Returns the number of bytes needed to hold a checksum of `width` bits, rounding up for widths
+/// which aren't a multiple of 8 (such as CRC-24 or CRC-17)
+#[inline(always)]
+fn checksum_byte_len(width: u8) -> usize {
+    (width as usize).div_ceil(8)
+}
+
+/// Writes the low `width` bits of a checksum to `buf` as big-endian bytes, with any unused high
+/// bits of the first byte cleared. `buf` must be `checksum_byte_len(width)` bytes long.
+#[inline(always)]
+fn write_checksum_bytes(checksum: u64, width: u8, buf: &mut [u8]) {
+    let masked = if width >= 64 {
+        checksum
+    } else {
+        checksum & ((1u64 << width) - 1)
+    };
+
+    buf.copy_from_slice(&masked.to_be_bytes()[8 - checksum_byte_len(width)..]);
+}
+
 impl Digest {
     /// Creates a new `Digest` instance for the specified CRC algorithm.
     ///
@@ -400,9 +683,13 @@ impl Digest {
 
         Self {
             state: params.init,
+            init_state: params.init,
             amount: 0,
             params,
             calculator,
+            parallel_threshold: None,
+            staging: None,
+            perf_stats: None,
         }
     }
 
@@ -435,9 +722,13 @@ impl Digest {
 
         Self {
             state: init_state,
+            init_state,
             amount: 0,
             params,
             calculator,
+            parallel_threshold: None,
+            staging: None,
+            perf_stats: None,
         }
     }
 
@@ -471,118 +762,534 @@ impl Digest {
 
         Self {
             state: params.init,
+            init_state: params.init,
             amount: 0,
             params,
             calculator,
+            parallel_threshold: None,
+            staging: None,
+            perf_stats: None,
         }
     }
 
+    /// Creates a new `Digest` instance with custom CRC parameters and a custom initial state, for
+    /// resuming or protocol-specific seeding with user-defined algorithms.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{Digest, CrcParams};
+    ///
+    /// // Define custom CRC-32 parameters (equivalent to CRC-32/ISO-HDLC)
+    /// let custom_params = CrcParams::new(
+    ///     "CRC-32/CUSTOM",
+    ///     32,
+    ///     0x04c11db7,
+    ///     0xffffffff,
+    ///     true,
+    ///     0xffffffff,
+    ///     0xcbf43926,
+    /// );
+    ///
+    /// let mut digest = Digest::new_with_params(custom_params);
+    /// digest.update(b"1234");
+    ///
+    /// // resume from the saved state
+    /// let mut digest = Digest::new_with_params_and_state(custom_params, digest.get_state());
+    /// digest.update(b"56789");
+    ///
+    /// assert_eq!(digest.finalize(), 0xcbf43926);
+    /// ```
+    #[inline(always)]
+    pub fn new_with_params_and_state(params: CrcParams, state: u64) -> Self {
+        let mut digest = Self::new_with_params(params);
+        digest.reset_with_state(state);
+
+        digest
+    }
+
     /// Updates the CRC state with the given data.
+    ///
+    /// Data at least as large as the parallelism threshold is split across cores, see
+    /// `set_parallel_threshold()`.
     #[inline(always)]
     pub fn update(&mut self, data: &[u8]) {
-        self.state = (self.calculator)(self.state, data, self.params);
-        self.amount += data.len() as u64;
+        if let Some(staging) = self.staging.as_mut() {
+            if staging.try_push(data) {
+                record_staged(&mut self.perf_stats, data.len());
+                self.amount += data.len() as u128;
+                return;
+            }
+
+            // calculate what's been staged so far, and then stage this update if it fits (if
+            // nothing was staged, it's too large for the buffer and there's nothing to flush)
+            if !staging.is_empty() {
+                record_calculation(&mut self.perf_stats, staging.as_slice().len());
+                self.state = (self.calculator)(self.state, staging.as_slice(), self.params);
+                staging.clear();
+
+                if staging.try_push(data) {
+                    record_staged(&mut self.perf_stats, data.len());
+                    self.amount += data.len() as u128;
+                    return;
+                }
+            }
+        }
+
+        record_calculation(&mut self.perf_stats, data.len());
+        self.state = parallel::update(
+            self.state,
+            data,
+            self.params,
+            self.calculator,
+            self.parallel_threshold,
+        );
+        self.amount += data.len() as u128;
     }
 
-    /// Finalizes the CRC computation and returns the result.
-    #[inline(always)]
-    pub fn finalize(&self) -> u64 {
-        self.state ^ self.params.xorout
+    /// Updates the CRC state with data which wraps around a ring buffer, such as the two halves
+    /// from `VecDeque::as_slices()` or a DMA ring, as if `head` and `tail` were contiguous.
+    ///
+    /// Rather than updating with each half separately, a short half is joined with the adjacent
+    /// end of the other, so every calculation is long enough for the fast folding path, and wraps
+    /// which are short in total are calculated in a single pass.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::collections::VecDeque;
+    /// use crc_fast::{Digest, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let mut ring = VecDeque::with_capacity(9);
+    /// ring.extend(b"xx1234567");
+    /// ring.drain(..2);
+    /// ring.extend(b"89");
+    ///
+    /// let (head, tail) = ring.as_slices();
+    ///
+    /// let mut digest = Digest::new(Crc32IsoHdlc);
+    /// digest.update_split(head, tail);
+    ///
+    /// assert_eq!(digest.finalize(), 0xcbf43926);
+    /// ```
+    #[inline]
+    pub fn update_split(&mut self, head: &[u8], tail: &[u8]) {
+        const MIN: usize = consts::CRC_LARGE_CHUNK_THRESHOLD;
+
+        let mut bridge = [0u8; 2 * MIN];
+
+        if head.len() + tail.len() <= bridge.len() {
+            bridge[..head.len()].copy_from_slice(head);
+            bridge[head.len()..head.len() + tail.len()].copy_from_slice(tail);
+
+            self.update(&bridge[..head.len() + tail.len()]);
+        } else if head.len() < MIN {
+            // join the head with the start of the tail
+            let (start, rest) = tail.split_at(MIN - head.len());
+            bridge[..head.len()].copy_from_slice(head);
+            bridge[head.len()..MIN].copy_from_slice(start);
+
+            self.update(&bridge[..MIN]);
+            self.update(rest);
+        } else if tail.len() < MIN {
+            // join the end of the head with the tail
+            let (rest, end) = head.split_at(head.len() - (MIN - tail.len()));
+            bridge[..end.len()].copy_from_slice(end);
+            bridge[end.len()..MIN].copy_from_slice(tail);
+
+            self.update(rest);
+            self.update(&bridge[..MIN]);
+        } else {
+            self.update(head);
+            self.update(tail);
+        }
     }
 
-    /// Finalizes the CRC computation, resets the state, and returns the result.
+    /// Updates the CRC state with the initialized start of a buffer which may not be fully
+    /// initialized, such as a DMA or NIC receive buffer, without ever creating a `&[u8]` over the
+    /// uninitialized remainder.
+    ///
+    /// # Safety
+    ///
+    /// The first `initialized` bytes of `buf` must be initialized (e.g. the device must have
+    /// completed its write), and mustn't be written to during the update.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `initialized` is greater than the length of `buf`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{Digest, CrcAlgorithm::Crc32Iscsi};
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut buf = [MaybeUninit::<u8>::uninit(); 2048];
+    ///
+    /// // the device writes a 9-byte frame into the start of the buffer
+    /// for (slot, byte) in buf.iter_mut().zip(b"123456789") {
+    ///     slot.write(*byte);
+    /// }
+    ///
+    /// let mut digest = Digest::new(Crc32Iscsi);
+    /// unsafe { digest.update_uninit(&buf, 9) };
+    ///
+    /// assert_eq!(digest.finalize(), 0xe3069283);
+    /// ```
     #[inline(always)]
-    pub fn finalize_reset(&mut self) -> u64 {
-        let result = self.finalize();
-        self.reset();
+    pub unsafe fn update_uninit(&mut self, buf: &[MaybeUninit<u8>], initialized: usize) {
+        assert!(
+            initialized <= buf.len(),
+            "initialized length {initialized} exceeds the buffer length {}",
+            buf.len()
+        );
 
-        result
+        // the caller guarantees the prefix is initialized, and MaybeUninit<u8> has u8's layout
+        self.update(slice::from_raw_parts(
+            buf.as_ptr() as *const u8,
+            initialized,
+        ));
     }
 
-    /// Resets the CRC state to its initial value.
+    /// Updates the CRC state with `len` bytes starting at a raw pointer, such as a buffer owned by
+    /// a device driver. A null pointer is allowed when `len` is zero.
+    ///
+    /// # Safety
+    ///
+    /// Unless `len` is zero, `data` must be valid for reads of `len` initialized bytes, which
+    /// mustn't be written to during the update (the same requirements as
+    /// `std::slice::from_raw_parts()`).
     #[inline(always)]
-    pub fn reset(&mut self) {
-        self.state = self.params.init;
-        self.amount = 0;
-    }
+    pub unsafe fn update_raw(&mut self, data: *const u8, len: usize) {
+        if len == 0 {
+            return;
+        }
 
-    /// Combines the CRC state with a second `Digest` instance.
-    #[inline(always)]
-    pub fn combine(&mut self, other: &Self) {
-        self.amount += other.amount;
-        let other_crc = other.finalize();
+        self.update(slice::from_raw_parts(data, len));
+    }
 
-        // note the xorout for the input, since it's already been applied so it has to be removed,
-        // and then re-adding it on the final output
-        self.state = combine::checksums(
-            self.state ^ self.params.xorout,
-            other_crc,
-            other.amount,
-            self.params,
-        ) ^ self.params.xorout;
+    /// Updates the CRC state with the given data, using non-temporal loads.
+    ///
+    /// Intended for data far larger than the last-level cache which won't be read again soon, so
+    /// calculating the CRC doesn't evict the working set of co-located services. See
+    /// `checksum_non_temporal()`.
+    #[inline]
+    pub fn update_non_temporal(&mut self, data: &[u8]) {
+        self.flush_staging();
+
+        record_calculation(&mut self.perf_stats, data.len());
+        self.state = non_temporal::update(self.state, data, self.params, self.calculator);
+        self.amount += data.len() as u128;
     }
 
-    /// Gets the amount of data processed so far
+    /// Sets the input size, in bytes, at or above which a single update of this `Digest` is split
+    /// across cores and then combined, or `None` to disable it, overriding the global threshold
+    /// from `set_parallel_threshold()`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{Digest, CrcAlgorithm::Crc64Nvme};
+    ///
+    /// let mut digest = Digest::new(Crc64Nvme);
+    /// digest.set_parallel_threshold(Some(256 * 1024 * 1024));
+    /// digest.update(b"123456789");
+    ///
+    /// assert_eq!(digest.finalize(), 0xae8b14860a799888);
+    /// ```
     #[inline(always)]
-    pub fn get_amount(&self) -> u64 {
-        self.amount
+    pub fn set_parallel_threshold(&mut self, threshold: Option<usize>) {
+        self.parallel_threshold = Some(parallel::threshold_or_disabled(threshold));
     }
 
-    /// Gets the current CRC state.
+    /// Enables or disables an internal staging buffer, which batches tiny updates (such as
+    /// protocol headers or varints) into a single calculation, where the per-call setup would
+    /// otherwise dominate.
+    ///
+    /// Updates are staged until the next one would overflow the 64-byte buffer, and larger
+    /// updates bypass it. Disabling it calculates anything still staged.
     ///
     /// # Examples
+    ///
     /// ```rust
     /// use crc_fast::{Digest, CrcAlgorithm::Crc32IsoHdlc};
     ///
     /// let mut digest = Digest::new(Crc32IsoHdlc);
-    /// digest.update(b"123456789");
-    /// let state = digest.get_state();
+    /// digest.set_staging_buffer(true);
     ///
-    /// // non-finalized state, so it won't match the final checksum
-    /// assert_eq!(state, 0x340bc6d9);
+    /// for fragment in [b"12", b"34", b"56", b"78"] {
+    ///     digest.update(fragment);
+    /// }
+    /// digest.update(b"9");
     ///
-    /// // finalized state will match the checksum
     /// assert_eq!(digest.finalize(), 0xcbf43926);
     /// ```
     #[inline(always)]
-    pub fn get_state(&self) -> u64 {
-        self.state
+    pub fn set_staging_buffer(&mut self, enabled: bool) {
+        if enabled {
+            self.staging.get_or_insert_with(StagingBuffer::new);
+        } else {
+            self.flush_staging();
+            self.staging = None;
+        }
     }
-}
 
-#[cfg(feature = "std")]
-impl Write for Digest {
+    /// Calculates anything in the staging buffer, leaving it empty.
     #[inline(always)]
-    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
-        self.update(buf);
-        Ok(buf.len())
+    fn flush_staging(&mut self) {
+        if let Some(staging) = self.staging.as_mut() {
+            if !staging.is_empty() {
+                record_calculation(&mut self.perf_stats, staging.as_slice().len());
+                self.state = (self.calculator)(self.state, staging.as_slice(), self.params);
+                staging.clear();
+            }
+        }
     }
 
+    /// Enables or disables performance counters, which count the calculations this Digest makes
+    /// (split by whether they were long enough for the folding kernel) and the bytes absorbed by
+    /// the staging buffer, to verify updates are hitting the SIMD path. Enabling them when they're
+    /// already enabled resets them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{Digest, CrcAlgorithm::Crc64Nvme};
+    ///
+    /// let mut digest = Digest::new(Crc64Nvme);
+    /// digest.set_perf_stats(true);
+    ///
+    /// digest.update(&[0u8; 4096]);
+    /// digest.update(b"123456789");
+    ///
+    /// let stats = digest.perf_stats().unwrap();
+    ///
+    /// assert_eq!((stats.kernel_invocations, stats.kernel_bytes), (1, 4096));
+    /// assert_eq!((stats.short_invocations, stats.short_bytes), (1, 9));
+    /// ```
     #[inline(always)]
-    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
-        let len: usize = bufs
-            .iter()
-            .map(|buf| {
-                self.update(buf);
-                buf.len()
-            })
-            .sum();
-
-        Ok(len)
+    pub fn set_perf_stats(&mut self, enabled: bool) {
+        self.perf_stats = enabled.then(PerfStats::new);
     }
 
+    /// Returns the performance counters, or `None` if they're disabled, see `set_perf_stats()`.
     #[inline(always)]
-    fn flush(&mut self) -> std::io::Result<()> {
-        Ok(())
+    pub fn perf_stats(&self) -> Option<PerfStats> {
+        self.perf_stats
     }
 
+    /// Returns the CRC state including anything in the staging buffer.
     #[inline(always)]
-    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
-        self.update(buf);
+    fn current_state(&self) -> u64 {
+        match &self.staging {
+            Some(staging) if !staging.is_empty() => {
+                (self.calculator)(self.state, staging.as_slice(), self.params)
+            }
+            _ => self.state,
+        }
+    }
 
-        Ok(())
+    /// Finalizes the CRC computation and returns the result.
+    #[inline(always)]
+    pub fn finalize(&self) -> u64 {
+        self.current_state() ^ self.params.xorout
     }
-}
+
+    /// Finalizes the CRC computation, resets the state, and returns the result.
+    #[inline(always)]
+    pub fn finalize_reset(&mut self) -> u64 {
+        let result = self.finalize();
+        self.reset();
+
+        result
+    }
+
+    /// Resets the CRC state to the algorithm's initial value.
+    ///
+    /// For Digests which started from a custom state, see `reset_keep_params()`.
+    #[inline(always)]
+    pub fn reset(&mut self) {
+        self.reset_with_state(self.params.init);
+    }
+
+    /// Resets the CRC state to the given state, as if the Digest had been created with
+    /// `new_with_init_state()`, keeping its parameters and settings, so pooled Digests which start
+    /// mid-stream can be reused without being reconstructed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{Digest, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let mut digest = Digest::new(Crc32IsoHdlc);
+    /// digest.update(b"1234");
+    /// let state = digest.get_state();
+    ///
+    /// digest.reset_with_state(state);
+    /// digest.update(b"56789");
+    ///
+    /// assert_eq!(digest.finalize(), 0xcbf43926);
+    /// ```
+    #[inline(always)]
+    pub fn reset_with_state(&mut self, state: u64) {
+        self.state = state;
+        self.init_state = state;
+        self.amount = 0;
+
+        if let Some(staging) = self.staging.as_mut() {
+            staging.clear();
+        }
+    }
+
+    /// Resets the CRC state to the state the Digest started from, which is either the state it was
+    /// created with, or the state it was last reset to (by `reset()` or `reset_with_state()`),
+    /// keeping its parameters and settings.
+    #[inline(always)]
+    pub fn reset_keep_params(&mut self) {
+        self.reset_with_state(self.init_state);
+    }
+
+    /// Combines the CRC state with a second `Digest` instance.
+    ///
+    /// Both Digests must use the same CRC parameters, which is only checked in debug builds, see
+    /// `try_combine()`.
+    #[inline(always)]
+    pub fn combine(&mut self, other: &Self) {
+        debug_assert!(
+            self.params == other.params,
+            "Combining a {} Digest with a {} Digest",
+            self.params.name,
+            other.params.name
+        );
+
+        self.flush_staging();
+        self.amount += other.amount;
+        let other_crc = other.finalize();
+
+        // note the xorout for the input, since it's already been applied so it has to be removed,
+        // and then re-adding it on the final output
+        self.state = combine::checksums_wide(
+            self.state ^ self.params.xorout,
+            other_crc,
+            other.amount,
+            self.params,
+        ) ^ self.params.xorout;
+    }
+
+    /// Combines the CRC state with a second `Digest` instance, like `combine()`, but returns an
+    /// error rather than a wrong answer if they use different CRC parameters.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CrcFastError::ParamsMismatch`] if the Digests use different CRC parameters, and
+    /// leaves this Digest unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{CrcFastError, Digest, CrcAlgorithm::{Crc32IsoHdlc, Crc64Nvme}};
+    ///
+    /// let mut digest = Digest::new(Crc32IsoHdlc);
+    /// digest.update(b"1234");
+    ///
+    /// let mut other = Digest::new(Crc32IsoHdlc);
+    /// other.update(b"56789");
+    ///
+    /// assert!(digest.try_combine(&other).is_ok());
+    /// assert_eq!(digest.finalize(), 0xcbf43926);
+    ///
+    /// assert!(matches!(
+    ///     digest.try_combine(&Digest::new(Crc64Nvme)),
+    ///     Err(CrcFastError::ParamsMismatch)
+    /// ));
+    /// ```
+    pub fn try_combine(&mut self, other: &Self) -> Result<(), CrcFastError> {
+        if self.params != other.params {
+            return Err(CrcFastError::ParamsMismatch);
+        }
+
+        self.combine(other);
+
+        Ok(())
+    }
+
+    /// Gets the amount of data processed so far.
+    ///
+    /// Saturates at `u64::MAX`, which combined Digests can exceed, see `get_amount_u128()`.
+    #[inline(always)]
+    pub fn get_amount(&self) -> u64 {
+        u64::try_from(self.amount).unwrap_or(u64::MAX)
+    }
+
+    /// Gets the amount of data processed so far, including data beyond `u64::MAX` bytes which
+    /// combined Digests can describe.
+    #[inline(always)]
+    pub fn get_amount_u128(&self) -> u128 {
+        self.amount
+    }
+
+    /// Gets the current CRC state.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use crc_fast::{Digest, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let mut digest = Digest::new(Crc32IsoHdlc);
+    /// digest.update(b"123456789");
+    /// let state = digest.get_state();
+    ///
+    /// // non-finalized state, so it won't match the final checksum
+    /// assert_eq!(state, 0x340bc6d9);
+    ///
+    /// // finalized state will match the checksum
+    /// assert_eq!(digest.finalize(), 0xcbf43926);
+    /// ```
+    #[inline(always)]
+    pub fn get_state(&self) -> u64 {
+        self.current_state()
+    }
+
+    /// Gets the CRC algorithm this Digest calculates, which is `Crc32Custom` or `Crc64Custom` for
+    /// custom parameters.
+    #[inline(always)]
+    pub fn algorithm(&self) -> CrcAlgorithm {
+        self.params.algorithm
+    }
+}
+
+#[cfg(feature = "std")]
+impl Write for Digest {
+    #[inline(always)]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline(always)]
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> std::io::Result<usize> {
+        let len: usize = bufs
+            .iter()
+            .map(|buf| {
+                self.update(buf);
+                buf.len()
+            })
+            .sum();
+
+        Ok(len)
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.update(buf);
+
+        Ok(())
+    }
+}
 
 /// Computes the CRC checksum for the given data using the specified algorithm.
 ///
@@ -596,7 +1303,103 @@ impl Write for Digest {
 pub fn checksum(algorithm: CrcAlgorithm, buf: &[u8]) -> u64 {
     let (calculator, params) = get_calculator_params(algorithm);
 
-    calculator(params.init, buf, params) ^ params.xorout
+    parallel::update(params.init, buf, params, calculator, None) ^ params.xorout
+}
+
+/// Computes the CRC checksum for the given data using the specified algorithm, in a const context,
+/// such as to embed the precomputed checksum of a protocol's magic header.
+///
+/// This uses a table-free bitwise calculation, with no SIMD or runtime feature detection, so it's
+/// much slower than `checksum()` at runtime, and is only meant for constants. Custom algorithms
+/// aren't supported, and (like algorithms excluded by `crc32-only` or `crc64-only`) fail to
+/// compile when used in a const context.
+///
+///```rust
+/// use crc_fast::{checksum_const, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// const MAGIC: &[u8] = b"123456789";
+/// const MAGIC_CRC: u64 = checksum_const(Crc32IsoHdlc, MAGIC);
+///
+/// assert_eq!(MAGIC_CRC, 0xcbf43926);
+/// assert_eq!(MAGIC_CRC, crc_fast::checksum(Crc32IsoHdlc, MAGIC));
+/// ```
+pub const fn checksum_const(algorithm: CrcAlgorithm, buf: &[u8]) -> u64 {
+    let params = get_builtin_params(algorithm);
+
+    bitwise::update(params.init, buf, params) ^ params.xorout
+}
+
+/// Returns the parameters for the specified built-in CRC algorithm, in a const context.
+const fn get_builtin_params(algorithm: CrcAlgorithm) -> CrcParams {
+    match algorithm {
+        #[cfg(not(feature = "crc64-only"))]
+        CrcAlgorithm::Crc32Aixm => CRC32_AIXM,
+        #[cfg(not(feature = "crc64-only"))]
+        CrcAlgorithm::Crc32Autosar => CRC32_AUTOSAR,
+        #[cfg(not(feature = "crc64-only"))]
+        CrcAlgorithm::Crc32Base91D => CRC32_BASE91_D,
+        #[cfg(not(feature = "crc64-only"))]
+        CrcAlgorithm::Crc32Bzip2 => CRC32_BZIP2,
+        #[cfg(not(feature = "crc64-only"))]
+        CrcAlgorithm::Crc32CdRomEdc => CRC32_CD_ROM_EDC,
+        #[cfg(not(feature = "crc64-only"))]
+        CrcAlgorithm::Crc32Cksum => CRC32_CKSUM,
+        CrcAlgorithm::Crc32Custom => {
+            panic!("Custom CRC-32 requires parameters via CrcParams::new()")
+        }
+        #[cfg(not(feature = "crc64-only"))]
+        CrcAlgorithm::Crc32Iscsi => CRC32_ISCSI,
+        #[cfg(not(feature = "crc64-only"))]
+        CrcAlgorithm::Crc32IsoHdlc => CRC32_ISO_HDLC,
+        #[cfg(not(feature = "crc64-only"))]
+        CrcAlgorithm::Crc32Jamcrc => CRC32_JAMCRC,
+        #[cfg(not(feature = "crc64-only"))]
+        CrcAlgorithm::Crc32Mef => CRC32_MEF,
+        #[cfg(not(feature = "crc64-only"))]
+        CrcAlgorithm::Crc32Mpeg2 => CRC32_MPEG_2,
+        #[cfg(not(feature = "crc64-only"))]
+        CrcAlgorithm::Crc32Xfer => CRC32_XFER,
+        CrcAlgorithm::Crc64Custom => {
+            panic!("Custom CRC-64 requires parameters via CrcParams::new()")
+        }
+        #[cfg(not(feature = "crc32-only"))]
+        CrcAlgorithm::Crc64Ecma182 => CRC64_ECMA_182,
+        #[cfg(not(feature = "crc32-only"))]
+        CrcAlgorithm::Crc64GoIso => CRC64_GO_ISO,
+        #[cfg(not(feature = "crc32-only"))]
+        CrcAlgorithm::Crc64Ms => CRC64_MS,
+        #[cfg(not(feature = "crc32-only"))]
+        CrcAlgorithm::Crc64Nvme => CRC64_NVME,
+        #[cfg(not(feature = "crc32-only"))]
+        CrcAlgorithm::Crc64Redis => CRC64_REDIS,
+        #[cfg(not(feature = "crc32-only"))]
+        CrcAlgorithm::Crc64We => CRC64_WE,
+        #[cfg(not(feature = "crc32-only"))]
+        CrcAlgorithm::Crc64Xz => CRC64_XZ,
+        #[cfg(any(feature = "crc32-only", feature = "crc64-only"))]
+        _ => panic!("Algorithm isn't compiled in, see the crc32-only and crc64-only features"),
+    }
+}
+
+/// Computes the checksum PHP's `hash("crc32", ...)` produces, which is CRC-32/BZIP2 with the
+/// checksum's bytes reversed.
+///
+/// This isn't PHP's `crc32()` function (or `hash("crc32b", ...)`), which is CRC-32/ISO-HDLC. To
+/// stream the data instead, use a `Crc32Bzip2` Digest and reverse the bytes of the finalized
+/// checksum with `(digest.finalize() as u32).swap_bytes()`.
+///
+/// <https://www.php.net/manual/en/function.hash-file.php#104836>
+///
+///```rust
+/// use crc_fast::php_crc32;
+///
+/// // the same as PHP's hash("crc32", "123456789")
+/// assert_eq!(php_crc32(b"123456789"), 0x181989fc);
+/// ```
+#[cfg(not(feature = "crc64-only"))]
+#[inline(always)]
+pub fn php_crc32(buf: &[u8]) -> u32 {
+    (checksum(CrcAlgorithm::Crc32Bzip2, buf) as u32).swap_bytes()
 }
 
 /// Computes the CRC checksum for the given data using custom CRC parameters.
@@ -619,12 +1422,277 @@ pub fn checksum(algorithm: CrcAlgorithm, buf: &[u8]) -> u64 {
 ///
 /// let checksum = checksum_with_params(custom_params, b"123456789");
 ///
-/// assert_eq!(checksum, 0xcbf43926);
-/// ```
-pub fn checksum_with_params(params: CrcParams, buf: &[u8]) -> u64 {
+/// assert_eq!(checksum, 0xcbf43926);
+/// ```
+pub fn checksum_with_params(params: CrcParams, buf: &[u8]) -> u64 {
+    let calculator = Calculator::calculate as CalculatorFn;
+
+    parallel::update(params.init, buf, params, calculator, None) ^ params.xorout
+}
+
+/// Computes the CRC checksum for the given data using the specified algorithm, using
+/// non-temporal loads.
+///
+/// For single-pass checksums of buffers far larger than the last-level cache, the data is
+/// prefetched with a non-temporal (streaming) hint, so checksumming doesn't evict the working set
+/// of co-located services from the shared caches. This bypasses the hardware prefetchers, so it
+/// trades some throughput for cache friendliness. For smaller or frequently re-read buffers, use
+/// `checksum()` instead.
+///
+///```rust
+/// use crc_fast::{checksum_non_temporal, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let checksum = checksum_non_temporal(Crc32IsoHdlc, b"123456789");
+///
+/// assert_eq!(checksum, 0xcbf43926);
+/// ```
+pub fn checksum_non_temporal(algorithm: CrcAlgorithm, buf: &[u8]) -> u64 {
+    let (calculator, params) = get_calculator_params(algorithm);
+
+    non_temporal::update(params.init, buf, params, calculator) ^ params.xorout
+}
+
+/// Computes the CRC checksum for the given data using custom CRC parameters, using non-temporal
+/// loads.
+///
+/// See `checksum_non_temporal()` for details.
+pub fn checksum_non_temporal_with_params(params: CrcParams, buf: &[u8]) -> u64 {
+    let calculator = Calculator::calculate as CalculatorFn;
+
+    non_temporal::update(params.init, buf, params, calculator) ^ params.xorout
+}
+
+/// Computes the CRC checksum for the given data using the specified algorithm and performance
+/// tier, rather than the selected one.
+///
+/// Intended for benchmark harnesses and support engineers comparing kernels on the same machine
+/// without rebuilding or forcing a global tier. The tier's carryless multiplication folding is
+/// always used, without fusion, so fused algorithms (such as CRC-32/ISCSI) may be slower than with
+/// `checksum()`. Tiers can be listed with `available_tiers()`.
+///
+/// # Errors
+///
+/// Returns [`ForceTierError::Unsupported`] if the tier isn't supported on this system (including
+/// `software-fallback-tables` where a SIMD tier is available, and tiers which need a disabled CPU
+/// feature).
+///
+/// # Examples
+///
+///```rust
+/// use crc_fast::{available_tiers, checksum_with_tier, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// for tier in available_tiers() {
+///     assert_eq!(checksum_with_tier(Crc32IsoHdlc, b"123456789", tier), Ok(0xcbf43926));
+/// }
+/// ```
+pub fn checksum_with_tier(
+    algorithm: CrcAlgorithm,
+    buf: &[u8],
+    tier: PerformanceTier,
+) -> Result<u64, ForceTierError> {
+    let (_, params) = get_calculator_params(algorithm);
+    let arch_ops = feature_detection::arch_ops_for_tier(tier)?;
+
+    // SAFETY: the tier is supported by this CPU
+    let state = unsafe { arch::update_with_arch_ops(params.init, buf, params, &arch_ops) };
+
+    Ok(state ^ params.xorout)
+}
+
+/// Computes the CRC checksum for the given data using the specified algorithm, for buffers which
+/// are (or are mostly) aligned to `CHECKSUM_ALIGNMENT` bytes.
+///
+/// Page-aligned buffers, such as memory-mapped files, can be checksummed in place with no copies,
+/// and get the best-case SIMD kernel from the first byte. Misaligned buffers are still safe: any
+/// bytes before the first aligned block are calculated separately, and the rest from the aligned
+/// block. Unlike `checksum()`, large inputs aren't split across cores, since that would split
+/// them on unaligned boundaries.
+///
+///```rust
+/// use crc_fast::{checksum_aligned, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let checksum = checksum_aligned(Crc32IsoHdlc, b"123456789");
+///
+/// assert_eq!(checksum, 0xcbf43926);
+/// ```
+pub fn checksum_aligned(algorithm: CrcAlgorithm, buf: &[u8]) -> u64 {
+    let (calculator, params) = get_calculator_params(algorithm);
+
+    aligned::update(params.init, buf, params, calculator) ^ params.xorout
+}
+
+/// Computes the CRC checksum for the given data using custom CRC parameters, for buffers which are
+/// (or are mostly) aligned to `CHECKSUM_ALIGNMENT` bytes.
+///
+/// See `checksum_aligned()` for details.
+pub fn checksum_aligned_with_params(params: CrcParams, buf: &[u8]) -> u64 {
+    let calculator = Calculator::calculate as CalculatorFn;
+
+    aligned::update(params.init, buf, params, calculator) ^ params.xorout
+}
+
+/// Computes the CRC checksums of several independent buffers at once using the specified
+/// algorithm.
+///
+/// The folding of every buffer is interleaved on a single core, which hides the latency of the
+/// carryless multiplications, so this is faster than calling `checksum()` for each buffer when
+/// checksumming many small-to-medium buffers. Three or four buffers of similar lengths are
+/// usually the sweet spot.
+///
+/// Algorithms which use fusion (such as CRC-32/ISCSI) are already interleaved internally, as is
+/// 512-bit VPCLMULQDQ folding, so in those cases the buffers are checksummed one at a time.
+///
+///```rust
+/// use crc_fast::{checksum, checksum_multi, CrcAlgorithm::Crc64Nvme};
+///
+/// let (a, b, c) = ([1u8; 4096], [2u8; 4096], [3u8; 4000]);
+/// let checksums = checksum_multi(Crc64Nvme, [&a, &b, &c]);
+///
+/// assert_eq!(checksums[2], checksum(Crc64Nvme, &c));
+/// ```
+pub fn checksum_multi<const N: usize>(algorithm: CrcAlgorithm, buffers: [&[u8]; N]) -> [u64; N] {
+    let (calculator, params) = get_calculator_params(algorithm);
+
+    if uses_fusion(algorithm) {
+        return buffers.map(|buf| calculator(params.init, buf, params) ^ params.xorout);
+    }
+
+    checksum_multi_with_params(params, buffers)
+}
+
+/// Computes the CRC checksums of several independent buffers at once using custom CRC
+/// parameters.
+///
+/// See `checksum_multi()` for details.
+pub fn checksum_multi_with_params<const N: usize>(
+    params: CrcParams,
+    buffers: [&[u8]; N],
+) -> [u64; N] {
+    use crate::feature_detection::get_arch_ops;
+
+    let states = unsafe {
+        arch::update_multi_with_arch_ops([params.init; N], buffers, params, get_arch_ops())
+    };
+
+    states.map(|state| state ^ params.xorout)
+}
+
+/// Computes the CRC checksums of many independent buffers using the specified algorithm.
+///
+/// The dispatch and parameter lookup happen once for the whole batch, and buffers of similar
+/// lengths are grouped and checksummed together with the interleaved kernel from
+/// `checksum_multi()`, which makes this ideal for packet processors and object stores hashing
+/// thousands of buffers per second.
+///
+/// The checksums are returned in the same order as the buffers.
+///
+///```rust
+/// use crc_fast::{checksum, checksum_batch, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let packets: [&[u8]; 3] = [b"123456789", b"1234", b"56789"];
+/// let checksums = checksum_batch(Crc32IsoHdlc, &packets);
+///
+/// assert_eq!(checksums[0], 0xcbf43926);
+/// assert_eq!(checksums[2], checksum(Crc32IsoHdlc, b"56789"));
+/// ```
+pub fn checksum_batch(algorithm: CrcAlgorithm, buffers: &[&[u8]]) -> Vec<u64> {
+    let (calculator, params) = get_calculator_params(algorithm);
+
+    if uses_fusion(algorithm) {
+        return buffers
+            .iter()
+            .map(|buf| calculator(params.init, buf, params) ^ params.xorout)
+            .collect();
+    }
+
+    checksum_batch_with_params(params, buffers)
+}
+
+/// Computes the CRC checksums of many independent buffers using custom CRC parameters.
+///
+/// See `checksum_batch()` for details.
+pub fn checksum_batch_with_params(params: CrcParams, buffers: &[&[u8]]) -> Vec<u64> {
+    use crate::consts::CRC_BATCH_STREAMS;
+    use crate::feature_detection::get_arch_ops;
+
+    let arch_ops = get_arch_ops();
+
+    // only the blocks which every interleaved buffer has are interleaved, so group by length
+    let mut order: Vec<usize> = (0..buffers.len()).collect();
+    order.sort_unstable_by_key(|&i| buffers[i].len());
+
+    let mut checksums = vec![0; buffers.len()];
+
+    let mut groups = order.chunks_exact(CRC_BATCH_STREAMS);
+    for group in &mut groups {
+        let indices: [usize; CRC_BATCH_STREAMS] = group.try_into().unwrap();
+
+        let states = unsafe {
+            arch::update_multi_with_arch_ops(
+                [params.init; CRC_BATCH_STREAMS],
+                indices.map(|i| buffers[i]),
+                params,
+                arch_ops,
+            )
+        };
+
+        for (i, state) in indices.into_iter().zip(states) {
+            checksums[i] = state ^ params.xorout;
+        }
+    }
+
+    for &i in groups.remainder() {
+        checksums[i] = unsafe {
+            arch::update_with_arch_ops(params.init, buffers[i], params, arch_ops) ^ params.xorout
+        };
+    }
+
+    checksums
+}
+
+/// Computes the CRC checksum of data arriving as a sequence of chunks, such as the frames of an
+/// HTTP body or the messages from a queue, as if they were one contiguous buffer.
+///
+///```rust
+/// use crc_fast::{checksum_from_iter, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let frames = vec![b"1234".to_vec(), b"".to_vec(), b"56789".to_vec()];
+///
+/// assert_eq!(checksum_from_iter(Crc32IsoHdlc, &frames), 0xcbf43926);
+/// ```
+pub fn checksum_from_iter<I>(algorithm: CrcAlgorithm, chunks: I) -> u64
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    let (calculator, params) = get_calculator_params(algorithm);
+
+    update_from_iter(params.init, chunks, params, calculator) ^ params.xorout
+}
+
+/// Computes the CRC checksum of data arriving as a sequence of chunks using custom CRC parameters.
+///
+/// See `checksum_from_iter()` for details.
+pub fn checksum_from_iter_with_params<I>(params: CrcParams, chunks: I) -> u64
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
     let calculator = Calculator::calculate as CalculatorFn;
 
-    calculator(params.init, buf, params) ^ params.xorout
+    update_from_iter(params.init, chunks, params, calculator) ^ params.xorout
+}
+
+/// Updates the state with each chunk in turn
+#[inline(always)]
+fn update_from_iter<I>(state: u64, chunks: I, params: CrcParams, calculator: CalculatorFn) -> u64
+where
+    I: IntoIterator,
+    I::Item: AsRef<[u8]>,
+{
+    chunks.into_iter().fold(state, |state, chunk| {
+        parallel::update(state, chunk.as_ref(), params, calculator, None)
+    })
 }
 
 /// Computes the CRC checksum for the given file using the specified algorithm.
@@ -655,8 +1723,9 @@ pub fn checksum_file(
     algorithm: CrcAlgorithm,
     path: &str,
     chunk_size: Option<usize>,
-) -> Result<u64, std::io::Error> {
+) -> Result<u64, CrcFastError> {
     checksum_file_with_digest(Digest::new(algorithm), path, chunk_size)
+        .map(|file_checksum| file_checksum.checksum)
 }
 
 /// Computes the CRC checksum for the given file using custom CRC parameters.
@@ -697,7 +1766,69 @@ pub fn checksum_file_with_params(
     params: CrcParams,
     path: &str,
     chunk_size: Option<usize>,
-) -> Result<u64, std::io::Error> {
+) -> Result<u64, CrcFastError> {
+    checksum_file_with_digest(Digest::new_with_params(params), path, chunk_size)
+        .map(|file_checksum| file_checksum.checksum)
+}
+
+/// The checksum of a file, and the number of bytes which were read to calculate it
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FileChecksum {
+    /// The checksum of the file
+    pub checksum: u64,
+
+    /// The number of bytes read, which is the file's length when it wasn't modified while reading
+    pub len: u64,
+}
+
+/// Computes the CRC checksum for the given file using the specified algorithm, like
+/// `checksum_file()`, and also returns the number of bytes read.
+///
+/// The length can be cross-checked against the expected file size, and the result is a ready-made
+/// `(checksum, len)` pair for `checksum_combine()` or `checksum_combine_parts()`, without a second
+/// `stat` call (which could race with writers anyway).
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::env;
+/// use crc_fast::{checksum_file_with_len, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// // for example/test purposes only, use your own file path
+/// let file_path = env::current_dir().expect("missing working dir").join("crc-check.txt");
+/// let file_on_disk = file_path.to_str().unwrap();
+///
+/// let file_checksum = checksum_file_with_len(Crc32IsoHdlc, file_on_disk, None).unwrap();
+///
+/// assert_eq!(file_checksum.checksum, 0xcbf43926);
+/// assert_eq!(file_checksum.len, 9);
+/// ```
+#[cfg(feature = "std")]
+pub fn checksum_file_with_len(
+    algorithm: CrcAlgorithm,
+    path: &str,
+    chunk_size: Option<usize>,
+) -> Result<FileChecksum, CrcFastError> {
+    checksum_file_with_digest(Digest::new(algorithm), path, chunk_size)
+}
+
+/// Computes the CRC checksum for the given file using custom CRC parameters, and also returns the
+/// number of bytes read. See `checksum_file_with_len()` for details.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read.
+#[cfg(feature = "std")]
+pub fn checksum_file_with_params_and_len(
+    params: CrcParams,
+    path: &str,
+    chunk_size: Option<usize>,
+) -> Result<FileChecksum, CrcFastError> {
     checksum_file_with_digest(Digest::new_with_params(params), path, chunk_size)
 }
 
@@ -711,26 +1842,320 @@ fn checksum_file_with_digest(
     mut digest: Digest,
     path: &str,
     chunk_size: Option<usize>,
-) -> Result<u64, std::io::Error> {
-    let mut file = File::open(path)?;
+) -> Result<FileChecksum, CrcFastError> {
+    let file = File::open(path)?;
+
+    let chunk_size = chunk_size.unwrap_or(consts::CRC_FILE_CHUNK_SIZE);
 
-    // 512KiB KiB was fastest in my benchmarks on an Apple M2 Ultra
-    //
-    // 4KiB ~7GiB/s
-    // 64KiB ~22 GiB/s
-    // 512KiB ~24 GiB/s
-    let chunk_size = chunk_size.unwrap_or(524288);
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!(
+        "checksum_file",
+        path,
+        chunk_size,
+        algorithm = %digest.params.algorithm,
+        target = %get_calculator_target(digest.params.algorithm),
+    )
+    .entered();
 
     let mut buf = vec![0; chunk_size];
 
-    while let Ok(n) = file.read(&mut buf) {
-        if n == 0 {
-            break;
+    update_from_reader(&mut digest, file, &mut buf)?;
+
+    #[cfg(feature = "tracing")]
+    tracing::debug!(total = digest.get_amount(), "checksummed file");
+
+    Ok(FileChecksum {
+        checksum: digest.finalize(),
+        len: digest.get_amount(),
+    })
+}
+
+/// Continues checksumming a file which has been appended to, such as a log, using the specified
+/// algorithm, from the `state` and `amount` of a Digest saved (via `get_state()` and
+/// `get_amount()`) when the file was last checksummed, reading only the newly appended bytes.
+///
+/// Returns the resumed Digest, which can be finalized for the updated whole-file checksum, and
+/// whose state and amount can be saved again for the next append.
+///
+/// If `verify_prefix` is true, the first `amount` bytes are re-read to verify they still match
+/// `state`, which costs a full read of the file, but catches files which were rewritten rather
+/// than appended to. This requires the saved Digest to have started from the algorithm's default
+/// initial state.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, or an error of kind
+/// `InvalidData` if the file is shorter than `amount` or (when verifying) its prefix has changed.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::env;
+/// use crc_fast::{checksum_file_append, CrcAlgorithm::Crc32IsoHdlc, Digest};
+///
+/// // for example/test purposes only, use your own file path
+/// let file_path = env::current_dir().expect("missing working dir").join("crc-check.txt");
+/// let file_on_disk = file_path.to_str().unwrap();
+///
+/// // the state saved when the file only contained "1234"
+/// let mut digest = Digest::new(Crc32IsoHdlc);
+/// digest.update(b"1234");
+///
+/// let digest =
+///     checksum_file_append(Crc32IsoHdlc, file_on_disk, digest.get_state(), 4, true).unwrap();
+///
+/// assert_eq!(digest.finalize(), 0xcbf43926);
+/// assert_eq!(digest.get_amount(), 9);
+/// ```
+#[cfg(feature = "std")]
+pub fn checksum_file_append(
+    algorithm: CrcAlgorithm,
+    path: &str,
+    state: u64,
+    amount: u64,
+    verify_prefix: bool,
+) -> Result<Digest, CrcFastError> {
+    checksum_file_append_with_digest(Digest::new(algorithm), path, state, amount, verify_prefix)
+}
+
+/// Continues checksumming a file which has been appended to, using custom CRC parameters. See
+/// `checksum_file_append()` for details.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, or an error of kind
+/// `InvalidData` if the file is shorter than `amount` or (when verifying) its prefix has changed.
+#[cfg(feature = "std")]
+pub fn checksum_file_append_with_params(
+    params: CrcParams,
+    path: &str,
+    state: u64,
+    amount: u64,
+    verify_prefix: bool,
+) -> Result<Digest, CrcFastError> {
+    checksum_file_append_with_digest(
+        Digest::new_with_params(params),
+        path,
+        state,
+        amount,
+        verify_prefix,
+    )
+}
+
+/// Continues checksumming a file which has been appended to, using the specified (new) Digest.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, is shorter than `amount`, or
+/// (when verifying) its prefix has changed.
+#[cfg(feature = "std")]
+fn checksum_file_append_with_digest(
+    mut digest: Digest,
+    path: &str,
+    state: u64,
+    amount: u64,
+    verify_prefix: bool,
+) -> Result<Digest, CrcFastError> {
+    use std::io::{Error, ErrorKind, Seek, SeekFrom};
+
+    let mut file = File::open(path)?;
+
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!(
+        "checksum_file_append",
+        path,
+        amount,
+        verify_prefix,
+        algorithm = %digest.params.algorithm,
+    )
+    .entered();
+
+    if file.metadata()?.len() < amount {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "file is shorter than the checksummed amount",
+        )
+        .into());
+    }
+
+    let mut buf = vec![0; consts::CRC_FILE_CHUNK_SIZE];
+
+    if verify_prefix {
+        update_from_reader(&mut digest, (&mut file).take(amount), &mut buf)?;
+
+        if digest.get_amount() != amount || digest.get_state() != state {
+            return Err(Error::new(ErrorKind::InvalidData, "file prefix has changed").into());
+        }
+    } else {
+        file.seek(SeekFrom::Start(amount))?;
+    }
+
+    digest.state = state;
+    digest.amount = amount.into();
+
+    update_from_reader(&mut digest, file, &mut buf)?;
+
+    Ok(digest)
+}
+
+/// Follows a file which is being written to (like `tail -f`) using the specified algorithm,
+/// checksumming new bytes as they're appended, and calling `callback` with the Digest after every
+/// poll, so incremental snapshots can be taken via `finalize()` and `get_amount()`.
+///
+/// The file is polled every `poll_interval` once all of its current contents have been read.
+/// Following stops, returning the Digest, when `callback` returns false.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, or an error of kind
+/// `InvalidData` if it's truncated while being followed.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::env;
+/// use std::time::Duration;
+/// use crc_fast::{checksum_file_follow, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// // for example/test purposes only, use your own file path
+/// let file_path = env::current_dir().expect("missing working dir").join("crc-check.txt");
+/// let file_on_disk = file_path.to_str().unwrap();
+///
+/// let digest = checksum_file_follow(
+///     Crc32IsoHdlc,
+///     file_on_disk,
+///     Duration::from_millis(100),
+///     |digest| digest.get_amount() < 9, // stop once the expected length has been seen
+/// )
+/// .unwrap();
+///
+/// assert_eq!(digest.finalize(), 0xcbf43926);
+/// ```
+#[cfg(feature = "std")]
+pub fn checksum_file_follow(
+    algorithm: CrcAlgorithm,
+    path: &str,
+    poll_interval: std::time::Duration,
+    callback: impl FnMut(&Digest) -> bool,
+) -> Result<Digest, CrcFastError> {
+    checksum_file_follow_with_digest(Digest::new(algorithm), path, poll_interval, callback)
+}
+
+/// Follows a file which is being written to (like `tail -f`) using custom CRC parameters. See
+/// `checksum_file_follow()` for details.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, or an error of kind
+/// `InvalidData` if it's truncated while being followed.
+#[cfg(feature = "std")]
+pub fn checksum_file_follow_with_params(
+    params: CrcParams,
+    path: &str,
+    poll_interval: std::time::Duration,
+    callback: impl FnMut(&Digest) -> bool,
+) -> Result<Digest, CrcFastError> {
+    checksum_file_follow_with_digest(
+        Digest::new_with_params(params),
+        path,
+        poll_interval,
+        callback,
+    )
+}
+
+/// Follows a file which is being written to, using the specified Digest.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, or is truncated.
+#[cfg(feature = "std")]
+fn checksum_file_follow_with_digest(
+    mut digest: Digest,
+    path: &str,
+    poll_interval: std::time::Duration,
+    mut callback: impl FnMut(&Digest) -> bool,
+) -> Result<Digest, CrcFastError> {
+    let mut file = File::open(path)?;
+    let mut buf = vec![0; consts::CRC_FILE_CHUNK_SIZE];
+
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!(
+        "checksum_file_follow",
+        path,
+        algorithm = %digest.params.algorithm,
+    )
+    .entered();
+
+    loop {
+        update_from_reader(&mut digest, &mut file, &mut buf)?;
+
+        if !callback(&digest) {
+            return Ok(digest);
+        }
+
+        std::thread::sleep(poll_interval);
+
+        if file.metadata()?.len() < digest.get_amount() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "file was truncated while being followed",
+            )
+            .into());
         }
-        digest.update(&buf[..n]);
     }
+}
+
+/// Updates the Digest with everything the reader returns, using the buffer
+#[cfg(feature = "std")]
+fn update_from_reader(
+    digest: &mut Digest,
+    mut reader: impl Read,
+    buf: &mut [u8],
+) -> Result<(), std::io::Error> {
+    loop {
+        match reader.read(buf) {
+            Ok(0) => return Ok(()),
+            Ok(n) => {
+                digest.update(&buf[..n]);
+
+                #[cfg(feature = "tracing")]
+                tracing::trace!(bytes = n, total = digest.get_amount(), "checksummed chunk");
+            }
+            Err(error) if error.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// Returns whether `value` is the algorithm's checksum of empty (zero-length) input.
+///
+/// Every entry point treats empty input the same way: `checksum()`, a `Digest` which was never
+/// updated (or only with empty slices), an empty file, and the C `crc_fast_checksum*()` functions
+/// with a `NULL` pointer and a length of 0 all return this checksum, which is `init ^ xorout` and
+/// isn't always 0 (e.g. it's `0xffffffff` for CRC-32/MPEG-2). Combining with an empty part
+/// (`checksum2_len` of 0 and this `checksum2`) returns `checksum1` unchanged.
+///
+/// Protocols which send a CRC with an empty payload can use this to distinguish an empty message
+/// from a missing or zeroed checksum field.
+///
+/// # Examples
+///```rust
+/// use crc_fast::{is_empty_crc, CrcAlgorithm::{Crc32IsoHdlc, Crc32Mpeg2}};
+///
+/// assert!(is_empty_crc(Crc32IsoHdlc, 0));
+/// assert!(is_empty_crc(Crc32Mpeg2, 0xffffffff));
+/// assert!(!is_empty_crc(Crc32Mpeg2, 0));
+/// ```
+#[inline(always)]
+pub fn is_empty_crc(algorithm: CrcAlgorithm, value: u64) -> bool {
+    is_empty_crc_with_params(get_calculator_params(algorithm).1, value)
+}
 
-    Ok(digest.finalize())
+/// Returns whether `value` is the checksum of empty (zero-length) input using custom CRC
+/// parameters. See `is_empty_crc()`.
+#[inline(always)]
+pub fn is_empty_crc_with_params(params: CrcParams, value: u64) -> bool {
+    value == params.init ^ params.xorout
 }
 
 /// Combines two CRC checksums using the specified algorithm.
@@ -745,6 +2170,9 @@ fn checksum_file_with_digest(
 ///
 /// assert_eq!(checksum, 0xcbf43926);
 /// ```
+///
+/// Both checksums must fit in the algorithm's width, which is only checked in debug builds, see
+/// `try_checksum_combine()`.
 #[inline(always)]
 pub fn checksum_combine(
     algorithm: CrcAlgorithm,
@@ -754,7 +2182,41 @@ pub fn checksum_combine(
 ) -> u64 {
     let params = get_calculator_params(algorithm).1;
 
-    combine::checksums(checksum1, checksum2, checksum2_len, params)
+    checksum_combine_with_params(params, checksum1, checksum2, checksum2_len)
+}
+
+/// Combines two CRC checksums using the specified algorithm, like `checksum_combine()`, but returns
+/// an error rather than a wrong answer if either checksum doesn't fit in the algorithm's width
+/// (such as a CRC-64 combined as a CRC-32).
+///
+/// # Errors
+///
+/// Returns [`CrcFastError::ChecksumTooWide`] if either checksum has bits set above the width.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, try_checksum_combine, CrcFastError, CrcAlgorithm::{Crc32IsoHdlc, Crc64Nvme}};
+///
+/// let checksum_1 = checksum(Crc32IsoHdlc, b"1234");
+/// let checksum_2 = checksum(Crc32IsoHdlc, b"56789");
+/// assert_eq!(try_checksum_combine(Crc32IsoHdlc, checksum_1, checksum_2, 5).unwrap(), 0xcbf43926);
+///
+/// let checksum_2 = checksum(Crc64Nvme, b"56789");
+/// assert!(matches!(
+///     try_checksum_combine(Crc32IsoHdlc, checksum_1, checksum_2, 5),
+///     Err(CrcFastError::ChecksumTooWide { width: 32, .. })
+/// ));
+/// ```
+pub fn try_checksum_combine(
+    algorithm: CrcAlgorithm,
+    checksum1: u64,
+    checksum2: u64,
+    checksum2_len: u64,
+) -> Result<u64, CrcFastError> {
+    let params = get_calculator_params(algorithm).1;
+
+    try_checksum_combine_with_params(params, checksum1, checksum2, checksum2_len)
 }
 
 /// Combines two CRC checksums using custom CRC parameters.
@@ -787,20 +2249,117 @@ pub fn checksum_combine_with_params(
     checksum2: u64,
     checksum2_len: u64,
 ) -> u64 {
+    debug_assert!(
+        combine::validate_width(checksum1, params).is_ok()
+            && combine::validate_width(checksum2, params).is_ok(),
+        "Checksums {checksum1:#x} and {checksum2:#x} don't fit in a {}-bit CRC",
+        params.width
+    );
+
     combine::checksums(checksum1, checksum2, checksum2_len, params)
 }
 
+/// Combines two CRC checksums using custom CRC parameters, returning an error if either checksum
+/// doesn't fit in the width. See `try_checksum_combine()`.
+pub fn try_checksum_combine_with_params(
+    params: CrcParams,
+    checksum1: u64,
+    checksum2: u64,
+    checksum2_len: u64,
+) -> Result<u64, CrcFastError> {
+    combine::validate_width(checksum1, params)?;
+    combine::validate_width(checksum2, params)?;
+
+    Ok(combine::checksums(
+        checksum1,
+        checksum2,
+        checksum2_len,
+        params,
+    ))
+}
+
+/// Combines the checksums of many consecutive parts, given as (checksum, length) pairs in order,
+/// into the checksum of the whole, using the specified algorithm.
+///
+/// Rather than a serial fold of `checksum_combine()`, the parts are reduced as a tree of pairs, and
+/// large levels of the tree are split across cores, which suits restoring the checksum of objects
+/// uploaded in thousands of parts.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, checksum_combine_parts, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let parts = [
+///     (checksum(Crc32IsoHdlc, b"123"), 3),
+///     (checksum(Crc32IsoHdlc, b"4567"), 4),
+///     (checksum(Crc32IsoHdlc, b"89"), 2),
+/// ];
+///
+/// assert_eq!(checksum_combine_parts(Crc32IsoHdlc, &parts), 0xcbf43926);
+/// ```
+pub fn checksum_combine_parts(algorithm: CrcAlgorithm, parts: &[(u64, u64)]) -> u64 {
+    checksum_combine_parts_with_params(get_calculator_params(algorithm).1, parts)
+}
+
+/// Combines the checksums of many consecutive parts, given as (checksum, length) pairs in order,
+/// into the checksum of the whole, using custom CRC parameters. See `checksum_combine_parts()`.
+pub fn checksum_combine_parts_with_params(params: CrcParams, parts: &[(u64, u64)]) -> u64 {
+    debug_assert!(
+        parts
+            .iter()
+            .all(|&(checksum, _)| combine::validate_width(checksum, params).is_ok()),
+        "A part's checksum doesn't fit in a {}-bit CRC",
+        params.width
+    );
+
+    parallel::combine_parts(parts, params)
+}
+
+/// Combines the checksums of many consecutive parts like `checksum_combine_parts()`, but returns an
+/// error rather than a wrong answer if any part's checksum doesn't fit in the algorithm's width.
+///
+/// # Errors
+///
+/// Returns [`CrcFastError::ChecksumTooWide`] for the first checksum with bits set above the width.
+pub fn try_checksum_combine_parts(
+    algorithm: CrcAlgorithm,
+    parts: &[(u64, u64)],
+) -> Result<u64, CrcFastError> {
+    try_checksum_combine_parts_with_params(get_calculator_params(algorithm).1, parts)
+}
+
+/// Combines the checksums of many consecutive parts using custom CRC parameters, returning an error
+/// if any part's checksum doesn't fit in the width. See `try_checksum_combine_parts()`.
+pub fn try_checksum_combine_parts_with_params(
+    params: CrcParams,
+    parts: &[(u64, u64)],
+) -> Result<u64, CrcFastError> {
+    for &(checksum, _) in parts {
+        combine::validate_width(checksum, params)?;
+    }
+
+    Ok(parallel::combine_parts(parts, params))
+}
+
 /// Returns the target used to calculate the CRC checksum for the specified algorithm.
 ///
 /// This function provides visibility into the active performance tier being used for CRC calculations.
 /// The target string follows the format `{architecture}-{intrinsics-family}-{intrinsics-features}`,
-/// such as `aarch64-aes-sha3` or `x86_64-avx512-vpclmulqdq`.
+/// such as `aarch64-neon-pmull-sha3` or `x86_64-avx512-vpclmulqdq`.
 ///
 /// The performance tier system provides graceful degradation across different hardware capabilities:
-/// - **AArch64**: `aarch64-aes-sha3` (highest) → `aarch64-aes-pmull` (baseline)
+/// - **AArch64**: `aarch64-neon-pmull-sha3` (highest) → `aarch64-neon-pmull` (baseline)
 /// - **x86_64**: `x86_64-avx512-vpclmulqdq` (highest) → `x86_64-avx512-pclmulqdq` (mid) → `x86_64-sse-pclmulqdq` (baseline)
+///   → `x86_64-sse-gfni` (experimental, only without PCLMULQDQ)
 /// - **x86**: `x86-sse-pclmulqdq` (baseline) → `software-fallback-tables` (fallback)
-/// - **Other architectures**: `software-fallback-tables`
+/// - **Other architectures**: `portable-simd-u64x8` (with the nightly-only `portable-simd` feature) →
+///   `software-fallback-tables`
+///
+/// Algorithms which use a fusion of native CRC instructions and carryless multiplication, rather
+/// than carryless multiplication alone, have a `-fusion` suffix, such as
+/// `x86_64-avx512-vpclmulqdq-fusion` for CRC-32/ISCSI, or `aarch64-neon-pmull-sha3-fusion` for
+/// CRC-32/ISCSI and CRC-32/ISO-HDLC.
 ///
 /// The tier selection is deterministic and consistent across runs on the same hardware,
 /// combining compile-time and runtime feature detection for safety and optimal performance.
@@ -815,46 +2374,121 @@ pub fn checksum_combine_with_params(
 /// let target = get_calculator_target(Crc32IsoHdlc);
 /// println!("Using performance tier: {}", target);
 /// // Example outputs:
-/// // "aarch64-aes-sha3" - AArch64 with SHA3 and AES support
+/// // "aarch64-neon-pmull-sha3-fusion" - AArch64 with SHA3 and AES support, using fusion
 /// // "x86_64-avx512-vpclmulqdq" - x86_64 with VPCLMULQDQ support
 /// // "x86_64-sse-pclmulqdq" - x86_64 baseline with SSE4.1 and PCLMULQDQ
 /// ```
-pub fn get_calculator_target(_algorithm: CrcAlgorithm) -> String {
+pub fn get_calculator_target(algorithm: CrcAlgorithm) -> String {
     use crate::feature_detection::get_arch_ops;
 
     let arch_ops = get_arch_ops();
-    arch_ops.get_target_string()
+    let target = arch_ops.get_target_string();
+
+    if uses_fusion(algorithm) && arch_ops.get_tier().supports_fusion() {
+        return format!("{target}-fusion");
+    }
+
+    target
+}
+
+/// Returns structured information about the hardware acceleration used to calculate the CRC
+/// checksum for the specified algorithm.
+///
+/// Unlike the informational string from `get_calculator_target()`, this is intended for monitoring
+/// and support tooling which needs to report capabilities without string parsing.
+///
+/// # Examples
+///```rust
+/// use crc_fast::{get_accelerator_info, CrcAlgorithm::Crc32Iscsi};
+///
+/// let info = get_accelerator_info(Crc32Iscsi);
+/// println!(
+///     "{} using {} ({}-bit SIMD, fusion: {}, features: {})",
+///     info.arch,
+///     info.tier,
+///     info.simd_width,
+///     info.uses_fusion,
+///     info.features.join(", ")
+/// );
+/// ```
+pub fn get_accelerator_info(algorithm: CrcAlgorithm) -> AcceleratorInfo {
+    use crate::feature_detection::get_arch_ops;
+
+    AcceleratorInfo::new(get_arch_ops().get_tier(), uses_fusion(algorithm))
+}
+
+/// Returns whether the specified CRC algorithm uses a fusion of native CRC instructions and
+/// carryless multiplication, rather than carryless multiplication alone.
+#[inline(always)]
+const fn uses_fusion(algorithm: CrcAlgorithm) -> bool {
+    if cfg!(feature = "crc64-only") {
+        return false;
+    }
+
+    match algorithm {
+        // both aarch64 and x86 have native CRC-32/ISCSI support
+        // (the aarch64 fused kernels assume little-endian loads)
+        CrcAlgorithm::Crc32Iscsi => cfg!(any(
+            all(target_arch = "aarch64", target_endian = "little"),
+            target_arch = "x86_64",
+            target_arch = "x86"
+        )),
+        // only aarch64 has native CRC-32/ISO-HDLC support
+        CrcAlgorithm::Crc32IsoHdlc => cfg!(all(target_arch = "aarch64", target_endian = "little")),
+        _ => false,
+    }
 }
 
 /// Returns the calculator function and parameters for the specified CRC algorithm.
 #[inline(always)]
 fn get_calculator_params(algorithm: CrcAlgorithm) -> (CalculatorFn, CrcParams) {
     match algorithm {
+        #[cfg(not(feature = "crc64-only"))]
         CrcAlgorithm::Crc32Aixm => (Calculator::calculate as CalculatorFn, CRC32_AIXM),
+        #[cfg(not(feature = "crc64-only"))]
         CrcAlgorithm::Crc32Autosar => (Calculator::calculate as CalculatorFn, CRC32_AUTOSAR),
+        #[cfg(not(feature = "crc64-only"))]
         CrcAlgorithm::Crc32Base91D => (Calculator::calculate as CalculatorFn, CRC32_BASE91_D),
+        #[cfg(not(feature = "crc64-only"))]
         CrcAlgorithm::Crc32Bzip2 => (Calculator::calculate as CalculatorFn, CRC32_BZIP2),
+        #[cfg(not(feature = "crc64-only"))]
         CrcAlgorithm::Crc32CdRomEdc => (Calculator::calculate as CalculatorFn, CRC32_CD_ROM_EDC),
+        #[cfg(not(feature = "crc64-only"))]
         CrcAlgorithm::Crc32Cksum => (Calculator::calculate as CalculatorFn, CRC32_CKSUM),
         CrcAlgorithm::Crc32Custom => {
             panic!("Custom CRC-32 requires parameters via CrcParams::new()")
         }
+        #[cfg(not(feature = "crc64-only"))]
         CrcAlgorithm::Crc32Iscsi => (crc32_iscsi_calculator as CalculatorFn, CRC32_ISCSI),
+        #[cfg(not(feature = "crc64-only"))]
         CrcAlgorithm::Crc32IsoHdlc => (crc32_iso_hdlc_calculator as CalculatorFn, CRC32_ISO_HDLC),
+        #[cfg(not(feature = "crc64-only"))]
         CrcAlgorithm::Crc32Jamcrc => (Calculator::calculate as CalculatorFn, CRC32_JAMCRC),
+        #[cfg(not(feature = "crc64-only"))]
         CrcAlgorithm::Crc32Mef => (Calculator::calculate as CalculatorFn, CRC32_MEF),
+        #[cfg(not(feature = "crc64-only"))]
         CrcAlgorithm::Crc32Mpeg2 => (Calculator::calculate as CalculatorFn, CRC32_MPEG_2),
+        #[cfg(not(feature = "crc64-only"))]
         CrcAlgorithm::Crc32Xfer => (Calculator::calculate as CalculatorFn, CRC32_XFER),
         CrcAlgorithm::Crc64Custom => {
             panic!("Custom CRC-64 requires parameters via CrcParams::new()")
         }
+        #[cfg(not(feature = "crc32-only"))]
         CrcAlgorithm::Crc64Ecma182 => (Calculator::calculate as CalculatorFn, CRC64_ECMA_182),
+        #[cfg(not(feature = "crc32-only"))]
         CrcAlgorithm::Crc64GoIso => (Calculator::calculate as CalculatorFn, CRC64_GO_ISO),
+        #[cfg(not(feature = "crc32-only"))]
         CrcAlgorithm::Crc64Ms => (Calculator::calculate as CalculatorFn, CRC64_MS),
+        #[cfg(not(feature = "crc32-only"))]
         CrcAlgorithm::Crc64Nvme => (Calculator::calculate as CalculatorFn, CRC64_NVME),
+        #[cfg(not(feature = "crc32-only"))]
         CrcAlgorithm::Crc64Redis => (Calculator::calculate as CalculatorFn, CRC64_REDIS),
+        #[cfg(not(feature = "crc32-only"))]
         CrcAlgorithm::Crc64We => (Calculator::calculate as CalculatorFn, CRC64_WE),
+        #[cfg(not(feature = "crc32-only"))]
         CrcAlgorithm::Crc64Xz => (Calculator::calculate as CalculatorFn, CRC64_XZ),
+        #[cfg(any(feature = "crc32-only", feature = "crc64-only"))]
+        _ => panic!("{algorithm} isn't compiled in, see the crc32-only and crc64-only features"),
     }
 }
 
@@ -862,19 +2496,29 @@ fn get_calculator_params(algorithm: CrcAlgorithm) -> (CalculatorFn, CrcParams) {
 ///
 /// Because both aarch64 and x86 have native hardware support for CRC-32/ISCSI, we can use
 /// fusion techniques to accelerate the calculation beyond what SIMD can do alone.
+#[cfg(not(feature = "crc64-only"))]
 #[inline(always)]
-fn crc32_iscsi_calculator(state: u64, data: &[u8], _params: CrcParams) -> u64 {
-    // both aarch64 and x86 have native CRC-32/ISCSI support, so we can use fusion
-    #[cfg(any(target_arch = "aarch64", target_arch = "x86_64", target_arch = "x86"))]
-    return fusion::crc32_iscsi(state as u32, data) as u64;
+pub(crate) fn crc32_iscsi_calculator(state: u64, data: &[u8], params: CrcParams) -> u64 {
+    if let Some(state) = non_fusion_update(state, data, params) {
+        return state;
+    }
 
-    #[cfg(all(
-        not(target_arch = "aarch64"),
-        not(target_arch = "x86_64"),
-        not(target_arch = "x86")
+    // both aarch64 and x86 have native CRC-32/ISCSI support, so we can use fusion (the aarch64
+    // kernels assume little-endian loads)
+    #[cfg(any(
+        all(target_arch = "aarch64", target_endian = "little"),
+        target_arch = "x86_64",
+        target_arch = "x86"
     ))]
+    return crc32::fusion::crc32_iscsi(state as u32, data) as u64;
+
+    #[cfg(not(any(
+        all(target_arch = "aarch64", target_endian = "little"),
+        target_arch = "x86_64",
+        target_arch = "x86"
+    )))]
     // Fallback to traditional calculation for other architectures
-    Calculator::calculate(state, data, _params)
+    Calculator::calculate(state, data, params)
 }
 
 /// Calculates the CRC-32/ISO-HDLC ("crc32" in many, but not all, implementations) checksum.
@@ -882,16 +2526,40 @@ fn crc32_iscsi_calculator(state: u64, data: &[u8], _params: CrcParams) -> u64 {
 /// Because aarch64 has native hardware support for CRC-32/ISO-HDLC, we can use fusion techniques
 /// to accelerate the calculation beyond what SIMD can do alone. x86 does not have native support,
 /// so we use the traditional calculation.
+#[cfg(not(feature = "crc64-only"))]
 #[inline(always)]
-fn crc32_iso_hdlc_calculator(state: u64, data: &[u8], _params: CrcParams) -> u64 {
+pub(crate) fn crc32_iso_hdlc_calculator(state: u64, data: &[u8], params: CrcParams) -> u64 {
+    if let Some(state) = non_fusion_update(state, data, params) {
+        return state;
+    }
+
     // aarch64 CPUs have native CRC-32/ISO-HDLC support, so we can use the fusion implementation
-    #[cfg(target_arch = "aarch64")]
-    return fusion::crc32_iso_hdlc(state as u32, data) as u64;
+    // (on little-endian, which its loads assume)
+    #[cfg(all(target_arch = "aarch64", target_endian = "little"))]
+    return crc32::fusion::crc32_iso_hdlc(state as u32, data) as u64;
 
     // x86 CPUs don't have native CRC-32/ISO-HDLC support, so there's no fusion to be had, use
     // traditional calculation
-    #[cfg(not(target_arch = "aarch64"))]
-    Calculator::calculate(state, data, _params)
+    #[cfg(not(all(target_arch = "aarch64", target_endian = "little")))]
+    Calculator::calculate(state, data, params)
+}
+
+/// Updates the state without fusion if the active tier can't fuse: a registered custom backend,
+/// which replaces the fusion implementations too, a tier with no carryless multiplication to fuse
+/// with (which may still have the native CRC-32 instructions alone), or any tier if the native
+/// CRC-32 instructions have been disabled.
+#[cfg(not(feature = "crc64-only"))]
+#[inline(always)]
+fn non_fusion_update(state: u64, data: &[u8], params: CrcParams) -> Option<u64> {
+    use crate::feature_detection::{get_arch_ops, ArchOpsInstance};
+
+    match get_arch_ops() {
+        ArchOpsInstance::Custom(backend) => Some(backend.update(state, data, params)),
+        arch_ops if !arch_ops.get_tier().supports_fusion() => {
+            Some(Calculator::calculate(state, data, params))
+        }
+        _ => None,
+    }
 }
 
 #[cfg(test)]
@@ -928,27 +2596,31 @@ mod lib {
 
     #[test]
     fn test_checksum_with_custom_params() {
-        crate::cache::clear_cache();
+        crate::cache::clear_key_cache();
 
         // CRC-32 reflected
+        #[cfg(not(feature = "crc64-only"))]
         assert_eq!(
             checksum_with_params(get_custom_crc32_reflected(), TEST_CHECK_STRING),
             CRC32_ISCSI.check,
         );
 
         // CRC-32 forward
+        #[cfg(not(feature = "crc64-only"))]
         assert_eq!(
             checksum_with_params(get_custom_crc32_forward(), TEST_CHECK_STRING),
             CRC32_BZIP2.check,
         );
 
         // CRC-64 reflected
+        #[cfg(not(feature = "crc32-only"))]
         assert_eq!(
             checksum_with_params(get_custom_crc64_reflected(), TEST_CHECK_STRING),
             CRC64_NVME.check,
         );
 
         // CRC-64 forward
+        #[cfg(not(feature = "crc32-only"))]
         assert_eq!(
             checksum_with_params(get_custom_crc64_forward(), TEST_CHECK_STRING),
             CRC64_ECMA_182.check,
@@ -957,23 +2629,27 @@ mod lib {
 
     #[test]
     fn test_get_custom_params() {
-        crate::cache::clear_cache();
+        crate::cache::clear_key_cache();
 
+        #[cfg(not(feature = "crc64-only"))]
         assert_eq!(
             checksum_with_params(get_custom_crc32_reflected(), TEST_CHECK_STRING),
             CRC32_ISCSI.check,
         );
 
+        #[cfg(not(feature = "crc64-only"))]
         assert_eq!(
             checksum_with_params(get_custom_crc32_forward(), TEST_CHECK_STRING),
             CRC32_BZIP2.check,
         );
 
+        #[cfg(not(feature = "crc32-only"))]
         assert_eq!(
             checksum_with_params(get_custom_crc64_reflected(), TEST_CHECK_STRING),
             CRC64_NVME.check,
         );
 
+        #[cfg(not(feature = "crc32-only"))]
         assert_eq!(
             checksum_with_params(get_custom_crc64_forward(), TEST_CHECK_STRING),
             CRC64_ECMA_182.check,
@@ -988,7 +2664,7 @@ mod lib {
         assert!(!target.is_empty());
 
         // Should follow the expected format with valid architecture prefixes
-        let valid_prefixes = ["aarch64-", "x86_64-", "x86-", "software-"];
+        let valid_prefixes = ["aarch64-", "x86_64-", "x86-", "portable-", "software-"];
         assert!(
             valid_prefixes
                 .iter()
@@ -1008,9 +2684,9 @@ mod lib {
 
     #[test]
     fn test_get_calculator_target_consistency() {
-        // Multiple calls should return the same result (deterministic)
-        let target1 = get_calculator_target(CrcAlgorithm::Crc32IsoHdlc);
-        let target2 = get_calculator_target(CrcAlgorithm::Crc32Iscsi);
+        // Algorithms which don't use fusion should share the same folding target
+        let target1 = get_calculator_target(CrcAlgorithm::Crc32Bzip2);
+        let target2 = get_calculator_target(CrcAlgorithm::Crc32Autosar);
         let target3 = get_calculator_target(CrcAlgorithm::Crc64Nvme);
 
         assert_eq!(
@@ -1023,6 +2699,35 @@ mod lib {
         );
     }
 
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_get_calculator_target_reports_fusion() {
+        let folding_target = get_calculator_target(CrcAlgorithm::Crc64Nvme);
+
+        for algorithm in [CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc32IsoHdlc] {
+            let target = get_calculator_target(algorithm);
+
+            if get_accelerator_info(algorithm).uses_fusion {
+                assert_eq!(target, format!("{folding_target}-fusion"));
+            } else {
+                assert_eq!(target, folding_target);
+            }
+        }
+
+        let supports_fusion = feature_detection::get_arch_ops()
+            .get_tier()
+            .supports_fusion();
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        assert_eq!(
+            get_calculator_target(CrcAlgorithm::Crc32Iscsi).ends_with("-fusion"),
+            supports_fusion
+        );
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        assert!(!get_calculator_target(CrcAlgorithm::Crc32IsoHdlc).ends_with("-fusion"));
+    }
+
     #[test]
     fn test_get_calculator_target_uses_cached_detection() {
         // This test verifies that the function uses cached feature detection
@@ -1038,6 +2743,22 @@ mod lib {
         );
     }
 
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_get_accelerator_info() {
+        let info = get_accelerator_info(CrcAlgorithm::Crc64Nvme);
+
+        assert_eq!(info.arch, std::env::consts::ARCH);
+        assert_eq!(info.tier, feature_detection::get_arch_ops().get_tier());
+        assert!(!info.uses_fusion, "CRC-64 never uses fusion");
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))]
+        assert_eq!(
+            get_accelerator_info(CrcAlgorithm::Crc32Iscsi).uses_fusion,
+            info.tier.supports_fusion()
+        );
+    }
+
     #[test]
     fn test_digest_updates_check() {
         for config in TEST_ALL_CONFIGS {
@@ -1047,27 +2768,31 @@ mod lib {
 
     #[test]
     fn test_digest_updates_check_with_custom_params() {
-        crate::cache::clear_cache();
+        crate::cache::clear_key_cache();
 
         // CRC-32 reflected
+        #[cfg(not(feature = "crc64-only"))]
         check_digest(
             Digest::new_with_params(get_custom_crc32_reflected()),
             CRC32_ISCSI.check,
         );
 
         // CRC-32 forward
+        #[cfg(not(feature = "crc64-only"))]
         check_digest(
             Digest::new_with_params(get_custom_crc32_forward()),
             CRC32_BZIP2.check,
         );
 
         // CRC-64 reflected
+        #[cfg(not(feature = "crc32-only"))]
         check_digest(
             Digest::new_with_params(get_custom_crc64_reflected()),
             CRC64_NVME.check,
         );
 
         // CRC-64 forward
+        #[cfg(not(feature = "crc32-only"))]
         check_digest(
             Digest::new_with_params(get_custom_crc64_forward()),
             CRC64_ECMA_182.check,
@@ -1081,54 +2806,561 @@ mod lib {
         assert_eq!(digest.finalize(), check,);
     }
 
-    #[test]
-    fn test_small_all_lengths() {
-        for config in TEST_ALL_CONFIGS {
-            // Test each length from 1 to 255
-            for len in 1..=255 {
-                test_length(len, config);
-            }
+    #[test]
+    fn test_small_all_lengths() {
+        for config in TEST_ALL_CONFIGS {
+            // Test each length from 1 to 255
+            for len in 1..=255 {
+                test_length(len, config);
+            }
+        }
+    }
+
+    #[test]
+    fn test_medium_lengths() {
+        for config in TEST_ALL_CONFIGS {
+            // Test each length from 256 to 1024, which should fold and include handling remainders
+            for len in 256..=1024 {
+                test_length(len, config);
+            }
+        }
+    }
+
+    #[test]
+    fn test_large_lengths() {
+        for config in TEST_ALL_CONFIGS {
+            // Test 1 MiB just before, at, and just after the folding boundaries
+            for len in 1048575..1048577 {
+                test_length(len, config);
+            }
+        }
+    }
+
+    fn test_length(length: usize, config: &AnyCrcTestConfig) {
+        let mut data = vec![0u8; length];
+        rng().fill(&mut data[..]);
+
+        // Calculate expected CRC using the reference implementation
+        let expected = config.checksum_with_reference(&data);
+
+        let result = checksum(config.get_algorithm(), &data);
+
+        assert_eq!(
+            result,
+            expected,
+            "Failed for algorithm: {:?}, length: {}, expected: {:#x}, got: {:#x}",
+            config.get_algorithm(),
+            length,
+            expected,
+            result
+        );
+    }
+
+    #[test]
+    fn test_checksum_multi() {
+        let mut data = vec![0u8; 4096];
+        rng().fill(&mut data[..]);
+
+        // equal, unequal, tiny, and empty buffers, with different alignments
+        let lengths = [
+            (1024, 1024, 1024),
+            (4000, 513, 2048),
+            (300, 7, 0),
+            (256, 256, 255),
+        ];
+
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+
+            for (a, b, c) in lengths {
+                let buffers = [&data[..a], &data[1..=b], &data[3..3 + c]];
+                let expected = buffers.map(|buf| config.checksum_with_reference(buf));
+
+                assert_eq!(
+                    checksum_multi(algorithm, buffers),
+                    expected,
+                    "Failed for algorithm: {algorithm:?}, lengths: {a}, {b}, {c}"
+                );
+                assert_eq!(
+                    checksum_multi_with_params(*config.get_params(), buffers),
+                    expected,
+                    "Failed for params: {algorithm:?}, lengths: {a}, {b}, {c}"
+                );
+            }
+
+            assert_eq!(
+                checksum_multi(algorithm, [&data[..]]),
+                [config.checksum_with_reference(&data)]
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc32-only"))]
+    fn test_checksum_batch() {
+        let mut data = vec![0u8; 8192];
+        rng().fill(&mut data[..]);
+
+        // a mix of lengths (and alignments) which don't fill the last group
+        let buffers: Vec<&[u8]> = (0..11).map(|i| &data[i..i + (i * 977) % 4096]).collect();
+
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+            let expected: Vec<u64> = buffers
+                .iter()
+                .map(|buf| config.checksum_with_reference(buf))
+                .collect();
+
+            assert_eq!(checksum_batch(algorithm, &buffers), expected);
+            assert_eq!(
+                checksum_batch_with_params(*config.get_params(), &buffers),
+                expected
+            );
+        }
+
+        assert!(checksum_batch(CrcAlgorithm::Crc64Nvme, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_empty_input() {
+        let empty_file = std::env::temp_dir().join("crc_fast_test_empty_input.bin");
+        write(&empty_file, []).unwrap();
+
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+            let empty = config.checksum_with_reference(&[]);
+
+            assert!(is_empty_crc(algorithm, empty));
+            assert!(!is_empty_crc(algorithm, config.get_check()));
+
+            assert_eq!(checksum(algorithm, &[]), empty);
+            assert_eq!(checksum_with_params(*config.get_params(), &[]), empty);
+            assert_eq!(checksum_aligned(algorithm, &[]), empty);
+            assert_eq!(checksum_non_temporal(algorithm, &[]), empty);
+            assert_eq!(checksum_multi(algorithm, [&[], b"1"])[0], empty);
+            assert_eq!(checksum_batch(algorithm, &[&[]]), vec![empty]);
+            assert_eq!(checksum_from_iter(algorithm, [&[]; 3]), empty);
+            assert_eq!(checksum_combine_parts(algorithm, &[]), empty);
+            assert_eq!(
+                checksum_file(algorithm, empty_file.to_str().unwrap(), None).unwrap(),
+                empty
+            );
+
+            for tier in available_tiers() {
+                assert_eq!(checksum_with_tier(algorithm, &[], tier), Ok(empty));
+            }
+
+            let mut digest = Digest::new(algorithm);
+            assert_eq!(digest.finalize(), empty);
+            digest.update(&[]);
+            assert_eq!(digest.finalize(), empty);
+            assert_eq!(digest.get_amount(), 0);
+
+            // combining with an empty part (on either side) changes nothing
+            let check = config.get_check();
+            assert_eq!(checksum_combine(algorithm, check, empty, 0), check);
+            assert_eq!(checksum_combine(algorithm, empty, check, 9), check);
+
+            let mut other = Digest::new(algorithm);
+            digest.update(TEST_CHECK_STRING);
+            digest.combine(&other);
+            assert_eq!(digest.finalize(), check);
+
+            other.combine(&digest);
+            assert_eq!(other.finalize(), check);
+        }
+
+        std::fs::remove_file(&empty_file).unwrap();
+    }
+
+    #[test]
+    fn test_checksum_from_iter() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+            let expected = checksum(algorithm, &data);
+
+            // uneven frames, including empty ones
+            let frames: Vec<&[u8]> = data.chunks(7_919).flat_map(|chunk| [chunk, &[]]).collect();
+
+            assert_eq!(checksum_from_iter(algorithm, &frames), expected);
+            assert_eq!(
+                checksum_from_iter(algorithm, data.chunks(3).map(|chunk| chunk.to_vec())),
+                expected
+            );
+            assert_eq!(
+                checksum_from_iter(algorithm, std::iter::empty::<&[u8]>()),
+                checksum(algorithm, b"")
+            );
+        }
+
+        #[cfg(not(feature = "crc32-only"))]
+        {
+            let params = get_custom_crc64_reflected();
+            assert_eq!(
+                checksum_from_iter_with_params(params, data.chunks(1_000)),
+                checksum_with_params(params, &data)
+            );
+        }
+    }
+
+    #[test]
+    fn test_update_uninit_and_raw() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+
+            let mut buf = vec![MaybeUninit::<u8>::uninit(); data.len() + 4_096];
+            for (slot, byte) in buf.iter_mut().zip(&data) {
+                slot.write(*byte);
+            }
+
+            let mut digest = Digest::new(algorithm);
+            unsafe {
+                digest.update_uninit(&buf, 1_000);
+                digest.update_uninit(&buf[1_000..], data.len() - 1_000);
+                digest.update_uninit(&buf, 0);
+            }
+            assert_eq!(digest.finalize(), checksum(algorithm, &data));
+
+            let mut digest = Digest::new(algorithm);
+            unsafe {
+                digest.update_raw(std::ptr::null(), 0);
+                digest.update_raw(data.as_ptr(), data.len());
+            }
+            assert_eq!(digest.finalize(), checksum(algorithm, &data));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the buffer length")]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_update_uninit_too_long() {
+        let buf = [MaybeUninit::<u8>::uninit(); 8];
+
+        unsafe { Digest::new(CrcAlgorithm::Crc32Iscsi).update_uninit(&buf, 9) };
+    }
+
+    #[test]
+    fn test_checksum_const() {
+        #[cfg(not(feature = "crc32-only"))]
+        {
+            const NVME_CHECK: u64 = checksum_const(CrcAlgorithm::Crc64Nvme, b"123456789");
+            assert_eq!(NVME_CHECK, CRC64_NVME.check);
+        }
+
+        let data: Vec<u8> = (0..1_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+
+            for len in [0, 1, 9, 16, 255, data.len()] {
+                assert_eq!(
+                    checksum_const(algorithm, &data[..len]),
+                    checksum(algorithm, &data[..len]),
+                    "Failed for {} with length {}",
+                    config.get_name(),
+                    len
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_digest_parallel_threshold() {
+        let mut data = vec![0u8; 16 * 1024 * 1024 + 7];
+        rng().fill(&mut data[..]);
+
+        for algorithm in [CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc64Nvme] {
+            let expected = checksum(algorithm, &data);
+
+            let mut digest = Digest::new(algorithm);
+            digest.set_parallel_threshold(Some(0));
+            digest.update(b"123456789");
+            digest.update(&data);
+
+            let mut serial = Digest::new(algorithm);
+            serial.set_parallel_threshold(None);
+            serial.update(b"123456789");
+            serial.update(&data);
+
+            assert_eq!(digest.finalize(), serial.finalize());
+            assert_eq!(digest.get_amount(), serial.get_amount());
+
+            let mut digest = Digest::new(algorithm);
+            digest.set_parallel_threshold(Some(1024));
+            digest.update(&data);
+            assert_eq!(digest.finalize(), expected);
+        }
+    }
+
+    #[test]
+    fn test_digest_staging_buffer() {
+        let mut data = vec![0u8; 1024];
+        rng().fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+
+            let mut digest = Digest::new(algorithm);
+            digest.set_staging_buffer(true);
+
+            // fragments of 0-16 bytes, with an occasional large one which bypasses the buffer
+            let mut offset = 0;
+            for i in 0.. {
+                let len = if i % 20 == 19 { 200 } else { i % 17 };
+                if offset + len > data.len() {
+                    break;
+                }
+
+                digest.update(&data[offset..offset + len]);
+                offset += len;
+
+                assert_eq!(digest.finalize(), checksum(algorithm, &data[..offset]));
+            }
+
+            assert_eq!(digest.get_amount(), offset as u64);
+
+            // combining and disabling calculate anything still staged
+            let mut other = Digest::new(algorithm);
+            other.set_staging_buffer(true);
+            other.update(&data[offset..]);
+            digest.combine(&other);
+            assert_eq!(digest.finalize(), checksum(algorithm, &data));
+
+            digest.reset();
+            digest.update(b"1234");
+            digest.set_staging_buffer(false);
+            digest.update(b"56789");
+            assert_eq!(digest.finalize(), config.get_check());
+        }
+    }
+
+    #[test]
+    fn test_checksum_non_temporal() {
+        let mut data = vec![0u8; 100_000];
+        rng().fill(&mut data[..]);
+
+        for config in TEST_ALL_CONFIGS {
+            let expected = config.checksum_with_reference(&data);
+
+            assert_eq!(
+                checksum_non_temporal(config.get_algorithm(), &data),
+                expected
+            );
+            assert_eq!(
+                checksum_non_temporal_with_params(*config.get_params(), &data),
+                expected
+            );
+
+            let mut digest = Digest::new(config.get_algorithm());
+            digest.set_staging_buffer(true);
+            digest.update(&data[..10]);
+            digest.update_non_temporal(&data[10..]);
+            assert_eq!(digest.finalize(), expected);
+            assert_eq!(digest.get_amount(), data.len() as u64);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_checksum_with_tier() {
+        let mut data = vec![0u8; 4096];
+        rng().fill(&mut data[..]);
+
+        for tier in available_tiers() {
+            for config in TEST_ALL_CONFIGS {
+                for len in [0, 9, 200, 4096] {
+                    assert_eq!(
+                        checksum_with_tier(config.get_algorithm(), &data[..len], tier),
+                        Ok(config.checksum_with_reference(&data[..len])),
+                        "Mismatch CRC, {}, {}, length {}",
+                        config.get_name(),
+                        tier,
+                        len
+                    );
+                }
+            }
+        }
+
+        #[cfg(not(target_arch = "aarch64"))]
+        let unsupported = PerformanceTier::AArch64Aes;
+        #[cfg(target_arch = "aarch64")]
+        let unsupported = PerformanceTier::X86_64SsePclmulqdq;
+
+        assert_eq!(
+            checksum_with_tier(CrcAlgorithm::Crc32IsoHdlc, b"123456789", unsupported),
+            Err(ForceTierError::Unsupported(unsupported))
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_digest_perf_stats() {
+        let mut digest = Digest::new(CrcAlgorithm::Crc32IsoHdlc);
+        digest.update(b"before");
+        assert_eq!(digest.perf_stats(), None);
+
+        digest.set_perf_stats(true);
+        digest.set_staging_buffer(true);
+
+        digest.update(b"1234");
+        digest.update(b"56789");
+        digest.update(&[0u8; 1024]);
+        digest.update_non_temporal(&[0u8; 256]);
+
+        let stats = digest.perf_stats().unwrap();
+
+        assert_eq!(stats.staged_bytes, 9);
+        assert_eq!((stats.short_invocations, stats.short_bytes), (1, 9));
+        assert_eq!((stats.kernel_invocations, stats.kernel_bytes), (2, 1280));
+
+        digest.set_perf_stats(false);
+        assert_eq!(digest.perf_stats(), None);
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_digest_perf_stats_staged_then_large() {
+        let mut digest = Digest::new(CrcAlgorithm::Crc32IsoHdlc);
+        digest.set_perf_stats(true);
+        digest.set_staging_buffer(true);
+
+        // the first large update flushes the staged bytes, the second finds nothing staged
+        digest.update(b"1234");
+        digest.update(b"56789");
+        digest.update(&[0u8; 1024]);
+        digest.update(&[0u8; 1024]);
+
+        let stats = digest.perf_stats().unwrap();
+
+        assert_eq!(stats.staged_bytes, 9);
+        assert_eq!((stats.short_invocations, stats.short_bytes), (1, 9));
+        assert_eq!((stats.kernel_invocations, stats.kernel_bytes), (2, 2048));
+        assert_eq!(
+            digest.finalize(),
+            checksum(
+                CrcAlgorithm::Crc32IsoHdlc,
+                &[b"123456789" as &[u8], &[0u8; 2048]].concat()
+            )
+        );
+    }
+
+    #[test]
+    fn test_checksum_aligned() {
+        let mut data = vec![0u8; 10_000 + CHECKSUM_ALIGNMENT];
+        rng().fill(&mut data[..]);
+
+        let base = data.as_ptr().align_offset(CHECKSUM_ALIGNMENT);
+
+        for config in TEST_ALL_CONFIGS {
+            for offset in [0, 1, 15, 63] {
+                for len in [0, 5, 64, 200, 10_000 - offset] {
+                    let data = &data[base + offset..base + offset + len];
+                    let expected = config.checksum_with_reference(data);
+
+                    assert_eq!(checksum_aligned(config.get_algorithm(), data), expected);
+                    assert_eq!(
+                        checksum_aligned_with_params(*config.get_params(), data),
+                        expected
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_digest_reset_with_state() {
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+
+            let mut prefix = Digest::new(algorithm);
+            prefix.update(&TEST_CHECK_STRING[..4]);
+            let state = prefix.get_state();
+
+            let mut digest = Digest::new_with_init_state(algorithm, state);
+            digest.update(b"42");
+            digest.reset_keep_params();
+            digest.update(&TEST_CHECK_STRING[4..]);
+
+            assert_eq!(digest.finalize(), config.get_check());
+            assert_eq!(digest.get_amount(), 5);
+
+            // reset() still returns to the algorithm's initial state
+            digest.reset();
+            digest.update(TEST_CHECK_STRING);
+
+            assert_eq!(digest.finalize(), config.get_check());
+
+            // and reset_keep_params() follows the last reset_with_state()
+            digest.reset_with_state(state);
+            digest.update(b"42");
+            digest.reset_keep_params();
+            digest.update(&TEST_CHECK_STRING[4..]);
+
+            assert_eq!(digest.finalize(), config.get_check());
         }
     }
 
     #[test]
-    fn test_medium_lengths() {
-        for config in TEST_ALL_CONFIGS {
-            // Test each length from 256 to 1024, which should fold and include handling remainders
-            for len in 256..=1024 {
-                test_length(len, config);
-            }
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_digest_with_params_and_state() {
+        crate::cache::clear_key_cache();
+
+        for params in [
+            get_custom_crc32_reflected(),
+            get_custom_crc32_forward(),
+            get_custom_crc64_reflected(),
+            get_custom_crc64_forward(),
+        ] {
+            let mut prefix = Digest::new_with_params(params);
+            prefix.update(&TEST_CHECK_STRING[..4]);
+
+            let mut digest = Digest::new_with_params_and_state(params, prefix.get_state());
+            digest.update(&TEST_CHECK_STRING[4..]);
+
+            assert_eq!(digest.finalize(), params.check);
+
+            // resetting keeps the custom state
+            digest.reset_keep_params();
+            digest.update(&TEST_CHECK_STRING[4..]);
+
+            assert_eq!(digest.finalize(), params.check);
+
+            // and it combines like any other Digest
+            let mut first = Digest::new_with_params(params);
+            first.update(&TEST_CHECK_STRING[..4]);
+            let mut second = Digest::new_with_params(params);
+            second.update(&TEST_CHECK_STRING[4..]);
+            first.combine(&second);
+
+            assert_eq!(first.finalize(), digest.finalize());
         }
     }
 
     #[test]
-    fn test_large_lengths() {
-        for config in TEST_ALL_CONFIGS {
-            // Test 1 MiB just before, at, and just after the folding boundaries
-            for len in 1048575..1048577 {
-                test_length(len, config);
-            }
-        }
-    }
+    fn test_digest_update_split() {
+        let data: Vec<u8> = (0..3000u32).map(|i| (i * 31 % 257) as u8).collect();
 
-    fn test_length(length: usize, config: &AnyCrcTestConfig) {
-        let mut data = vec![0u8; length];
-        rng().fill(&mut data[..]);
+        for config in TEST_ALL_CONFIGS {
+            for len in [0, 1, 100, 255, 256, 257, 511, 512, 513, 700, 3000] {
+                let expected = config.checksum_with_reference(&data[..len]);
 
-        // Calculate expected CRC using the reference implementation
-        let expected = config.checksum_with_reference(&data);
+                for split in [0, 1, 10, 255, 256, 257, len / 2, len.saturating_sub(1), len] {
+                    let split = split.min(len);
+                    let (head, tail) = data[..len].split_at(split);
 
-        let result = checksum(config.get_algorithm(), &data);
+                    let mut digest = Digest::new(config.get_algorithm());
+                    digest.update_split(head, tail);
 
-        assert_eq!(
-            result,
-            expected,
-            "Failed for algorithm: {:?}, length: {}, expected: {:#x}, got: {:#x}",
-            config.get_algorithm(),
-            length,
-            expected,
-            result
-        );
+                    assert_eq!(digest.finalize(), expected, "len {len} split {split}");
+                    assert_eq!(digest.get_amount(), len as u64);
+                }
+            }
+        }
     }
 
     #[test]
@@ -1158,46 +3390,222 @@ mod lib {
     }
 
     #[test]
-    fn test_combine_with_custom_params() {
-        crate::cache::clear_cache();
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_combine_validation() {
+        let crc32 = checksum(CrcAlgorithm::Crc32IsoHdlc, b"1234");
+        let crc64 = checksum(CrcAlgorithm::Crc64Nvme, b"56789");
 
-        // CRC-32 reflected
-        let crc32_params = get_custom_crc32_reflected();
-        let checksum1 = checksum_with_params(crc32_params, "1234".as_ref());
-        let checksum2 = checksum_with_params(crc32_params, "56789".as_ref());
         assert_eq!(
-            checksum_combine_with_params(crc32_params, checksum1, checksum2, 5),
-            CRC32_ISCSI.check,
+            try_checksum_combine(
+                CrcAlgorithm::Crc32IsoHdlc,
+                crc32,
+                checksum(CrcAlgorithm::Crc32IsoHdlc, b"56789"),
+                5
+            )
+            .unwrap(),
+            0xcbf43926
         );
+        assert!(matches!(
+            try_checksum_combine(CrcAlgorithm::Crc32IsoHdlc, crc32, crc64, 5),
+            Err(CrcFastError::ChecksumTooWide { width: 32, checksum }) if checksum == crc64
+        ));
+
+        // CRC-64 checksums can't be too wide
+        assert!(try_checksum_combine(CrcAlgorithm::Crc64Nvme, crc64, u64::MAX, 5).is_ok());
+
+        assert!(matches!(
+            try_checksum_combine_parts(CrcAlgorithm::Crc32IsoHdlc, &[(crc32, 4), (crc64, 5)]),
+            Err(CrcFastError::ChecksumTooWide { .. })
+        ));
+
+        let mut digest = Digest::new(CrcAlgorithm::Crc32IsoHdlc);
+        digest.update(b"1234");
+
+        let mut other = Digest::new(CrcAlgorithm::Crc32Iscsi);
+        other.update(b"56789");
+
+        assert!(matches!(
+            digest.try_combine(&other),
+            Err(CrcFastError::ParamsMismatch)
+        ));
+        assert_eq!(digest.finalize(), crc32);
+        assert_eq!(digest.get_amount(), 4);
+    }
 
-        // CRC-32 forward
-        let crc32_params = get_custom_crc32_forward();
-        let checksum1 = checksum_with_params(crc32_params, "1234".as_ref());
-        let checksum2 = checksum_with_params(crc32_params, "56789".as_ref());
-        assert_eq!(
-            checksum_combine_with_params(crc32_params, checksum1, checksum2, 5),
-            CRC32_BZIP2.check,
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "don't fit in a 32-bit CRC")]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_checksum_combine_too_wide() {
+        checksum_combine(CrcAlgorithm::Crc32IsoHdlc, 0, u64::MAX, 5);
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_params_eq_and_hash() {
+        use std::collections::HashSet;
+
+        let (_, iso_hdlc) = get_calculator_params(CrcAlgorithm::Crc32IsoHdlc);
+        let custom = CrcParams::new(
+            "CRC-32/CUSTOM",
+            32,
+            0x04c11db7,
+            0xffffffff,
+            true,
+            0xffffffff,
+            0xcbf43926,
+        );
+        let legacy_keys = CrcParams::new_with_key_count(
+            "CRC-32/LEGACY",
+            32,
+            0x04c11db7,
+            0xffffffff,
+            true,
+            0xffffffff,
+            0xcbf43926,
+            23,
         );
+        let jamcrc = get_calculator_params(CrcAlgorithm::Crc32Jamcrc).1;
 
-        // CRC-64 reflected
-        let crc64_params = get_custom_crc64_reflected();
-        let checksum1 = checksum_with_params(crc64_params, "1234".as_ref());
-        let checksum2 = checksum_with_params(crc64_params, "56789".as_ref());
+        // the name, algorithm, and key format don't matter, but the XOROUT does
+        assert_eq!(custom, iso_hdlc);
+        assert_eq!(legacy_keys, iso_hdlc);
+        assert_ne!(jamcrc, iso_hdlc);
+
+        let set: HashSet<CrcParams> = [iso_hdlc, custom, legacy_keys, jamcrc]
+            .into_iter()
+            .collect();
+        assert_eq!(set.len(), 2);
+
+        // equal parameters can be combined
+        let mut digest = Digest::new(CrcAlgorithm::Crc32IsoHdlc);
+        digest.update(b"1234");
+
+        let mut other = Digest::new_with_params(custom);
+        other.update(b"56789");
+
+        assert!(digest.try_combine(&other).is_ok());
+        assert_eq!(digest.finalize(), 0xcbf43926);
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_params_display_and_debug() {
+        let (_, iso_hdlc) = get_calculator_params(CrcAlgorithm::Crc32IsoHdlc);
+        assert_eq!(iso_hdlc.key_source(), KeySource::Const);
         assert_eq!(
-            checksum_combine_with_params(crc64_params, checksum1, checksum2, 5),
-            CRC64_NVME.check,
+            iso_hdlc.to_string(),
+            "width=32 poly=0x04c11db7 init=0xffffffff refin=true refout=true xorout=0xffffffff \
+             check=0xcbf43926 name=\"CRC-32/ISO-HDLC\" keys=const"
         );
 
-        // CRC-64 forward
-        let crc64_params = get_custom_crc64_forward();
-        let checksum1 = checksum_with_params(crc64_params, "1234".as_ref());
-        let checksum2 = checksum_with_params(crc64_params, "56789".as_ref());
+        // values are padded to the width, not to the u64 they're stored in
+        let (_, xz) = get_calculator_params(CrcAlgorithm::Crc64Xz);
+        assert!(xz
+            .to_string()
+            .starts_with("width=64 poly=0x42f0e1eba9ea3693 init=0xffffffffffffffff "));
+
+        let debug = format!("{iso_hdlc:?}");
+        assert!(debug.starts_with("CrcParams { algorithm: Crc32IsoHdlc, name: \"CRC-32/ISO-HDLC\""));
+        assert!(debug.contains("poly: 0x04c11db7"));
+        assert!(debug.contains(&format!("key_count: {}", iso_hdlc.key_count())));
+        assert!(debug.contains("key_source: Const"));
+
+        // a polynomial which isn't compiled in (or a key preset), so its keys are either cached or
+        // generated (other tests clear the cache concurrently)
+        let custom = CrcParams::new("CRC-32/DISPLAY", 32, 0x2f7a64c3, 0, false, 0, 0);
+        assert_ne!(custom.key_source(), KeySource::Const);
+        assert!(custom.to_string().contains("init=0x00000000"));
+        assert!(matches!(
+            custom.to_string().rsplit_once("keys="),
+            Some((_, "cached" | "generated"))
+        ));
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc32-only"))]
+    fn test_combine_beyond_u64_amount() {
+        for config in TEST_ALL_CONFIGS {
+            let (_, params) = get_calculator_params(config.get_algorithm());
+            let adjust = params.init ^ params.xorout;
+            let crc = checksum(config.get_algorithm(), TEST_CHECK_STRING);
+
+            // appending u64::MAX + 7 zero-length-checksum bytes in one step must match appending
+            // u64::MAX and then 7, since combining only shifts the first checksum by the length
+            let wide = combine::checksums_wide(crc, 0, u64::MAX as u128 + 7, params);
+            let shifted = combine::checksums(crc, 0, u64::MAX, params);
+            let narrow = combine::checksums(shifted ^ adjust, 0, 7, params);
+
+            assert_eq!(wide, narrow);
+        }
+
+        let mut digest1 = Digest::new(CrcAlgorithm::Crc64Nvme);
+        digest1.amount = u64::MAX as u128;
+
+        let mut digest2 = Digest::new(CrcAlgorithm::Crc64Nvme);
+        digest2.update(TEST_CHECK_STRING);
+        digest2.combine(&digest1);
+
+        assert_eq!(digest2.get_amount(), u64::MAX);
         assert_eq!(
-            checksum_combine_with_params(crc64_params, checksum1, checksum2, 5),
-            CRC64_ECMA_182.check,
+            digest2.get_amount_u128(),
+            u64::MAX as u128 + TEST_CHECK_STRING.len() as u128
         );
     }
 
+    #[test]
+    fn test_combine_with_custom_params() {
+        crate::cache::clear_key_cache();
+
+        // CRC-32 reflected
+        #[cfg(not(feature = "crc64-only"))]
+        {
+            let crc32_params = get_custom_crc32_reflected();
+            let checksum1 = checksum_with_params(crc32_params, "1234".as_ref());
+            let checksum2 = checksum_with_params(crc32_params, "56789".as_ref());
+            assert_eq!(
+                checksum_combine_with_params(crc32_params, checksum1, checksum2, 5),
+                CRC32_ISCSI.check,
+            );
+        }
+
+        // CRC-32 forward
+        #[cfg(not(feature = "crc64-only"))]
+        {
+            let crc32_params = get_custom_crc32_forward();
+            let checksum1 = checksum_with_params(crc32_params, "1234".as_ref());
+            let checksum2 = checksum_with_params(crc32_params, "56789".as_ref());
+            assert_eq!(
+                checksum_combine_with_params(crc32_params, checksum1, checksum2, 5),
+                CRC32_BZIP2.check,
+            );
+        }
+
+        // CRC-64 reflected
+        #[cfg(not(feature = "crc32-only"))]
+        {
+            let crc64_params = get_custom_crc64_reflected();
+            let checksum1 = checksum_with_params(crc64_params, "1234".as_ref());
+            let checksum2 = checksum_with_params(crc64_params, "56789".as_ref());
+            assert_eq!(
+                checksum_combine_with_params(crc64_params, checksum1, checksum2, 5),
+                CRC64_NVME.check,
+            );
+        }
+
+        // CRC-64 forward
+        #[cfg(not(feature = "crc32-only"))]
+        {
+            let crc64_params = get_custom_crc64_forward();
+            let checksum1 = checksum_with_params(crc64_params, "1234".as_ref());
+            let checksum2 = checksum_with_params(crc64_params, "56789".as_ref());
+            assert_eq!(
+                checksum_combine_with_params(crc64_params, checksum1, checksum2, 5),
+                CRC64_ECMA_182.check,
+            );
+        }
+    }
+
     #[test]
     fn test_checksum_file() {
         // Create a test file with repeating zeros
@@ -1218,7 +3626,7 @@ mod lib {
 
     #[test]
     fn test_checksum_file_with_custom_params() {
-        crate::cache::clear_cache();
+        crate::cache::clear_key_cache();
 
         // Create a test file with repeating zeros
         let test_file_path = "test/test_crc32_hash_file_custom.bin";
@@ -1229,6 +3637,7 @@ mod lib {
         }
 
         // CRC-32 reflected
+        #[cfg(not(feature = "crc64-only"))]
         check_file(
             get_custom_crc32_reflected(),
             test_file_path,
@@ -1236,6 +3645,7 @@ mod lib {
         );
 
         // CRC-32 forward
+        #[cfg(not(feature = "crc64-only"))]
         check_file(
             get_custom_crc32_forward(),
             test_file_path,
@@ -1243,6 +3653,7 @@ mod lib {
         );
 
         // CRC-64 reflected
+        #[cfg(not(feature = "crc32-only"))]
         check_file(
             get_custom_crc64_reflected(),
             test_file_path,
@@ -1250,6 +3661,7 @@ mod lib {
         );
 
         // CRC-64 forward
+        #[cfg(not(feature = "crc32-only"))]
         check_file(
             get_custom_crc64_forward(),
             test_file_path,
@@ -1259,6 +3671,234 @@ mod lib {
         std::fs::remove_file(test_file_path).unwrap();
     }
 
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_checksum_file_with_len() {
+        let test_file_path = "test/test_crc32_checksum_file_with_len.bin";
+        let data = vec![0x5au8; 1024 * 1024 + 7];
+        if let Err(e) = write(test_file_path, &data) {
+            eprintln!("Skipping test due to write error: {}", e);
+            return;
+        }
+
+        let file_checksum =
+            checksum_file_with_len(CrcAlgorithm::Crc64Nvme, test_file_path, Some(4096)).unwrap();
+
+        assert_eq!(
+            file_checksum,
+            FileChecksum {
+                checksum: checksum(CrcAlgorithm::Crc64Nvme, &data),
+                len: data.len() as u64,
+            }
+        );
+
+        let params = get_custom_crc32_reflected();
+        let file_checksum =
+            checksum_file_with_params_and_len(params, test_file_path, None).unwrap();
+
+        assert_eq!(file_checksum.checksum, checksum_with_params(params, &data));
+        assert_eq!(file_checksum.len, data.len() as u64);
+
+        std::fs::remove_file(test_file_path).unwrap();
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_checksum_file_errors() {
+        let result = checksum_file(CrcAlgorithm::Crc32IsoHdlc, "test/missing.bin", None);
+
+        assert!(matches!(
+            result,
+            Err(CrcFastError::Io(error)) if error.kind() == std::io::ErrorKind::NotFound
+        ));
+
+        assert!(matches!(
+            CrcParams::try_new(
+                "CRC-32/BAD",
+                32,
+                0x04c11db7,
+                0xffffffff,
+                true,
+                0xffffffff,
+                0
+            ),
+            Err(CrcFastError::InvalidParams(_))
+        ));
+        assert!(matches!(
+            CrcParams::try_new("CRC-8/BAD", 8, 0x07, 0, false, 0, 0xf4),
+            Err(CrcFastError::UnsupportedWidth(8))
+        ));
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_checksum_file_append() {
+        let test_file_path = "test/test_crc_append_file.bin";
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        if let Err(e) = write(test_file_path, &data[..60_000]) {
+            eprintln!("Skipping test due to write error: {}", e);
+            return;
+        }
+
+        for config in TEST_ALL_CONFIGS {
+            let mut saved = Digest::new(config.get_algorithm());
+            saved.update(&data[..60_000]);
+
+            // nothing appended yet
+            let digest = checksum_file_append(
+                config.get_algorithm(),
+                test_file_path,
+                saved.get_state(),
+                saved.get_amount(),
+                true,
+            )
+            .unwrap();
+            assert_eq!(digest.finalize(), saved.finalize());
+        }
+
+        write(test_file_path, &data).unwrap();
+
+        for config in TEST_ALL_CONFIGS {
+            let mut saved = Digest::new(config.get_algorithm());
+            saved.update(&data[..60_000]);
+
+            for verify_prefix in [false, true] {
+                let digest = checksum_file_append(
+                    config.get_algorithm(),
+                    test_file_path,
+                    saved.get_state(),
+                    saved.get_amount(),
+                    verify_prefix,
+                )
+                .unwrap();
+
+                assert_eq!(digest.get_amount(), data.len() as u64);
+                assert_eq!(digest.finalize(), config.checksum_with_reference(&data));
+            }
+        }
+
+        let params = get_custom_crc64_reflected();
+        let mut saved = Digest::new_with_params(params);
+        saved.update(&data[..60_000]);
+
+        let digest = checksum_file_append_with_params(
+            params,
+            test_file_path,
+            saved.get_state(),
+            saved.get_amount(),
+            true,
+        )
+        .unwrap();
+        assert_eq!(digest.finalize(), checksum_with_params(params, &data));
+
+        // a changed prefix is only caught when verifying
+        let mut changed = Digest::new(CrcAlgorithm::Crc32Iscsi);
+        changed.update(&data[1..60_001]);
+
+        let error = checksum_file_append(
+            CrcAlgorithm::Crc32Iscsi,
+            test_file_path,
+            changed.get_state(),
+            60_000,
+            true,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, CrcFastError::Io(error) if error.kind() == std::io::ErrorKind::InvalidData)
+        );
+
+        assert!(checksum_file_append(
+            CrcAlgorithm::Crc32Iscsi,
+            test_file_path,
+            changed.get_state(),
+            60_000,
+            false,
+        )
+        .is_ok());
+
+        // the file can't be shorter than what was checksummed
+        let error = checksum_file_append(
+            CrcAlgorithm::Crc32Iscsi,
+            test_file_path,
+            changed.get_state(),
+            200_000,
+            false,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, CrcFastError::Io(error) if error.kind() == std::io::ErrorKind::InvalidData)
+        );
+
+        std::fs::remove_file(test_file_path).unwrap();
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_checksum_file_follow() {
+        let test_file_path = "test/test_crc_follow_file.bin";
+        let data: Vec<u8> = (0..30_000u32).map(|i| (i % 253) as u8).collect();
+        if let Err(e) = write(test_file_path, &data[..10_000]) {
+            eprintln!("Skipping test due to write error: {}", e);
+            return;
+        }
+
+        let writer = {
+            let data = data.clone();
+
+            std::thread::spawn(move || {
+                let mut file = std::fs::OpenOptions::new()
+                    .append(true)
+                    .open(test_file_path)
+                    .unwrap();
+
+                for chunk in data[10_000..].chunks(5_000) {
+                    std::thread::sleep(std::time::Duration::from_millis(5));
+                    file.write_all(chunk).unwrap();
+                }
+            })
+        };
+
+        let mut snapshots = Vec::new();
+        let digest = checksum_file_follow(
+            CrcAlgorithm::Crc64Nvme,
+            test_file_path,
+            std::time::Duration::from_millis(1),
+            |digest| {
+                snapshots.push((digest.get_amount(), digest.finalize()));
+                digest.get_amount() < data.len() as u64
+            },
+        )
+        .unwrap();
+
+        writer.join().unwrap();
+
+        assert_eq!(digest.finalize(), checksum(CrcAlgorithm::Crc64Nvme, &data));
+
+        for (amount, snapshot) in snapshots {
+            assert_eq!(
+                snapshot,
+                checksum(CrcAlgorithm::Crc64Nvme, &data[..amount as usize])
+            );
+        }
+
+        // truncation is an error
+        let error = checksum_file_follow_with_params(
+            get_custom_crc32_reflected(),
+            test_file_path,
+            std::time::Duration::from_millis(1),
+            |_| {
+                write(test_file_path, &data[..100]).unwrap();
+                true
+            },
+        )
+        .unwrap_err();
+        assert!(
+            matches!(error, CrcFastError::Io(error) if error.kind() == std::io::ErrorKind::InvalidData)
+        );
+
+        std::fs::remove_file(test_file_path).unwrap();
+    }
+
     fn check_file(params: CrcParams, file_path: &str, check: u64) {
         let result = checksum_file_with_params(params, file_path, None).unwrap();
         assert_eq!(result, check);
@@ -1357,6 +3997,58 @@ mod lib {
         }
     }
 
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_dyn_digest_downcast() {
+        let mut registry: Vec<Box<dyn DynDigestAny>> = vec![
+            Box::new(Digest::new(CrcAlgorithm::Crc32Iscsi)),
+            Box::new(Digest::new_with_params(get_custom_crc64_reflected())),
+        ];
+
+        for hasher in registry.iter_mut() {
+            hasher.update(TEST_CHECK_STRING);
+        }
+
+        let digest = registry[0].as_any().downcast_ref::<Digest>().unwrap();
+        assert_eq!(digest.algorithm(), CrcAlgorithm::Crc32Iscsi);
+        assert_eq!(digest.get_amount(), TEST_CHECK_STRING.len() as u64);
+
+        registry[1]
+            .as_any_mut()
+            .downcast_mut::<Digest>()
+            .unwrap()
+            .update(TEST_CHECK_STRING);
+
+        let digest = registry
+            .pop()
+            .unwrap()
+            .into_any()
+            .downcast::<Digest>()
+            .unwrap();
+        assert_eq!(digest.algorithm(), CrcAlgorithm::Crc64Custom);
+        assert_eq!(digest.get_amount(), 2 * TEST_CHECK_STRING.len() as u64);
+    }
+
+    #[test]
+    fn test_checksum_bytes_for_odd_widths() {
+        assert_eq!(checksum_byte_len(17), 3);
+        assert_eq!(checksum_byte_len(24), 3);
+        assert_eq!(checksum_byte_len(32), 4);
+        assert_eq!(checksum_byte_len(64), 8);
+
+        // bits beyond the width are cleared, and the bytes are rounded up
+        let mut output = [0u8; 3];
+        write_checksum_bytes(0xffff_1234_5678, 24, &mut output);
+        assert_eq!(output, [0x34, 0x56, 0x78]);
+
+        write_checksum_bytes(0xffff_ffff, 17, &mut output);
+        assert_eq!(output, [0x01, 0xff, 0xff]);
+
+        let mut output = [0u8; 8];
+        write_checksum_bytes(0x0123_4567_89ab_cdef, 64, &mut output);
+        assert_eq!(output, 0x0123_4567_89ab_cdefu64.to_be_bytes());
+    }
+
     /// Tests whether the FFI header is up-to-date
     #[test]
     fn test_ffi_header() -> Result<(), String> {
@@ -1376,7 +4068,7 @@ mod lib {
         cbindgen::Builder::new()
             .with_crate(crate_dir)
             .with_include_guard("CRC_FAST_H")
-            .with_header("/* crc_fast library C/C++ API - Copyright 2025 Don MacAskill */\n/* This header is auto-generated. Do not edit directly. */\n")
+            .with_header("/* crc_fast library C/C++ API - Copyright 2025 Don MacAskill */\n/* This header is auto-generated. Do not edit directly. */\n/* Strings returned by this library are owned by it, remain valid for the life of the process, and must not be freed. */\n/* Checksums are uint64_t for every CRC width, with the bits above the width always zero. */\n")
             // exclude internal implementation functions
             .exclude_item("crc32_iscsi_impl")
             .exclude_item("crc32_iso_hdlc_impl")
@@ -1385,7 +4077,16 @@ mod lib {
             .exclude_item("ISO_HDLC_TARGET")
             .exclude_item("ISCSI_TARGET")
             .exclude_item("CrcParams")
+            .exclude_item("CHECKSUM_ALIGNMENT")
             .rename_item("Digest", "CrcFastDigest")
+            .with_target_os_define("windows", "_WIN32")
+            // use the platform's own wchar_t
+            .exclude_item("wchar_t")
+            // use the platform's own struct iovec, where there is one
+            .exclude_item("iovec")
+            .with_after_include(
+                "\n#if !defined(_WIN32)\n#include <sys/uio.h>\ntypedef struct iovec iovec;\n#endif",
+            )
             .with_style(Both)
             // generate C header
             .with_language(C)
@@ -1418,6 +4119,46 @@ mod lib {
         Ok(())
     }
 
+    /// Tests whether the static library's symbol list matches the functions in the FFI header
+    #[test]
+    fn test_ffi_symbols() -> Result<(), String> {
+        const HEADER: &str = "libcrc_fast.h";
+        const SYMBOLS: &str = "libcrc_fast.syms";
+
+        let header = std::fs::read_to_string(HEADER).map_err(|error| error.to_string())?;
+
+        // declarations start at the beginning of a line, unlike the comments which mention them
+        let regex = regex::Regex::new(r"(?m)^[a-z].*?\b(crc_fast_\w+)\(")
+            .map_err(|error| error.to_string())?;
+        let mut symbols: Vec<&str> = regex
+            .captures_iter(&header)
+            .map(|captures| captures.get(1).unwrap().as_str())
+            .collect();
+        symbols.sort_unstable();
+        symbols.dedup();
+
+        let mut expected = String::from(
+            "# The symbols exported by the minimal static library, see `make staticlib`. This file is\n\
+             # auto-generated from libcrc_fast.h. Do not edit directly.\n",
+        );
+        for symbol in symbols {
+            expected.push_str(symbol);
+            expected.push('\n');
+        }
+
+        let actual = read(SYMBOLS).unwrap_or_default();
+
+        if expected.as_bytes() != actual {
+            write(SYMBOLS, expected).map_err(|error| error.to_string())?;
+            return Err(format!(
+                "{SYMBOLS} is not up-to-date, commit the generated file and try again"
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "crc64-only"))]
     fn get_custom_crc32_reflected() -> CrcParams {
         CrcParams::new(
             "Custom CRC-32/ISCSI",
@@ -1430,6 +4171,7 @@ mod lib {
         )
     }
 
+    #[cfg(not(feature = "crc64-only"))]
     fn get_custom_crc32_forward() -> CrcParams {
         CrcParams::new(
             "Custom CRC-32/BZIP2",
@@ -1442,6 +4184,7 @@ mod lib {
         )
     }
 
+    #[cfg(not(feature = "crc32-only"))]
     fn get_custom_crc64_reflected() -> CrcParams {
         CrcParams::new(
             "Custom CRC-64/NVME",
@@ -1454,6 +4197,7 @@ mod lib {
         )
     }
 
+    #[cfg(not(feature = "crc32-only"))]
     fn get_custom_crc64_forward() -> CrcParams {
         CrcParams::new(
             "Custom CRC-64/ECMA-182",
@@ -1474,8 +4218,8 @@ mod lib {
         let storage = CrcKeysStorage::from_keys_fold_256(test_keys);
 
         // Test valid key access
-        for i in 0..23 {
-            assert_eq!(storage.get_key(i), test_keys[i]);
+        for (i, &key) in test_keys.iter().enumerate() {
+            assert_eq!(storage.get_key(i), key);
         }
 
         // Test out-of-bounds access returns 0
@@ -1488,16 +4232,16 @@ mod lib {
     }
 
     #[test]
-    fn test_crc_keys_storage_future_test() {
+    fn test_crc_keys_storage_fold_512() {
         let test_keys = [
             1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
             25,
         ];
-        let storage = CrcKeysStorage::from_keys_fold_future_test(test_keys);
+        let storage = CrcKeysStorage::KeysFold512(test_keys);
 
         // Test valid key access
-        for i in 0..25 {
-            assert_eq!(storage.get_key(i), test_keys[i]);
+        for (i, &key) in test_keys.iter().enumerate() {
+            assert_eq!(storage.get_key(i), key);
         }
 
         // Test out-of-bounds access returns 0
@@ -1529,9 +4273,9 @@ mod lib {
         };
 
         // Test valid key access
-        for i in 0..23 {
-            assert_eq!(params.get_key(i), test_keys[i]);
-            assert_eq!(params.get_key_checked(i), Some(test_keys[i]));
+        for (i, &key) in test_keys.iter().enumerate() {
+            assert_eq!(params.get_key(i), key);
+            assert_eq!(params.get_key_checked(i), Some(key));
         }
 
         // Test out-of-bounds access
@@ -1554,8 +4298,7 @@ mod lib {
         const TEST_KEYS_25: [u64; 25] = [2; 25];
 
         const STORAGE_256: CrcKeysStorage = CrcKeysStorage::from_keys_fold_256(TEST_KEYS_23);
-        const STORAGE_FUTURE: CrcKeysStorage =
-            CrcKeysStorage::from_keys_fold_future_test(TEST_KEYS_25);
+        const STORAGE_FUTURE: CrcKeysStorage = CrcKeysStorage::KeysFold512(TEST_KEYS_25);
 
         // Verify the const constructors work correctly
         assert_eq!(STORAGE_256.get_key(0), 1);
@@ -1568,7 +4311,7 @@ mod lib {
     #[test]
     fn test_crc_keys_storage_bounds_safety() {
         let storage_256 = CrcKeysStorage::from_keys_fold_256([42; 23]);
-        let storage_future = CrcKeysStorage::from_keys_fold_future_test([84; 25]);
+        let storage_future = CrcKeysStorage::KeysFold512([84; 25]);
 
         // Test edge cases for bounds checking
         assert_eq!(storage_256.get_key(22), 42); // Last valid index
@@ -1581,4 +4324,71 @@ mod lib {
         assert_eq!(storage_256.get_key(usize::MAX), 0);
         assert_eq!(storage_future.get_key(usize::MAX), 0);
     }
+
+    #[test]
+    fn test_crc_keys_storage_with_key_count() {
+        let mut keys = [0; 25];
+        for (i, key) in keys.iter_mut().enumerate() {
+            *key = i as u64 + 1;
+        }
+        let storage_512 = CrcKeysStorage::from_keys_fold_512(keys);
+
+        let storage_256 = storage_512.with_key_count(23).unwrap();
+        assert_eq!(storage_256.key_count(), 23);
+        assert_eq!(storage_256, storage_512.to_keys_array_23());
+
+        assert_eq!(storage_512.with_key_count(25), Some(storage_512));
+        assert_eq!(storage_256.with_key_count(25), None);
+        assert_eq!(storage_512.with_key_count(24), None);
+
+        // narrower formats are zero-padded
+        assert_eq!(storage_256.to_keys_array_25()[..23], keys[..23]);
+        assert_eq!(storage_256.to_keys_array_25()[23..], [0, 0]);
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_crc_params_new_with_key_count() {
+        let params_25 = CrcParams::new_with_key_count(
+            "CRC-32/CUSTOM",
+            32,
+            0x1edc6f41,
+            0xffffffff,
+            true,
+            0xffffffff,
+            0xe3069283,
+            25,
+        );
+        let params_23 = CrcParams::new_with_key_count(
+            "CRC-32/CUSTOM",
+            32,
+            0x1edc6f41,
+            0xffffffff,
+            true,
+            0xffffffff,
+            0xe3069283,
+            23,
+        );
+
+        assert_eq!(params_25.key_count(), 25);
+        assert_eq!(params_23.key_count(), 23);
+        for i in 0..23 {
+            assert_eq!(params_23.get_key(i), params_25.get_key(i));
+        }
+
+        assert_eq!(
+            checksum_with_params(params_25, TEST_CHECK_STRING),
+            0xe3069283
+        );
+        assert_eq!(
+            checksum_with_params(params_23, TEST_CHECK_STRING),
+            0xe3069283
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Unsupported key count: 24")]
+    fn test_crc_params_new_with_unsupported_key_count() {
+        CrcParams::new_with_key_count("CRC-32/CUSTOM", 32, 0x1edc6f41, 0, true, 0, 0, 24);
+    }
 }
@@ -0,0 +1,395 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Per-block checksum manifests of files.
+//!
+//! A manifest holds a checksum for every fixed-size block of a file, plus the checksum of the
+//! whole file, which are calculated in a single read pass by combining the block checksums. They're
+//! useful for deduplication and delta tools, and torrent-style piece verification.
+//!
+//! Comparing the manifests of two files (or streams) localizes the byte ranges which differ between
+//! them, so sync tools can cheaply narrow down changes before comparing bytes.
+
+use crate::{CrcAlgorithm, CrcFastError, CrcParams, Digest};
+use std::fs::File;
+use std::io::{Error, ErrorKind, Read};
+use std::ops::Range;
+
+/// The checksums of each fixed-size block of a file, and of the whole file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockManifest {
+    /// The size of every block but the last, which may be shorter
+    pub block_size: usize,
+
+    /// The checksum of each block, in file order
+    pub blocks: Vec<u64>,
+
+    /// The checksum of the whole file
+    pub checksum: u64,
+
+    /// The length of the file, in bytes
+    pub len: u64,
+}
+
+impl BlockManifest {
+    /// Calculates the manifest of everything the reader returns, using the digest (which must be
+    /// newly created) for each block
+    pub(crate) fn from_reader(
+        digest: Digest,
+        mut reader: impl Read,
+        block_size: usize,
+    ) -> Result<Self, CrcFastError> {
+        if block_size == 0 {
+            return Err(Error::new(ErrorKind::InvalidInput, "block size must be non-zero").into());
+        }
+
+        let mut whole = digest;
+        let mut block = digest;
+        let mut blocks = Vec::new();
+        let mut buf = vec![0; block_size];
+
+        loop {
+            let n = read_full(&mut reader, &mut buf)?;
+            if n == 0 {
+                break;
+            }
+
+            block.update(&buf[..n]);
+            blocks.push(block.finalize());
+
+            #[cfg(feature = "tracing")]
+            tracing::trace!(
+                block = blocks.len() - 1,
+                bytes = n,
+                checksum = block.finalize(),
+                "checksummed block"
+            );
+
+            whole.combine(&block);
+            block.reset();
+
+            if n < block_size {
+                break;
+            }
+        }
+
+        Ok(Self {
+            block_size,
+            blocks,
+            checksum: whole.finalize(),
+            len: whole.get_amount(),
+        })
+    }
+
+    /// Returns the byte range of the block at index, which is shorter than `block_size` for the
+    /// last block of a file whose length isn't a multiple of it
+    pub fn block_range(&self, index: usize) -> Range<u64> {
+        let start = index as u64 * self.block_size as u64;
+
+        start.min(self.len)..(start + self.block_size as u64).min(self.len)
+    }
+
+    /// Returns the byte ranges whose blocks differ between this manifest and another, in order,
+    /// with adjacent differing blocks merged into a single range.
+    ///
+    /// A block differs if its checksum or length differs, or if it only exists in one of the
+    /// manifests, so the tail of the longer of two files is always reported. Matching checksums
+    /// don't guarantee matching bytes, but differing ones guarantee differing bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the manifests were calculated with different block sizes.
+    pub fn diff(&self, other: &BlockManifest) -> Vec<Range<u64>> {
+        assert_eq!(
+            self.block_size, other.block_size,
+            "Manifests must have the same block size"
+        );
+
+        let mut ranges: Vec<Range<u64>> = Vec::new();
+
+        for i in 0..self.blocks.len().max(other.blocks.len()) {
+            let ours = self.block_range(i);
+            let theirs = other.block_range(i);
+
+            if self.blocks.get(i) == other.blocks.get(i) && ours == theirs {
+                continue;
+            }
+
+            let start = i as u64 * self.block_size as u64;
+            let end = ours.end.max(theirs.end);
+
+            match ranges.last_mut() {
+                Some(last) if last.end == start => last.end = end,
+                _ => ranges.push(start..end),
+            }
+        }
+
+        ranges
+    }
+}
+
+/// Reads until the buffer is full or the reader is exhausted, returning the number of bytes read
+fn read_full(reader: &mut impl Read, buf: &mut [u8]) -> Result<usize, Error> {
+    let mut filled = 0;
+
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(filled)
+}
+
+/// Computes the CRC checksum of each `block_size` block of the given file, and of the whole file,
+/// using the specified algorithm, in a single read pass.
+///
+/// The file is read one block at a time, so `block_size` bytes are buffered.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, or `block_size` is 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::env;
+/// use crc_fast::{checksum_file_blocks, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// // for example/test purposes only, use your own file path
+/// let file_path = env::current_dir().expect("missing working dir").join("crc-check.txt");
+/// let file_on_disk = file_path.to_str().unwrap();
+///
+/// let manifest = checksum_file_blocks(Crc32IsoHdlc, file_on_disk, 4).unwrap();
+///
+/// assert_eq!(manifest.blocks.len(), 3); // "1234", "5678", "9"
+/// assert_eq!(manifest.checksum, 0xcbf43926);
+/// ```
+pub fn checksum_file_blocks(
+    algorithm: CrcAlgorithm,
+    path: &str,
+    block_size: usize,
+) -> Result<BlockManifest, CrcFastError> {
+    BlockManifest::from_reader(Digest::new(algorithm), File::open(path)?, block_size)
+}
+
+/// Computes the CRC checksum of each `block_size` block of the given file, and of the whole file,
+/// using custom CRC parameters, in a single read pass.
+///
+/// # Errors
+///
+/// This function will return an error if the file cannot be read, or `block_size` is 0.
+pub fn checksum_file_blocks_with_params(
+    params: CrcParams,
+    path: &str,
+    block_size: usize,
+) -> Result<BlockManifest, CrcFastError> {
+    BlockManifest::from_reader(
+        Digest::new_with_params(params),
+        File::open(path)?,
+        block_size,
+    )
+}
+
+/// Compares two files block-by-block using the specified algorithm, and returns the byte ranges
+/// which differ between them. See [`BlockManifest::diff`] for details.
+///
+/// # Errors
+///
+/// This function will return an error if either file cannot be read, or `block_size` is 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::env;
+/// use crc_fast::{diff_file_blocks, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// // for example/test purposes only, use your own file paths
+/// let file_path = env::current_dir().expect("missing working dir").join("crc-check.txt");
+/// let file_on_disk = file_path.to_str().unwrap();
+///
+/// let ranges = diff_file_blocks(Crc32IsoHdlc, file_on_disk, file_on_disk, 4).unwrap();
+///
+/// assert!(ranges.is_empty());
+/// ```
+pub fn diff_file_blocks(
+    algorithm: CrcAlgorithm,
+    path1: &str,
+    path2: &str,
+    block_size: usize,
+) -> Result<Vec<Range<u64>>, CrcFastError> {
+    diff_reader_blocks(
+        algorithm,
+        File::open(path1)?,
+        File::open(path2)?,
+        block_size,
+    )
+}
+
+/// Compares two streams block-by-block using the specified algorithm, reading each to the end,
+/// and returns the byte ranges which differ between them. See [`BlockManifest::diff`] for details.
+///
+/// # Errors
+///
+/// This function will return an error if either stream cannot be read, or `block_size` is 0.
+pub fn diff_reader_blocks(
+    algorithm: CrcAlgorithm,
+    reader1: impl Read,
+    reader2: impl Read,
+    block_size: usize,
+) -> Result<Vec<Range<u64>>, CrcFastError> {
+    let digest = Digest::new(algorithm);
+
+    let manifest1 = BlockManifest::from_reader(digest, reader1, block_size)?;
+    let manifest2 = BlockManifest::from_reader(digest, reader2, block_size)?;
+
+    Ok(manifest1.diff(&manifest2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum;
+    #[cfg(not(feature = "crc64-only"))]
+    use crate::test::consts::TEST_CHECK_STRING;
+
+    #[test]
+    #[cfg(not(feature = "crc32-only"))]
+    fn test_block_manifest() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i * 7 % 251) as u8).collect();
+
+        for block_size in [1, 7, 4096, 10_000, 20_000] {
+            let manifest = BlockManifest::from_reader(
+                Digest::new(CrcAlgorithm::Crc64Nvme),
+                &data[..],
+                block_size,
+            )
+            .unwrap();
+
+            assert_eq!(manifest.len, data.len() as u64);
+            assert_eq!(manifest.checksum, checksum(CrcAlgorithm::Crc64Nvme, &data));
+            assert_eq!(manifest.blocks.len(), data.len().div_ceil(block_size));
+
+            for (i, chunk) in data.chunks(block_size).enumerate() {
+                assert_eq!(manifest.blocks[i], checksum(CrcAlgorithm::Crc64Nvme, chunk));
+
+                let range = manifest.block_range(i);
+                assert_eq!(&data[range.start as usize..range.end as usize], chunk);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_block_manifest_empty() {
+        let manifest =
+            BlockManifest::from_reader(Digest::new(CrcAlgorithm::Crc32Iscsi), &[][..], 16).unwrap();
+
+        assert!(manifest.blocks.is_empty());
+        assert_eq!(manifest.len, 0);
+        assert_eq!(manifest.checksum, checksum(CrcAlgorithm::Crc32Iscsi, &[]));
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_block_manifest_zero_block_size() {
+        let result =
+            BlockManifest::from_reader(Digest::new(CrcAlgorithm::Crc32Iscsi), TEST_CHECK_STRING, 0);
+
+        assert!(
+            matches!(result, Err(CrcFastError::Io(error)) if error.kind() == ErrorKind::InvalidInput)
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_checksum_file_blocks() {
+        let manifest =
+            checksum_file_blocks(CrcAlgorithm::Crc32IsoHdlc, "crc-check.txt", 4).unwrap();
+
+        assert_eq!(manifest.checksum, 0xcbf43926);
+        assert_eq!(
+            manifest.blocks,
+            vec![
+                checksum(CrcAlgorithm::Crc32IsoHdlc, b"1234"),
+                checksum(CrcAlgorithm::Crc32IsoHdlc, b"5678"),
+                checksum(CrcAlgorithm::Crc32IsoHdlc, b"9"),
+            ]
+        );
+
+        assert!(checksum_file_blocks(CrcAlgorithm::Crc32IsoHdlc, "missing.txt", 4).is_err());
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_diff_reader_blocks() {
+        let original: Vec<u8> = (0..1000u32).map(|i| (i % 256) as u8).collect();
+
+        assert!(
+            diff_reader_blocks(CrcAlgorithm::Crc32Iscsi, &original[..], &original[..], 64)
+                .unwrap()
+                .is_empty()
+        );
+
+        // two adjacent changed blocks merge, and a separate one stands alone
+        let mut changed = original.clone();
+        changed[70] ^= 1;
+        changed[130] ^= 1;
+        changed[900] ^= 1;
+
+        assert_eq!(
+            diff_reader_blocks(CrcAlgorithm::Crc32Iscsi, &original[..], &changed[..], 64).unwrap(),
+            vec![64..192, 896..960]
+        );
+
+        // a truncated copy differs in its partial last block and the missing tail
+        assert_eq!(
+            diff_reader_blocks(
+                CrcAlgorithm::Crc32Iscsi,
+                &original[..],
+                &original[..900],
+                64
+            )
+            .unwrap(),
+            vec![896..1000]
+        );
+        assert_eq!(
+            diff_reader_blocks(
+                CrcAlgorithm::Crc32Iscsi,
+                &original[..896],
+                &original[..],
+                64
+            )
+            .unwrap(),
+            vec![896..1000]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Manifests must have the same block size")]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_diff_block_size_mismatch() {
+        let digest = Digest::new(CrcAlgorithm::Crc32Iscsi);
+
+        let manifest1 = BlockManifest::from_reader(digest, TEST_CHECK_STRING, 4).unwrap();
+        let manifest2 = BlockManifest::from_reader(digest, TEST_CHECK_STRING, 3).unwrap();
+
+        manifest1.diff(&manifest2);
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc32-only"))]
+    fn test_diff_file_blocks() {
+        assert!(
+            diff_file_blocks(CrcAlgorithm::Crc64Nvme, "crc-check.txt", "crc-check.txt", 4)
+                .unwrap()
+                .is_empty()
+        );
+
+        assert!(
+            diff_file_blocks(CrcAlgorithm::Crc64Nvme, "crc-check.txt", "missing.txt", 4).is_err()
+        );
+    }
+}
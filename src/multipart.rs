@@ -0,0 +1,449 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Per-part and whole-object checksums for multipart uploads.
+//!
+//! Object stores such as S3 and GCS take an upload as a sequence of parts, each with its own
+//! checksum, and then check the whole object against either a combined checksum or one derived from
+//! the parts. The data is only checksummed once: each part has its own Digest, which is combined
+//! into the whole-object Digest when the part is closed.
+//!
+//! For parts which were uploaded earlier, `compose_object_crc()` recovers the whole-object
+//! checksum from the stored part checksums and lengths alone, without re-reading any data. Stores
+//! report checksums as the base64 of their big-endian bytes (S3's `x-amz-checksum-crc64nvme`,
+//! `x-amz-checksum-crc32c`, and `x-amz-checksum-crc32` headers, and GCS's `crc32c`), which
+//! `encode_object_crc()` and `decode_object_crc()` convert to and from.
+//!
+//! Only full-object checksums can be recovered this way. S3's composite checksums (with a `-N`
+//! part count suffix) are a checksum of the part checksums, not of the data, so they're rejected.
+
+use crate::{
+    get_calculator_params, try_checksum_combine_parts_with_params, CrcAlgorithm, CrcFastError,
+    CrcParams, Digest,
+};
+use std::io::Write;
+
+/// Checksums data written as a sequence of parts, and the whole object they make up
+#[derive(Clone, Debug)]
+pub struct MultipartHasher {
+    /// The checksum of the current part so far
+    part: Digest,
+
+    /// The combined checksum of all the closed parts
+    object: Digest,
+
+    /// The checksum and length of each closed part, in order
+    parts: Vec<(u64, u64)>,
+}
+
+impl MultipartHasher {
+    /// Creates a new hasher for the specified algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{checksum, MultipartHasher, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let mut hasher = MultipartHasher::new(Crc32IsoHdlc);
+    ///
+    /// hasher.update(b"1234");
+    /// assert_eq!(hasher.next_part(), (checksum(Crc32IsoHdlc, b"1234"), 4));
+    ///
+    /// hasher.update(b"56789");
+    /// assert_eq!(hasher.next_part(), (checksum(Crc32IsoHdlc, b"56789"), 5));
+    ///
+    /// assert_eq!(hasher.finish(), 0xcbf43926);
+    /// ```
+    pub fn new(algorithm: CrcAlgorithm) -> Self {
+        Self::from_digest(Digest::new(algorithm))
+    }
+
+    /// Creates a new hasher using custom CRC parameters.
+    pub fn new_with_params(params: CrcParams) -> Self {
+        Self::from_digest(Digest::new_with_params(params))
+    }
+
+    fn from_digest(digest: Digest) -> Self {
+        Self {
+            part: digest,
+            object: digest,
+            parts: Vec::new(),
+        }
+    }
+
+    /// Updates the current part with data.
+    #[inline(always)]
+    pub fn update(&mut self, data: &[u8]) {
+        self.part.update(data);
+    }
+
+    /// Closes the current part, returning its checksum and length, and starts the next one.
+    ///
+    /// Closing a part with no data returns the checksum of empty input and a length of 0, and
+    /// records it like any other part.
+    pub fn next_part(&mut self) -> (u64, u64) {
+        let part = (self.part.finalize(), self.part.get_amount());
+
+        self.object.combine(&self.part);
+        self.part.reset();
+        self.parts.push(part);
+
+        part
+    }
+
+    /// Returns the length of the current part so far.
+    pub fn part_len(&self) -> u64 {
+        self.part.get_amount()
+    }
+
+    /// Returns the checksum and length of each closed part, in order.
+    pub fn parts(&self) -> &[(u64, u64)] {
+        &self.parts
+    }
+
+    /// Returns the length of everything written so far, including the current part.
+    pub fn len(&self) -> u64 {
+        self.object.get_amount() + self.part.get_amount()
+    }
+
+    /// Returns true if nothing has been written yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the checksum of the whole object, including any data written since the last
+    /// `next_part()`.
+    ///
+    /// Call `next_part()` before this to get the final part's own checksum.
+    pub fn finish(&self) -> u64 {
+        let mut object = self.object;
+        object.combine(&self.part);
+
+        object.finalize()
+    }
+}
+
+impl Write for MultipartHasher {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.update(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// The standard base64 alphabet, as used by S3 and GCS checksum headers
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Recovers the checksum of a whole object from the checksums and lengths of its parts, given as
+/// (checksum, length) pairs in upload order, using the specified algorithm.
+///
+/// This is the full-object checksum S3 reports for a multipart upload, which can then be checked
+/// without re-reading the data.
+///
+/// # Errors
+///
+/// Returns [`CrcFastError::ChecksumTooWide`] for the first part checksum with bits set above the
+/// algorithm's width, such as a CRC-64 part checksum given for a CRC-32 object.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, compose_object_crc, CrcAlgorithm::Crc64Nvme};
+///
+/// let parts = [
+///     (checksum(Crc64Nvme, b"1234"), 4),
+///     (checksum(Crc64Nvme, b"56789"), 5),
+/// ];
+///
+/// assert_eq!(
+///     compose_object_crc(Crc64Nvme, &parts).unwrap(),
+///     checksum(Crc64Nvme, b"123456789")
+/// );
+/// ```
+pub fn compose_object_crc(
+    algorithm: CrcAlgorithm,
+    parts: &[(u64, u64)],
+) -> Result<u64, CrcFastError> {
+    compose_object_crc_with_params(get_calculator_params(algorithm).1, parts)
+}
+
+/// Recovers the checksum of a whole object from the checksums and lengths of its parts, using
+/// custom CRC parameters. See `compose_object_crc()`.
+pub fn compose_object_crc_with_params(
+    params: CrcParams,
+    parts: &[(u64, u64)],
+) -> Result<u64, CrcFastError> {
+    try_checksum_combine_parts_with_params(params, parts)
+}
+
+/// Checks an object's stored, base64-encoded, full-object checksum against the one recovered from
+/// its parts, using the specified algorithm.
+///
+/// Returns false, rather than an error, if the expected checksum isn't a valid encoding for the
+/// algorithm's width, including S3's composite checksums.
+///
+/// # Errors
+///
+/// Returns [`CrcFastError::ChecksumTooWide`] for the first part checksum with bits set above the
+/// algorithm's width.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{checksum, verify_object_crc, CrcAlgorithm::Crc32IsoHdlc};
+///
+/// let parts = [
+///     (checksum(Crc32IsoHdlc, b"1234"), 4),
+///     (checksum(Crc32IsoHdlc, b"56789"), 5),
+/// ];
+///
+/// assert!(verify_object_crc(Crc32IsoHdlc, &parts, "y/Q5Jg==").unwrap());
+/// ```
+pub fn verify_object_crc(
+    algorithm: CrcAlgorithm,
+    parts: &[(u64, u64)],
+    expected: &str,
+) -> Result<bool, CrcFastError> {
+    verify_object_crc_with_params(get_calculator_params(algorithm).1, parts, expected)
+}
+
+/// Checks an object's stored, base64-encoded, full-object checksum against the one recovered from
+/// its parts, using custom CRC parameters. See `verify_object_crc()`.
+pub fn verify_object_crc_with_params(
+    params: CrcParams,
+    parts: &[(u64, u64)],
+    expected: &str,
+) -> Result<bool, CrcFastError> {
+    let actual = compose_object_crc_with_params(params, parts)?;
+
+    Ok(decode_object_crc(params.width, expected) == Some(actual))
+}
+
+/// Encodes a checksum the way object stores report it: the base64 (with padding) of its
+/// big-endian bytes, 4 for a 32-bit CRC and 8 for a 64-bit one.
+///
+/// Only the bottom `width` bits of the checksum are encoded.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::encode_object_crc;
+///
+/// assert_eq!(encode_object_crc(32, 0xcbf43926), "y/Q5Jg==");
+/// assert_eq!(encode_object_crc(64, 0xae8b14860a799888), "rosUhgp5mIg=");
+/// ```
+pub fn encode_object_crc(width: u8, checksum: u64) -> String {
+    let len = (width as usize).div_ceil(8).min(8);
+    let bytes = &checksum.to_be_bytes()[8 - len..];
+
+    let mut encoded = String::with_capacity(len.div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let mut group = [0u8; 3];
+        group[..chunk.len()].copy_from_slice(chunk);
+        let bits = u32::from_be_bytes([0, group[0], group[1], group[2]]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64[(bits >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+/// Decodes a checksum reported by an object store as the base64 of its big-endian bytes.
+///
+/// Returns None unless the encoding is canonical and padded, and decodes to exactly the number of
+/// bytes for the width, so S3's composite checksums and checksums of another width are rejected.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::decode_object_crc;
+///
+/// assert_eq!(decode_object_crc(32, "y/Q5Jg=="), Some(0xcbf43926));
+/// assert_eq!(decode_object_crc(32, "y/Q5Jg==-2"), None);
+/// assert_eq!(decode_object_crc(64, "y/Q5Jg=="), None);
+/// ```
+pub fn decode_object_crc(width: u8, encoded: &str) -> Option<u64> {
+    let len = (width as usize).div_ceil(8).min(8);
+    let encoded = encoded.as_bytes();
+    if len == 0 || encoded.len() != len.div_ceil(3) * 4 {
+        return None;
+    }
+
+    let mut checksum = 0u64;
+    let mut decoded = 0;
+    for (index, group) in encoded.chunks(4).enumerate() {
+        // only the final group may be padded, by as many bytes as the length is short of a group
+        let group_len = (len - index * 3).min(3);
+        let mut bits = 0u32;
+        for (i, &c) in group.iter().enumerate() {
+            let value = if i <= group_len {
+                BASE64.iter().position(|&b| b == c)? as u32
+            } else if c == b'=' {
+                0
+            } else {
+                return None;
+            };
+            bits = bits << 6 | value;
+        }
+
+        // unused bits in the final character must be zero for a canonical encoding
+        if bits & (0xff_ffff >> (8 * group_len)) != 0 {
+            return None;
+        }
+
+        for byte in &bits.to_be_bytes()[1..=group_len] {
+            checksum = checksum << 8 | *byte as u64;
+            decoded += 1;
+        }
+    }
+
+    (decoded == len).then_some(checksum)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "crc64-only"))]
+    use crate::checksum;
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    use crate::checksum_combine_parts;
+
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_parts_and_object() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+        for algorithm in [CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc64Nvme] {
+            let mut hasher = MultipartHasher::new(algorithm);
+
+            for chunk in data.chunks(8 * 1024 + 3) {
+                hasher.write_all(chunk).unwrap();
+                assert_eq!(hasher.part_len(), chunk.len() as u64);
+
+                assert_eq!(
+                    hasher.next_part(),
+                    (checksum(algorithm, chunk), chunk.len() as u64)
+                );
+            }
+
+            assert_eq!(hasher.len(), data.len() as u64);
+            assert_eq!(hasher.finish(), checksum(algorithm, &data));
+            assert_eq!(
+                checksum_combine_parts(algorithm, hasher.parts()),
+                checksum(algorithm, &data)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_finish_includes_current_part() {
+        let mut hasher = MultipartHasher::new(CrcAlgorithm::Crc32IsoHdlc);
+        assert!(hasher.is_empty());
+
+        hasher.update(b"1234");
+        hasher.next_part();
+        hasher.update(b"56789");
+
+        assert_eq!(hasher.parts().len(), 1);
+        assert_eq!(hasher.finish(), 0xcbf43926);
+
+        // finishing doesn't close the current part
+        assert_eq!(
+            hasher.next_part(),
+            (checksum(CrcAlgorithm::Crc32IsoHdlc, b"56789"), 5)
+        );
+        assert_eq!(hasher.finish(), 0xcbf43926);
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_compose_object_crc() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+        for algorithm in [
+            CrcAlgorithm::Crc32IsoHdlc,
+            CrcAlgorithm::Crc32Iscsi,
+            CrcAlgorithm::Crc64Nvme,
+        ] {
+            let mut hasher = MultipartHasher::new(algorithm);
+            for chunk in data.chunks(5 * 1024 + 1) {
+                hasher.update(chunk);
+                hasher.next_part();
+            }
+
+            let expected = checksum(algorithm, &data);
+            let width = get_calculator_params(algorithm).1.width;
+
+            assert_eq!(
+                compose_object_crc(algorithm, hasher.parts()).unwrap(),
+                expected
+            );
+            assert!(verify_object_crc(
+                algorithm,
+                hasher.parts(),
+                &encode_object_crc(width, expected)
+            )
+            .unwrap());
+            assert!(!verify_object_crc(
+                algorithm,
+                hasher.parts(),
+                &encode_object_crc(width, expected ^ 1)
+            )
+            .unwrap());
+        }
+
+        assert!(matches!(
+            compose_object_crc(CrcAlgorithm::Crc32IsoHdlc, &[(1 << 32, 1)]),
+            Err(CrcFastError::ChecksumTooWide { width: 32, .. })
+        ));
+    }
+
+    #[test]
+    fn test_object_crc_encoding() {
+        for checksum in [0, 1, 0xcbf43926, 0xffff_ffff, 0x8000_0000] {
+            let encoded = encode_object_crc(32, checksum);
+            assert_eq!(encoded.len(), 8);
+            assert_eq!(decode_object_crc(32, &encoded), Some(checksum));
+        }
+
+        for checksum in [0, 1, 0xae8b14860a799888, u64::MAX, 1 << 63] {
+            let encoded = encode_object_crc(64, checksum);
+            assert_eq!(encoded.len(), 12);
+            assert_eq!(decode_object_crc(64, &encoded), Some(checksum));
+        }
+
+        assert_eq!(encode_object_crc(32, 0), "AAAAAA==");
+        assert_eq!(encode_object_crc(64, u64::MAX), "//////////8=");
+
+        // the wrong padding, length, or alphabet, and non-zero unused bits
+        for encoded in [
+            "y/Q5Jg=",
+            "y/Q5Jg=A",
+            "y/Q5J===",
+            "y/Q5Jh==",
+            "y_Q5Jg==",
+            "",
+            "rosUhgp5mIg=",
+        ] {
+            assert_eq!(decode_object_crc(32, encoded), None, "{encoded}");
+        }
+        assert_eq!(decode_object_crc(64, "rosUhgp5mIh="), None);
+    }
+}
@@ -0,0 +1,126 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Non-temporal load path for buffers far larger than the last-level cache.
+//!
+//! The data is calculated in small chunks, while the chunks a few kilobytes ahead are prefetched
+//! with a non-temporal (streaming) hint, so they're loaded close to the core without displacing
+//! the working set of co-located services from the shared caches.
+//!
+//! On architectures without a non-temporal prefetch hint, the data is calculated as usual.
+
+use crate::{CalculatorFn, CrcParams};
+
+/// Size of the chunks which are calculated at a time
+const CHUNK_SIZE: usize = 1024;
+
+/// Number of chunks ahead of the current one to prefetch, which needs to cover memory latency
+/// without exceeding the L1 cache
+const PREFETCH_DISTANCE: usize = 8;
+
+/// Cache line size to step through the prefetched chunks
+const CACHE_LINE_SIZE: usize = 64;
+
+/// Updates the state using the calculator, loading the data with non-temporal prefetch hints
+#[inline]
+pub(crate) fn update(
+    mut state: u64,
+    data: &[u8],
+    params: CrcParams,
+    calculator: CalculatorFn,
+) -> u64 {
+    if !HAS_NON_TEMPORAL_PREFETCH {
+        return calculator(state, data, params);
+    }
+
+    // warm up the prefetch window, then keep it PREFETCH_DISTANCE chunks ahead
+    let prefetch_len = data.len().min(CHUNK_SIZE * PREFETCH_DISTANCE);
+    prefetch_non_temporal(&data[..prefetch_len]);
+
+    for (i, chunk) in data.chunks(CHUNK_SIZE).enumerate() {
+        let ahead = (i + PREFETCH_DISTANCE) * CHUNK_SIZE;
+        if ahead < data.len() {
+            prefetch_non_temporal(&data[ahead..data.len().min(ahead + CHUNK_SIZE)]);
+        }
+
+        state = calculator(state, chunk, params);
+    }
+
+    state
+}
+
+/// Whether this architecture has a non-temporal prefetch hint
+const HAS_NON_TEMPORAL_PREFETCH: bool = cfg!(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse"),
+    target_arch = "aarch64"
+));
+
+/// Prefetches every cache line of the data with a non-temporal hint
+#[inline(always)]
+fn prefetch_non_temporal(data: &[u8]) {
+    for offset in (0..data.len()).step_by(CACHE_LINE_SIZE) {
+        let ptr = data[offset..].as_ptr();
+
+        // SAFETY: prefetches are hints which never fault, and the pointer is in bounds anyway
+        #[cfg(any(
+            target_arch = "x86_64",
+            all(target_arch = "x86", target_feature = "sse")
+        ))]
+        unsafe {
+            #[cfg(target_arch = "x86")]
+            use std::arch::x86::{_mm_prefetch, _MM_HINT_NTA};
+            #[cfg(target_arch = "x86_64")]
+            use std::arch::x86_64::{_mm_prefetch, _MM_HINT_NTA};
+
+            _mm_prefetch::<_MM_HINT_NTA>(ptr as *const i8);
+        }
+
+        // SAFETY: prefetches are hints which never fault, and the pointer is in bounds anyway
+        #[cfg(target_arch = "aarch64")]
+        unsafe {
+            std::arch::asm!(
+                "prfm pldl1strm, [{ptr}]",
+                ptr = in(reg) ptr,
+                options(nostack, preserves_flags, readonly)
+            );
+        }
+
+        #[cfg(not(any(
+            target_arch = "x86_64",
+            all(target_arch = "x86", target_feature = "sse"),
+            target_arch = "aarch64"
+        )))]
+        let _ = ptr;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::Calculator;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use crate::traits::CrcCalculator;
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_update_matches_calculator() {
+        let mut data = vec![0u8; CHUNK_SIZE * (PREFETCH_DISTANCE + 3) + 100];
+        rng().fill(&mut data[..]);
+
+        let calculator = Calculator::calculate as CalculatorFn;
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            for len in [0, 1, CHUNK_SIZE - 1, CHUNK_SIZE + 1, data.len()] {
+                assert_eq!(
+                    update(params.init, &data[..len], params, calculator),
+                    calculator(params.init, &data[..len], params),
+                    "Mismatch CRC, {}, length {}",
+                    config.get_name(),
+                    len
+                );
+            }
+        }
+    }
+}
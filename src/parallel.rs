@@ -0,0 +1,257 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Automatic intra-buffer parallelism for very large inputs.
+//!
+//! When enabled, a single update over a slice at least as large as the threshold is split into
+//! one chunk per core, each chunk is calculated on its own thread, and the results are combined.
+//! It's disabled by default, since spawning threads isn't appropriate for every application.
+//!
+//! Combining many part checksums is reduced as a tree, whose levels are split across cores once
+//! they're large enough.
+
+use crate::{combine, CalculatorFn, CrcParams};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Threshold value which disables parallelism
+const DISABLED: usize = usize::MAX;
+
+/// Smallest chunk worth calculating on its own thread, so small inputs above a low threshold don't
+/// spawn more threads than they can use
+const MIN_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Smallest number of pairs worth combining on their own thread, since each combine is cheap
+const MIN_PAIRS_PER_THREAD: usize = 64;
+
+/// Global parallelism threshold, in bytes
+static PARALLEL_THRESHOLD: AtomicUsize = AtomicUsize::new(DISABLED);
+
+/// Sets the global input size, in bytes, at or above which a single update is split across cores
+/// and then combined, or `None` to disable it (the default).
+///
+/// This applies to `checksum()`, `checksum_with_params()`, and `Digest::update()`, so large inputs
+/// get parallel speedups with no other code changes. Use `Digest::set_parallel_threshold()` to
+/// override it for a single `Digest`.
+///
+/// Inputs are split into at most one chunk per available core, and each chunk is at least 4MiB,
+/// so the threshold should typically be in the hundreds of megabytes.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::set_parallel_threshold;
+///
+/// // split inputs of 256MiB or more across cores
+/// set_parallel_threshold(Some(256 * 1024 * 1024));
+/// ```
+pub fn set_parallel_threshold(threshold: Option<usize>) {
+    PARALLEL_THRESHOLD.store(threshold.unwrap_or(DISABLED), Ordering::Relaxed);
+}
+
+/// Returns the global parallelism threshold, in bytes, or `None` if it's disabled.
+pub fn get_parallel_threshold() -> Option<usize> {
+    match PARALLEL_THRESHOLD.load(Ordering::Relaxed) {
+        DISABLED => None,
+        threshold => Some(threshold),
+    }
+}
+
+/// Converts an optional threshold to its internal representation, where `usize::MAX` disables it
+#[inline(always)]
+pub(crate) fn threshold_or_disabled(threshold: Option<usize>) -> usize {
+    threshold.unwrap_or(DISABLED)
+}
+
+/// Updates the state using the calculator, splitting the data across cores if it's at least as
+/// large as the threshold (the global one, unless `threshold` overrides it)
+#[inline(always)]
+pub(crate) fn update(
+    state: u64,
+    data: &[u8],
+    params: CrcParams,
+    calculator: CalculatorFn,
+    threshold: Option<usize>,
+) -> u64 {
+    let threshold = threshold.unwrap_or_else(|| PARALLEL_THRESHOLD.load(Ordering::Relaxed));
+
+    if data.len() < threshold {
+        return calculator(state, data, params);
+    }
+
+    update_parallel(state, data, params, calculator)
+}
+
+/// Splits the data into one chunk per core, calculates each on its own thread, and combines them
+#[cold]
+fn update_parallel(state: u64, data: &[u8], params: CrcParams, calculator: CalculatorFn) -> u64 {
+    let threads = std::thread::available_parallelism()
+        .map(|threads| threads.get())
+        .unwrap_or(1)
+        .min(data.len() / MIN_CHUNK_SIZE);
+
+    if threads < 2 {
+        return calculator(state, data, params);
+    }
+
+    let chunk_size = data.len().div_ceil(threads);
+    let (first, rest) = data.split_at(chunk_size);
+
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = rest
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let worker = scope.spawn(move || calculator(params.init, chunk, params));
+
+                (worker, chunk.len() as u64)
+            })
+            .collect();
+
+        // the first chunk continues from the current state, on the current thread
+        let crc = calculator(state, first, params) ^ params.xorout;
+
+        workers.into_iter().fold(crc, |crc, (worker, len)| {
+            let chunk_crc = worker.join().expect("CRC worker thread panicked") ^ params.xorout;
+
+            combine::checksums(crc, chunk_crc, len, params)
+        }) ^ params.xorout
+    })
+}
+
+/// Combines the (checksum, length) parts, in order, by combining adjacent pairs into a level half
+/// the size until one remains, so the combines have O(log n) depth instead of O(n), and splitting
+/// each large level across cores
+pub(crate) fn combine_parts(parts: &[(u64, u64)], params: CrcParams) -> u64 {
+    let threads = std::thread::available_parallelism()
+        .map(|threads| threads.get())
+        .unwrap_or(1);
+
+    let mut level: Vec<(u64, u128)> = parts.iter().map(|&(crc, len)| (crc, len.into())).collect();
+
+    if level.is_empty() {
+        return params.init ^ params.xorout;
+    }
+
+    while level.len() > 1 {
+        level = combine_level(&level, params, threads);
+    }
+
+    level[0].0
+}
+
+/// Combines each adjacent pair of parts in the level, on up to `threads` threads
+fn combine_level(level: &[(u64, u128)], params: CrcParams, threads: usize) -> Vec<(u64, u128)> {
+    let pairs = level.len().div_ceil(2);
+    let threads = threads.min(pairs / MIN_PAIRS_PER_THREAD);
+
+    if threads < 2 {
+        return combine_pairs(level, params);
+    }
+
+    // an even number of parts per thread, so no pair is split between threads
+    let parts_per_thread = pairs.div_ceil(threads) * 2;
+
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = level
+            .chunks(parts_per_thread)
+            .map(|parts| scope.spawn(move || combine_pairs(parts, params)))
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("CRC combine thread panicked"))
+            .collect()
+    })
+}
+
+/// Combines each adjacent pair of parts, passing through a trailing unpaired part
+fn combine_pairs(parts: &[(u64, u128)], params: CrcParams) -> Vec<(u64, u128)> {
+    parts
+        .chunks(2)
+        .map(|pair| match *pair {
+            [(crc1, len1), (crc2, len2)] => (
+                combine::checksums_wide(crc1, crc2, len2, params),
+                len1 + len2,
+            ),
+            [part] => part,
+            _ => unreachable!("chunks(2) yields one or two parts"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::Calculator;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+    use crate::traits::CrcCalculator;
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_update_parallel_matches_serial() {
+        let mut data = vec![0u8; 3 * MIN_CHUNK_SIZE + 12345];
+        rng().fill(&mut data[..]);
+
+        let calculator = Calculator::calculate as CalculatorFn;
+
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            // a non-initial starting state, as with a Digest which has already been updated
+            let state = calculator(params.init, b"123456789", params);
+
+            assert_eq!(
+                update_parallel(state, &data, params, calculator),
+                calculator(state, &data, params),
+                "Mismatch CRC, {}",
+                config.get_name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_threshold_override() {
+        let calculator = Calculator::calculate as CalculatorFn;
+        let params = *TEST_ALL_CONFIGS[0].get_params();
+
+        // a zero threshold always takes the parallel path, which falls back for small inputs
+        assert_eq!(
+            update(params.init, b"123456789", params, calculator, Some(0)) ^ params.xorout,
+            TEST_ALL_CONFIGS[0].get_check()
+        );
+
+        assert_eq!(threshold_or_disabled(None), DISABLED);
+        assert_eq!(threshold_or_disabled(Some(1024)), 1024);
+    }
+
+    #[test]
+    fn test_combine_parts_matches_serial() {
+        let mut rng = rng();
+        let data: Vec<u8> = (0..200_000).map(|_| rng.random()).collect();
+
+        for config in TEST_ALL_CONFIGS {
+            let algorithm = config.get_algorithm();
+            let params = crate::get_calculator_params(algorithm).1;
+
+            // enough parts to split levels across threads, of uneven (and empty) sizes
+            let mut parts = Vec::new();
+            let mut offset = 0;
+            while offset < data.len() {
+                let len = rng.random_range(0..300).min(data.len() - offset);
+                parts.push((
+                    crate::checksum(algorithm, &data[offset..offset + len]),
+                    len as u64,
+                ));
+                offset += len;
+            }
+
+            assert_eq!(
+                combine_parts(&parts, params),
+                config.checksum_with_reference(&data)
+            );
+            assert_eq!(combine_parts(&parts[..1], params), parts[0].0);
+            assert_eq!(
+                combine_parts(&[], params),
+                config.checksum_with_reference(&[])
+            );
+        }
+    }
+}
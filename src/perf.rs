@@ -0,0 +1,195 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Opt-in performance counters for a `Digest`.
+//!
+//! When enabled with `Digest::set_perf_stats()`, every calculation the Digest makes is counted,
+//! split by whether it was long enough for the tier's folding kernel, along with the bytes the
+//! staging buffer absorbed, so users can verify they're hitting the SIMD path rather than the
+//! short-input path.
+//!
+//! `estimate_throughput()` measures what a single thread can sustain on the selected tier, for
+//! sizing worker pools.
+
+use crate::consts::CRC_FOLDING_THRESHOLD;
+use crate::feature_detection::{get_arch_ops, PerformanceTier};
+#[cfg(feature = "std")]
+use crate::CrcAlgorithm;
+
+/// The buffer size for calibrating throughput, large enough that the per-call overhead is noise
+#[cfg(feature = "std")]
+const CALIBRATION_BUFFER_SIZE: usize = 64 * 1024;
+
+/// How long to calibrate throughput for
+#[cfg(feature = "std")]
+const CALIBRATION_DURATION: std::time::Duration = std::time::Duration::from_millis(1);
+
+/// Counters for the calculations a `Digest` has made since they were enabled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PerfStats {
+    /// The tier the calculations ran on, which is selected once per process
+    pub tier: PerformanceTier,
+
+    /// Calculations long enough for the tier's folding kernel
+    pub kernel_invocations: u64,
+
+    /// Bytes calculated by the tier's folding kernel
+    pub kernel_bytes: u64,
+
+    /// Calculations too short for the folding kernel, which use the short-input path
+    pub short_invocations: u64,
+
+    /// Bytes calculated by the short-input path
+    pub short_bytes: u64,
+
+    /// Bytes absorbed by the staging buffer, which are later calculated in batches
+    pub staged_bytes: u64,
+}
+
+impl PerfStats {
+    /// Creates empty counters for the active tier
+    pub(crate) fn new() -> Self {
+        Self {
+            tier: get_arch_ops().get_tier(),
+            kernel_invocations: 0,
+            kernel_bytes: 0,
+            short_invocations: 0,
+            short_bytes: 0,
+            staged_bytes: 0,
+        }
+    }
+
+    /// Returns the total number of calculations
+    pub fn invocations(&self) -> u64 {
+        self.kernel_invocations + self.short_invocations
+    }
+
+    /// Returns the total number of bytes calculated
+    pub fn bytes(&self) -> u64 {
+        self.kernel_bytes + self.short_bytes
+    }
+
+    /// Counts a calculation of `len` bytes
+    #[inline(always)]
+    pub(crate) fn record_calculation(&mut self, len: usize) {
+        if len == 0 {
+            // nothing was calculated
+            return;
+        }
+
+        if len < CRC_FOLDING_THRESHOLD {
+            self.short_invocations += 1;
+            self.short_bytes += len as u64;
+        } else {
+            self.kernel_invocations += 1;
+            self.kernel_bytes += len as u64;
+        }
+    }
+
+    /// Counts `len` bytes absorbed by the staging buffer
+    #[inline(always)]
+    pub(crate) fn record_staged(&mut self, len: usize) {
+        self.staged_bytes += len as u64;
+    }
+}
+
+/// Counts a calculation of `len` bytes, if the counters are enabled
+#[inline(always)]
+pub(crate) fn record_calculation(stats: &mut Option<PerfStats>, len: usize) {
+    if let Some(stats) = stats {
+        stats.record_calculation(len);
+    }
+}
+
+/// Counts `len` bytes absorbed by the staging buffer, if the counters are enabled
+#[inline(always)]
+pub(crate) fn record_staged(stats: &mut Option<PerfStats>, len: usize) {
+    if let Some(stats) = stats {
+        stats.record_staged(len);
+    }
+}
+
+/// Estimates the throughput of one thread calculating `algorithm` on the selected tier, in GiB/s.
+///
+/// Runs a calibration of about a millisecond on large buffers, without the multi-threading of
+/// `checksum()` for very large inputs, so capacity planners and schedulers can decide how many
+/// hashing workers to spawn per node. This selects the tier, if it hasn't been already.
+///
+/// Returns `None` for `Crc32Custom` and `Crc64Custom`, which have no parameters of their own, and
+/// for algorithms which aren't compiled in (see the `crc32-only` and `crc64-only` features).
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{estimate_throughput, CrcAlgorithm::Crc32Iscsi};
+///
+/// if let Some(gib_per_second) = estimate_throughput(Crc32Iscsi) {
+///     println!("CRC-32/ISCSI: {gib_per_second:.1} GiB/s per thread");
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn estimate_throughput(algorithm: CrcAlgorithm) -> Option<f64> {
+    use std::hint::black_box;
+    use std::time::Instant;
+
+    if !algorithm.is_compiled_in()
+        || matches!(
+            algorithm,
+            CrcAlgorithm::Crc32Custom | CrcAlgorithm::Crc64Custom
+        )
+    {
+        return None;
+    }
+
+    let (calculator, params) = crate::get_calculator_params(algorithm);
+    let buffer: Vec<u8> = (0..CALIBRATION_BUFFER_SIZE).map(|i| i as u8).collect();
+
+    // warm up, which also selects the tier
+    black_box(calculator(params.init, black_box(&buffer), params));
+
+    let mut bytes = 0u64;
+    let start = Instant::now();
+    while start.elapsed() < CALIBRATION_DURATION {
+        black_box(calculator(params.init, black_box(&buffer), params));
+        bytes += CALIBRATION_BUFFER_SIZE as u64;
+    }
+
+    Some(bytes as f64 / start.elapsed().as_secs_f64() / (1u64 << 30) as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let mut stats = PerfStats::new();
+
+        stats.record_calculation(0);
+        stats.record_calculation(CRC_FOLDING_THRESHOLD - 1);
+        stats.record_calculation(CRC_FOLDING_THRESHOLD);
+        stats.record_calculation(4096);
+        stats.record_staged(10);
+
+        assert_eq!(stats.short_invocations, 1);
+        assert_eq!(stats.short_bytes, CRC_FOLDING_THRESHOLD as u64 - 1);
+        assert_eq!(stats.kernel_invocations, 2);
+        assert_eq!(stats.kernel_bytes, CRC_FOLDING_THRESHOLD as u64 + 4096);
+        assert_eq!(stats.invocations(), 3);
+        assert_eq!(stats.bytes(), 2 * CRC_FOLDING_THRESHOLD as u64 + 4095);
+        assert_eq!(stats.staged_bytes, 10);
+    }
+
+    #[test]
+    fn test_estimate_throughput() {
+        for algorithm in crate::enums::PREDEFINED_ALGORITHMS
+            .into_iter()
+            .filter(|algorithm| algorithm.is_compiled_in())
+        {
+            let throughput = estimate_throughput(algorithm).unwrap();
+            assert!(throughput.is_finite() && throughput > 0.0, "{algorithm}");
+        }
+
+        assert_eq!(estimate_throughput(CrcAlgorithm::Crc32Custom), None);
+        assert_eq!(estimate_throughput(CrcAlgorithm::Crc64Custom), None);
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! A pool of reset `Digest` instances for services which compute very many small checksums.
+//!
+//! Creating a Digest looks up the algorithm's parameters and calculator, and `CrcParams::new()`
+//! probes the keys cache, which adds up at millions of checksums per second. A `DigestPool` does
+//! that once, along with any settings (such as the parallelism threshold or staging buffer), and
+//! then hands out copies of a reset Digest. Since `Digest` is `Copy`, handing one out is a plain
+//! copy, with no locking between threads.
+
+use crate::{CrcAlgorithm, CrcParams, Digest};
+
+/// Hands out reset `Digest` instances for one algorithm, and accepts them back
+#[derive(Debug, Clone, Copy)]
+pub struct DigestPool {
+    /// A reset Digest, with the pool's parameters and settings, which is copied for each `get()`
+    digest: Digest,
+}
+
+impl DigestPool {
+    /// Creates a new pool for the specified algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{DigestPool, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let pool = DigestPool::new(Crc32IsoHdlc);
+    ///
+    /// for request in [&b"123456789"[..], b"hello"] {
+    ///     let mut digest = pool.get();
+    ///     digest.update(request);
+    ///     let checksum = digest.finalize();
+    ///     pool.put(digest);
+    ///
+    ///     assert_eq!(checksum, crc_fast::checksum(Crc32IsoHdlc, request));
+    /// }
+    /// ```
+    pub fn new(algorithm: CrcAlgorithm) -> Self {
+        Self::from_digest(Digest::new(algorithm))
+    }
+
+    /// Creates a new pool using custom CRC parameters.
+    pub fn new_with_params(params: CrcParams) -> Self {
+        Self::from_digest(Digest::new_with_params(params))
+    }
+
+    /// Creates a pool which hands out Digests with the same parameters, initial state, and
+    /// settings as the specified Digest, reset to that initial state.
+    pub fn from_digest(mut digest: Digest) -> Self {
+        digest.reset_keep_params();
+
+        Self { digest }
+    }
+
+    /// Returns a reset Digest.
+    #[inline(always)]
+    pub fn get(&self) -> Digest {
+        self.digest
+    }
+
+    /// Accepts a Digest back into the pool, once its checksum has been read.
+    ///
+    /// Every `get()` already returns a fresh copy, so this only checks (in debug builds) that the
+    /// Digest came from a pool with the same parameters.
+    #[inline(always)]
+    pub fn put(&self, digest: Digest) {
+        debug_assert!(
+            self.owns(&digest),
+            "Digest for {} returned to a pool for {}",
+            digest.params.name,
+            self.digest.params.name
+        );
+    }
+
+    /// Returns the parameters of the Digests handed out by the pool.
+    pub fn params(&self) -> CrcParams {
+        self.digest.params
+    }
+
+    /// Returns whether the Digest has the pool's parameters.
+    fn owns(&self, digest: &Digest) -> bool {
+        digest.params == self.digest.params
+    }
+}
+
+impl From<Digest> for DigestPool {
+    fn from(digest: Digest) -> Self {
+        Self::from_digest(digest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "crc64-only"))]
+    use super::*;
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    use crate::checksum;
+
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_get_is_reset() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+        for algorithm in [CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc64Nvme] {
+            let mut used = Digest::new(algorithm);
+            used.update(b"already used");
+
+            let pool = DigestPool::from_digest(used);
+
+            for chunk in data.chunks(1_000) {
+                let mut digest = pool.get();
+                assert_eq!(digest.get_amount(), 0);
+
+                digest.update(chunk);
+                assert_eq!(digest.finalize(), checksum(algorithm, chunk));
+
+                pool.put(digest);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_keeps_settings() {
+        let mut digest = Digest::new(CrcAlgorithm::Crc32IsoHdlc);
+        digest.set_staging_buffer(true);
+        digest.update(b"1234");
+
+        let pool = DigestPool::from(digest);
+
+        let mut digest = pool.get();
+        digest.update(b"123456789");
+
+        assert_eq!(digest.finalize(), 0xcbf43926);
+        assert_eq!(pool.params().algorithm, CrcAlgorithm::Crc32IsoHdlc);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "returned to a pool")]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_put_other_params() {
+        let pool = DigestPool::new(CrcAlgorithm::Crc32IsoHdlc);
+
+        pool.put(Digest::new(CrcAlgorithm::Crc64Nvme));
+    }
+}
@@ -0,0 +1,405 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Compiled-in folding keys for common parameters, see the `key-presets` feature
+//!
+//! Custom parameters which share the polynomial and reflection of a predefined algorithm (such as
+//! CRC-32/ISCSI with a nonstandard init) reuse its keys, and the table below covers frequently
+//! requested polynomials which aren't predefined, so `CrcParams::new()` never pays the key
+//! generation cost for them, even on first use.
+
+use crate::enums::PREDEFINED_ALGORITHMS;
+use crate::CrcKeysStorage;
+
+/// Precomputed keys for a polynomial and reflection
+struct Preset {
+    width: u8,
+    poly: u64,
+    reflected: bool,
+    keys: [u64; 25],
+}
+
+const PRESETS: [Preset; 10] = [
+    // CRC-32C (Castagnoli), forward
+    Preset {
+        width: 32,
+        poly: 0x1edc6f41,
+        reflected: false,
+        keys: [
+            0x0000000000000000,
+            0xd7a0166500000000,
+            0xff60609e00000000,
+            0x920db96000000000,
+            0x8ae6932c00000000,
+            0xd7a0166500000000,
+            0x3aab457600000000,
+            0x000000011f91caf6,
+            0x000000011edc6f41,
+            0xcf5fd88500000000,
+            0x187136a800000000,
+            0x8a3b3e8100000000,
+            0xd3399d0700000000,
+            0xd29b973800000000,
+            0x6eecb82000000000,
+            0x3f76a4f200000000,
+            0x81eee05c00000000,
+            0xb78c683700000000,
+            0x4171287000000000,
+            0xe287e4ba00000000,
+            0xb8caa43900000000,
+            0xc3a80f3a00000000,
+            0x4abd1a7600000000,
+            0x61b8fd7600000000,
+            0x3f03ed7a00000000,
+        ],
+    },
+    // CRC-32K (Koopman), forward
+    Preset {
+        width: 32,
+        poly: 0x741b8cd7,
+        reflected: false,
+        keys: [
+            0x0000000000000000,
+            0x3e80c1a500000000,
+            0x3c27a5bc00000000,
+            0x20421b8800000000,
+            0x4a2fbb2f00000000,
+            0x3e80c1a500000000,
+            0x64d5463000000000,
+            0x00000001669897d0,
+            0x00000001741b8cd7,
+            0x888fb75f00000000,
+            0xa78fe12900000000,
+            0x82ad624000000000,
+            0x44345b8c00000000,
+            0xd95fdb2b00000000,
+            0xa7b7ea1300000000,
+            0x39e36cfa00000000,
+            0xd032ac0700000000,
+            0x892c4b5b00000000,
+            0xb1f349d200000000,
+            0x3314bf6a00000000,
+            0x1813227200000000,
+            0xe2129f6b00000000,
+            0x1e25b13f00000000,
+            0xa5650b2400000000,
+            0x829a20c900000000,
+        ],
+    },
+    // CRC-32K2 (Koopman), reflected
+    Preset {
+        width: 32,
+        poly: 0x32583499,
+        reflected: true,
+        keys: [
+            0x0000000000000000,
+            0x00000000be4c0a24,
+            0x00000001553ed216,
+            0x0000000189280c70,
+            0x00000000f25b991c,
+            0x00000000be4c0a24,
+            0x000000019471c338,
+            0x00000001bbb35bd9,
+            0x0000000132583499,
+            0x000000016ff7e612,
+            0x000000002f4e903c,
+            0x00000000b86b5f28,
+            0x000000018d2aeebc,
+            0x00000001a5d859ac,
+            0x00000000af6cc07a,
+            0x00000001ea0b1952,
+            0x0000000113e405da,
+            0x00000000b8c34c82,
+            0x00000001b2192e94,
+            0x00000000c117f97e,
+            0x000000006b3597b0,
+            0x00000001cbea8a68,
+            0x00000000f2ef8492,
+            0x00000000c633821a,
+            0x00000001b76fbe00,
+        ],
+    },
+    // CRC-32K2 (Koopman), forward
+    Preset {
+        width: 32,
+        poly: 0x32583499,
+        reflected: false,
+        keys: [
+            0x0000000000000000,
+            0x48a064fa00000000,
+            0xd096f95500000000,
+            0x1c60292300000000,
+            0x7133b49e00000000,
+            0x48a064fa00000000,
+            0x39871c5300000000,
+            0x0000000137b59bbb,
+            0x0000000132583499,
+            0x90cfdfed00000000,
+            0x7812e5e800000000,
+            0x29f5ac3a00000000,
+            0x7aeea96300000000,
+            0x6b34374b00000000,
+            0xbc066dea00000000,
+            0x9531a0af00000000,
+            0xb7404f9100000000,
+            0x8265863a00000000,
+            0x52e9309b00000000,
+            0xfd3fd10600000000,
+            0x1bd359ac00000000,
+            0x2ca2afa700000000,
+            0x9243ee9e00000000,
+            0xb08398c600000000,
+            0x00fbeddb00000000,
+        ],
+    },
+    // CRC-32Q, reflected
+    Preset {
+        width: 32,
+        poly: 0x814141ab,
+        reflected: true,
+        keys: [
+            0x0000000000000000,
+            0x00000001e3c32fb2,
+            0x000000004eca9b42,
+            0x000000018fb02aa8,
+            0x00000000648a224a,
+            0x00000001e3c32fb2,
+            0x00000000df47ef1a,
+            0x000000008dfdfeff,
+            0x00000001ab050503,
+            0x000000009673b850,
+            0x0000000131019432,
+            0x00000000b8ced83c,
+            0x00000001030328aa,
+            0x00000001ca6d37b8,
+            0x000000005674080c,
+            0x00000001a2f09e5c,
+            0x00000001b1c20558,
+            0x0000000013208440,
+            0x000000008039f0d8,
+            0x00000001e8efd5cc,
+            0x00000001dce847fe,
+            0x00000001822a9160,
+            0x0000000023b80b92,
+            0x00000001ff810c12,
+            0x00000001ced64ff2,
+        ],
+    },
+    // CRC-32D, forward
+    Preset {
+        width: 32,
+        poly: 0xa833982b,
+        reflected: false,
+        keys: [
+            0x0000000000000000,
+            0xd2374c0f00000000,
+            0x1336ca6b00000000,
+            0xf442c6fc00000000,
+            0xfb9e0ab200000000,
+            0xd2374c0f00000000,
+            0x9fe12de600000000,
+            0x00000001d97fcd12,
+            0x00000001a833982b,
+            0x24f8801f00000000,
+            0x6166ba5400000000,
+            0xbb291dcd00000000,
+            0x2eee946a00000000,
+            0x1c50fa2c00000000,
+            0x947e209d00000000,
+            0x7f2d0e8700000000,
+            0x337b3e8900000000,
+            0x6116d69300000000,
+            0xbfcd885300000000,
+            0x4d44408600000000,
+            0x588bbc0b00000000,
+            0xce347fe100000000,
+            0xcbd6093500000000,
+            0xbfbbbe8300000000,
+            0xe7c7278100000000,
+        ],
+    },
+    // CRC-32/AUTOSAR polynomial, forward
+    Preset {
+        width: 32,
+        poly: 0xf4acfb13,
+        reflected: false,
+        keys: [
+            0x0000000000000000,
+            0xa812190d00000000,
+            0x72a2841400000000,
+            0x20b2f1a100000000,
+            0x543bbdd600000000,
+            0xa812190d00000000,
+            0x06cd561b00000000,
+            0x0000000189fb7e79,
+            0x00000001f4acfb13,
+            0x321f411a00000000,
+            0xa3060c9b00000000,
+            0x3102d61b00000000,
+            0x975a1ec600000000,
+            0x25f2b2e600000000,
+            0xdfa6487e00000000,
+            0x2891c63000000000,
+            0x0d28c5a500000000,
+            0xe7a6e24300000000,
+            0x2c6da72400000000,
+            0xdd75572600000000,
+            0xd4d3128800000000,
+            0xf2adbd2f00000000,
+            0x7d7a600000000000,
+            0xaa7f016100000000,
+            0x600dffb400000000,
+        ],
+    },
+    // CRC-64/NVME and CRC-64/REDIS polynomial, forward
+    Preset {
+        width: 64,
+        poly: 0xad93d23594c935a9,
+        reflected: false,
+        keys: [
+            0x0000000000000000,
+            0x4445ed2750017038,
+            0x698b74157cfbd736,
+            0x0cfcfb5101c4b775,
+            0x65403fd47cbec866,
+            0x4445ed2750017038,
+            0x0000000000000000,
+            0xddf3eeb298be6cf8,
+            0xad93d23594c935a9,
+            0xd8dc208e2ba527b4,
+            0xf032cfec76bb2bc5,
+            0xb536044f357f4238,
+            0xfdbf104d938ba67a,
+            0xeeddad9297a843e7,
+            0x3550bce629466473,
+            0x4e501e58ca43d25e,
+            0x13c961588f27f643,
+            0x3b60d00dcb1099bc,
+            0x44bf1f468c53b9a3,
+            0x96f2236e317179ee,
+            0xf00839aa0dd64bac,
+            0x44ff5212394b1c52,
+            0x956d6cb0582122b2,
+            0xdf1cdd56b4d87d14,
+            0xf11add56dc73e85d,
+        ],
+    },
+    // CRC-64/GO-ISO polynomial, forward
+    Preset {
+        width: 64,
+        poly: 0x000000000000001b,
+        reflected: false,
+        keys: [
+            0x0000000000000000,
+            0x0000000000000145,
+            0x0000000000001db7,
+            0x000100000001001a,
+            0x001b0000001b015e,
+            0x0000000000000145,
+            0x0000000000000000,
+            0x000000000000001b,
+            0x000000000000001b,
+            0x0150145145145015,
+            0x1c71db6db6db71c7,
+            0x0001110110110111,
+            0x001aab1ab1ab1aab,
+            0x0000014445014445,
+            0x00001daab71daab7,
+            0x0000000101000101,
+            0x0000001b1b001b1b,
+            0x0000000001514515,
+            0x000000001c6db6c7,
+            0x0000000000011011,
+            0x00000000001ab1ab,
+            0x0000001a00000144,
+            0x0000015e00001dac,
+            0x0000000000010dbc,
+            0x00000000001ba0e4,
+        ],
+    },
+    // CRC-64/MS polynomial, forward
+    Preset {
+        width: 64,
+        poly: 0x259c84cba6426349,
+        reflected: false,
+        keys: [
+            0x0000000000000000,
+            0xd3c33e9b00367daf,
+            0x38bb5a01c0bd757e,
+            0xc285bae18a1cf7ea,
+            0x98873a5a5d56a887,
+            0xd3c33e9b00367daf,
+            0x0000000000000000,
+            0x210bd30882c1afd7,
+            0x259c84cba6426349,
+            0xc9f86c2b0e39d6bb,
+            0xb95dd997bfb50e14,
+            0x5e4569094652a4c0,
+            0x1c79ec571940aaa4,
+            0x2e1fe9652d0c34e7,
+            0x1f9c42afe9c3b528,
+            0xa5e49b6af0c5cb83,
+            0x2b7a33df1e34ecdf,
+            0x8cbc00ce029629ed,
+            0xd158e752c8015e55,
+            0x3ae6be5108957a1c,
+            0x785bc2d5fd202308,
+            0x7162c30f197ce493,
+            0xbe172d0d73ea6762,
+            0x9e019e2d69c431a5,
+            0x491f0e6f55a01ed8,
+        ],
+    },
+];
+
+/// Returns the compiled-in keys for the parameters, if there are any
+pub(crate) fn lookup(width: u8, poly: u64, reflected: bool) -> Option<CrcKeysStorage> {
+    PREDEFINED_ALGORITHMS
+        .iter()
+        .map(|algorithm| crate::get_calculator_params(*algorithm).1)
+        .find(|params| params.width == width && params.poly == poly && params.refin == reflected)
+        .map(|params| params.keys)
+        .or_else(|| {
+            PRESETS
+                .iter()
+                .find(|preset| {
+                    preset.width == width && preset.poly == poly && preset.reflected == reflected
+                })
+                .map(|preset| CrcKeysStorage::from_keys_fold_512(preset.keys))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate;
+
+    #[test]
+    fn test_presets_match_generated_keys() {
+        for preset in PRESETS {
+            assert_eq!(
+                preset.keys,
+                generate::keys(preset.width, preset.poly, preset.reflected),
+                "width {} poly {:#x} reflected {}",
+                preset.width,
+                preset.poly,
+                preset.reflected
+            );
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        // CRC-32/ISCSI's polynomial
+        assert_eq!(
+            lookup(32, 0x1edc6f41, true),
+            Some(CrcKeysStorage::from_keys_fold_512(generate::keys(
+                32, 0x1edc6f41, true
+            )))
+        );
+
+        // CRC-32K2 is in the table
+        assert!(lookup(32, 0x32583499, true).is_some());
+
+        assert_eq!(lookup(32, 0x0badc0de, true), None);
+    }
+}
@@ -0,0 +1,272 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! A slow, scalar reference implementation, for verifying the accelerated ones.
+//!
+//! This calculates checksums a byte at a time from a 256-entry lookup table built for each call,
+//! straight from the Rocksoft parameters. It shares no code with the SIMD, hardware CRC, or
+//! software fallback paths, and doesn't use the folding keys, so downstream users can
+//! differential-test their own integration, or check exotic hardware in the field, against a
+//! known-good result.
+//!
+//! It's far too slow for production use.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::{checksum, reference, CrcAlgorithm::Crc32IsoHdlc};
+//!
+//! let data = b"123456789";
+//!
+//! assert_eq!(reference::checksum_algorithm(Crc32IsoHdlc, data), checksum(Crc32IsoHdlc, data));
+//! ```
+
+use crate::{CrcAlgorithm, CrcParams};
+
+/// Calculates the checksum for the parameters, using the reference implementation.
+///
+/// Supports any width from 1 to 64 bits, and mismatched `refin`/`refout`, not only the parameters
+/// the accelerated implementations support.
+///
+/// As everywhere else in the library, `init` is the initial CRC register, so for reflected
+/// parameters it's the CRC catalogue's value reflected. The two only differ for inits which
+/// aren't their own reflection, which none of the predefined algorithms have.
+///
+/// # Panics
+///
+/// Panics if the width is 0 or greater than 64.
+///
+/// # Examples
+///
+/// ```rust
+/// use crc_fast::{reference, CrcParams};
+///
+/// let params = CrcParams::new(
+///     "CRC-32/CUSTOM",
+///     32,
+///     0x04c11db7,
+///     0xffffffff,
+///     true,
+///     0xffffffff,
+///     0xcbf43926,
+/// );
+///
+/// assert_eq!(reference::checksum(params, b"123456789"), 0xcbf43926);
+/// ```
+pub fn checksum(params: CrcParams, data: &[u8]) -> u64 {
+    let width = params.width as u32;
+    assert!(
+        (1..=64).contains(&width),
+        "Unsupported CRC width: {}",
+        params.width
+    );
+
+    let mask = u64::MAX >> (64 - width);
+    let table = table(params.poly & mask, width, params.refin);
+
+    let crc = if params.refin {
+        // the register holds the reflected CRC, shifting out from the bottom
+        let mut crc = params.init & mask;
+        for &byte in data {
+            crc = (crc >> 8) ^ table[((crc ^ byte as u64) & 0xff) as usize];
+        }
+
+        // reflected back for unreflected output
+        if params.refout {
+            crc
+        } else {
+            reflect(crc, width)
+        }
+    } else {
+        // the register holds the CRC in its top bits, shifting out from the top
+        let shift = 64 - width;
+        let mut crc = (params.init & mask) << shift;
+        for &byte in data {
+            crc = (crc << 8) ^ table[((crc >> 56) ^ byte as u64) as usize];
+        }
+
+        let crc = crc >> shift;
+        if params.refout {
+            reflect(crc, width)
+        } else {
+            crc
+        }
+    };
+
+    (crc ^ params.xorout) & mask
+}
+
+/// Calculates the checksum for a predefined algorithm, using the reference implementation.
+///
+/// # Panics
+///
+/// Panics for `Crc32Custom` and `Crc64Custom`, which have no parameters of their own, use
+/// `checksum()` with the custom `CrcParams` instead.
+pub fn checksum_algorithm(algorithm: CrcAlgorithm, data: &[u8]) -> u64 {
+    checksum(crate::get_calculator_params(algorithm).1, data)
+}
+
+/// Builds the lookup table for one byte at a time.
+///
+/// Reflected tables are for a register shifting right, and unreflected ones for a register
+/// shifting left with the CRC in its top bits, which avoids special cases for widths under 8 bits.
+fn table(poly: u64, width: u32, reflected: bool) -> [u64; 256] {
+    let mut table = [0u64; 256];
+
+    if reflected {
+        let poly = reflect(poly, width);
+        for (byte, entry) in table.iter_mut().enumerate() {
+            let mut crc = byte as u64;
+            for _ in 0..8 {
+                crc = if crc & 1 == 1 {
+                    (crc >> 1) ^ poly
+                } else {
+                    crc >> 1
+                };
+            }
+            *entry = crc;
+        }
+    } else {
+        let poly = poly << (64 - width);
+        for (byte, entry) in table.iter_mut().enumerate() {
+            let mut crc = (byte as u64) << 56;
+            for _ in 0..8 {
+                crc = if crc & (1 << 63) != 0 {
+                    (crc << 1) ^ poly
+                } else {
+                    crc << 1
+                };
+            }
+            *entry = crc;
+        }
+    }
+
+    table
+}
+
+/// Reverses the bottom `width` bits
+#[inline(always)]
+fn reflect(value: u64, width: u32) -> u64 {
+    value.reverse_bits() >> (64 - width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "crc64-only"))]
+    use crate::checksum_with_params;
+    use crate::enums::compiled_algorithms;
+    use crate::test::consts::{TEST_ALL_CONFIGS, TEST_CHECK_STRING};
+    use rand::{rng, Rng};
+
+    #[test]
+    fn test_check_values() {
+        for config in TEST_ALL_CONFIGS {
+            let params = *config.get_params();
+
+            assert_eq!(
+                checksum(params, TEST_CHECK_STRING),
+                config.get_check(),
+                "Mismatch CRC, {}",
+                config.get_name()
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches_library() {
+        let mut rng = rng();
+
+        for algorithm in compiled_algorithms() {
+            for len in [0, 1, 15, 16, 63, 64, 255, 256, 1000, 4096, 16_385] {
+                let mut data = vec![0u8; len];
+                rng.fill(&mut data[..]);
+
+                assert_eq!(
+                    checksum_algorithm(algorithm, &data),
+                    crate::checksum(algorithm, &data),
+                    "Mismatch CRC, {algorithm}, length {len}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_custom_params_match_library() {
+        // an init which isn't its own reflection, and an unusual polynomial
+        let params = CrcParams::new("CRC-32/CUSTOM", 32, 0x1edc6f41, 0x12345678, true, 0, 0);
+        let data = b"The quick brown fox jumps over the lazy dog";
+
+        assert_eq!(checksum(params, data), checksum_with_params(params, data));
+    }
+
+    #[test]
+    fn test_catalogue_widths() {
+        // only the fields which differ from the zeroed, unreflected, base are set
+        let base = CrcParams {
+            algorithm: CrcAlgorithm::Crc32Custom,
+            name: "",
+            width: 0,
+            poly: 0,
+            init: 0,
+            refin: false,
+            refout: false,
+            xorout: 0,
+            check: 0,
+            keys: crate::CrcKeysStorage::from_keys_fold_256([0; 23]),
+        };
+
+        // widths and mismatched reflection which the accelerated implementations don't support
+        let catalogue = [
+            CrcParams {
+                name: "CRC-3/GSM",
+                width: 3,
+                poly: 0x3,
+                xorout: 0x7,
+                check: 0x4,
+                ..base
+            },
+            CrcParams {
+                name: "CRC-8/SMBUS",
+                width: 8,
+                poly: 0x07,
+                check: 0xf4,
+                ..base
+            },
+            CrcParams {
+                name: "CRC-12/UMTS",
+                width: 12,
+                poly: 0x80f,
+                refout: true,
+                check: 0xdaf,
+                ..base
+            },
+            CrcParams {
+                name: "CRC-16/ARC",
+                width: 16,
+                poly: 0x8005,
+                refin: true,
+                refout: true,
+                check: 0xbb3d,
+                ..base
+            },
+            CrcParams {
+                name: "CRC-16/IBM-3740",
+                width: 16,
+                poly: 0x1021,
+                init: 0xffff,
+                check: 0x29b1,
+                ..base
+            },
+        ];
+
+        for params in catalogue {
+            assert_eq!(
+                checksum(params, TEST_CHECK_STRING),
+                params.check,
+                "Mismatch CRC, {}",
+                params.name
+            );
+        }
+    }
+}
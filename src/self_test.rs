@@ -0,0 +1,217 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Runtime self-test of the selected acceleration target.
+//!
+//! Runs every predefined algorithm's check string, plus longer vectors which exercise the folding
+//! paths, through the currently selected target, and compares them against the check values and a
+//! table-based reference implementation. Intended for regulated environments which must prove the
+//! accelerated results are correct on the deployed CPU.
+
+use crate::consts::*;
+use crate::enums::compiled_algorithms;
+use crate::{checksum, get_calculator_params, get_calculator_target, CrcAlgorithm, Digest};
+use std::fmt;
+
+/// Lengths of the vectors run through each algorithm, covering the small-input, single-fold, and
+/// multi-fold paths, and lengths which aren't multiples of the fold sizes
+const VECTOR_LENGTHS: [usize; 14] = [
+    0, 1, 15, 16, 17, 63, 64, 127, 128, 255, 256, 257, 4_099, 65_581,
+];
+
+/// Update sizes for the chunked Digest vectors, so state carries across uneven boundaries
+const DIGEST_CHUNK_SIZES: [usize; 4] = [1, 63, 4_096, 16_411];
+
+/// A successful self-test
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelfTestReport {
+    /// The target which was tested, see `get_calculator_target()`
+    pub target: String,
+
+    /// The number of algorithms which were tested
+    pub algorithms: usize,
+
+    /// The number of vectors which were tested, across all algorithms
+    pub vectors: usize,
+}
+
+/// The first vector which failed a self-test
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelfTestFailure {
+    /// The algorithm which failed
+    pub algorithm: CrcAlgorithm,
+
+    /// The target which calculated the failing result, see `get_calculator_target()`
+    pub target: String,
+
+    /// The length of the failing vector, in bytes
+    pub len: usize,
+
+    /// The expected checksum
+    pub expected: u64,
+
+    /// The calculated checksum
+    pub actual: u64,
+}
+
+impl fmt::Display for SelfTestFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} self-test failed on {} for {} bytes: expected {:#x}, got {:#x}",
+            self.algorithm, self.target, self.len, self.expected, self.actual
+        )
+    }
+}
+
+impl std::error::Error for SelfTestFailure {}
+
+/// Runs the check string and longer vectors for every compiled-in predefined algorithm through the currently
+/// selected acceleration target, both in one call and as chunked Digest updates, and compares them
+/// against the check values and a table-based reference implementation.
+///
+/// It only checksums a few hundred KiB per algorithm, so it's suited to running once at startup.
+///
+/// # Errors
+///
+/// Returns the first vector which didn't match.
+///
+/// # Examples
+///
+/// ```rust
+/// let report = crc_fast::self_test().expect("accelerated CRC results are wrong on this CPU");
+///
+/// assert_eq!(report.algorithms, 19);
+/// ```
+pub fn self_test() -> Result<SelfTestReport, SelfTestFailure> {
+    let data = vector_data(VECTOR_LENGTHS[VECTOR_LENGTHS.len() - 1]);
+    let mut algorithms = 0;
+    let mut vectors = 0;
+
+    for algorithm in compiled_algorithms() {
+        algorithms += 1;
+
+        let fail = |len: usize, expected: u64, actual: u64| SelfTestFailure {
+            algorithm,
+            target: get_calculator_target(algorithm),
+            len,
+            expected,
+            actual,
+        };
+
+        let check = get_calculator_params(algorithm).1.check;
+        let actual = checksum(algorithm, b"123456789");
+        if actual != check {
+            return Err(fail(9, check, actual));
+        }
+        vectors += 1;
+
+        for len in VECTOR_LENGTHS {
+            let expected = reference_checksum(algorithm, &data[..len]);
+
+            let actual = checksum(algorithm, &data[..len]);
+            if actual != expected {
+                return Err(fail(len, expected, actual));
+            }
+
+            for chunk_size in DIGEST_CHUNK_SIZES {
+                let mut digest = Digest::new(algorithm);
+                data[..len]
+                    .chunks(chunk_size)
+                    .for_each(|chunk| digest.update(chunk));
+
+                let actual = digest.finalize();
+                if actual != expected {
+                    return Err(fail(len, expected, actual));
+                }
+            }
+
+            vectors += 1;
+        }
+    }
+
+    Ok(SelfTestReport {
+        target: get_calculator_target(CrcAlgorithm::Crc64Nvme),
+        algorithms,
+        vectors,
+    })
+}
+
+/// Generates deterministic pseudo-random vector data with xorshift
+fn vector_data(len: usize) -> Vec<u8> {
+    let mut state: u64 = 0x9e3779b97f4a7c15;
+
+    (0..len)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+
+            (state >> 56) as u8
+        })
+        .collect()
+}
+
+/// Calculates the checksum with the table-based reference implementation, which shares no code
+/// with the accelerated targets
+pub(crate) fn reference_checksum(algorithm: CrcAlgorithm, data: &[u8]) -> u64 {
+    match algorithm {
+        CrcAlgorithm::Crc32Aixm => RUST_CRC32_AIXM.checksum(data) as u64,
+        CrcAlgorithm::Crc32Autosar => RUST_CRC32_AUTOSAR.checksum(data) as u64,
+        CrcAlgorithm::Crc32Base91D => RUST_CRC32_BASE91_D.checksum(data) as u64,
+        CrcAlgorithm::Crc32Bzip2 => RUST_CRC32_BZIP2.checksum(data) as u64,
+        CrcAlgorithm::Crc32CdRomEdc => RUST_CRC32_CD_ROM_EDC.checksum(data) as u64,
+        CrcAlgorithm::Crc32Cksum => RUST_CRC32_CKSUM.checksum(data) as u64,
+        CrcAlgorithm::Crc32Iscsi => RUST_CRC32_ISCSI.checksum(data) as u64,
+        CrcAlgorithm::Crc32IsoHdlc => RUST_CRC32_ISO_HDLC.checksum(data) as u64,
+        CrcAlgorithm::Crc32Jamcrc => RUST_CRC32_JAMCRC.checksum(data) as u64,
+        CrcAlgorithm::Crc32Mef => RUST_CRC32_MEF.checksum(data) as u64,
+        CrcAlgorithm::Crc32Mpeg2 => RUST_CRC32_MPEG_2.checksum(data) as u64,
+        CrcAlgorithm::Crc32Xfer => RUST_CRC32_XFER.checksum(data) as u64,
+        CrcAlgorithm::Crc64Ecma182 => RUST_CRC64_ECMA_182.checksum(data),
+        CrcAlgorithm::Crc64GoIso => RUST_CRC64_GO_ISO.checksum(data),
+        CrcAlgorithm::Crc64Ms => RUST_CRC64_MS.checksum(data),
+        CrcAlgorithm::Crc64Nvme => RUST_CRC64_NVME.checksum(data),
+        CrcAlgorithm::Crc64Redis => RUST_CRC64_REDIS.checksum(data),
+        CrcAlgorithm::Crc64We => RUST_CRC64_WE.checksum(data),
+        CrcAlgorithm::Crc64Xz => RUST_CRC64_XZ.checksum(data),
+        CrcAlgorithm::Crc32Custom | CrcAlgorithm::Crc64Custom => {
+            unreachable!("self-tests only cover the predefined algorithms")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_test() {
+        let report = self_test().unwrap();
+
+        assert_eq!(report.algorithms, compiled_algorithms().count());
+        assert_eq!(
+            report.vectors,
+            report.algorithms * (VECTOR_LENGTHS.len() + 1)
+        );
+        assert_eq!(
+            report.target,
+            get_calculator_target(CrcAlgorithm::Crc64Nvme)
+        );
+    }
+
+    #[test]
+    fn test_self_test_failure_display() {
+        let failure = SelfTestFailure {
+            algorithm: CrcAlgorithm::Crc32Iscsi,
+            target: "software-fallback-tables".to_string(),
+            len: 9,
+            expected: 0xe3069283,
+            actual: 0,
+        };
+
+        assert_eq!(
+            failure.to_string(),
+            "CRC-32/ISCSI self-test failed on software-fallback-tables for 9 bytes: expected 0xe3069283, got 0x0"
+        );
+    }
+}
@@ -0,0 +1,212 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! A logical checksum fed by several producer threads at once.
+//!
+//! Each producer checksums its own ordered part of the input with a private `Digest`, so updates
+//! never contend with each other. A part is only published to the shared state (a single short
+//! lock) when it's dropped, and the parts are combined in order when the checksum is finalized.
+
+use crate::{CrcAlgorithm, CrcParams, Digest};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::sync::{Mutex, PoisonError};
+
+/// A checksum over ordered parts, each of which can be fed from a different thread
+#[derive(Debug)]
+pub struct SharedDigest {
+    /// A new Digest for the algorithm, copied to start each part and the combination
+    digest: Digest,
+
+    /// The published parts, by index
+    parts: Mutex<BTreeMap<u64, Digest>>,
+}
+
+/// One ordered part of a `SharedDigest`, which is published when it's dropped
+#[derive(Debug)]
+pub struct SharedDigestPart<'a> {
+    /// The SharedDigest this part belongs to
+    shared: &'a SharedDigest,
+
+    /// The position of this part in the logical input
+    index: u64,
+
+    /// The checksum of this part so far
+    digest: Digest,
+}
+
+impl SharedDigest {
+    /// Creates a new, empty SharedDigest for the specified algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{SharedDigest, CrcAlgorithm::Crc32IsoHdlc};
+    /// use std::thread;
+    ///
+    /// let shared = SharedDigest::new(Crc32IsoHdlc);
+    ///
+    /// thread::scope(|s| {
+    ///     s.spawn(|| shared.part(1).update(b"56789"));
+    ///     s.spawn(|| shared.part(0).update(b"1234"));
+    /// });
+    ///
+    /// assert_eq!(shared.finalize(), 0xcbf43926);
+    /// ```
+    pub fn new(algorithm: CrcAlgorithm) -> Self {
+        Self::from_digest(Digest::new(algorithm))
+    }
+
+    /// Creates a new, empty SharedDigest using custom CRC parameters.
+    pub fn new_with_params(params: CrcParams) -> Self {
+        Self::from_digest(Digest::new_with_params(params))
+    }
+
+    fn from_digest(digest: Digest) -> Self {
+        Self {
+            digest,
+            parts: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Starts the part at `index` in the logical input, which is checksummed privately and then
+    /// published when it's dropped.
+    ///
+    /// Parts are combined in ascending order of their indexes, which don't need to be contiguous.
+    /// Parts which share an index are combined in the order they're published.
+    pub fn part(&self, index: u64) -> SharedDigestPart<'_> {
+        SharedDigestPart {
+            shared: self,
+            index,
+            digest: self.digest,
+        }
+    }
+
+    /// Returns the checksum of all the parts published so far, combined in order.
+    pub fn finalize(&self) -> u64 {
+        let parts = self.parts.lock().unwrap_or_else(PoisonError::into_inner);
+
+        parts
+            .values()
+            .fold(self.digest, |mut digest, part| {
+                digest.combine(part);
+                digest
+            })
+            .finalize()
+    }
+
+    /// Returns the amount of data published so far, across all the parts.
+    pub fn get_amount(&self) -> u64 {
+        let parts = self.parts.lock().unwrap_or_else(PoisonError::into_inner);
+
+        parts.values().fold(0u64, |amount, part| {
+            amount.saturating_add(part.get_amount())
+        })
+    }
+
+    /// Discards all the published parts.
+    pub fn reset(&self) {
+        self.parts
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    fn publish(&self, index: u64, digest: &Digest) {
+        let mut parts = self.parts.lock().unwrap_or_else(PoisonError::into_inner);
+
+        parts
+            .entry(index)
+            .and_modify(|part| part.combine(digest))
+            .or_insert_with(|| *digest);
+    }
+}
+
+impl SharedDigestPart<'_> {
+    /// Updates this part with data.
+    #[inline(always)]
+    pub fn update(&mut self, data: &[u8]) {
+        self.digest.update(data);
+    }
+
+    /// Returns the position of this part in the logical input.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Returns the amount of data in this part so far.
+    pub fn get_amount(&self) -> u64 {
+        self.digest.get_amount()
+    }
+}
+
+impl Write for SharedDigestPart<'_> {
+    #[inline(always)]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline(always)]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for SharedDigestPart<'_> {
+    fn drop(&mut self) {
+        self.shared.publish(self.index, &self.digest);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "crc64-only"))]
+    use super::*;
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    use crate::checksum;
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    use std::thread;
+
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_parts_combine_in_order() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+        for algorithm in [CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc64Nvme] {
+            let shared = SharedDigest::new(algorithm);
+
+            thread::scope(|s| {
+                // publish in reverse, and leave a gap in the indexes
+                for (index, chunk) in data.chunks(7_919).enumerate().rev() {
+                    let shared = &shared;
+                    s.spawn(move || {
+                        let mut part = shared.part(index as u64 * 2);
+                        for piece in chunk.chunks(1_000) {
+                            part.update(piece);
+                        }
+                    });
+                }
+            });
+
+            assert_eq!(shared.finalize(), checksum(algorithm, &data));
+            assert_eq!(shared.get_amount(), data.len() as u64);
+
+            shared.reset();
+
+            assert_eq!(shared.finalize(), checksum(algorithm, b""));
+            assert_eq!(shared.get_amount(), 0);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_shared_index_appends() {
+        let shared = SharedDigest::new(CrcAlgorithm::Crc32IsoHdlc);
+
+        shared.part(3).update(b"6789");
+        write!(shared.part(0), "123").unwrap();
+        shared.part(0).update(b"45");
+
+        assert_eq!(shared.finalize(), 0xcbf43926);
+    }
+}
@@ -2,8 +2,10 @@
 
 #![allow(dead_code)]
 
+use crate::consts::CRC_STAGING_BUFFER_SIZE;
 use crate::traits::{CrcCalculator, CrcWidth};
-use crate::{arch, cache, CrcAlgorithm, CrcParams};
+use crate::{arch, CrcAlgorithm, CrcFastError, CrcKeysStorage, CrcParams, KeySource};
+use core::fmt;
 
 /// CRC-32 width implementation
 #[derive(Clone, Copy)]
@@ -32,6 +34,51 @@ pub struct CrcState<T> {
 
 pub(crate) struct Calculator {}
 
+/// Small buffer which accumulates tiny `Digest` updates, so the SIMD kernel runs on larger inputs
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StagingBuffer {
+    bytes: [u8; CRC_STAGING_BUFFER_SIZE],
+    len: usize,
+}
+
+impl StagingBuffer {
+    pub(crate) const fn new() -> Self {
+        Self {
+            bytes: [0; CRC_STAGING_BUFFER_SIZE],
+            len: 0,
+        }
+    }
+
+    /// Appends the data if it fits, returning whether it did
+    #[inline(always)]
+    pub(crate) fn try_push(&mut self, data: &[u8]) -> bool {
+        let end = self.len + data.len();
+        if end > CRC_STAGING_BUFFER_SIZE {
+            return false;
+        }
+
+        self.bytes[self.len..end].copy_from_slice(data);
+        self.len = end;
+
+        true
+    }
+
+    #[inline(always)]
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    #[inline(always)]
+    pub(crate) fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    #[inline(always)]
+    pub(crate) fn clear(&mut self) {
+        self.len = 0;
+    }
+}
+
 impl CrcCalculator for Calculator {
     #[inline(always)]
     fn calculate(state: u64, data: &[u8], params: CrcParams) -> u64 {
@@ -58,15 +105,94 @@ impl CrcParams {
         xorout: u64,
         check: u64,
     ) -> Self {
-        let keys_array = cache::get_or_generate_keys(width, poly, reflected);
-        let keys = crate::CrcKeysStorage::from_keys_fold_256(keys_array);
+        Self::new_with_key_count(name, width, poly, init, reflected, xorout, check, 25)
+    }
+
+    /// Creates custom CRC parameters like `new()`, but returns an error rather than panicking if
+    /// the width isn't supported, and verifies the parameters produce their own check value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CrcFastError::UnsupportedWidth`] if the width isn't 32 or 64, or
+    /// [`CrcFastError::InvalidParams`] if the parameters don't produce their check value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{CrcFastError, CrcParams};
+    ///
+    /// let params = CrcParams::try_new("CRC-32/CUSTOM", 32, 0x04c11db7, 0xffffffff, true, 0xffffffff, 0xcbf43926);
+    /// assert!(params.is_ok());
+    ///
+    /// let params = CrcParams::try_new("CRC-16/CUSTOM", 16, 0x1021, 0xffff, true, 0xffff, 0x906e);
+    /// assert!(matches!(params, Err(CrcFastError::UnsupportedWidth(16))));
+    /// ```
+    pub fn try_new(
+        name: &'static str,
+        width: u8,
+        poly: u64,
+        init: u64,
+        reflected: bool,
+        xorout: u64,
+        check: u64,
+    ) -> Result<Self, CrcFastError> {
+        if width != 32 && width != 64 {
+            return Err(CrcFastError::UnsupportedWidth(width));
+        }
+
+        let params = Self::new(name, width, poly, init, reflected, xorout, check);
+        if crate::checksum_with_params(params, b"123456789") != check {
+            return Err(CrcFastError::InvalidParams(
+                "the parameters don't produce their check value",
+            ));
+        }
 
+        Ok(params)
+    }
+
+    /// Creates custom CRC parameters like `new()`, with the folding keys in the format with the
+    /// given number of keys: 25 (the current format, up to 512-byte folding distances) or 23 (the
+    /// legacy format, up to 256-byte folding distances).
+    ///
+    /// The keys are cached in the widest format, so every format shares the same cache entry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width isn't 32 or 64, or the key count isn't supported.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_with_key_count(
+        name: &'static str,
+        width: u8,
+        poly: u64,
+        init: u64,
+        reflected: bool,
+        xorout: u64,
+        check: u64,
+        key_count: usize,
+    ) -> Self {
         let algorithm = match width {
             32 => CrcAlgorithm::Crc32Custom,
             64 => CrcAlgorithm::Crc64Custom,
             _ => panic!("Unsupported width: {width}",),
         };
 
+        // only the SIMD implementations use the keys, so the `tiny` feature skips generating them
+        // on targets which don't have any
+        #[cfg(not(all(
+            feature = "tiny",
+            not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))
+        )))]
+        let keys = crate::cache::get_or_generate_key_storage(width, poly, reflected);
+        #[cfg(all(
+            feature = "tiny",
+            not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64"))
+        ))]
+        let keys = crate::CrcKeysStorage::from_keys_fold_512([0; 25]);
+
+        let keys = keys
+            .with_key_count(key_count)
+            .unwrap_or_else(|| panic!("Unsupported key count: {key_count}"));
+
         Self {
             algorithm,
             name,
@@ -104,4 +230,111 @@ impl CrcParams {
     pub fn key_count(self) -> usize {
         self.keys.key_count()
     }
+
+    /// Returns where the folding keys came from: compiled in, shared with the key cache, or
+    /// generated for these parameters alone.
+    ///
+    /// This reflects the key cache as it is now, so keys which were cached when the parameters
+    /// were created, but have since been evicted or cleared, are reported as generated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{CrcParams, KeySource};
+    ///
+    /// // the keys are generated on first use, and shared through the cache after that
+    /// let params = CrcParams::new(
+    ///     "CRC-32/CUSTOM",
+    ///     32,
+    ///     0x04c11db7,
+    ///     0xffffffff,
+    ///     true,
+    ///     0xffffffff,
+    ///     0xcbf43926,
+    /// );
+    ///
+    /// assert_eq!(params.key_source(), KeySource::Cached);
+    /// ```
+    pub fn key_source(&self) -> KeySource {
+        if !matches!(
+            self.algorithm,
+            CrcAlgorithm::Crc32Custom | CrcAlgorithm::Crc64Custom
+        ) {
+            return KeySource::Const;
+        }
+
+        // the presets and the cache both hold the widest format, so compare in this one's format
+        let matches =
+            |keys: CrcKeysStorage| keys.with_key_count(self.key_count()) == Some(self.keys);
+
+        #[cfg(feature = "key-presets")]
+        if crate::presets::lookup(self.width, self.poly, self.refin).is_some_and(matches) {
+            return KeySource::Const;
+        }
+
+        match crate::cache::peek_key_storage(self.width, self.poly, self.refin) {
+            Some(keys) if matches(keys) => KeySource::Cached,
+            _ => KeySource::Generated,
+        }
+    }
+
+    /// Formats a value as hex, zero-padded to the width, as the CRC catalogue does
+    fn hex(&self, value: u64) -> impl fmt::Display {
+        HexWidth(value, self.width)
+    }
+}
+
+/// A value formatted as hex, zero-padded to a CRC width
+struct HexWidth(u64, u8);
+
+impl fmt::Display for HexWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:#0digits$x}",
+            self.0,
+            digits = 2 + self.1.div_ceil(4) as usize
+        )
+    }
+}
+
+impl fmt::Display for CrcParams {
+    /// Formats the parameters in the CRC catalogue's notation, followed by the key source, such as
+    /// `width=32 poly=0x04c11db7 init=0xffffffff refin=true refout=true xorout=0xffffffff
+    /// check=0xcbf43926 name="CRC-32/ISO-HDLC" keys=const`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "width={} poly={} init={} refin={} refout={} xorout={} check={} name=\"{}\" keys={}",
+            self.width,
+            self.hex(self.poly),
+            self.hex(self.init),
+            self.refin,
+            self.refout,
+            self.hex(self.xorout),
+            self.hex(self.check),
+            self.name,
+            self.key_source()
+        )
+    }
+}
+
+impl fmt::Debug for CrcParams {
+    /// Shows the parameters in hex, with the key format and source rather than every key, see
+    /// `get_key()` for those
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CrcParams")
+            .field("algorithm", &self.algorithm)
+            .field("name", &self.name)
+            .field("width", &self.width)
+            .field("poly", &format_args!("{}", self.hex(self.poly)))
+            .field("init", &format_args!("{}", self.hex(self.init)))
+            .field("refin", &self.refin)
+            .field("refout", &self.refout)
+            .field("xorout", &format_args!("{}", self.hex(self.xorout)))
+            .field("check", &format_args!("{}", self.hex(self.check)))
+            .field("key_count", &self.key_count())
+            .field("key_source", &self.key_source())
+            .finish()
+    }
 }
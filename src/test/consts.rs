@@ -174,23 +174,42 @@ pub(crate) const TEST_CRC32_XFER: Crc32TestConfig = Crc32TestConfig {
 };
 
 pub(crate) const TEST_ALL_CONFIGS: &[AnyCrcTestConfig] = &[
+    #[cfg(not(feature = "crc64-only"))]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_AIXM),
+    #[cfg(not(feature = "crc64-only"))]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_AUTOSAR),
+    #[cfg(not(feature = "crc64-only"))]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_BASE91_D),
+    #[cfg(not(feature = "crc64-only"))]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_BZIP2),
+    #[cfg(not(feature = "crc64-only"))]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_CD_ROM_EDC),
+    #[cfg(not(feature = "crc64-only"))]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_CKSUM),
+    #[cfg(not(feature = "crc64-only"))]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_ISCSI),
+    #[cfg(not(feature = "crc64-only"))]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_ISO_HDLC),
+    #[cfg(not(feature = "crc64-only"))]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_JAMCRC),
+    #[cfg(not(feature = "crc64-only"))]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_MEF),
+    #[cfg(not(feature = "crc64-only"))]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_MPEG_2),
+    #[cfg(not(feature = "crc64-only"))]
     AnyCrcTestConfig::CRC32(&TEST_CRC32_XFER),
+    #[cfg(not(feature = "crc32-only"))]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_ECMA_182),
+    #[cfg(not(feature = "crc32-only"))]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_GO_ISO),
+    #[cfg(not(feature = "crc32-only"))]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_MS),
+    #[cfg(not(feature = "crc32-only"))]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_NVME),
+    #[cfg(not(feature = "crc32-only"))]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_REDIS),
+    #[cfg(not(feature = "crc32-only"))]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_WE),
+    #[cfg(not(feature = "crc32-only"))]
     AnyCrcTestConfig::CRC64(&TEST_CRC64_XZ),
 ];
@@ -53,8 +53,10 @@ impl AnyCrcTestConfig {
         self.get_params().name
     }
 
-    pub fn get_keys(&self) -> [u64; 23] {
-        self.get_params().keys.to_keys_array_23()
+    pub fn get_keys(&self) -> [u64; 25] {
+        let keys = self.get_params().keys;
+
+        std::array::from_fn(|i| keys.get_key(i))
     }
 
     pub fn checksum_with_reference(&self, data: &[u8]) -> u64 {
@@ -0,0 +1,139 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Golden test vectors for the supported CRC algorithms.
+//!
+//! These are the same vectors this crate is tested against, published so downstream bindings and
+//! reimplementations can validate against the same data. Each vector is an `(input, checksum)`
+//! pair.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use crc_fast::{checksum, test_vectors, CrcAlgorithm::Crc64Nvme};
+//!
+//! for (input, expected) in test_vectors::CRC64_NVME_VECTORS {
+//!     assert_eq!(checksum(Crc64Nvme, input), *expected);
+//! }
+//! ```
+
+use crate::crc32::consts::{
+    CRC32_AIXM, CRC32_AUTOSAR, CRC32_BASE91_D, CRC32_BZIP2, CRC32_CD_ROM_EDC, CRC32_CKSUM,
+    CRC32_ISCSI, CRC32_ISO_HDLC, CRC32_JAMCRC, CRC32_MEF, CRC32_MPEG_2, CRC32_XFER,
+};
+use crate::crc64::consts::{
+    CRC64_ECMA_182, CRC64_GO_ISO, CRC64_MS, CRC64_NVME, CRC64_REDIS, CRC64_WE, CRC64_XZ,
+};
+use crate::CrcAlgorithm;
+
+/// The input whose checksum is each algorithm's "check" value in the
+/// [CRC catalogue](https://reveng.sourceforge.io/crc-catalogue/all.htm)
+pub const CHECK_STRING: &[u8] = b"123456789";
+
+/// The checksum of [`CHECK_STRING`] for every predefined algorithm
+pub const CHECK_VALUES: [(CrcAlgorithm, u64); 19] = [
+    (CrcAlgorithm::Crc32Aixm, CRC32_AIXM.check),
+    (CrcAlgorithm::Crc32Autosar, CRC32_AUTOSAR.check),
+    (CrcAlgorithm::Crc32Base91D, CRC32_BASE91_D.check),
+    (CrcAlgorithm::Crc32Bzip2, CRC32_BZIP2.check),
+    (CrcAlgorithm::Crc32CdRomEdc, CRC32_CD_ROM_EDC.check),
+    (CrcAlgorithm::Crc32Cksum, CRC32_CKSUM.check),
+    (CrcAlgorithm::Crc32Iscsi, CRC32_ISCSI.check),
+    (CrcAlgorithm::Crc32IsoHdlc, CRC32_ISO_HDLC.check),
+    (CrcAlgorithm::Crc32Jamcrc, CRC32_JAMCRC.check),
+    (CrcAlgorithm::Crc32Mef, CRC32_MEF.check),
+    (CrcAlgorithm::Crc32Mpeg2, CRC32_MPEG_2.check),
+    (CrcAlgorithm::Crc32Xfer, CRC32_XFER.check),
+    (CrcAlgorithm::Crc64Ecma182, CRC64_ECMA_182.check),
+    (CrcAlgorithm::Crc64GoIso, CRC64_GO_ISO.check),
+    (CrcAlgorithm::Crc64Ms, CRC64_MS.check),
+    (CrcAlgorithm::Crc64Nvme, CRC64_NVME.check),
+    (CrcAlgorithm::Crc64Redis, CRC64_REDIS.check),
+    (CrcAlgorithm::Crc64We, CRC64_WE.check),
+    (CrcAlgorithm::Crc64Xz, CRC64_XZ.check),
+];
+
+/// CRC-64/NVME vectors, from the NVM Express® NVM Command Set Specification, the Linux kernel, and
+/// this crate's own tests.
+///
+/// The Check value in the NVM Express® NVM Command Set Specification (Revision 1.0d, December
+/// 2023) is incorrect (Section 5.2.1.3.4, Figure 120, page 83), so the first vector comes from
+/// this crate's own tests. The 4KiB vectors are from Section 5.2.1.3.5, Figure 122, page 84, and
+/// the [Linux kernel](https://github.com/torvalds/linux/blob/f3813f4b287e480b1fcd62ca798d8556644b8278/crypto/testmgr.h#L3685-L3695).
+pub const CRC64_NVME_VECTORS: &[(&[u8], u64)] = &[
+    (b"123456789", 0xae8b14860a799888),
+    (&[0; 4096], 0x6482d367eb22b64e),
+    (&[255; 4096], 0xc0ddba7302eca3ac),
+    (b"1234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456789012345678901234567890123456", 0xabdb9e6c30937916),
+    (b"", 0),
+    (b"@", 0x2808afa9582aa47),
+    (b"1\x97", 0xb4af0ae0feb08e0f),
+    (b"M\"\xdf", 0x85d7cd041a2a8a5d),
+    (b"l\xcd\x13\xd7", 0x1860820ea79b0fa3),
+    (&[0; 32], 0xcf3473434d4ecf3b),
+    (&[255; 32], 0xa0a06974c34d63c4),
+    (b"\x00\x01\x02\x03\x04\x05\x06\x07\x08\x09\x0A\x0B\x0C\x0D\x0E\x0F\x10\x11\x12\x13\x14\x15\x16\x17\x18\x19\x1A\x1B\x1C\x1D\x1E\x1F", 0xb9d9d4a8492cbd7f),
+    (&[0; 1024], 0x691bb2b09be5498a),
+    (b"hello, world!", 0xf8046e40c403f1d0),
+];
+
+/// Vectors for the "crc32" algorithm of PHP's `hash()` function, which is CRC-32/BZIP2 with the
+/// checksum's bytes reversed (so compare against `php_crc32(input)`).
+///
+/// <https://www.php.net/manual/en/function.hash-file.php#104836>
+pub const PHP_CRC32_VECTORS: &[(&[u8], u32)] = &[
+    (b"123456789", 0x181989fc),
+    (&[0; 4096], 0xe3380088),
+    (&[255; 4096], 0x8f2ae650),
+    (b"hello, world!", 0x5eacce7),
+];
+
+/// Vectors for the "crc32b" algorithm of PHP's `hash()` function (and its `crc32()` function),
+/// which is CRC-32/ISO-HDLC.
+pub const PHP_CRC32B_VECTORS: &[(&[u8], u32)] = &[
+    (b"123456789", 0xcbf43926),
+    (&[0; 4096], 0xc71c0011),
+    (&[255; 4096], 0xf154670a),
+    (b"hello, world!", 0x58988d13),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum;
+    use crate::enums::PREDEFINED_ALGORITHMS;
+    use crate::test::consts::TEST_ALL_CONFIGS;
+
+    #[test]
+    fn test_check_values() {
+        assert_eq!(CHECK_VALUES.len(), PREDEFINED_ALGORITHMS.len());
+
+        for (algorithm, check) in CHECK_VALUES {
+            if !algorithm.is_compiled_in() {
+                continue;
+            }
+
+            assert_eq!(checksum(algorithm, CHECK_STRING), check);
+
+            let config = TEST_ALL_CONFIGS
+                .iter()
+                .find(|config| config.get_algorithm() == algorithm)
+                .unwrap();
+            assert_eq!(config.checksum_with_reference(CHECK_STRING), check);
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_php_vectors() {
+        for (input, expected) in PHP_CRC32_VECTORS {
+            assert_eq!(crate::php_crc32(input), *expected);
+        }
+
+        for (input, expected) in PHP_CRC32B_VECTORS {
+            assert_eq!(
+                checksum(CrcAlgorithm::Crc32IsoHdlc, input) as u32,
+                *expected
+            );
+        }
+    }
+}
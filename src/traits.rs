@@ -42,13 +42,16 @@ pub trait ArchOps: Sized + Copy + Clone {
     ///
     /// Returns true if the operation was handled by the accelerated path (for example,
     /// using VPCLMULQDQ)
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn process_enhanced_simd_blocks<W: EnhancedCrcWidth>(
         &self,
         _state: &mut CrcState<Self::Vector>,
         _first: &[Self::Vector; 8],
         _rest: &[[Self::Vector; 8]],
         _reflector: &Reflector<Self::Vector>,
-        _keys: [u64; 23],
+        _keys: [u64; 25],
     ) -> bool
     where
         Self::Vector: Copy,
@@ -196,34 +199,64 @@ pub trait ArchOps: Sized + Copy + Clone {
     unsafe fn shift_right_8(&self, vector: Self::Vector) -> Self::Vector;
 
     /// Shift a vector right by 5 bytes
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn shift_right_5(&self, vector: Self::Vector) -> Self::Vector;
 
     /// Shift a vector right by 6 bytes
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn shift_right_6(&self, vector: Self::Vector) -> Self::Vector;
 
     /// Shift a vector right by 7 bytes
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn shift_right_7(&self, vector: Self::Vector) -> Self::Vector;
 
     /// Shift a vector right by 12 bytes
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn shift_right_12(&self, vector: Self::Vector) -> Self::Vector;
 
     /// Shift a vector left by 12 bytes
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn shift_left_12(&self, vector: Self::Vector) -> Self::Vector;
 
     /// Perform carryless multiplication with immediate value 0x00 (low 64 bits of both vectors)
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn carryless_mul_00(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector;
 
     /// Perform carryless multiplication with immediate value 0x01 (low 64 bits of a, high 64 bits of b)
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn carryless_mul_01(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector;
 
     /// Perform carryless multiplication with immediate value 0x10 (high 64 bits of a, low 64 bits of b)
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn carryless_mul_10(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector;
 
     /// Perform carryless multiplication with immediate value 0x11 (high 64 bits of both vectors)
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn carryless_mul_11(&self, a: Self::Vector, b: Self::Vector) -> Self::Vector;
 
     /// XOR three vectors together: a XOR b XOR c
     /// Uses native XOR3 instructions when available, falls back to two XOR operations otherwise
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn xor3_vectors(
         &self,
         a: Self::Vector,
@@ -239,6 +272,9 @@ pub trait EnhancedCrcWidth: CrcWidth {
     fn load_constants(reflected: bool) -> [[u64; 2]; 4];
 
     /// Create a CRC state with the initial value positioned correctly for the width
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn create_state<T: ArchOps>(
         value: Self::Value,
         reflected: bool,
@@ -248,6 +284,9 @@ pub trait EnhancedCrcWidth: CrcWidth {
         T::Vector: Copy;
 
     /// Extract the final CRC result from a SIMD vector
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn extract_result<T: ArchOps>(
         vector: T::Vector,
         reflected: bool,
@@ -257,6 +296,9 @@ pub trait EnhancedCrcWidth: CrcWidth {
         T::Vector: Copy;
 
     /// Perform width-specific folding operations using CLMUL and two XOR operations (or one XOR3)
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn fold_16<T: ArchOps>(
         state: &mut CrcState<T::Vector>,
         coefficient: T::Vector,
@@ -266,11 +308,17 @@ pub trait EnhancedCrcWidth: CrcWidth {
         T::Vector: Copy;
 
     /// Fold width-specific number of bytes
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn fold_width<T: ArchOps>(state: &mut CrcState<T::Vector>, high: u64, low: u64, ops: &T)
     where
         T::Vector: Copy;
 
     /// Width-specific Barrett reduction
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn barrett_reduction<T: ArchOps>(
         state: &CrcState<T::Vector>,
         poly: u64,
@@ -281,6 +329,9 @@ pub trait EnhancedCrcWidth: CrcWidth {
         T::Vector: Copy;
 
     /// Create a coefficient vector for folding operations
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn create_coefficient<T: ArchOps>(
         high: u64,
         low: u64,
@@ -291,10 +342,13 @@ pub trait EnhancedCrcWidth: CrcWidth {
         T::Vector: Copy;
 
     /// Perform final reduction for the specific width
+    ///
+    /// # Safety
+    /// May use native CPU features
     unsafe fn perform_final_reduction<T: ArchOps>(
         state: T::Vector,
         reflected: bool,
-        keys: [u64; 23],
+        keys: [u64; 25],
         ops: &T,
     ) -> Self::Value
     where
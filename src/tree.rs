@@ -0,0 +1,330 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! Hierarchical (tree) checksums of large objects.
+//!
+//! A tree has two levels: a leaf checksum for every fixed-size chunk of the object, and an interior
+//! node for every `fanout` leaves, whose checksum is the combination of its children's. The root
+//! is the combination of the interior nodes, and equals the checksum of the whole object.
+//!
+//! Chunks can be verified individually, and rewriting a chunk only rehashes that chunk, then
+//! recombines its node and the root, rather than rehashing the whole object.
+
+use crate::{combine, CrcAlgorithm, CrcParams, Digest};
+use std::ops::Range;
+
+/// A two-level checksum tree over an object split into fixed-size chunks
+#[derive(Clone, Debug)]
+pub struct ChecksumTree {
+    /// A new Digest for the algorithm, copied to checksum each chunk
+    digest: Digest,
+
+    /// The size of every chunk but the last, which may be shorter
+    chunk_size: usize,
+
+    /// The number of leaves per interior node
+    fanout: usize,
+
+    /// The length of the object, in bytes
+    len: u64,
+
+    /// The checksum of each chunk
+    leaves: Vec<u64>,
+
+    /// The combined checksum of each run of `fanout` leaves
+    nodes: Vec<u64>,
+
+    /// The combined checksum of all the nodes, which is the checksum of the object
+    root: u64,
+}
+
+impl ChecksumTree {
+    /// Builds a checksum tree over the data using the specified algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` or `fanout` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{checksum, ChecksumTree, CrcAlgorithm::Crc32IsoHdlc};
+    ///
+    /// let mut tree = ChecksumTree::new(Crc32IsoHdlc, b"123456789", 2, 2);
+    ///
+    /// assert_eq!(tree.root(), 0xcbf43926);
+    /// assert!(tree.verify_chunk(1, b"34"));
+    ///
+    /// tree.update_chunk(1, b"43");
+    ///
+    /// assert_eq!(tree.root(), checksum(Crc32IsoHdlc, b"124356789"));
+    /// ```
+    pub fn new(algorithm: CrcAlgorithm, data: &[u8], chunk_size: usize, fanout: usize) -> Self {
+        Self::build(Digest::new(algorithm), data, chunk_size, fanout)
+    }
+
+    /// Builds a checksum tree over the data using custom CRC parameters.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` or `fanout` is 0.
+    pub fn new_with_params(
+        params: CrcParams,
+        data: &[u8],
+        chunk_size: usize,
+        fanout: usize,
+    ) -> Self {
+        Self::build(Digest::new_with_params(params), data, chunk_size, fanout)
+    }
+
+    fn build(digest: Digest, data: &[u8], chunk_size: usize, fanout: usize) -> Self {
+        assert!(chunk_size > 0, "Chunk size must be non-zero");
+        assert!(fanout > 0, "Fanout must be non-zero");
+
+        let mut tree = Self {
+            digest,
+            chunk_size,
+            fanout,
+            len: data.len() as u64,
+            leaves: Vec::new(),
+            nodes: Vec::new(),
+            root: 0,
+        };
+
+        tree.leaves = data
+            .chunks(chunk_size)
+            .map(|chunk| tree.checksum(chunk))
+            .collect();
+
+        tree.nodes = (0..tree.leaves.len().div_ceil(fanout))
+            .map(|index| tree.combine_node(index))
+            .collect();
+
+        tree.root = tree.combine_root();
+
+        tree
+    }
+
+    /// Returns the checksum of the whole object
+    pub fn root(&self) -> u64 {
+        self.root
+    }
+
+    /// Returns the length of the object, in bytes
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns true if the object is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the size of every chunk but the last, which may be shorter
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Returns the number of leaves per interior node
+    pub fn fanout(&self) -> usize {
+        self.fanout
+    }
+
+    /// Returns the checksum of each chunk, in order
+    pub fn leaves(&self) -> &[u64] {
+        &self.leaves
+    }
+
+    /// Returns the checksum of each interior node, in order
+    pub fn nodes(&self) -> &[u64] {
+        &self.nodes
+    }
+
+    /// Returns the byte range of the chunk at index
+    pub fn chunk_range(&self, index: usize) -> Range<u64> {
+        let start = index as u64 * self.chunk_size as u64;
+
+        start.min(self.len)..(start + self.chunk_size as u64).min(self.len)
+    }
+
+    /// Returns the byte range covered by the interior node at index
+    pub fn node_range(&self, index: usize) -> Range<u64> {
+        let span = self.chunk_size as u64 * self.fanout as u64;
+        let start = index as u64 * span;
+
+        start.min(self.len)..(start + span).min(self.len)
+    }
+
+    /// Returns true if the chunk at index matches the tree, without needing the rest of the object
+    pub fn verify_chunk(&self, index: usize, chunk: &[u8]) -> bool {
+        index < self.leaves.len()
+            && self.range_len(self.chunk_range(index)) == chunk.len() as u64
+            && self.checksum(chunk) == self.leaves[index]
+    }
+
+    /// Returns true if the data covered by the interior node at index matches the tree
+    pub fn verify_node(&self, index: usize, data: &[u8]) -> bool {
+        index < self.nodes.len()
+            && self.range_len(self.node_range(index)) == data.len() as u64
+            && self.checksum(data) == self.nodes[index]
+    }
+
+    /// Replaces the chunk at index, rehashing only that chunk, then recombining its interior node
+    /// and the root.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range, or the chunk's length differs from the one it replaces.
+    pub fn update_chunk(&mut self, index: usize, chunk: &[u8]) {
+        assert!(
+            index < self.leaves.len(),
+            "Chunk index {index} out of range"
+        );
+        assert_eq!(
+            self.range_len(self.chunk_range(index)),
+            chunk.len() as u64,
+            "Chunk length must match the chunk it replaces"
+        );
+
+        self.leaves[index] = self.checksum(chunk);
+
+        let node = index / self.fanout;
+        self.nodes[node] = self.combine_node(node);
+        self.root = self.combine_root();
+    }
+
+    fn checksum(&self, data: &[u8]) -> u64 {
+        let mut digest = self.digest;
+        digest.update(data);
+
+        digest.finalize()
+    }
+
+    /// Combines the leaves of the interior node at index
+    fn combine_node(&self, index: usize) -> u64 {
+        let first = index * self.fanout;
+        let last = (first + self.fanout).min(self.leaves.len());
+
+        self.fold(
+            (first..last).map(|leaf| (self.leaves[leaf], self.range_len(self.chunk_range(leaf)))),
+        )
+    }
+
+    /// Combines the interior nodes
+    fn combine_root(&self) -> u64 {
+        self.fold(
+            (0..self.nodes.len())
+                .map(|node| (self.nodes[node], self.range_len(self.node_range(node)))),
+        )
+    }
+
+    /// Combines checksums of consecutive (checksum, length) runs, starting from the checksum of
+    /// no data so an empty run yields it
+    fn fold(&self, checksums: impl Iterator<Item = (u64, u64)>) -> u64 {
+        checksums.fold(self.digest.finalize(), |combined, (checksum, len)| {
+            combine::checksums(combined, checksum, len, self.digest.params)
+        })
+    }
+
+    fn range_len(&self, range: Range<u64>) -> u64 {
+        range.end - range.start
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum;
+    #[cfg(not(feature = "crc64-only"))]
+    use crate::test::consts::TEST_CHECK_STRING;
+
+    #[test]
+    #[cfg(not(feature = "crc32-only"))]
+    fn test_tree_matches_checksums() {
+        let data: Vec<u8> = (0..5000u32).map(|i| (i * 13 % 241) as u8).collect();
+
+        for (chunk_size, fanout) in [(1, 1), (7, 3), (64, 4), (1024, 16), (8192, 2)] {
+            let tree = ChecksumTree::new(CrcAlgorithm::Crc64Nvme, &data, chunk_size, fanout);
+
+            assert_eq!(tree.root(), checksum(CrcAlgorithm::Crc64Nvme, &data));
+            assert_eq!(tree.len(), data.len() as u64);
+            assert_eq!(tree.leaves().len(), data.len().div_ceil(chunk_size));
+            assert_eq!(tree.nodes().len(), tree.leaves().len().div_ceil(fanout));
+
+            for (i, chunk) in data.chunks(chunk_size).enumerate() {
+                assert_eq!(tree.leaves()[i], checksum(CrcAlgorithm::Crc64Nvme, chunk));
+                assert!(tree.verify_chunk(i, chunk));
+            }
+
+            for (i, span) in data.chunks(chunk_size * fanout).enumerate() {
+                assert_eq!(tree.nodes()[i], checksum(CrcAlgorithm::Crc64Nvme, span));
+                assert!(tree.verify_node(i, span));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_tree_update_chunk() {
+        let mut data: Vec<u8> = (0..1000u32).map(|i| (i % 256) as u8).collect();
+        let mut tree = ChecksumTree::new(CrcAlgorithm::Crc32Iscsi, &data, 64, 4);
+
+        data[700..764].fill(0xaa);
+        tree.update_chunk(10, &data[640..704]);
+        tree.update_chunk(11, &data[704..768]);
+
+        assert_eq!(
+            tree.root(),
+            ChecksumTree::new(CrcAlgorithm::Crc32Iscsi, &data, 64, 4).root()
+        );
+        assert_eq!(tree.root(), checksum(CrcAlgorithm::Crc32Iscsi, &data));
+
+        // the short last chunk
+        data[990] = 0;
+        tree.update_chunk(15, &data[960..]);
+
+        assert_eq!(tree.root(), checksum(CrcAlgorithm::Crc32Iscsi, &data));
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_tree_verify_mismatches() {
+        let tree = ChecksumTree::new(CrcAlgorithm::Crc32Iscsi, TEST_CHECK_STRING, 4, 2);
+
+        assert!(tree.verify_chunk(2, b"9"));
+        assert!(!tree.verify_chunk(0, b"1235"));
+        assert!(!tree.verify_chunk(0, b"123"));
+        assert!(!tree.verify_chunk(3, b""));
+        assert!(tree.verify_node(0, b"12345678"));
+        assert!(!tree.verify_node(0, b"1234"));
+        assert!(!tree.verify_node(2, b""));
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc32-only"))]
+    fn test_tree_empty() {
+        let tree = ChecksumTree::new(CrcAlgorithm::Crc64Nvme, &[], 16, 4);
+
+        assert!(tree.is_empty());
+        assert!(tree.leaves().is_empty());
+        assert!(tree.nodes().is_empty());
+        assert_eq!(tree.root(), checksum(CrcAlgorithm::Crc64Nvme, &[]));
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_tree_with_params() {
+        let params = crate::get_calculator_params(CrcAlgorithm::Crc32Bzip2).1;
+        let tree = ChecksumTree::new_with_params(params, TEST_CHECK_STRING, 2, 3);
+
+        assert_eq!(tree.root(), params.check);
+    }
+
+    #[test]
+    #[should_panic(expected = "Chunk length must match the chunk it replaces")]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_tree_update_chunk_length_mismatch() {
+        let mut tree = ChecksumTree::new(CrcAlgorithm::Crc32Iscsi, TEST_CHECK_STRING, 4, 2);
+
+        tree.update_chunk(2, b"98");
+    }
+}
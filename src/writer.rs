@@ -0,0 +1,159 @@
+// Copyright 2025 Don MacAskill. Licensed under MIT or Apache-2.0.
+
+//! A cheap, cloneable `Write` handle to a shared `Digest`.
+//!
+//! `&mut Digest` is already `Write`, but APIs which take ownership of an `impl Write` (often with a
+//! `'static` or `Send` bound, such as a writer handed to another thread or a logging sink) can't
+//! borrow a Digest. Every clone of a `DigestWriter` updates the same Digest, so one clone can be
+//! given away while another is kept to read the checksum.
+
+use crate::{CrcAlgorithm, CrcParams, Digest};
+use std::io::{IoSlice, Write};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// A cloneable `Write` handle, whose clones all update the same `Digest`
+#[derive(Debug, Clone)]
+pub struct DigestWriter {
+    /// The Digest shared by all the clones
+    digest: Arc<Mutex<Digest>>,
+}
+
+impl DigestWriter {
+    /// Creates a new writer for the specified algorithm.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use crc_fast::{DigestWriter, CrcAlgorithm::Crc32IsoHdlc};
+    /// use std::io::Write;
+    /// use std::thread;
+    ///
+    /// let writer = DigestWriter::new(Crc32IsoHdlc);
+    ///
+    /// let mut sink = writer.clone();
+    /// thread::spawn(move || sink.write_all(b"123456789"))
+    ///     .join()
+    ///     .unwrap()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(writer.finalize(), 0xcbf43926);
+    /// ```
+    pub fn new(algorithm: CrcAlgorithm) -> Self {
+        Self::from_digest(Digest::new(algorithm))
+    }
+
+    /// Creates a new writer using custom CRC parameters.
+    pub fn new_with_params(params: CrcParams) -> Self {
+        Self::from_digest(Digest::new_with_params(params))
+    }
+
+    /// Creates a writer continuing from an existing Digest, such as one which has already been
+    /// updated with a prefix of the data.
+    pub fn from_digest(digest: Digest) -> Self {
+        Self {
+            digest: Arc::new(Mutex::new(digest)),
+        }
+    }
+
+    /// Returns a copy of the shared Digest as it is now.
+    pub fn digest(&self) -> Digest {
+        *self.lock()
+    }
+
+    /// Returns the checksum of everything written through any of the clones so far.
+    pub fn finalize(&self) -> u64 {
+        self.lock().finalize()
+    }
+
+    /// Returns the amount of data written through any of the clones so far.
+    pub fn get_amount(&self) -> u64 {
+        self.lock().get_amount()
+    }
+
+    /// Resets the shared Digest to its initial state, for all the clones.
+    pub fn reset(&self) {
+        self.lock().reset();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Digest> {
+        self.digest.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl From<Digest> for DigestWriter {
+    fn from(digest: Digest) -> Self {
+        Self::from_digest(digest)
+    }
+}
+
+impl Write for DigestWriter {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.lock().update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> std::io::Result<usize> {
+        // one lock for all the slices, so they stay contiguous
+        self.lock().write_vectored(bufs)
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()> {
+        self.lock().update(buf);
+        Ok(())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "crc64-only"))]
+    use super::*;
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    use crate::checksum;
+
+    /// Takes ownership of its writer, like many I/O APIs
+    #[cfg(not(feature = "crc64-only"))]
+    fn write_owned(mut writer: impl Write, data: &[u8]) {
+        writer.write_all(data).unwrap();
+    }
+
+    #[test]
+    #[cfg(not(feature = "crc64-only"))]
+    fn test_borrowed_digest_by_value() {
+        let mut digest = Digest::new(CrcAlgorithm::Crc32IsoHdlc);
+
+        write_owned(&mut digest, b"1234");
+        write_owned(std::io::BufWriter::new(&mut digest), b"56789");
+
+        assert_eq!(digest.finalize(), 0xcbf43926);
+    }
+
+    #[test]
+    #[cfg(not(any(feature = "crc32-only", feature = "crc64-only")))]
+    fn test_clones_share_digest() {
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+        for algorithm in [CrcAlgorithm::Crc32Iscsi, CrcAlgorithm::Crc64Nvme] {
+            let writer = DigestWriter::new(algorithm);
+
+            for chunk in data.chunks(7_919) {
+                write_owned(writer.clone(), chunk);
+            }
+
+            assert_eq!(writer.finalize(), checksum(algorithm, &data));
+            assert_eq!(writer.get_amount(), data.len() as u64);
+            assert_eq!(writer.digest().finalize(), writer.finalize());
+
+            writer.reset();
+
+            assert_eq!(writer.finalize(), checksum(algorithm, b""));
+        }
+    }
+}
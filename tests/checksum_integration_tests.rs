@@ -8,7 +8,7 @@ use std::process::Command;
 #[test]
 fn test_benchmark_flag_parsing() {
     let output = Command::new("cargo")
-        .args(&[
+        .args([
             "run",
             "--features",
             "cli",
@@ -35,7 +35,7 @@ fn test_benchmark_flag_parsing() {
 #[test]
 fn test_benchmark_with_size_parameter() {
     let output = Command::new("cargo")
-        .args(&[
+        .args([
             "run",
             "--features",
             "cli",
@@ -59,7 +59,7 @@ fn test_benchmark_with_size_parameter() {
 #[test]
 fn test_benchmark_with_duration_parameter() {
     let output = Command::new("cargo")
-        .args(&[
+        .args([
             "run",
             "--features",
             "cli",
@@ -83,7 +83,7 @@ fn test_benchmark_with_duration_parameter() {
 #[test]
 fn test_benchmark_invalid_size() {
     let output = Command::new("cargo")
-        .args(&[
+        .args([
             "run",
             "--features",
             "cli",
@@ -107,7 +107,7 @@ fn test_benchmark_invalid_size() {
 #[test]
 fn test_benchmark_invalid_duration() {
     let output = Command::new("cargo")
-        .args(&[
+        .args([
             "run",
             "--features",
             "cli",
@@ -135,7 +135,7 @@ fn test_benchmark_with_file_input() {
     fs::write(test_file, "Hello, benchmark world!").expect("Failed to create test file");
 
     let output = Command::new("cargo")
-        .args(&[
+        .args([
             "run",
             "--features",
             "cli",
@@ -164,7 +164,7 @@ fn test_benchmark_with_file_input() {
 #[test]
 fn test_benchmark_with_string_input() {
     let output = Command::new("cargo")
-        .args(&[
+        .args([
             "run",
             "--features",
             "cli",
@@ -193,7 +193,7 @@ fn test_benchmark_different_algorithms() {
 
     for algorithm in &algorithms {
         let output = Command::new("cargo")
-            .args(&[
+            .args([
                 "run",
                 "--features",
                 "cli",
@@ -222,7 +222,7 @@ fn test_benchmark_different_algorithms() {
 #[test]
 fn test_benchmark_size_without_benchmark_flag() {
     let output = Command::new("cargo")
-        .args(&[
+        .args([
             "run",
             "--features",
             "cli",
@@ -245,7 +245,7 @@ fn test_benchmark_size_without_benchmark_flag() {
 #[test]
 fn test_benchmark_nonexistent_file() {
     let output = Command::new("cargo")
-        .args(&[
+        .args([
             "run",
             "--features",
             "cli",